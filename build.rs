@@ -4,6 +4,13 @@ fn main() {
         dim2: { all(feature = "2d", not(feature = "3d")) },
         // 3D feature takes precedence over 2D feature
         dim3: { all(feature = "3d") },
-        debug: { any(feature = "debug-2d", feature = "debug-3d") }
+        debug: { any(feature = "debug-2d", feature = "debug-3d") },
+        playground: { any(feature = "playground-2d", feature = "playground-3d") }
     }
+
+    // Declare the aliases, so that rustc doesn't report them as unexpected cfg names
+    println!("cargo:rustc-check-cfg=cfg(dim2)");
+    println!("cargo:rustc-check-cfg=cfg(dim3)");
+    println!("cargo:rustc-check-cfg=cfg(debug)");
+    println!("cargo:rustc-check-cfg=cfg(playground)");
 }
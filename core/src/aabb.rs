@@ -0,0 +1,103 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::Vec3;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component which will be filled (if present) with the axis-aligned bounding box of the collision
+/// shape of the entity, as computed by the physics engine
+///
+/// It is updated after each physics step, and can be used for culling, camera framing or other
+/// spatial logic without recomputing the bounds.
+///
+/// It must be added to the entity containing the [`CollisionShape`](crate::CollisionShape).
+/// In 2d, the `z` coordinates are always zero.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ColliderAabb::default());
+/// }
+///
+/// fn log_bounds(query: Query<&ColliderAabb>) {
+///     for aabb in query.iter() {
+///         println!("The body spans from {} to {}", aabb.min(), aabb.max());
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ColliderAabb {
+    min: Vec3,
+    max: Vec3,
+}
+
+impl ColliderAabb {
+    #[must_use]
+    #[allow(missing_docs)]
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the corner with the smallest coordinates
+    #[must_use]
+    pub fn min(&self) -> Vec3 {
+        self.min
+    }
+
+    /// Returns the corner with the largest coordinates
+    #[must_use]
+    pub fn max(&self) -> Vec3 {
+        self.max
+    }
+
+    /// Returns the center of the bounding box
+    #[must_use]
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Returns the half of the size of the bounding box along each axis
+    #[must_use]
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) / 2.0
+    }
+
+    /// Returns true if the point is inside the bounding box
+    #[must_use]
+    pub fn contains(&self, point: Vec3) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// Returns true if the two bounding boxes overlap
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn center_and_extents() {
+        let aabb = ColliderAabb::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 2.0, 2.0));
+        assert_eq!(aabb.center(), Vec3::new(1.0, 1.0, 2.0));
+        assert_eq!(aabb.half_extents(), Vec3::new(2.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn contains_and_intersects() {
+        let aabb = ColliderAabb::new(Vec3::ZERO, Vec3::ONE);
+        assert!(aabb.contains(Vec3::splat(0.5)));
+        assert!(!aabb.contains(Vec3::splat(1.5)));
+        assert!(aabb.intersects(&ColliderAabb::new(Vec3::splat(0.5), Vec3::splat(2.0))));
+        assert!(!aabb.intersects(&ColliderAabb::new(Vec3::splat(1.5), Vec3::splat(2.0))));
+    }
+}
@@ -0,0 +1,85 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that defines which pairs of body types can collide with the collision shape
+///
+/// It must be inserted on the same entity as the [`CollisionShape`](crate::CollisionShape).
+///
+/// By default, all the collision types are active. Disabling the pairs that cannot matter for the
+/// game (like kinematic-static in a scene with many static walls) saves the cost of computing them.
+///
+/// Note that the collision types are only about detecting contacts. A pair that isn't active will
+/// neither generate contacts nor fire [`CollisionEvent`](crate::CollisionEvent)s.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::KinematicPositionBased)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ActiveCollisions {
+///             kinematic_static: false, // Don't compute contacts with the walls
+///             ..ActiveCollisions::all()
+///         });
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ActiveCollisions {
+    /// Set to true to detect collisions between two dynamic bodies
+    pub dynamic_dynamic: bool,
+
+    /// Set to true to detect collisions between a dynamic and a kinematic body
+    pub dynamic_kinematic: bool,
+
+    /// Set to true to detect collisions between a dynamic and a static body
+    pub dynamic_static: bool,
+
+    /// Set to true to detect collisions between two kinematic bodies
+    pub kinematic_kinematic: bool,
+
+    /// Set to true to detect collisions between a kinematic and a static body
+    pub kinematic_static: bool,
+
+    /// Set to true to detect collisions between two static bodies
+    pub static_static: bool,
+}
+
+impl Default for ActiveCollisions {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl ActiveCollisions {
+    /// Detect the collisions between all the body types
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            dynamic_dynamic: true,
+            dynamic_kinematic: true,
+            dynamic_static: true,
+            kinematic_kinematic: true,
+            kinematic_static: true,
+            static_static: true,
+        }
+    }
+
+    /// Only detect the collisions involving at least one dynamic body
+    #[must_use]
+    pub fn dynamic_only() -> Self {
+        Self {
+            dynamic_dynamic: true,
+            dynamic_kinematic: true,
+            dynamic_static: true,
+            kinematic_kinematic: false,
+            kinematic_static: false,
+            static_static: false,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::Vec3;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component which will be filled (if present) with the mass, kinetic energy and momentum of the
+/// rigid body, as computed by the physics engine
+///
+/// It is updated after each physics step, and is meant for gameplay logic like damage from
+/// impacts or physics puzzles. Changing it has no effect on the physics world.
+///
+/// It must be added to the entity containing the [`RigidBody`](crate::RigidBody). The values are
+/// expressed in the time space defined by the [`VelocityTimeSpace`](crate::VelocityTimeSpace)
+/// resource, like the [`Velocity`](crate::Velocity).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(PhysicsBodyInfo::default());
+/// }
+///
+/// fn break_fast_crates(mut commands: Commands, query: Query<(Entity, &PhysicsBodyInfo)>) {
+///     for (entity, info) in query.iter() {
+///         if info.kinetic_energy() > 1000.0 {
+///             commands.entity(entity).despawn();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct PhysicsBodyInfo {
+    mass: f32,
+    kinetic_energy: f32,
+    linear_momentum: Vec3,
+}
+
+impl PhysicsBodyInfo {
+    #[must_use]
+    #[allow(missing_docs)]
+    pub fn new(mass: f32, kinetic_energy: f32, linear_momentum: Vec3) -> Self {
+        Self {
+            mass,
+            kinetic_energy,
+            linear_momentum,
+        }
+    }
+
+    /// Returns the mass of the body, including the mass of all its collision shapes
+    #[must_use]
+    pub fn mass(&self) -> f32 {
+        self.mass
+    }
+
+    /// Returns the kinetic energy of the body, accounting for both its linear and angular velocity
+    #[must_use]
+    pub fn kinetic_energy(&self) -> f32 {
+        self.kinetic_energy
+    }
+
+    /// Returns the linear momentum of the body (its mass multiplied by its linear velocity)
+    #[must_use]
+    pub fn linear_momentum(&self) -> Vec3 {
+        self.linear_momentum
+    }
+
+    /// Returns the speed change that the given impulse would cause to the body
+    ///
+    /// It is zero if the body has no mass.
+    #[must_use]
+    pub fn speed_change(&self, impulse: f32) -> f32 {
+        if self.mass > 0.0 {
+            impulse / self.mass
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speed_change_is_zero_without_mass() {
+        assert_eq!(PhysicsBodyInfo::default().speed_change(10.0), 0.0);
+        assert_eq!(
+            PhysicsBodyInfo::new(2.0, 0.0, Vec3::ZERO).speed_change(10.0),
+            5.0
+        );
+    }
+}
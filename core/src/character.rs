@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+use crate::{
+    CollisionShape, GroundDetection, PhysicMaterial, RigidBody, RotationConstraints, Velocity,
+};
+
+/// Bundle of the components needed for a typical character
+///
+/// It contains:
+/// * a [dynamic](RigidBody::Dynamic) rigid body
+/// * a [capsule](CollisionShape::Capsule) collision shape, standing along the `Y` axis
+/// * [locked rotations](RotationConstraints::lock), so that the character doesn't tip over
+/// * a [`PhysicMaterial`] without friction nor restitution, so that the character doesn't stick to
+///   the walls nor bounce
+/// * a [`Velocity`] to move the character
+/// * a [`GroundDetection`] to know whether the character stands on the ground
+///
+/// Each of these components can be changed (or removed) later on, like any other component.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert_bundle(CharacterBundle::default().with_capsule(0.5, 0.3));
+/// }
+/// ```
+#[derive(Debug, Clone, Bundle)]
+pub struct CharacterBundle {
+    /// The rigid body, dynamic by default
+    pub rigid_body: RigidBody,
+    /// The collision shape, a capsule by default
+    pub shape: CollisionShape,
+    /// The rotation constraints, all rotations are locked by default
+    pub rotation_constraints: RotationConstraints,
+    /// The physic material, without friction nor restitution by default
+    pub material: PhysicMaterial,
+    /// The velocity of the character
+    pub velocity: Velocity,
+    /// The ground detection of the character
+    pub ground_detection: GroundDetection,
+}
+
+impl Default for CharacterBundle {
+    fn default() -> Self {
+        Self {
+            rigid_body: RigidBody::Dynamic,
+            shape: CollisionShape::Capsule {
+                half_segment: 0.5,
+                radius: 0.5,
+            },
+            rotation_constraints: RotationConstraints::lock(),
+            material: PhysicMaterial {
+                restitution: PhysicMaterial::PERFECTLY_INELASTIC_RESTITUTION,
+                density: 1.0,
+                friction: 0.0,
+            },
+            velocity: Velocity::default(),
+            ground_detection: GroundDetection::default(),
+        }
+    }
+}
+
+impl CharacterBundle {
+    /// Returns a new version of this bundle with a capsule of the given dimensions
+    ///
+    /// `half_segment` is the distance from the center of the capsule to the center of an
+    /// hemisphere.
+    #[must_use]
+    pub fn with_capsule(mut self, half_segment: f32, radius: f32) -> Self {
+        self.shape = CollisionShape::Capsule {
+            half_segment,
+            radius,
+        };
+        self
+    }
+
+    /// Returns a new version of this bundle with the given density
+    #[must_use]
+    pub fn with_density(mut self, density: f32) -> Self {
+        self.material.density = density;
+        self
+    }
+
+    /// Returns a new version of this bundle with the given initial velocity
+    #[must_use]
+    pub fn with_velocity(mut self, velocity: Velocity) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Returns a new version of this bundle with the given maximum slope (in radians) on which the
+    /// character is considered grounded
+    #[must_use]
+    pub fn with_max_slope(mut self, max_slope: f32) -> Self {
+        self.ground_detection.max_slope = max_slope;
+        self
+    }
+}
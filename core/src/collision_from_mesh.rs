@@ -1,5 +1,6 @@
 use std::collections::LinkedList;
 
+use bevy::reflect::FromReflect;
 use bevy::{prelude::*, render::mesh::VertexAttributeValues};
 
 use crate::{CollisionLayers, CollisionShape, RigidBody};
@@ -27,7 +28,8 @@ use crate::{CollisionLayers, CollisionShape, RigidBody};
 ///         });
 /// }
 /// ```
-#[derive(Component, Clone, Copy, Reflect)]
+#[derive(Component, Clone, Copy, Reflect, FromReflect)]
+#[reflect(Component)]
 pub struct PendingConvexCollision {
     /// Rigid body type which will be assigned to every scene entity.
     #[deprecated(note = "Insert body type component into the entity with this component")]
@@ -119,7 +121,7 @@ fn recursive_scene_children(
     scene_elements: &Query<'_, '_, &Children, Without<PendingConvexCollision>>,
 ) -> LinkedList<Entity> {
     let mut all_children = LinkedList::new();
-    for child in children.iter() {
+    for child in children {
         if let Ok(children) = scene_elements.get(*child) {
             let mut children = recursive_scene_children(children, scene_elements);
             all_children.append(&mut children);
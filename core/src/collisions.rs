@@ -1,9 +1,16 @@
+use bevy::ecs::entity::{EntityMap, MapEntities, MapEntitiesError};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::reflect::FromReflect;
 use bevy::{prelude::*, utils::HashMap};
 
 use crate::{CollisionData, CollisionEvent, RigidBody};
 
 /// Component which will be filled (if present) with a list of entities with which the current entity is currently in contact.
-#[derive(Component, Default, Reflect)]
+///
+/// The contact normals of the [`CollisionData`] are refreshed after each physics step, as long as
+/// the contact persists.
+#[derive(Component, Default, Reflect, FromReflect)]
+#[reflect(Component, MapEntities)]
 pub struct Collisions(HashMap<Entity, CollisionData>);
 
 impl Collisions {
@@ -41,6 +48,35 @@ impl Collisions {
     pub fn collision_data(&self) -> impl Iterator<Item = &CollisionData> + '_ {
         self.0.values()
     }
+
+    /// Returns the collision data of the given colliding entity, if it is colliding
+    #[must_use]
+    pub fn get(&self, entity: &Entity) -> Option<&CollisionData> {
+        self.0.get(entity)
+    }
+
+    /// Replaces the contact normals of the given colliding entity
+    ///
+    /// It is called by the physics backend after each step, and has no effect if the entity isn't
+    /// colliding. Returns `true` if the normals have changed.
+    pub fn update_normals(&mut self, entity: Entity, normals: &[Vec3]) -> bool {
+        match self.0.get_mut(&entity) {
+            Some(data) if data.normals() != normals => {
+                *data = data.clone().with_normals(normals.iter().copied());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl MapEntities for Collisions {
+    /// The collisions are not carried over when spawning a scene, the new bodies fire their own
+    /// [`CollisionEvent::Started`]
+    fn map_entities(&mut self, _: &EntityMap) -> Result<(), MapEntitiesError> {
+        self.0.clear();
+        Ok(())
+    }
 }
 
 /// Adds entity to [`CollidingEntities`] on starting collision and removes from it when the
@@ -78,7 +114,7 @@ pub(super) fn cleanup_collisions_system(
     mut collisions: Query<'_, '_, &mut Collisions>,
 ) {
     for rigid_body in removed_rigid_bodies.iter() {
-        for mut colliding_entities in collisions.iter_mut() {
+        for mut colliding_entities in &mut collisions {
             colliding_entities.0.remove(&rigid_body);
         }
     }
@@ -92,6 +128,25 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn update_normals_only_changes_colliding_entities() {
+        let entity = Entity::from_raw(1);
+        let mut collisions = Collisions::default();
+        collisions.0.insert(
+            entity,
+            CollisionData::new(entity, entity, CollisionLayers::default(), [Vec3::X]).with_step(3),
+        );
+
+        assert!(!collisions.update_normals(entity, &[Vec3::X]));
+        assert!(!collisions.update_normals(Entity::from_raw(2), &[Vec3::Y]));
+        assert!(collisions.update_normals(entity, &[Vec3::Y]));
+
+        let data = collisions.get(&entity).unwrap();
+        assert_eq!(data.normals(), &[Vec3::Y]);
+        assert_eq!(data.step(), 3);
+        assert!(collisions.get(&Entity::from_raw(2)).is_none());
+    }
+
     #[test]
     fn collisions_updates() {
         let mut app = App::new();
@@ -1,4 +1,8 @@
-use bevy::{ecs::component::Component, reflect::Reflect};
+use bevy::{
+    ecs::{component::Component, reflect::ReflectComponent},
+    math::{Quat, Vec3},
+    reflect::{FromReflect, Reflect},
+};
 
 /// Component that restrict what rotations can be caused by forces.
 ///
@@ -19,7 +23,8 @@ use bevy::{ecs::component::Component, reflect::Reflect};
 ///         .insert(RotationConstraints::lock()); // Prevent rotation caused by forces
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, Reflect)]
+#[derive(Debug, Component, Copy, Clone, Reflect, FromReflect)]
+#[reflect(Component)]
 pub struct RotationConstraints {
     /// Set to true to prevent rotations around the x axis
     pub allow_x: bool,
@@ -101,12 +106,125 @@ impl RotationConstraints {
     }
 }
 
+/// Component that clamps the linear velocity of a dynamic body, axis by axis
+///
+/// The velocity is clamped right after each physics step, which allows expressing stylized
+/// movement rules that would be hard to achieve with forces (like never moving backward, or never
+/// exceeding a given upward speed).
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         // Never go up faster than 5 units per second
+///         .insert(VelocityConstraint::default().with_max(Vec3::new(f32::INFINITY, 5.0, f32::INFINITY)));
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct VelocityConstraint {
+    /// Minimum linear velocity on each axis
+    ///
+    /// Use `f32::NEG_INFINITY` to leave an axis unconstrained.
+    pub min: Vec3,
+
+    /// Maximum linear velocity on each axis
+    ///
+    /// Use `f32::INFINITY` to leave an axis unconstrained.
+    pub max: Vec3,
+
+    /// Set to true to express the `min` and `max` in the local space of the body instead of the
+    /// world space
+    pub local: bool,
+}
+
+impl Default for VelocityConstraint {
+    fn default() -> Self {
+        Self {
+            min: Vec3::splat(f32::NEG_INFINITY),
+            max: Vec3::splat(f32::INFINITY),
+            local: false,
+        }
+    }
+}
+
+impl VelocityConstraint {
+    /// Returns a new version of this constraint with the given minimum velocity
+    #[must_use]
+    pub fn with_min(mut self, min: Vec3) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Returns a new version of this constraint with the given maximum velocity
+    #[must_use]
+    pub fn with_max(mut self, max: Vec3) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Returns a new version of this constraint, expressed in the local space of the body
+    #[must_use]
+    pub fn in_local_space(mut self) -> Self {
+        self.local = true;
+        self
+    }
+
+    /// Returns the `velocity` clamped by this constraint, for a body with the given `rotation`
+    #[must_use]
+    pub fn clamp(&self, velocity: Vec3, rotation: Quat) -> Vec3 {
+        if self.local {
+            rotation * (rotation.inverse() * velocity).clamp(self.min, self.max)
+        } else {
+            velocity.clamp(self.min, self.max)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     use super::*;
 
+    #[test]
+    fn unconstrained_velocity_is_unchanged() {
+        let velocity = Vec3::new(1.0, -2.0, 3.0);
+        assert_eq!(
+            VelocityConstraint::default().clamp(velocity, Quat::IDENTITY),
+            velocity
+        );
+    }
+
+    #[test]
+    fn clamps_velocity_in_world_space() {
+        let constraint = VelocityConstraint::default()
+            .with_min(Vec3::new(0.0, f32::NEG_INFINITY, f32::NEG_INFINITY))
+            .with_max(Vec3::new(f32::INFINITY, 1.0, f32::INFINITY));
+        assert_eq!(
+            constraint.clamp(Vec3::new(-1.0, 2.0, 3.0), Quat::from_rotation_y(1.0)),
+            Vec3::new(0.0, 1.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn clamps_velocity_in_local_space() {
+        let constraint = VelocityConstraint::default()
+            .with_max(Vec3::new(f32::INFINITY, 1.0, f32::INFINITY))
+            .in_local_space();
+        let rotation = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let clamped = constraint.clamp(Vec3::new(-2.0, 0.0, 0.0), rotation);
+        assert!((clamped - Vec3::new(-1.0, 0.0, 0.0)).length() < 0.0001);
+    }
+
     #[test]
     fn is_lock() {
         assert!(RotationConstraints::lock().is_lock());
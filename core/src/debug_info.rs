@@ -0,0 +1,180 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+use bevy::utils::HashMap;
+
+use crate::{ColliderAabb, CollisionLayers, CollisionShape, PhysicsLayer};
+
+/// Component automatically maintained on the collision shape entities, with a human-readable
+/// summary of their physical footprint
+///
+/// It makes the output of `dbg!` or of an inspector immediately show what the collision shape is
+/// about. It is updated after each physics step.
+///
+/// The layers are only named if they are registered in the [`LayerNames`] resource.
+///
+/// This component is only available with the `collider-debug-info` feature.
+#[derive(Debug, Component, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ColliderDebugInfo {
+    /// Kind of collision shape (e.g. `"Sphere"` or `"Cuboid"`)
+    pub shape_kind: String,
+    /// Volume (or area in 2d) of the collision shape
+    pub volume: f32,
+    /// Bounding box of the collision shape, in world space
+    pub aabb: ColliderAabb,
+    /// Names of the collision layer groups the collision shape belongs to
+    pub layer_names: Vec<String>,
+}
+
+impl fmt::Display for ColliderDebugInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (volume: {:.3}, aabb: {} to {}, layers: [{}])",
+            self.shape_kind,
+            self.volume,
+            self.aabb.min(),
+            self.aabb.max(),
+            self.layer_names.join(", ")
+        )
+    }
+}
+
+impl ColliderDebugInfo {
+    /// Returns the debug info of the given collision shape
+    #[must_use]
+    pub fn new(
+        shape: &CollisionShape,
+        volume: f32,
+        aabb: ColliderAabb,
+        layer_names: Vec<String>,
+    ) -> Self {
+        Self {
+            shape_kind: shape_kind(shape).to_owned(),
+            volume,
+            aabb,
+            layer_names,
+        }
+    }
+}
+
+fn shape_kind(shape: &CollisionShape) -> &'static str {
+    match shape {
+        CollisionShape::Sphere { .. } => "Sphere",
+        CollisionShape::Capsule { .. } => "Capsule",
+        CollisionShape::Cuboid { .. } => "Cuboid",
+        CollisionShape::ConvexHull { .. } => "ConvexHull",
+        CollisionShape::ConvexPolyhedron { .. } => "ConvexPolyhedron",
+        CollisionShape::HeightField { .. } => "HeightField",
+        #[cfg(dim3)]
+        CollisionShape::Cone { .. } => "Cone",
+        #[cfg(dim3)]
+        CollisionShape::Cylinder { .. } => "Cylinder",
+        #[cfg(dim2)]
+        CollisionShape::Triangle { .. } => "Triangle",
+        CollisionShape::Custom { shape } => shape.type_name(),
+    }
+}
+
+/// Resource that gives a name to the collision layers, used by the [`ColliderDebugInfo`]
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # enum Layer {
+/// #   World,
+/// #   Player,
+/// # }
+/// # impl PhysicsLayer for Layer {
+/// #     fn to_bits(&self) -> u32 {
+/// #         todo!()
+/// #     }
+/// #     fn all_bits() -> u32 {
+/// #         todo!()
+/// #     }
+/// # }
+/// fn name_layers(mut names: ResMut<LayerNames>) {
+///     names.insert(Layer::World, "World");
+///     names.insert(Layer::Player, "Player");
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Reflect, FromReflect)]
+pub struct LayerNames(HashMap<u32, String>);
+
+impl LayerNames {
+    /// Gives a name to the layer
+    pub fn insert(&mut self, layer: impl PhysicsLayer, name: impl Into<String>) {
+        self.0.insert(layer.to_bits(), name.into());
+    }
+
+    /// Returns the names of the groups of the layers, sorted alphabetically
+    #[must_use]
+    pub fn group_names(&self, layers: CollisionLayers) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .0
+            .iter()
+            .filter(|(bits, _)| layers.groups_bits() & **bits != 0)
+            .map(|(_, name)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum TestLayer {
+        A,
+        B,
+        C,
+    }
+
+    impl PhysicsLayer for TestLayer {
+        fn to_bits(&self) -> u32 {
+            match self {
+                TestLayer::A => 1,
+                TestLayer::B => 2,
+                TestLayer::C => 4,
+            }
+        }
+
+        fn all_bits() -> u32 {
+            7
+        }
+    }
+
+    #[test]
+    fn group_names() {
+        let mut names = LayerNames::default();
+        names.insert(TestLayer::A, "A");
+        names.insert(TestLayer::B, "B");
+
+        let layers = CollisionLayers::none()
+            .with_groups([TestLayer::B, TestLayer::A, TestLayer::C])
+            .with_mask(TestLayer::A);
+
+        assert_eq!(names.group_names(layers), vec!["A", "B"]);
+        assert!(names.group_names(CollisionLayers::none()).is_empty());
+    }
+
+    #[test]
+    fn display() {
+        let info = ColliderDebugInfo::new(
+            &CollisionShape::Sphere { radius: 1.0 },
+            1.0,
+            ColliderAabb::new(Vec3::ZERO, Vec3::ONE),
+            vec!["A".into(), "B".into()],
+        );
+
+        assert_eq!(
+            info.to_string(),
+            "Sphere (volume: 1.000, aabb: [0, 0, 0] to [1, 1, 1], layers: [A, B])"
+        );
+    }
+}
@@ -0,0 +1,724 @@
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+use bevy::render::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+use bevy::utils::HashSet;
+
+use crate::CollisionShape;
+
+/// Number of segments used to approximate the round parts of the shapes
+const SEGMENTS: u32 = 32;
+
+/// Kind of [`Mesh`] built by [`CollisionShape::to_debug_mesh`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum DebugMeshMode {
+    /// A triangle list with normals and UVs, suited to render (translucent) volumes
+    Solid,
+
+    /// A line list of the edges of the triangles
+    Wireframe,
+}
+
+impl Default for DebugMeshMode {
+    fn default() -> Self {
+        Self::Solid
+    }
+}
+
+impl CollisionShape {
+    /// Build a [`Mesh`] approximating the collision shape
+    ///
+    /// It allows rendering the collision geometry with any material or pipeline, independently of
+    /// the debug plugins. The mesh is expressed in the local space of the shape, ignoring any
+    /// [`ColliderOffset`](crate::ColliderOffset), and border radiuses are not rendered.
+    ///
+    /// In 2d the mesh lies in the `XY` plane, facing `+Z`, and a
+    /// [`HeightField`](CollisionShape::HeightField) is always a line list.
+    ///
+    /// Returns `None` for the [`Custom`](CollisionShape::Custom) shapes, and for a
+    /// [`ConvexHull`](CollisionShape::ConvexHull) of coplanar points in 3d. The
+    /// [`ConvexPolyhedron`](CollisionShape::ConvexPolyhedron) uses the given points and triangles
+    /// as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use heron_core::*;
+    /// let shape = CollisionShape::Sphere { radius: 1.0 };
+    /// let mesh: Option<Mesh> = shape.to_debug_mesh(DebugMeshMode::Wireframe);
+    /// assert!(mesh.is_some());
+    /// ```
+    #[must_use]
+    pub fn to_debug_mesh(&self, mode: DebugMeshMode) -> Option<Mesh> {
+        let mesh = self.indexed_mesh()?;
+        Some(match mode {
+            DebugMeshMode::Solid => solid(mesh),
+            DebugMeshMode::Wireframe => wireframe(&mesh),
+        })
+    }
+
+    /// Create a [`ConvexHull`](CollisionShape::ConvexHull) enclosing the vertices of the mesh
+    ///
+    /// Returns `None` if the mesh has no vertex positions.
+    #[must_use]
+    pub fn from_mesh(mesh: &Mesh) -> Option<Self> {
+        let points: Vec<Vec3> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+            VertexAttributeValues::Float32x3(vertices) => {
+                vertices.iter().copied().map(Vec3::from).collect()
+            }
+            _ => return None,
+        };
+
+        if points.is_empty() {
+            None
+        } else {
+            Some(Self::ConvexHull {
+                points,
+                border_radius: None,
+            })
+        }
+    }
+
+    #[cfg(not(dim2))]
+    fn indexed_mesh(&self) -> Option<Mesh> {
+        match self {
+            CollisionShape::Sphere { radius } => Some(
+                shape::UVSphere {
+                    radius: *radius,
+                    sectors: SEGMENTS as usize,
+                    stacks: SEGMENTS as usize / 2,
+                }
+                .into(),
+            ),
+            CollisionShape::Capsule {
+                half_segment,
+                radius,
+            } => Some(
+                shape::Capsule {
+                    radius: *radius,
+                    depth: half_segment * 2.0,
+                    ..shape::Capsule::default()
+                }
+                .into(),
+            ),
+            CollisionShape::Cuboid { half_extends, .. } => Some(
+                shape::Box::new(half_extends.x * 2.0, half_extends.y * 2.0, half_extends.z * 2.0)
+                    .into(),
+            ),
+            CollisionShape::HeightField { size, heights } => Some(heightfield(*size, heights)),
+            #[cfg(dim3)]
+            CollisionShape::Cone {
+                half_height,
+                radius,
+            } => Some(cone(*half_height, *radius)),
+            #[cfg(dim3)]
+            CollisionShape::Cylinder {
+                half_height,
+                radius,
+            } => Some(cylinder(*half_height, *radius)),
+            CollisionShape::ConvexPolyhedron {
+                points, indices, ..
+            } => {
+                let indices: Vec<u32> = indices.iter().flatten().copied().collect();
+                if indices.iter().any(|index| *index as usize >= points.len()) {
+                    None
+                } else {
+                    Some(new_mesh(
+                        PrimitiveTopology::TriangleList,
+                        points.iter().map(bevy::prelude::Vec3::to_array).collect(),
+                        indices,
+                    ))
+                }
+            }
+            CollisionShape::ConvexHull { points, .. } => convex_hull(points),
+            CollisionShape::Custom { .. } => None,
+        }
+    }
+
+    #[cfg(dim2)]
+    fn indexed_mesh(&self) -> Option<Mesh> {
+        match self {
+            CollisionShape::Sphere { radius } => Some(fan(&arc(Vec2::ZERO, *radius, 0.0, 2.0))),
+            CollisionShape::Capsule {
+                half_segment,
+                radius,
+            } => {
+                let mut outline = arc(Vec2::Y * *half_segment, *radius, 0.0, 1.0);
+                outline.extend(arc(Vec2::Y * -*half_segment, *radius, 1.0, 1.0));
+                Some(fan(&outline))
+            }
+            CollisionShape::Cuboid { half_extends, .. } => Some(fan(&[
+                Vec2::new(-half_extends.x, -half_extends.y),
+                Vec2::new(half_extends.x, -half_extends.y),
+                Vec2::new(half_extends.x, half_extends.y),
+                Vec2::new(-half_extends.x, half_extends.y),
+            ])),
+            CollisionShape::ConvexHull { points, .. } => {
+                let hull = convex_hull(points.iter().map(|point| point.truncate()).collect());
+                if hull.len() < 3 {
+                    None
+                } else {
+                    Some(fan(&hull))
+                }
+            }
+            CollisionShape::ConvexPolyhedron { points, .. } => {
+                let outline: Vec<Vec2> = points.iter().map(|point| point.truncate()).collect();
+                if outline.len() < 3 {
+                    None
+                } else {
+                    Some(fan(&outline))
+                }
+            }
+            CollisionShape::HeightField { size, heights } => Some(heightfield(*size, heights)),
+            CollisionShape::Triangle { a, b, c, .. } => {
+                if (*b - *a).perp_dot(*c - *a) < 0.0 {
+                    Some(fan(&[*a, *c, *b]))
+                } else {
+                    Some(fan(&[*a, *b, *c]))
+                }
+            }
+            CollisionShape::Custom { .. } => None,
+        }
+    }
+}
+
+/// Create an indexed mesh without normals nor UVs
+fn new_mesh(topology: PrimitiveTopology, positions: Vec<[f32; 3]>, indices: Vec<u32>) -> Mesh {
+    let mut mesh = Mesh::new(topology);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
+
+/// Make sure the mesh has the normals and UVs required by the bevy render pipelines
+fn solid(mut mesh: Mesh) -> Mesh {
+    if mesh.primitive_topology() == PrimitiveTopology::TriangleList
+        && mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_none()
+    {
+        mesh.duplicate_vertices();
+        mesh.compute_flat_normals();
+    }
+    if mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_none() {
+        let normals = vertex_normals(&mesh);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    }
+    if mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_none() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; mesh.count_vertices()]);
+    }
+    mesh
+}
+
+/// Convert a triangle list into the line list of its (unique) edges
+fn wireframe(mesh: &Mesh) -> Mesh {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return solid(mesh.clone());
+    }
+
+    let mut seen = HashSet::default();
+    let mut edges = Vec::new();
+    for triangle in triangle_indices(mesh).chunks_exact(3) {
+        for (a, b) in [
+            (triangle[0], triangle[1]),
+            (triangle[1], triangle[2]),
+            (triangle[2], triangle[0]),
+        ] {
+            if seen.insert((a.min(b), a.max(b))) {
+                edges.push(a);
+                edges.push(b);
+            }
+        }
+    }
+
+    let normals = vertex_normals(mesh);
+    let mut lines = new_mesh(PrimitiveTopology::LineList, positions(mesh), edges);
+    lines.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    solid(lines)
+}
+
+fn positions(mesh: &Mesh) -> Vec<[f32; 3]> {
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(VertexAttributeValues::as_float3)
+        .map(<[[f32; 3]]>::to_vec)
+        .unwrap_or_default()
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn triangle_indices(mesh: &Mesh) -> Vec<u32> {
+    match mesh.indices() {
+        Some(indices) => indices.iter().map(|index| index as u32).collect(),
+        None => (0..mesh.count_vertices() as u32).collect(),
+    }
+}
+
+/// Existing normals of the mesh, or the average of the normals of the adjacent triangles
+fn vertex_normals(mesh: &Mesh) -> Vec<[f32; 3]> {
+    if let Some(normals) = mesh
+        .attribute(Mesh::ATTRIBUTE_NORMAL)
+        .and_then(VertexAttributeValues::as_float3)
+    {
+        return normals.to_vec();
+    }
+
+    let positions: Vec<Vec3> = positions(mesh).into_iter().map(Vec3::from).collect();
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return vec![Vec3::Z.into(); positions.len()];
+    }
+
+    let mut normals = vec![Vec3::ZERO; positions.len()];
+    for triangle in triangle_indices(mesh).chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| triangle[i] as usize);
+        let normal = (positions[b] - positions[a]).cross(positions[c] - positions[a]);
+        for i in [a, b, c] {
+            normals[i] += normal;
+        }
+    }
+    normals
+        .into_iter()
+        .map(|normal| normal.normalize_or_zero().into())
+        .collect()
+}
+
+#[cfg(not(dim2))]
+#[allow(clippy::cast_precision_loss)]
+fn heightfield(size: Vec2, heights: &[Vec<f32>]) -> Mesh {
+    // Same layout as the column-major matrix given to rapier: rows along `z` and columns along `x`
+    let flat: Vec<f32> = heights.iter().flatten().copied().collect();
+    let nrows = heights.len();
+    let ncols = heights.first().map(Vec::len).unwrap_or_default();
+    let step = |count: usize| if count > 1 { (count - 1) as f32 } else { 1.0 };
+
+    let mut positions = Vec::with_capacity(nrows * ncols);
+    for row in 0..nrows {
+        for col in 0..ncols {
+            positions.push([
+                (col as f32 / step(ncols) - 0.5) * size.x,
+                flat.get(col * nrows + row).copied().unwrap_or_default(),
+                (row as f32 / step(nrows) - 0.5) * size.y,
+            ]);
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let index = |row: usize, col: usize| (row * ncols + col) as u32;
+    let mut indices = Vec::new();
+    for row in 1..nrows {
+        for col in 1..ncols {
+            indices.extend([
+                index(row - 1, col - 1),
+                index(row, col - 1),
+                index(row - 1, col),
+                index(row - 1, col),
+                index(row, col - 1),
+                index(row, col),
+            ]);
+        }
+    }
+
+    new_mesh(PrimitiveTopology::TriangleList, positions, indices)
+}
+
+/// Points of a circle in the `XZ` plane
+#[cfg(dim3)]
+#[allow(clippy::cast_precision_loss)]
+fn ring(y: f32, radius: f32) -> impl Iterator<Item = [f32; 3]> {
+    (0..SEGMENTS).map(move |i| {
+        let angle = i as f32 / SEGMENTS as f32 * std::f32::consts::TAU;
+        [angle.cos() * radius, y, angle.sin() * radius]
+    })
+}
+
+#[cfg(dim3)]
+fn cone(half_height: f32, radius: f32) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = ring(-half_height, radius).collect();
+    let apex = SEGMENTS;
+    let base = SEGMENTS + 1;
+    positions.push([0.0, half_height, 0.0]);
+    positions.push([0.0, -half_height, 0.0]);
+
+    let mut indices = Vec::new();
+    for i in 0..SEGMENTS {
+        let next = (i + 1) % SEGMENTS;
+        indices.extend([i, apex, next, base, i, next]);
+    }
+
+    new_mesh(PrimitiveTopology::TriangleList, positions, indices)
+}
+
+#[cfg(dim3)]
+fn cylinder(half_height: f32, radius: f32) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = ring(-half_height, radius)
+        .chain(ring(half_height, radius))
+        .collect();
+    let bottom = SEGMENTS * 2;
+    let top = bottom + 1;
+    positions.push([0.0, -half_height, 0.0]);
+    positions.push([0.0, half_height, 0.0]);
+
+    let mut indices = Vec::new();
+    for i in 0..SEGMENTS {
+        let next = (i + 1) % SEGMENTS;
+        let (i_top, next_top) = (i + SEGMENTS, next + SEGMENTS);
+        indices.extend([i, i_top, next, next, i_top, next_top]);
+        indices.extend([bottom, i, next, top, next_top, i_top]);
+    }
+
+    new_mesh(PrimitiveTopology::TriangleList, positions, indices)
+}
+
+/// Points of an arc starting at `start` and spanning `span` (both expressed in half turns)
+/// Triangles of the convex hull of the points, counter-clockwise when seen from the outside
+/// (incremental algorithm)
+///
+/// Returns `None` if the points are coplanar.
+#[cfg(not(dim2))]
+#[allow(clippy::cast_possible_truncation)]
+fn convex_hull(points: &[Vec3]) -> Option<Mesh> {
+    let epsilon = points
+        .iter()
+        .map(|point| point.abs().max_element())
+        .fold(0.0, f32::max)
+        * 1e-5;
+    let farthest = |distance: &dyn Fn(Vec3) -> f32| {
+        (0..points.len()).max_by(|a, b| {
+            distance(points[*a])
+                .partial_cmp(&distance(points[*b]))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    };
+
+    // Initial tetrahedron
+    let origin = *points.first()?;
+    let i1 = farthest(&|point| point.distance(origin))?;
+    let line = points[i1] - origin;
+    let i2 = farthest(&|point| line.cross(point - origin).length())?;
+    let normal = line.cross(points[i2] - origin).normalize_or_zero();
+    let i3 = farthest(&|point| normal.dot(point - origin).abs())?;
+    if normal.dot(points[i3] - origin).abs() <= epsilon {
+        return None;
+    }
+    let mut faces: Vec<[usize; 3]> = [
+        [0, i1, i2, i3],
+        [0, i1, i3, i2],
+        [0, i2, i3, i1],
+        [i1, i2, i3, 0],
+    ]
+    .into_iter()
+    .map(|[a, b, c, opposite]| {
+        if face_normal(points, [a, b, c]).dot(points[opposite] - points[a]) > 0.0 {
+            [a, c, b]
+        } else {
+            [a, b, c]
+        }
+    })
+    .collect();
+
+    // Replace the faces visible from each point by a cone from their horizon to the point
+    for (index, point) in points.iter().enumerate() {
+        let (visible, hidden): (Vec<[usize; 3]>, Vec<[usize; 3]>) = faces
+            .into_iter()
+            .partition(|face| face_normal(points, *face).dot(*point - points[face[0]]) > epsilon);
+        faces = hidden;
+        let edges: Vec<(usize, usize)> = visible
+            .iter()
+            .flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+            .collect();
+        faces.extend(
+            edges
+                .iter()
+                .filter(|(a, b)| !edges.contains(&(*b, *a)))
+                .map(|(a, b)| [*a, *b, index]),
+        );
+    }
+
+    // Only keep the vertices of the hull
+    let mut vertices: Vec<Option<u32>> = vec![None; points.len()];
+    let mut positions = Vec::new();
+    let indices = faces
+        .iter()
+        .flatten()
+        .map(|index| {
+            *vertices[*index].get_or_insert_with(|| {
+                positions.push(points[*index].to_array());
+                positions.len() as u32 - 1
+            })
+        })
+        .collect();
+    Some(new_mesh(
+        PrimitiveTopology::TriangleList,
+        positions,
+        indices,
+    ))
+}
+
+#[cfg(not(dim2))]
+fn face_normal(points: &[Vec3], [a, b, c]: [usize; 3]) -> Vec3 {
+    (points[b] - points[a])
+        .cross(points[c] - points[a])
+        .normalize_or_zero()
+}
+
+#[cfg(dim2)]
+#[allow(clippy::cast_precision_loss)]
+fn arc(center: Vec2, radius: f32, start: f32, span: f32) -> Vec<Vec2> {
+    let full_circle = (span - 2.0).abs() < f32::EPSILON;
+    let count = if full_circle { SEGMENTS } else { SEGMENTS / 2 + 1 };
+    let divisor = if full_circle { SEGMENTS } else { SEGMENTS / 2 };
+    (0..count)
+        .map(|i| {
+            let angle = (start + span * i as f32 / divisor as f32) * std::f32::consts::PI;
+            center + Vec2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+/// Triangulate a convex outline given in counter-clockwise order
+#[cfg(dim2)]
+#[allow(clippy::cast_possible_truncation)]
+fn fan(outline: &[Vec2]) -> Mesh {
+    let positions = outline.iter().map(|point| point.extend(0.0).into()).collect();
+    let indices = (2..outline.len() as u32)
+        .flat_map(|i| [0, i - 1, i])
+        .collect();
+    new_mesh(PrimitiveTopology::TriangleList, positions, indices)
+}
+
+/// Counter-clockwise convex hull of the points (Andrew's monotone chain)
+#[cfg(dim2)]
+fn convex_hull(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    points.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: Vec<Vec2> = Vec::with_capacity(points.len() + 1);
+    for pass in 0..2 {
+        let start = hull.len();
+        let iter: Box<dyn Iterator<Item = &Vec2>> = if pass == 0 {
+            Box::new(points.iter())
+        } else {
+            Box::new(points.iter().rev())
+        };
+        for point in iter {
+            while hull.len() >= start + 2
+                && (hull[hull.len() - 1] - hull[hull.len() - 2])
+                    .perp_dot(*point - hull[hull.len() - 2])
+                    <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(*point);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+#[cfg(dim2)]
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn heightfield(size: Vec2, heights: &[Vec<f32>]) -> Mesh {
+    let heights = heights.first().map(Vec::as_slice).unwrap_or_default();
+    let step = if heights.len() > 1 {
+        (heights.len() - 1) as f32
+    } else {
+        1.0
+    };
+    let positions = heights
+        .iter()
+        .enumerate()
+        .map(|(i, height)| [(i as f32 / step - 0.5) * size.x, *height, 0.0])
+        .collect();
+    let indices = (1..heights.len() as u32).flat_map(|i| [i - 1, i]).collect();
+    new_mesh(PrimitiveTopology::LineList, positions, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solid_mesh_has_normals_and_uvs() {
+        let mesh = CollisionShape::Cuboid {
+            half_extends: Vec3::ONE,
+            border_radius: None,
+        }
+        .to_debug_mesh(DebugMeshMode::Solid)
+        .unwrap();
+
+        assert_eq!(mesh.primitive_topology(), PrimitiveTopology::TriangleList);
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some());
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_some());
+    }
+
+    #[test]
+    fn wireframe_is_a_line_list_without_duplicated_edges() {
+        let mesh = CollisionShape::Cuboid {
+            half_extends: Vec3::ONE,
+            border_radius: None,
+        }
+        .to_debug_mesh(DebugMeshMode::Wireframe)
+        .unwrap();
+
+        assert_eq!(mesh.primitive_topology(), PrimitiveTopology::LineList);
+        let mut edges: Vec<(u32, u32)> = triangle_indices(&mesh)
+            .chunks_exact(2)
+            .map(|edge| (edge[0].min(edge[1]), edge[0].max(edge[1])))
+            .collect();
+        let count = edges.len();
+        edges.sort_unstable();
+        edges.dedup();
+        assert_eq!(edges.len(), count);
+    }
+
+    #[test]
+    fn mesh_fits_in_the_shape() {
+        let radius = 2.0;
+        let mesh = CollisionShape::Sphere { radius }
+            .to_debug_mesh(DebugMeshMode::Solid)
+            .unwrap();
+
+        for position in positions(&mesh) {
+            assert!(Vec3::from(position).length() <= radius + 0.001);
+        }
+    }
+
+    #[test]
+    fn custom_shape_has_no_mesh() {
+        let shape = CollisionShape::Custom {
+            shape: crate::CustomCollisionShape::new(()),
+        };
+        assert!(shape.to_debug_mesh(DebugMeshMode::Solid).is_none());
+    }
+
+    #[test]
+    fn from_mesh_creates_a_convex_hull_of_the_vertices() {
+        let mesh: Mesh = shape::Cube::new(2.0).into();
+        match CollisionShape::from_mesh(&mesh) {
+            Some(CollisionShape::ConvexHull {
+                points,
+                border_radius: None,
+            }) => {
+                assert_eq!(points.len(), mesh.count_vertices());
+                assert!(points.contains(&Vec3::ONE));
+            }
+            shape => panic!("Unexpected shape: {shape:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(not(dim2))]
+    fn convex_polyhedron_mesh_uses_the_given_triangles() {
+        let mesh = CollisionShape::ConvexPolyhedron {
+            points: vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z],
+            indices: vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+            border_radius: None,
+        }
+        .to_debug_mesh(DebugMeshMode::Wireframe)
+        .unwrap();
+
+        assert_eq!(mesh.primitive_topology(), PrimitiveTopology::LineList);
+        assert_eq!(mesh.count_vertices(), 4);
+        assert_eq!(mesh.indices().unwrap().len(), 12);
+    }
+
+    #[test]
+    #[cfg(not(dim2))]
+    fn convex_hull_mesh_only_contains_the_hull() {
+        let mesh = CollisionShape::ConvexHull {
+            points: vec![
+                Vec3::new(-1.0, -1.0, -1.0),
+                Vec3::new(1.0, -1.0, -1.0),
+                Vec3::new(-1.0, 1.0, -1.0),
+                Vec3::new(1.0, 1.0, -1.0),
+                Vec3::ZERO,
+                Vec3::new(-1.0, -1.0, 1.0),
+                Vec3::new(1.0, -1.0, 1.0),
+                Vec3::new(0.5, -0.5, 0.2),
+                Vec3::new(-1.0, 1.0, 1.0),
+                Vec3::new(1.0, 1.0, 1.0),
+                Vec3::X,
+            ],
+            border_radius: None,
+        }
+        .to_debug_mesh(DebugMeshMode::Wireframe)
+        .unwrap();
+
+        // The 12 edges of the cube, and a diagonal on each of its 6 faces
+        assert_eq!(mesh.count_vertices(), 8);
+        assert_eq!(mesh.indices().unwrap().len(), 18 * 2);
+    }
+
+    #[test]
+    #[cfg(not(dim2))]
+    fn convex_hull_triangles_face_outward() {
+        let mesh = CollisionShape::ConvexHull {
+            points: vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z, Vec3::splat(0.1)],
+            border_radius: None,
+        }
+        .to_debug_mesh(DebugMeshMode::Solid)
+        .unwrap();
+
+        let positions = positions(&mesh);
+        let center = Vec3::splat(0.25);
+        for triangle in triangle_indices(&mesh).chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i] as usize]));
+            assert!((b - a).cross(c - a).dot(a - center) > 0.0);
+        }
+    }
+
+    #[test]
+    #[cfg(not(dim2))]
+    fn convex_hull_of_coplanar_points_has_no_mesh() {
+        let shape = CollisionShape::ConvexHull {
+            points: vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::new(1.0, 1.0, 0.0)],
+            border_radius: None,
+        };
+        assert!(shape.to_debug_mesh(DebugMeshMode::Solid).is_none());
+    }
+
+    #[test]
+    #[cfg(dim2)]
+    fn convex_hull_mesh_only_contains_the_hull() {
+        let mesh = CollisionShape::ConvexHull {
+            points: vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(2.0, 0.0, 0.0),
+                Vec3::new(1.0, 0.5, 0.0),
+                Vec3::new(2.0, 2.0, 0.0),
+                Vec3::new(0.0, 2.0, 0.0),
+            ],
+            border_radius: None,
+        }
+        .to_debug_mesh(DebugMeshMode::Solid)
+        .unwrap();
+
+        assert_eq!(mesh.count_vertices(), 6);
+        for normal in mesh
+            .attribute(Mesh::ATTRIBUTE_NORMAL)
+            .and_then(VertexAttributeValues::as_float3)
+            .unwrap()
+        {
+            assert!((Vec3::from(*normal) - Vec3::Z).length() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    #[cfg(not(dim2))]
+    fn heightfield_follows_the_heights() {
+        let mesh = CollisionShape::HeightField {
+            size: Vec2::new(2.0, 4.0),
+            heights: vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+        }
+        .to_debug_mesh(DebugMeshMode::Wireframe)
+        .unwrap();
+
+        let positions = positions(&mesh);
+        assert_eq!(positions.len(), 4);
+        assert!(positions.contains(&[-1.0, 1.0, -2.0]));
+        assert!(positions.contains(&[1.0, 4.0, 2.0]));
+    }
+}
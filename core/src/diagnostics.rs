@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use bevy::diagnostic::{Diagnostic, DiagnosticId, Diagnostics};
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+
+/// Resource containing diagnostics about the physics simulation
+///
+/// It is updated by the physics backend after each physics step.
+///
+/// Add the [`PhysicsDiagnosticsPlugin`] to also report these numbers to bevy's [`Diagnostics`],
+/// so that they show up in the logs of bevy's `LogDiagnosticsPlugin`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn print_step_time(diagnostics: Res<PhysicsDiagnostics>) {
+///     println!("Step {} took {:?}", diagnostics.step_count, diagnostics.step_time);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Reflect, FromReflect)]
+pub struct PhysicsDiagnostics {
+    /// Number of physics steps performed so far
+    pub step_count: u64,
+
+    /// Total simulated time so far
+    pub simulation_time: Duration,
+
+    /// Time spent computing the last step
+    pub step_time: Duration,
+
+    /// Time spent in the broad-phase during the last step
+    ///
+    /// Only measured when the `profiler` feature is enabled. Zero otherwise.
+    pub broad_phase_time: Duration,
+
+    /// Time spent in the narrow-phase during the last step
+    ///
+    /// Only measured when the `profiler` feature is enabled. Zero otherwise.
+    pub narrow_phase_time: Duration,
+
+    /// Time spent in the constraints solver during the last step
+    ///
+    /// Only measured when the `profiler` feature is enabled. Zero otherwise.
+    pub solver_time: Duration,
+
+    /// Number of rigid bodies that are not sleeping
+    pub active_bodies: usize,
+
+    /// Number of pairs of collision shapes that are in contact
+    pub contact_pairs: usize,
+
+    /// Number of pairs of collision shapes whose bounding boxes overlap
+    ///
+    /// These are the candidate pairs found by the broad-phase, for which the narrow-phase may have
+    /// to compute the contacts.
+    pub candidate_pairs: usize,
+
+    /// Number of [`candidate_pairs`](Self::candidate_pairs) that are skipped by the narrow-phase,
+    /// because their [`CollisionLayers`](crate::CollisionLayers) don't interact
+    ///
+    /// A high ratio of filtered pairs means that the layers are effective at cutting the
+    /// narrow-phase work, but that the broad-phase still has to find these pairs.
+    pub filtered_pairs: usize,
+
+    /// Number of joints
+    pub joints: usize,
+}
+
+/// Plugin that reports the [`PhysicsDiagnostics`] to bevy's [`Diagnostics`]
+///
+/// It requires bevy's `DiagnosticsPlugin` (included in the `DefaultPlugins`).
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PhysicsDiagnosticsPlugin;
+
+impl PhysicsDiagnosticsPlugin {
+    /// Number of physics steps performed
+    pub const STEP_COUNT: DiagnosticId =
+        DiagnosticId::from_u128(254_876_103_602_683_034_684_801_501_066_911_005_088);
+
+    /// Time spent computing a physics step
+    pub const STEP_TIME: DiagnosticId =
+        DiagnosticId::from_u128(62_254_910_185_370_221_282_796_939_113_217_034_683);
+
+    /// Time spent in the broad-phase
+    pub const BROAD_PHASE_TIME: DiagnosticId =
+        DiagnosticId::from_u128(34_238_368_821_365_127_883_057_237_534_959_129_933);
+
+    /// Time spent in the narrow-phase
+    pub const NARROW_PHASE_TIME: DiagnosticId =
+        DiagnosticId::from_u128(335_226_790_909_836_490_521_946_430_979_695_599_577);
+
+    /// Time spent in the constraints solver
+    pub const SOLVER_TIME: DiagnosticId =
+        DiagnosticId::from_u128(200_942_609_914_593_177_716_901_140_804_318_079_008);
+
+    /// Number of active rigid bodies
+    pub const ACTIVE_BODIES: DiagnosticId =
+        DiagnosticId::from_u128(163_580_849_893_883_302_080_991_036_365_438_675_119);
+
+    /// Number of contact pairs
+    pub const CONTACT_PAIRS: DiagnosticId =
+        DiagnosticId::from_u128(113_377_572_922_567_900_868_511_739_309_469_237_706);
+
+    /// Number of joints
+    pub const JOINTS: DiagnosticId =
+        DiagnosticId::from_u128(163_638_385_322_373_491_317_971_288_463_766_621_204);
+
+    /// Number of candidate pairs found by the broad-phase
+    pub const CANDIDATE_PAIRS: DiagnosticId =
+        DiagnosticId::from_u128(4_206_988_821_931_344_594_620_687_159_568_954_520);
+
+    /// Number of candidate pairs filtered out by the collision layers
+    pub const FILTERED_PAIRS: DiagnosticId =
+        DiagnosticId::from_u128(57_484_879_615_739_605_904_538_063_168_791_788_728);
+
+    fn setup_system(mut diagnostics: ResMut<'_, Diagnostics>) {
+        diagnostics.add(Diagnostic::new(Self::STEP_COUNT, "physics_step_count", 1));
+        diagnostics.add(Diagnostic::new(Self::STEP_TIME, "physics_step_time", 20).with_suffix("s"));
+        diagnostics.add(
+            Diagnostic::new(Self::BROAD_PHASE_TIME, "physics_broad_phase_time", 20)
+                .with_suffix("s"),
+        );
+        diagnostics.add(
+            Diagnostic::new(Self::NARROW_PHASE_TIME, "physics_narrow_phase_time", 20)
+                .with_suffix("s"),
+        );
+        diagnostics
+            .add(Diagnostic::new(Self::SOLVER_TIME, "physics_solver_time", 20).with_suffix("s"));
+        diagnostics.add(Diagnostic::new(
+            Self::ACTIVE_BODIES,
+            "physics_active_bodies",
+            1,
+        ));
+        diagnostics.add(Diagnostic::new(
+            Self::CONTACT_PAIRS,
+            "physics_contact_pairs",
+            1,
+        ));
+        diagnostics.add(Diagnostic::new(Self::JOINTS, "physics_joints", 1));
+        diagnostics.add(Diagnostic::new(
+            Self::CANDIDATE_PAIRS,
+            "physics_candidate_pairs",
+            1,
+        ));
+        diagnostics.add(Diagnostic::new(
+            Self::FILTERED_PAIRS,
+            "physics_filtered_pairs",
+            1,
+        ));
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn diagnostic_system(
+        mut diagnostics: ResMut<'_, Diagnostics>,
+        physics: Res<'_, PhysicsDiagnostics>,
+    ) {
+        if !physics.is_changed() {
+            return;
+        }
+
+        diagnostics.add_measurement(Self::STEP_COUNT, || physics.step_count as f64);
+        diagnostics.add_measurement(Self::STEP_TIME, || physics.step_time.as_secs_f64());
+        diagnostics.add_measurement(Self::BROAD_PHASE_TIME, || {
+            physics.broad_phase_time.as_secs_f64()
+        });
+        diagnostics.add_measurement(Self::NARROW_PHASE_TIME, || {
+            physics.narrow_phase_time.as_secs_f64()
+        });
+        diagnostics.add_measurement(Self::SOLVER_TIME, || physics.solver_time.as_secs_f64());
+        diagnostics.add_measurement(Self::ACTIVE_BODIES, || physics.active_bodies as f64);
+        diagnostics.add_measurement(Self::CONTACT_PAIRS, || physics.contact_pairs as f64);
+        diagnostics.add_measurement(Self::JOINTS, || physics.joints as f64);
+        diagnostics.add_measurement(Self::CANDIDATE_PAIRS, || physics.candidate_pairs as f64);
+        diagnostics.add_measurement(Self::FILTERED_PAIRS, || physics.filtered_pairs as f64);
+    }
+}
+
+impl Plugin for PhysicsDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsDiagnostics>()
+            .add_startup_system(Self::setup_system)
+            .add_system_to_stage(CoreStage::Last, Self::diagnostic_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::diagnostic::DiagnosticsPlugin;
+
+    use super::*;
+
+    #[test]
+    fn reports_physics_diagnostics() {
+        let mut app = App::new();
+        app.add_plugin(DiagnosticsPlugin)
+            .add_plugin(PhysicsDiagnosticsPlugin);
+
+        app.world.resource_mut::<PhysicsDiagnostics>().step_count = 42;
+        app.update();
+
+        let diagnostics = app.world.resource::<Diagnostics>();
+        assert_eq!(
+            diagnostics
+                .get_measurement(PhysicsDiagnosticsPlugin::STEP_COUNT)
+                .map(|it| it.value),
+            Some(42.0)
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Resource inserted by the physics backend, telling whether the simulation is in 2d or 3d
+///
+/// The dimension is chosen by the final binary, through the `2d` or `3d` feature of `heron`.
+/// Library crates shared between 2d and 3d games should depend on `heron_core` without enabling
+/// any of these features, and read this resource when they need to behave differently.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn_ground(mut commands: Commands, dimension: Res<PhysicsDimension>) {
+///     let half_extends = if dimension.is_2d() {
+///         Vec3::new(100.0, 1.0, 0.0)
+///     } else {
+///         Vec3::new(100.0, 1.0, 100.0)
+///     };
+///     commands
+///         .spawn_bundle(TransformBundle::default())
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Cuboid {
+///             half_extends,
+///             border_radius: None,
+///         });
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum PhysicsDimension {
+    /// The simulation only happens on the `x` and `y` axes
+    Two,
+
+    /// The simulation happens on the `x`, `y` and `z` axes
+    Three,
+}
+
+impl PhysicsDimension {
+    /// Returns true if the simulation is in 2d
+    #[must_use]
+    pub fn is_2d(self) -> bool {
+        self == Self::Two
+    }
+
+    /// Returns true if the simulation is in 3d
+    #[must_use]
+    pub fn is_3d(self) -> bool {
+        self == Self::Three
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{FromReflect, Reflect};
+use bevy::utils::HashSet;
+
+/// Resource listing the pairs of entities whose contacts are currently disabled
+///
+/// The contacts between the two entities are still detected (and the [`CollisionEvent`]s are
+/// fired), but the solver ignores them, so that the bodies go through each other. The pairs can be
+/// changed at any time, and are taken into account at the next physics step. It is well suited for
+/// temporary mechanics, like phasing through the enemies during a dodge roll.
+///
+/// Each entity may be either a rigid body entity (in which case all its collision shapes are
+/// affected) or a collision shape entity. The order of the entities of a pair doesn't matter.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # #[derive(Component)] struct Player { rolling: bool }
+/// # #[derive(Component)] struct Enemy;
+/// fn phase_through_enemies_while_rolling(
+///     mut disabled_contacts: ResMut<DisabledContacts>,
+///     players: Query<(Entity, &Player)>,
+///     enemies: Query<Entity, With<Enemy>>,
+/// ) {
+///     for (player, Player { rolling }) in players.iter() {
+///         for enemy in enemies.iter() {
+///             if *rolling {
+///                 disabled_contacts.disable(player, enemy);
+///             } else {
+///                 disabled_contacts.enable(player, enemy);
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`CollisionEvent`]: crate::CollisionEvent
+#[derive(Debug, Clone, Default)]
+pub struct DisabledContacts(HashSet<(Entity, Entity)>);
+
+impl DisabledContacts {
+    /// Disable the contacts between the two entities
+    pub fn disable(&mut self, entity1: Entity, entity2: Entity) {
+        self.0.insert(key(entity1, entity2));
+    }
+
+    /// Enable the contacts between the two entities again
+    pub fn enable(&mut self, entity1: Entity, entity2: Entity) {
+        self.0.remove(&key(entity1, entity2));
+    }
+
+    /// Enable all the contacts again
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns true if the contacts between the two entities are disabled
+    #[must_use]
+    pub fn is_disabled(&self, entity1: Entity, entity2: Entity) -> bool {
+        self.0.contains(&key(entity1, entity2))
+    }
+
+    /// Returns true if no contact is disabled
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the pairs of entities whose contacts are disabled
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+/// Marker component that prevents the collision shapes of the hierarchy below this entity from
+/// colliding with each other
+///
+/// It applies to all the collision shapes of this entity and of its descendants, whatever the
+/// rigid body they belong to. No contact is computed between them, so that no
+/// [`CollisionEvent`] is fired either. They still collide normally with the collision shapes
+/// outside of the hierarchy. It is well suited for ragdolls and articulated vehicles, whose parts
+/// overlap around the joints.
+///
+/// The sensors aren't affected, and still detect the other collision shapes of the hierarchy.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(2.0, 0.5, 1.0), border_radius: None })
+///         .insert(NoSelfCollision)
+///         .with_children(|vehicle| {
+///             vehicle.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///                 .insert(RigidBody::Dynamic)
+///                 .insert(CollisionShape::Sphere { radius: 0.6 })
+///                 .insert(AttachedToParent);
+///         });
+/// }
+/// ```
+///
+/// [`CollisionEvent`]: crate::CollisionEvent
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct NoSelfCollision;
+
+fn key(entity1: Entity, entity2: Entity) -> (Entity, Entity) {
+    if entity1 <= entity2 {
+        (entity1, entity2)
+    } else {
+        (entity2, entity1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_of_the_pair_does_not_matter() {
+        let (a, b, c) = (
+            Entity::from_raw(1),
+            Entity::from_raw(2),
+            Entity::from_raw(3),
+        );
+        let mut contacts = DisabledContacts::default();
+
+        contacts.disable(b, a);
+        assert!(contacts.is_disabled(a, b));
+        assert!(contacts.is_disabled(b, a));
+        assert!(!contacts.is_disabled(a, c));
+
+        contacts.enable(a, b);
+        assert!(!contacts.is_disabled(b, a));
+        assert!(contacts.is_empty());
+    }
+}
@@ -1,7 +1,12 @@
+use std::fmt;
+use std::time::Duration;
+
+use bevy::reflect::{ReflectDeserialize, ReflectSerialize};
 use bevy::{ecs::entity::Entity, math::Vec3, prelude::Reflect, reflect::FromReflect};
+use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
-use crate::CollisionLayers;
+use crate::{CollisionLayers, NetworkId};
 
 /// An event fired when the collision state between two entities changed
 ///
@@ -23,12 +28,14 @@ use crate::CollisionLayers;
 ///     }
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Reflect, FromReflect)]
 pub enum CollisionEvent {
     /// The two entities started to collide
     Started(CollisionData, CollisionData),
 
     /// The two entities no longer collide
+    ///
+    /// It is also fired when one of the collision shapes is removed (or its entity despawned).
     Stopped(CollisionData, CollisionData),
 }
 
@@ -39,6 +46,37 @@ pub struct CollisionData {
     collision_shape_entity: Entity,
     collision_layers: CollisionLayers,
     normals: SmallVec<[Vec3; 1]>,
+    velocity: Vec3,
+    stable_id: StableId,
+    step: u64,
+}
+
+/// Id of a collision shape that doesn't depend on the entity id, when the collision shape has a
+/// [`NetworkId`]
+///
+/// The source of the id is part of it, so that a [`NetworkId`] never collides with the bits of an
+/// entity.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Reflect,
+    FromReflect,
+    Serialize,
+    Deserialize,
+)]
+#[reflect(Serialize, Deserialize)]
+pub enum StableId {
+    /// The [`NetworkId`] of the collision shape entity
+    Network(NetworkId),
+
+    /// The collision shape entity, which has no [`NetworkId`]
+    Entity(Entity),
 }
 
 impl From<CollisionEvent> for (CollisionData, CollisionData) {
@@ -97,6 +135,48 @@ impl CollisionEvent {
             }
         }
     }
+
+    /// Returns the index of the physics step during which the collision state changed
+    ///
+    /// See [`CollisionData::step`]
+    #[must_use]
+    pub fn step(&self) -> u64 {
+        match self {
+            CollisionEvent::Started(d1, _) | CollisionEvent::Stopped(d1, _) => d1.step,
+        }
+    }
+
+    /// Returns a key identifying the pair of collision shapes, regardless of their order
+    ///
+    /// It is made of the [`CollisionData::stable_id`] of both collision shapes, so it doesn't depend
+    /// on the entity ids when the collision shapes have a [`NetworkId`]. It can
+    /// be used to match the events of the same collisions across replay or rollback sessions.
+    #[must_use]
+    pub fn pair_key(&self) -> (StableId, StableId) {
+        match self {
+            CollisionEvent::Started(d1, d2) | CollisionEvent::Stopped(d1, d2) => {
+                if d1.stable_id <= d2.stable_id {
+                    (d1.stable_id, d2.stable_id)
+                } else {
+                    (d2.stable_id, d1.stable_id)
+                }
+            }
+        }
+    }
+
+    /// Returns the velocity of the first rigid body relative to the second one, when the collision
+    /// state changed
+    ///
+    /// For a `Started` event, its length is the impact speed, which is useful to scale the volume of
+    /// a sound or the intensity of a visual effect. See [`CollisionData::velocity`]
+    #[must_use]
+    pub fn relative_velocity(&self) -> Vec3 {
+        match self {
+            CollisionEvent::Started(d1, d2) | CollisionEvent::Stopped(d1, d2) => {
+                d1.velocity - d2.velocity
+            }
+        }
+    }
 }
 
 impl CollisionData {
@@ -113,9 +193,42 @@ impl CollisionData {
             collision_shape_entity,
             collision_layers,
             normals: normals.into_iter().collect(),
+            velocity: Vec3::ZERO,
+            stable_id: StableId::Entity(collision_shape_entity),
+            step: 0,
         }
     }
 
+    /// Returns the collision data stamped with the given physics step index
+    #[must_use]
+    pub fn with_step(mut self, step: u64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Returns the collision data with the given contact normals
+    #[must_use]
+    pub fn with_normals(mut self, normals: impl IntoIterator<Item = Vec3>) -> Self {
+        self.normals = normals.into_iter().collect();
+        self
+    }
+
+    /// Returns the collision data with the given linear velocity of the rigid body
+    #[must_use]
+    pub fn with_velocity(mut self, velocity: Vec3) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Returns the collision data with the given stable id
+    ///
+    /// See [`CollisionData::stable_id`]
+    #[must_use]
+    pub fn with_stable_id(mut self, stable_id: StableId) -> Self {
+        self.stable_id = stable_id;
+        self
+    }
+
     /// Returns the entity containing the [`RigidBody`](crate::RigidBody)
     #[must_use]
     pub fn rigid_body_entity(&self) -> Entity {
@@ -139,4 +252,125 @@ impl CollisionData {
     pub fn normals(&self) -> &[Vec3] {
         &self.normals
     }
+
+    /// Returns the linear velocity of the rigid body when the collision state changed
+    ///
+    /// It is sampled before the physics engine resolves the contact, so that the velocity at the
+    /// moment of the impact is not affected by the collision response. It is zero for the `Stopped`
+    /// events fired because a collision shape was removed.
+    #[must_use]
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Returns the id of the collision shape that doesn't depend on the entity id
+    ///
+    /// It is the [`NetworkId`] of the collision shape entity if it has one, or the collision
+    /// shape entity otherwise.
+    #[must_use]
+    pub fn stable_id(&self) -> StableId {
+        self.stable_id
+    }
+
+    /// Returns the index of the physics step during which the collision state changed
+    ///
+    /// Steps are counted from 1, and the index matches both
+    /// [`PhysicsDiagnostics::step_count`](crate::PhysicsDiagnostics::step_count) and
+    /// [`PhysicsStepEvent::step`] of that step. It is 0 for collision data that wasn't produced by
+    /// a physics step.
+    #[must_use]
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+}
+
+/// An event fired after each physics step
+///
+/// Collision events fired by the step are stamped with the same [`step`](Self::step) index, which
+/// makes it possible to correlate them when many steps are performed during a single frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn log_steps(mut events: EventReader<PhysicsStepEvent>) {
+///     for event in events.iter() {
+///         println!("Step {} advanced the simulation by {:?}", event.step, event.duration);
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Reflect, FromReflect)]
+pub struct PhysicsStepEvent {
+    /// Index of the step, starting at 1 and monotonically increasing
+    pub step: u64,
+
+    /// Simulated time integrated by the step
+    pub duration: Duration,
+}
+
+/// An event fired when heron could not create a collider for a [`CollisionShape`](crate::CollisionShape)
+///
+/// The event is fired only once per entity. Heron keeps trying to create the collider at each
+/// physics step, so the problem can be fixed at runtime (for instance by inserting the missing
+/// [`RigidBody`](crate::RigidBody)).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn log_failures(mut events: EventReader<ColliderCreationFailed>) {
+///     for event in events.iter() {
+///         warn!("Cannot create collider for {:?}: {}", event.entity, event.reason);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, FromReflect)]
+pub struct ColliderCreationFailed {
+    /// The entity containing the [`CollisionShape`](crate::CollisionShape)
+    pub entity: Entity,
+
+    /// The reason why the collider could not be created
+    pub reason: ColliderCreationFailure,
+}
+
+/// Reason why a collider could not be created
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, FromReflect)]
+#[non_exhaustive]
+pub enum ColliderCreationFailure {
+    /// Neither the entity nor its parent have a [`RigidBody`](crate::RigidBody)
+    MissingRigidBody,
+
+    /// The [`CustomCollisionShape`](crate::CustomCollisionShape) is not supported by the backend
+    UnsupportedCustomShape {
+        /// Name of the type stored in the custom collision shape
+        type_name: &'static str,
+    },
+
+    /// The [`CollisionShape`](crate::CollisionShape) is degenerate or has invalid dimensions
+    ///
+    /// The entity is marked with the [`InvalidCollisionShape`](crate::InvalidCollisionShape)
+    /// component until the shape is fixed.
+    InvalidShape {
+        /// Description of what is wrong with the shape
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for ColliderCreationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColliderCreationFailure::MissingRigidBody => {
+                write!(f, "No rigid body found in the entity or its parent")
+            }
+            ColliderCreationFailure::UnsupportedCustomShape { type_name } => write!(
+                f,
+                "Unsupported custom collision shape is used: CustomCollisionShape({type_name})"
+            ),
+            ColliderCreationFailure::InvalidShape { reason } => {
+                write!(f, "Invalid collision shape: {reason}")
+            }
+        }
+    }
 }
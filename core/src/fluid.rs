@@ -0,0 +1,93 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::Vec3;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that turns a sensor into an area filled with a fluid, like water or air
+///
+/// The dynamic bodies overlapping the area are pushed up by buoyancy (against gravity), and slowed
+/// down by drag. The submerged volume of a body is approximated from the overlap of the bounding
+/// boxes of the collision shapes.
+///
+/// The entity should also have a [`Sensor`](crate::Sensor) component, so that bodies can enter the
+/// area.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     // Water
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(Sensor)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(50.0, 10.0, 50.0), border_radius: None })
+///         .insert(FluidArea::new(1000.0).with_linear_drag(1.0).with_angular_drag(1.0));
+///
+///     // Wind
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(Sensor)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(5.0, 50.0, 5.0), border_radius: None })
+///         .insert(FluidArea::new(0.0).with_linear_drag(2.0).with_flow_velocity(Vec3::Y * 10.0));
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct FluidArea {
+    /// Mass per unit of volume (or per unit of area in 2d) of the fluid
+    ///
+    /// The buoyancy force is the weight of the displaced fluid.
+    pub density: f32,
+    /// Rate at which the fluid slows down the linear velocity of fully submerged bodies, relative
+    /// to the `flow_velocity`
+    pub linear_drag: f32,
+    /// Rate at which the fluid slows down the angular velocity of fully submerged bodies
+    pub angular_drag: f32,
+    /// Velocity of the fluid
+    ///
+    /// The drag pulls the submerged bodies toward that velocity, which makes it possible to
+    /// simulate currents and wind.
+    pub flow_velocity: Vec3,
+}
+
+impl Default for FluidArea {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl FluidArea {
+    /// Returns a still fluid of the given density, without drag
+    #[must_use]
+    pub fn new(density: f32) -> Self {
+        Self {
+            density,
+            linear_drag: 0.0,
+            angular_drag: 0.0,
+            flow_velocity: Vec3::ZERO,
+        }
+    }
+
+    /// Returns a new version of this fluid with the given linear drag
+    #[must_use]
+    pub fn with_linear_drag(mut self, linear_drag: f32) -> Self {
+        self.linear_drag = linear_drag;
+        self
+    }
+
+    /// Returns a new version of this fluid with the given angular drag
+    #[must_use]
+    pub fn with_angular_drag(mut self, angular_drag: f32) -> Self {
+        self.angular_drag = angular_drag;
+        self
+    }
+
+    /// Returns a new version of this fluid with the given flow velocity
+    #[must_use]
+    pub fn with_flow_velocity(mut self, flow_velocity: Vec3) -> Self {
+        self.flow_velocity = flow_velocity;
+        self
+    }
+}
@@ -1,5 +1,8 @@
 use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
 use bevy::math::{Vec2, Vec3};
+use bevy::reflect::{FromReflect, Reflect, ReflectDeserialize, ReflectSerialize};
+use serde::{Deserialize, Serialize};
 
 /// Resource that defines world's gravity.
 ///
@@ -17,7 +20,8 @@ use bevy::math::{Vec2, Vec3};
 ///         .run();
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, PartialEq)]
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
 pub struct Gravity(Vec3);
 
 impl Gravity {
@@ -51,3 +55,186 @@ impl From<Gravity> for Vec3 {
         g.vector()
     }
 }
+
+/// Component that overrides the global [`Gravity`] for a dynamic rigid body
+///
+/// The body is affected by this gravity instead of the world's gravity and of any [`GravityArea`]
+/// it overlaps.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(GravityDirection::from(Vec3::Y * 9.81)); // This body falls up
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct GravityDirection(Vec3);
+
+impl GravityDirection {
+    /// Returns the underlying vector
+    #[must_use]
+    pub fn vector(self) -> Vec3 {
+        self.0
+    }
+}
+
+impl From<Vec3> for GravityDirection {
+    fn from(v: Vec3) -> Self {
+        Self(v)
+    }
+}
+
+impl From<Vec2> for GravityDirection {
+    fn from(v: Vec2) -> Self {
+        Self::from(v.extend(0.0))
+    }
+}
+
+impl From<Gravity> for GravityDirection {
+    fn from(gravity: Gravity) -> Self {
+        Self(gravity.vector())
+    }
+}
+
+/// Marker component that disables any gravity on a dynamic rigid body
+///
+/// The body is not affected by the global [`Gravity`], nor by the [`GravityArea`]s and the
+/// [`GravityDirection`]. Unlike a [`GravityDirection`] of zero, the physics engine is only updated
+/// when the marker is inserted or removed, which makes it cheap for the bodies that often switch
+/// between floating and falling, like pickups attracted by a magnet.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(DisableGravity); // This body floats
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct DisableGravity;
+
+/// Component that scales the gravity applied to a dynamic rigid body
+///
+/// It applies to the global [`Gravity`], as well as to the [`GravityArea`]s and the
+/// [`GravityDirection`]. A scale of `0.0` makes the body float, though [`DisableGravity`] is more
+/// explicit for that purpose. A negative scale makes the body fall upward.
+///
+/// The default scale is `1.0`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(GravityScale(0.5)); // This body falls slowly, like a feather
+/// }
+/// ```
+#[derive(
+    Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect, Serialize, Deserialize,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct GravityScale(pub f32);
+
+impl Default for GravityScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Component that turns a sensor into an area with its own gravity
+///
+/// The dynamic bodies overlapping the area are affected by the gravity of the area instead of the
+/// global [`Gravity`]. When a body overlaps several areas, their gravities are added up. A
+/// [`GravityDirection`] on the body takes precedence over the areas.
+///
+/// The entity should also have a [`Sensor`](crate::Sensor) component, so that bodies can enter the
+/// area.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     // Planet atmosphere
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(Sensor)
+///         .insert(CollisionShape::Sphere { radius: 100.0 })
+///         .insert(GravityArea::Radial { strength: 9.81 });
+/// }
+/// ```
+#[derive(
+    Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect, Serialize, Deserialize,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub enum GravityArea {
+    /// The same gravity everywhere in the area
+    Directional(Vec3),
+
+    /// A gravity pulling toward the center of the area
+    Radial {
+        /// Acceleration toward the center of the area
+        ///
+        /// A negative strength pushes the bodies away from the center.
+        strength: f32,
+    },
+}
+
+impl Default for GravityArea {
+    fn default() -> Self {
+        Self::Directional(Vec3::ZERO)
+    }
+}
+
+impl GravityArea {
+    /// Returns the gravity applied to a body at `position`, for an area centered at `center`
+    #[must_use]
+    pub fn gravity_at(self, center: Vec3, position: Vec3) -> Vec3 {
+        match self {
+            Self::Directional(gravity) => gravity,
+            Self::Radial { strength } => (center - position).normalize_or_zero() * strength,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directional_area_ignores_position() {
+        let area = GravityArea::Directional(Vec3::X);
+        assert_eq!(
+            area.gravity_at(Vec3::ZERO, Vec3::new(5.0, 2.0, 1.0)),
+            Vec3::X
+        );
+    }
+
+    #[test]
+    fn radial_area_pulls_toward_center() {
+        let area = GravityArea::Radial { strength: 2.0 };
+        assert_eq!(
+            area.gravity_at(Vec3::Y, Vec3::new(0.0, 11.0, 0.0)),
+            Vec3::NEG_Y * 2.0
+        );
+        assert_eq!(area.gravity_at(Vec3::Y, Vec3::Y), Vec3::ZERO);
+    }
+}
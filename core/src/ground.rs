@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+
+/// Tolerance on the cosine of the slope, so that a slope of exactly `max_slope` is a ground
+const SLOPE_EPSILON: f32 = 1e-5;
+
+/// Component which will be updated (if present) to tell whether the rigid body stands on the
+/// ground
+///
+/// The rigid body is considered grounded if any of its collision shapes is in contact with another
+/// shape, and the contact normal makes an angle with the up axis that is not greater than
+/// [`max_slope`](Self::max_slope). The up axis is opposite to the gravity of the body, that is its
+/// [`GravityDirection`](crate::GravityDirection) or the global [`Gravity`](crate::Gravity) (`Y`
+/// when there is no gravity).
+///
+/// When grounded, the rigid body entity of the ground and the contact normal are available with
+/// [`ground_entity`](Self::ground_entity) and [`ground_normal`](Self::ground_normal). If the body
+/// stands on many shapes, the flattest contact is used.
+///
+/// It is updated after each physics step.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn jump(mut query: Query<'_, '_, (&GroundDetection, &mut Velocity)>) {
+///     for (ground, mut velocity) in query.iter_mut() {
+///         if ground.is_grounded() {
+///             velocity.linear.y = 5.0;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct GroundDetection {
+    /// Maximum angle (in radians) between the contact normal and the up axis, for the contact to
+    /// be considered as ground
+    pub max_slope: f32,
+    grounded: bool,
+    #[reflect(ignore)]
+    ground: Option<(Entity, Vec3)>,
+}
+
+impl Default for GroundDetection {
+    fn default() -> Self {
+        Self::new(std::f32::consts::FRAC_PI_4)
+    }
+}
+
+impl GroundDetection {
+    /// Create a new ground detection with the given maximum slope (in radians)
+    #[must_use]
+    pub fn new(max_slope: f32) -> Self {
+        Self {
+            max_slope,
+            grounded: false,
+            ground: None,
+        }
+    }
+
+    /// Returns `true` if the rigid body was standing on the ground at the end of the last physics
+    /// step
+    #[must_use]
+    pub fn is_grounded(&self) -> bool {
+        self.grounded
+    }
+
+    /// Returns the rigid body entity the body was standing on at the end of the last physics step
+    #[must_use]
+    pub fn ground_entity(&self) -> Option<Entity> {
+        self.ground.map(|(entity, _)| entity)
+    }
+
+    /// Returns the normal (pointing toward the rigid body) of the contact with the ground at the
+    /// end of the last physics step
+    #[must_use]
+    pub fn ground_normal(&self) -> Option<Vec3> {
+        self.ground.map(|(_, normal)| normal)
+    }
+
+    /// Returns `true` if a contact with the given normal (pointing toward the rigid body) is a
+    /// contact with the ground, when the up axis is `Y`
+    #[must_use]
+    pub fn is_ground_normal(&self, normal: Vec3) -> bool {
+        self.is_ground_normal_toward(normal, Vec3::Y)
+    }
+
+    /// Returns `true` if a contact with the given normal (pointing toward the rigid body) is a
+    /// contact with the ground, when the up axis is `up`
+    ///
+    /// A slope of exactly [`max_slope`](Self::max_slope) is a ground, despite rounding errors.
+    #[must_use]
+    pub fn is_ground_normal_toward(&self, normal: Vec3, up: Vec3) -> bool {
+        let up = up.try_normalize().unwrap_or(Vec3::Y);
+        normal.dot(up) >= self.max_slope.cos() - SLOPE_EPSILON
+    }
+
+    #[doc(hidden)]
+    pub fn set_grounded(&mut self, grounded: bool) {
+        self.grounded = grounded;
+        if !grounded {
+            self.ground = None;
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn set_ground(&mut self, ground: Option<(Entity, Vec3)>) {
+        self.grounded = ground.is_some();
+        self.ground = ground;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_ground_normal() {
+        let detection = GroundDetection::default();
+        assert!(detection.is_ground_normal(Vec3::Y));
+        assert!(detection.is_ground_normal(Vec3::new(0.5, 1.0, 0.0).normalize()));
+        assert!(!detection.is_ground_normal(Vec3::new(1.0, 0.5, 0.0).normalize()));
+        assert!(!detection.is_ground_normal(Vec3::X));
+        assert!(!detection.is_ground_normal(-Vec3::Y));
+    }
+
+    #[test]
+    fn is_ground_normal_toward_the_up_axis() {
+        let detection = GroundDetection::default();
+        assert!(detection.is_ground_normal_toward(-Vec3::Y, -Vec3::Y * 10.0));
+        assert!(!detection.is_ground_normal_toward(Vec3::Y, -Vec3::Y));
+        assert!(detection.is_ground_normal_toward(Vec3::Y, Vec3::ZERO));
+    }
+
+    #[test]
+    fn max_slope_is_a_ground() {
+        let max_slope = 14_f32.to_radians();
+        let detection = GroundDetection::new(max_slope);
+        let normal = Quat::from_rotation_z(max_slope) * Vec3::Y;
+        assert!(detection.is_ground_normal(normal));
+    }
+
+    #[test]
+    fn set_ground() {
+        let mut detection = GroundDetection::default();
+        detection.set_ground(Some((Entity::from_raw(1), Vec3::Y)));
+        assert!(detection.is_grounded());
+        assert_eq!(detection.ground_entity(), Some(Entity::from_raw(1)));
+        assert_eq!(detection.ground_normal(), Some(Vec3::Y));
+
+        detection.set_ground(None);
+        assert!(!detection.is_grounded());
+        assert_eq!(detection.ground_entity(), None);
+    }
+}
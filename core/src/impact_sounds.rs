@@ -0,0 +1,290 @@
+use std::marker::PhantomData;
+
+use bevy::asset::Asset;
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+use bevy::utils::HashMap;
+
+use crate::{CollisionEvent, Velocity};
+
+/// Component that defines what kind of surface a collision shape is made of, so that the
+/// [`ImpactSounds`] can tell which sound to play when two surfaces hit each other
+///
+/// It can be inserted on the collision shape entity or on its rigid body entity.
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Hash, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct SurfaceTag(pub u32);
+
+/// Resource where to register the sounds to play when two surfaces hit each other
+///
+/// It is generic over the type of sound asset (for example bevy's `AudioSource`), and is
+/// added by the [`ImpactSoundsPlugin`].
+///
+/// The volume of the emitted [`ImpactSound`] events depends on the relative velocity of the
+/// rigid bodies at the moment of the impact. There is no sound below the
+/// [`min_velocity`](Self::min_velocity), and the volume is maximal (`1.0`) at the
+/// [`max_velocity`](Self::max_velocity).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::reflect::TypeUuid;
+/// # use heron_core::*;
+/// # #[derive(TypeUuid)]
+/// # #[uuid = "5f4e2cf4-ed3b-4d7c-a3a5-42d9f8f1f8ab"]
+/// # struct AudioSource;
+/// const METAL: SurfaceTag = SurfaceTag(1);
+/// const WOOD: SurfaceTag = SurfaceTag(2);
+///
+/// fn setup(asset_server: Res<AssetServer>, mut sounds: ResMut<ImpactSounds<AudioSource>>) {
+///     sounds.insert(METAL, WOOD, asset_server.load("sounds/metal_on_wood.ogg"));
+///     sounds.insert(METAL, METAL, asset_server.load("sounds/metal_on_metal.ogg"));
+/// }
+///
+/// fn play(mut impacts: EventReader<ImpactSound<AudioSource>>) {
+///     for impact in impacts.iter() {
+///         println!("Play {:?} with volume {}", impact.handle, impact.volume);
+///     }
+/// }
+/// ```
+pub struct ImpactSounds<A: Asset> {
+    sounds: HashMap<(SurfaceTag, SurfaceTag), Handle<A>>,
+    /// Relative velocity below which no sound is emitted
+    pub min_velocity: f32,
+    /// Relative velocity at which the sound is emitted with the maximum volume
+    pub max_velocity: f32,
+}
+
+impl<A: Asset> Clone for ImpactSounds<A> {
+    fn clone(&self) -> Self {
+        Self {
+            sounds: self.sounds.clone(),
+            min_velocity: self.min_velocity,
+            max_velocity: self.max_velocity,
+        }
+    }
+}
+
+impl<A: Asset> Default for ImpactSounds<A> {
+    fn default() -> Self {
+        Self {
+            sounds: HashMap::default(),
+            min_velocity: 1.0,
+            max_velocity: 10.0,
+        }
+    }
+}
+
+impl<A: Asset> ImpactSounds<A> {
+    /// Register the sound to play when the two surfaces hit each other
+    ///
+    /// The order of the tags doesn't matter.
+    pub fn insert(&mut self, tag1: SurfaceTag, tag2: SurfaceTag, sound: Handle<A>) {
+        self.sounds.insert(key(tag1, tag2), sound);
+    }
+
+    /// Remove the sound registered for the two surfaces
+    pub fn remove(&mut self, tag1: SurfaceTag, tag2: SurfaceTag) {
+        self.sounds.remove(&key(tag1, tag2));
+    }
+
+    /// Returns the sound registered for the two surfaces, if any
+    ///
+    /// The order of the tags doesn't matter.
+    #[must_use]
+    pub fn get(&self, tag1: SurfaceTag, tag2: SurfaceTag) -> Option<&Handle<A>> {
+        self.sounds.get(&key(tag1, tag2))
+    }
+
+    /// Volume of an impact at the given relative velocity, or `None` if it is too small to be heard
+    fn volume(&self, velocity: f32) -> Option<f32> {
+        if velocity < self.min_velocity {
+            return None;
+        }
+
+        let range = self.max_velocity - self.min_velocity;
+        if range <= 0.0 {
+            return Some(1.0);
+        }
+
+        Some(((velocity - self.min_velocity) / range).clamp(0.0, 1.0))
+    }
+}
+
+fn key(tag1: SurfaceTag, tag2: SurfaceTag) -> (SurfaceTag, SurfaceTag) {
+    if tag1.0 <= tag2.0 {
+        (tag1, tag2)
+    } else {
+        (tag2, tag1)
+    }
+}
+
+/// Event fired when two surfaces, for which a sound is registered in the [`ImpactSounds`], hit
+/// each other
+pub struct ImpactSound<A: Asset> {
+    /// Sound to play
+    pub handle: Handle<A>,
+    /// Volume at which the sound should be played, between `0.0` and `1.0`
+    pub volume: f32,
+}
+
+impl<A: Asset> Clone for ImpactSound<A> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+            volume: self.volume,
+        }
+    }
+}
+
+/// Plugin that emits [`ImpactSound`] events for the sounds registered in [`ImpactSounds`]
+///
+/// It is generic over the type of sound asset (for example bevy's `AudioSource`).
+pub struct ImpactSoundsPlugin<A>(PhantomData<A>);
+
+impl<A> Default for ImpactSoundsPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Asset> Plugin for ImpactSoundsPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ImpactSounds<A>>()
+            .add_event::<ImpactSound<A>>()
+            .add_system(impact_sounds_system::<A>);
+    }
+}
+
+/// Emits the impact sounds of the collisions that started during the last physics step
+///
+/// The velocities are recorded before each physics step, so that the impact velocity is not
+/// affected by the collision response.
+fn impact_sounds_system<A: Asset>(
+    mut velocities: Local<'_, HashMap<Entity, Vec3>>,
+    sounds: Res<'_, ImpactSounds<A>>,
+    mut collision_events: EventReader<'_, '_, CollisionEvent>,
+    mut impact_sounds: EventWriter<'_, '_, ImpactSound<A>>,
+    surfaces: Query<'_, '_, &SurfaceTag>,
+    bodies: Query<'_, '_, (Entity, &Velocity)>,
+) {
+    let tag_of = |shape: Entity, body: Entity| {
+        surfaces
+            .get(shape)
+            .or_else(|_| surfaces.get(body))
+            .ok()
+            .copied()
+    };
+
+    for event in collision_events.iter().filter(|e| e.is_started()) {
+        let (shape1, shape2) = event.collision_shape_entities();
+        let (body1, body2) = event.rigid_body_entities();
+
+        let (tag1, tag2) = match (tag_of(shape1, body1), tag_of(shape2, body2)) {
+            (Some(tag1), Some(tag2)) => (tag1, tag2),
+            _ => continue,
+        };
+
+        let handle = match sounds.get(tag1, tag2) {
+            Some(handle) => handle,
+            None => continue,
+        };
+
+        let velocity = |body| velocities.get(&body).copied().unwrap_or_default();
+        if let Some(volume) = sounds.volume((velocity(body1) - velocity(body2)).length()) {
+            impact_sounds.send(ImpactSound {
+                handle: handle.clone(),
+                volume,
+            });
+        }
+    }
+
+    velocities.clear();
+    velocities.extend(
+        bodies
+            .iter()
+            .map(|(entity, velocity)| (entity, velocity.linear)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::HandleId;
+    use bevy::ecs::event::{Events, ManualEventReader};
+    use bevy::reflect::TypeUuid;
+
+    use crate::{CollisionData, CollisionLayers};
+
+    use super::*;
+
+    #[derive(TypeUuid)]
+    #[uuid = "af48d8f1-cc7f-40d1-8f2f-9e13bc077820"]
+    struct Sound;
+
+    #[test]
+    fn sounds_are_symmetric() {
+        let mut sounds = ImpactSounds::<Sound>::default();
+        let handle = Handle::weak(HandleId::random::<Sound>());
+        sounds.insert(SurfaceTag(2), SurfaceTag(1), handle.clone());
+
+        assert_eq!(sounds.get(SurfaceTag(1), SurfaceTag(2)), Some(&handle));
+        assert_eq!(sounds.get(SurfaceTag(2), SurfaceTag(1)), Some(&handle));
+        assert_eq!(sounds.get(SurfaceTag(1), SurfaceTag(1)), None);
+    }
+
+    #[test]
+    fn volume_is_scaled_by_velocity() {
+        let sounds = ImpactSounds::<Sound> {
+            min_velocity: 2.0,
+            max_velocity: 4.0,
+            ..ImpactSounds::default()
+        };
+
+        assert_eq!(sounds.volume(1.0), None);
+        assert_eq!(sounds.volume(2.0), Some(0.0));
+        assert_eq!(sounds.volume(3.0), Some(0.5));
+        assert_eq!(sounds.volume(10.0), Some(1.0));
+    }
+
+    #[test]
+    fn emits_impact_sound_scaled_by_velocity_before_impact() {
+        let mut app = App::new();
+        app.add_event::<CollisionEvent>()
+            .add_plugin(ImpactSoundsPlugin::<Sound>::default());
+
+        let handle = Handle::weak(HandleId::random::<Sound>());
+        app.world.resource_mut::<ImpactSounds<Sound>>().insert(
+            SurfaceTag(1),
+            SurfaceTag(2),
+            handle.clone(),
+        );
+
+        let entity1 = app
+            .world
+            .spawn()
+            .insert_bundle((SurfaceTag(1), Velocity::from_linear(Vec3::X * 5.5)))
+            .id();
+        let entity2 = app.world.spawn().insert(SurfaceTag(2)).id();
+
+        app.update();
+
+        // The collision response changes the velocity, but the impact velocity is the one before
+        app.world.get_mut::<Velocity>(entity1).unwrap().linear = Vec3::ZERO;
+        app.world.send_event(CollisionEvent::Started(
+            CollisionData::new(entity1, entity1, CollisionLayers::default(), []),
+            CollisionData::new(entity2, entity2, CollisionLayers::default(), []),
+        ));
+
+        app.update();
+
+        let events = app.world.resource::<Events<ImpactSound<Sound>>>();
+        let sounds: Vec<_> = ManualEventReader::<ImpactSound<Sound>>::default()
+            .iter(events)
+            .cloned()
+            .collect();
+        assert_eq!(sounds.len(), 1);
+        assert_eq!(sounds[0].handle, handle);
+        assert!((sounds[0].volume - 0.5).abs() < f32::EPSILON);
+    }
+}
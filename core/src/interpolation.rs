@@ -0,0 +1,124 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{FromReflect, Reflect};
+
+use crate::PhysicsPose;
+
+/// Component that smooths the rendered movement of a rigid body, when the physics steps are less
+/// frequent than the frames
+///
+/// Heron keeps the poses of the rigid body after the last two physics steps, and writes an
+/// interpolation of them into the `Transform` at each frame, according to the time elapsed since
+/// the last step (see [`PhysicsSteps::interpolation_factor`](crate::PhysicsSteps::interpolation_factor)).
+/// The rendered body lags behind the simulation by at most one step.
+///
+/// The exact pose computed by the simulation is kept in the [`PhysicsPose`] component, which is
+/// what the gameplay logic should read. It is inserted from the `GlobalTransform` if missing. Like
+/// for any body that has a [`PhysicsPose`], changing the `Transform` no longer moves the body.
+///
+/// It only makes a difference when the physics steps are driven by a timer
+/// ([`PhysicsSteps::from_steps_per_seconds`](crate::PhysicsSteps::from_steps_per_seconds) or
+/// [`PhysicsSteps::from_delta_time`](crate::PhysicsSteps::from_delta_time)). Otherwise, the
+/// `Transform` simply follows the latest pose.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(Interpolate::default());
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct Interpolate {
+    previous: PhysicsPose,
+    current: PhysicsPose,
+    recorded: bool,
+}
+
+impl Interpolate {
+    /// Records the pose of the rigid body after a physics step
+    ///
+    /// It is called by the physics backend after each step.
+    pub fn record(&mut self, pose: PhysicsPose) {
+        if self.recorded {
+            self.previous = self.current;
+            self.current = pose;
+        } else {
+            self.reset(pose);
+        }
+    }
+
+    /// Makes the rendered pose jump to the given pose, without interpolating from the previous one
+    ///
+    /// It is called by the physics backend when the body is teleported.
+    pub fn reset(&mut self, pose: PhysicsPose) {
+        self.previous = pose;
+        self.current = pose;
+        self.recorded = true;
+    }
+
+    /// Returns the pose to render, given the progress (between `0.0` and `1.0`) from the previous
+    /// physics step to the latest one
+    ///
+    /// Returns `None` if no pose has been recorded yet.
+    #[must_use]
+    pub fn pose(&self, factor: f32) -> Option<PhysicsPose> {
+        if !self.recorded {
+            return None;
+        }
+
+        let factor = factor.clamp(0.0, 1.0);
+        Some(PhysicsPose::new(
+            self.previous
+                .translation
+                .lerp(self.current.translation, factor),
+            self.previous.rotation.slerp(self.current.rotation, factor),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::{Quat, Vec3};
+
+    use super::*;
+
+    #[test]
+    fn has_no_pose_until_recorded() {
+        assert_eq!(Interpolate::default().pose(0.5), None);
+    }
+
+    #[test]
+    fn first_recorded_pose_is_not_interpolated() {
+        let pose = PhysicsPose::new(Vec3::X, Quat::IDENTITY);
+        let mut interpolate = Interpolate::default();
+        interpolate.record(pose);
+        assert_eq!(interpolate.pose(0.0), Some(pose));
+    }
+
+    #[test]
+    fn interpolates_between_the_last_two_poses() {
+        let mut interpolate = Interpolate::default();
+        interpolate.record(PhysicsPose::new(Vec3::ZERO, Quat::IDENTITY));
+        interpolate.record(PhysicsPose::new(Vec3::X, Quat::IDENTITY));
+        interpolate.record(PhysicsPose::new(Vec3::X * 3.0, Quat::IDENTITY));
+
+        let pose = interpolate.pose(0.5).unwrap();
+        assert!(pose.translation.abs_diff_eq(Vec3::X * 2.0, 1e-5));
+        assert_eq!(interpolate.pose(2.0).unwrap().translation, Vec3::X * 3.0);
+    }
+
+    #[test]
+    fn reset_discards_the_previous_pose() {
+        let mut interpolate = Interpolate::default();
+        interpolate.record(PhysicsPose::new(Vec3::ZERO, Quat::IDENTITY));
+        interpolate.reset(PhysicsPose::new(Vec3::Y, Quat::IDENTITY));
+        assert_eq!(interpolate.pose(0.0).unwrap().translation, Vec3::Y);
+    }
+}
@@ -0,0 +1,392 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::{ReflectComponent, ReflectMapEntities};
+use bevy::ecs::world::{FromWorld, World};
+use bevy::ecs::entity::{Entity, EntityMap, MapEntities, MapEntitiesError};
+use bevy::math::Vec3;
+use bevy::reflect::{FromReflect, Reflect, ReflectDeserialize, ReflectSerialize};
+use serde::{Deserialize, Serialize};
+
+/// Component that attaches the rigid body of this entity to the rigid body of another entity
+///
+/// Both entities must have a [`RigidBody`](crate::RigidBody) in the same physics world.
+///
+/// The anchors are expressed in the local space of their respective rigid body.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     let frame = commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .id();
+///
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(1.0, 2.0, 0.1), border_radius: None })
+///         .insert(Joint::revolute(frame).with_local_anchor(Vec3::X)); // Hinge around the frame
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component, MapEntities)]
+pub struct Joint {
+    /// The other entity, which must have a [`RigidBody`](crate::RigidBody)
+    pub body: Entity,
+    /// Type of joint
+    pub kind: JointKind,
+    /// Axis of rotation (for a revolute joint) or of translation (for a prismatic joint)
+    ///
+    /// It is ignored by fixed joints, and by revolute joints in 2d.
+    pub axis: Vec3,
+    /// Anchor point in the local space of this entity's rigid body
+    pub local_anchor: Vec3,
+    /// Anchor point in the local space of the other rigid body
+    pub other_anchor: Vec3,
+}
+
+/// Type of [`Joint`]
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect, Serialize, Deserialize,
+)]
+#[reflect(Serialize, Deserialize)]
+pub enum JointKind {
+    /// Prevents any relative movement between the two bodies
+    Fixed,
+
+    /// Only allows the relative rotation around the joint axis
+    ///
+    /// It is well suited for doors and wheels.
+    Revolute,
+
+    /// Only allows the relative translation along the joint axis
+    ///
+    /// It is well suited for elevators and pistons.
+    Prismatic,
+}
+
+impl Joint {
+    /// Returns a fixed joint with the other entity
+    #[must_use]
+    pub fn fixed(body: Entity) -> Self {
+        Self::new(body, JointKind::Fixed, Vec3::Z)
+    }
+
+    /// Returns a revolute joint with the other entity, rotating around the `Z` axis
+    #[must_use]
+    pub fn revolute(body: Entity) -> Self {
+        Self::new(body, JointKind::Revolute, Vec3::Z)
+    }
+
+    /// Returns a prismatic joint with the other entity, translating along the given axis
+    #[must_use]
+    pub fn prismatic(body: Entity, axis: Vec3) -> Self {
+        Self::new(body, JointKind::Prismatic, axis)
+    }
+
+    fn new(body: Entity, kind: JointKind, axis: Vec3) -> Self {
+        Self {
+            body,
+            kind,
+            axis,
+            local_anchor: Vec3::ZERO,
+            other_anchor: Vec3::ZERO,
+        }
+    }
+
+    /// Returns a new version of this joint with the given axis
+    #[must_use]
+    pub fn with_axis(mut self, axis: Vec3) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Returns a new version of this joint with the given anchor in the local space of this
+    /// entity's rigid body
+    #[must_use]
+    pub fn with_local_anchor(mut self, anchor: Vec3) -> Self {
+        self.local_anchor = anchor;
+        self
+    }
+
+    /// Returns a new version of this joint with the given anchor in the local space of the other
+    /// rigid body
+    #[must_use]
+    pub fn with_other_anchor(mut self, anchor: Vec3) -> Self {
+        self.other_anchor = anchor;
+        self
+    }
+}
+
+impl FromWorld for Joint {
+    /// Placeholder used when spawning a scene, the actual value is applied right after
+    fn from_world(_world: &mut World) -> Self {
+        Self::fixed(Entity::from_raw(u32::MAX))
+    }
+}
+
+impl MapEntities for Joint {
+    fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+        // The other body may be outside of the scene, in which case it isn't mapped
+        if let Ok(body) = entity_map.get(self.body) {
+            self.body = body;
+        }
+        Ok(())
+    }
+}
+
+/// Component that connects the rigid body of this entity to the rigid body of another entity with a
+/// spring and a damper
+///
+/// Unlike a [`Joint`], it doesn't lock any relative movement of the bodies. The spring pulls the
+/// anchors toward each other when they are farther apart than the rest length, and pushes them
+/// apart when they are closer. The damper slows down the relative movement of the anchors. It is
+/// well suited for soft suspensions, bouncy bridges and objects that smoothly follow another one.
+///
+/// Both entities must have a [`RigidBody`](crate::RigidBody) in the same physics world. An entity
+/// can't have both a [`Joint`] and a [`SpringJoint`], in which case only the [`Joint`] is created.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands, player: Entity) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 0.5 })
+///         .insert(SpringJoint::new(player, 2.0, 50.0, 5.0)); // Floats 2 units away from the player
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component, MapEntities)]
+pub struct SpringJoint {
+    /// The other entity, which must have a [`RigidBody`](crate::RigidBody)
+    pub body: Entity,
+    /// Distance between the anchors at which the spring applies no force
+    pub rest_length: f32,
+    /// Force applied by the spring per unit of distance away from the rest length
+    pub stiffness: f32,
+    /// Force applied by the damper per unit of relative velocity of the anchors
+    pub damping: f32,
+    /// Anchor point in the local space of this entity's rigid body
+    pub local_anchor: Vec3,
+    /// Anchor point in the local space of the other rigid body
+    pub other_anchor: Vec3,
+}
+
+impl SpringJoint {
+    /// Returns a spring with the other entity
+    #[must_use]
+    pub fn new(body: Entity, rest_length: f32, stiffness: f32, damping: f32) -> Self {
+        Self {
+            body,
+            rest_length,
+            stiffness,
+            damping,
+            local_anchor: Vec3::ZERO,
+            other_anchor: Vec3::ZERO,
+        }
+    }
+
+    /// Returns a new version of this spring with the given anchor in the local space of this
+    /// entity's rigid body
+    #[must_use]
+    pub fn with_local_anchor(mut self, anchor: Vec3) -> Self {
+        self.local_anchor = anchor;
+        self
+    }
+
+    /// Returns a new version of this spring with the given anchor in the local space of the other
+    /// rigid body
+    #[must_use]
+    pub fn with_other_anchor(mut self, anchor: Vec3) -> Self {
+        self.other_anchor = anchor;
+        self
+    }
+}
+
+impl FromWorld for SpringJoint {
+    /// Placeholder used when spawning a scene, the actual value is applied right after
+    fn from_world(_world: &mut World) -> Self {
+        Self::new(Entity::from_raw(u32::MAX), 0.0, 0.0, 0.0)
+    }
+}
+
+impl MapEntities for SpringJoint {
+    fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+        // The other body may be outside of the scene, in which case it isn't mapped
+        if let Ok(body) = entity_map.get(self.body) {
+            self.body = body;
+        }
+        Ok(())
+    }
+}
+
+/// Marker component that welds the rigid body of this entity to the rigid body of its parent
+///
+/// A fixed joint is created between the two bodies, keeping their relative position and rotation
+/// at the time the joint is created. Unlike a kinematic child following its parent, the child
+/// body remains dynamic: it pushes and is pushed by the other bodies, and its mass is carried by
+/// the parent. It is well suited for the pieces of a ragdoll or a turret on a tank. The contacts
+/// between the two bodies are ignored.
+///
+/// The parent must have a [`RigidBody`](crate::RigidBody) in the same physics world. The joint is
+/// recreated when the entity is moved to another parent, and removed together with this component
+/// or the parent. An entity can't be attached to its parent and have a [`Joint`] or a
+/// [`SpringJoint`], in which case only the other joint is created.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(2.0, 1.0, 3.0), border_radius: None })
+///         .with_children(|tank| {
+///             tank.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///                 .insert(RigidBody::Dynamic)
+///                 .insert(CollisionShape::Sphere { radius: 0.5 })
+///                 .insert(AttachedToParent);
+///         });
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct AttachedToParent;
+
+/// Component that powers the [`Joint`] of the same entity
+///
+/// The motor drives the free axis of revolute and prismatic joints. It has no effect on fixed
+/// joints.
+///
+/// Positions are angles (in radians) for revolute joints and distances for prismatic joints.
+/// Velocities are expressed in the same unit per second.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands, frame: Entity) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(Joint::revolute(frame))
+///         .insert(JointMotor::velocity(std::f32::consts::PI, 1.0)); // Half a turn per second
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct JointMotor {
+    /// Position the motor tries to reach
+    pub target_position: f32,
+    /// Velocity the motor tries to reach
+    pub target_velocity: f32,
+    /// How strongly the motor pulls toward the target position
+    pub stiffness: f32,
+    /// How strongly the motor pulls toward the target velocity
+    pub damping: f32,
+    /// Maximum force the motor can apply
+    pub max_force: f32,
+}
+
+impl Default for JointMotor {
+    fn default() -> Self {
+        Self {
+            target_position: 0.0,
+            target_velocity: 0.0,
+            stiffness: 0.0,
+            damping: 0.0,
+            max_force: f32::MAX,
+        }
+    }
+}
+
+impl JointMotor {
+    /// Returns a motor that tries to reach the given velocity
+    ///
+    /// The `factor` defines how strongly the motor pulls toward the target velocity.
+    #[must_use]
+    pub fn velocity(target_velocity: f32, factor: f32) -> Self {
+        Self {
+            target_velocity,
+            damping: factor,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a motor that tries to reach the given position, like a spring
+    #[must_use]
+    pub fn position(target_position: f32, stiffness: f32, damping: f32) -> Self {
+        Self {
+            target_position,
+            stiffness,
+            damping,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a new version of this motor with the given maximum force
+    #[must_use]
+    pub fn with_max_force(mut self, max_force: f32) -> Self {
+        self.max_force = max_force;
+        self
+    }
+}
+
+/// Component that limits the free axis of the [`Joint`] of the same entity
+///
+/// The limits apply to the free axis of revolute and prismatic joints. They have no effect on fixed
+/// joints.
+///
+/// Like for the [`JointMotor`], positions are angles (in radians) for revolute joints and distances
+/// for prismatic joints. The angle of a revolute joint is zero when both bodies have the same
+/// rotation. The angles are limited to half a turn in 3d, and to a quarter turn in 2d, in both
+/// directions.
+///
+/// A missing `min` or `max` leaves the joint free in that direction. The default limits don't
+/// restrict the joint at all.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands, frame: Entity) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(1.0, 0.1, 0.0), border_radius: None })
+///         .insert(Joint::revolute(frame).with_local_anchor(Vec3::X))
+///         .insert(JointLimits::new(0.0, std::f32::consts::FRAC_PI_2)); // Opens up to a quarter turn
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct JointLimits {
+    /// Minimum position of the joint, if any
+    pub min: Option<f32>,
+    /// Maximum position of the joint, if any
+    pub max: Option<f32>,
+}
+
+impl JointLimits {
+    /// Returns limits between the given minimum and maximum positions
+    #[must_use]
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+
+    /// Returns limits allowing the joint to move up to the given distance (or angle) from zero, in
+    /// both directions
+    #[must_use]
+    pub fn symmetric(limit: f32) -> Self {
+        Self::new(-limit, limit)
+    }
+}
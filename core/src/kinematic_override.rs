@@ -0,0 +1,51 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that temporarily makes a [`RigidBody::Dynamic`](crate::RigidBody::Dynamic) body
+/// behave as a [`RigidBody::KinematicPositionBased`](crate::RigidBody::KinematicPositionBased) one
+///
+/// Unlike changing the [`RigidBody`](crate::RigidBody) component, the body isn't recreated, so
+/// that its colliders, joints and contacts are preserved. While the override is active, the body
+/// is moved by updating its `Transform` (or [`PhysicsPose`](crate::PhysicsPose)), which is
+/// convenient to take control of the actors during a cutscene.
+///
+/// When the component is removed, the body is dynamic again. By default, it keeps the velocity
+/// resulting from its last kinematic move, so that the hand-back to the physics is smooth.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # #[derive(Component)]
+/// # struct Actor;
+/// fn start_cutscene(mut commands: Commands, actors: Query<Entity, With<Actor>>) {
+///     for entity in actors.iter() {
+///         commands.entity(entity).insert(KinematicOverride::default());
+///     }
+/// }
+///
+/// fn end_cutscene(mut commands: Commands, actors: Query<Entity, With<Actor>>) {
+///     for entity in actors.iter() {
+///         commands.entity(entity).remove::<KinematicOverride>();
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct KinematicOverride {
+    /// Set to true to restore the velocity that the body had before the override, when the
+    /// override is removed
+    pub restore_velocity: bool,
+}
+
+impl KinematicOverride {
+    /// Returns an override that restores the velocity of the body when it is removed
+    #[must_use]
+    pub fn restoring_velocity() -> Self {
+        Self {
+            restore_velocity: true,
+        }
+    }
+}
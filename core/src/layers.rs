@@ -1,15 +1,34 @@
+#[cfg(feature = "layer-names")]
+use std::fmt;
+
 use bevy::{
-    ecs::component::Component,
+    ecs::{component::Component, reflect::ReflectComponent},
+    log::warn,
+    prelude::{Changed, Entity, Query},
     reflect::{FromReflect, Reflect},
 };
 
 /// Describes a collision layer
 ///
 /// It is recommended to implement it using the derive macro.
+///
+/// By default, the derive macro assigns the bit of its index to each variant. The bit can be set
+/// explicitly with `#[layer(bit = N)]`, so that the bits (and any saved data that uses them) stay
+/// stable when the enum changes. Variants can also be put in groups with
+/// `#[layer(group = "name")]`, for which a `NAME_MASK` constant is generated.
 #[allow(missing_docs)]
 pub trait PhysicsLayer: Sized {
     fn to_bits(&self) -> u32;
     fn all_bits() -> u32;
+
+    /// Returns the name of each layer, together with its bits
+    ///
+    /// It is implemented by the derive macro, and used to show the layer names in the `Debug`
+    /// output of [`CollisionLayers`] when the `layer-names` feature is enabled.
+    #[must_use]
+    fn layer_names() -> &'static [(u32, &'static str)] {
+        &[]
+    }
 }
 
 impl<T: PhysicsLayer> PhysicsLayer for &T {
@@ -20,6 +39,50 @@ impl<T: PhysicsLayer> PhysicsLayer for &T {
     fn all_bits() -> u32 {
         T::all_bits()
     }
+
+    fn layer_names() -> &'static [(u32, &'static str)] {
+        T::layer_names()
+    }
+}
+
+/// A [`PhysicsLayer`] made of raw bits, for projects that already define their layers as bitmask
+/// constants
+///
+/// Each bit set in the value is a layer, and all the 32 bits are valid layers. Prefer deriving
+/// [`PhysicsLayer`] on an enum otherwise, which gives names to the layers.
+///
+/// # Example
+///
+/// ```
+/// # use heron_core::*;
+/// const PLAYER: u32 = 1 << 0;
+/// const ENEMY: u32 = 1 << 1;
+/// const WORLD: u32 = 1 << 2;
+///
+/// let layers = CollisionLayers::none()
+///     .with_group(BitLayer(PLAYER))
+///     .with_mask(BitLayer(ENEMY | WORLD));
+///
+/// assert_eq!(layers.groups_bits(), PLAYER);
+/// assert_eq!(layers.masks_bits(), ENEMY | WORLD);
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct BitLayer(pub u32);
+
+impl PhysicsLayer for BitLayer {
+    fn to_bits(&self) -> u32 {
+        self.0
+    }
+
+    fn all_bits() -> u32 {
+        u32::MAX
+    }
+}
+
+impl From<u32> for BitLayer {
+    fn from(bits: u32) -> Self {
+        Self(bits)
+    }
 }
 
 /// Components that defines the collision layers of the collision shape.
@@ -74,18 +137,65 @@ impl<T: PhysicsLayer> PhysicsLayer for &T {
 ///         );
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+#[derive(Component, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+#[cfg_attr(not(feature = "layer-names"), derive(Debug))]
 pub struct CollisionLayers {
     groups: u32,
     masks: u32,
+    #[cfg(feature = "layer-names")]
+    #[reflect(ignore)]
+    names: LayerNameTable,
+}
+
+/// Names of the layers of the [`PhysicsLayer`] type used to build the [`CollisionLayers`]
+///
+/// It is ignored by the comparisons, since it doesn't change the behavior of the layers.
+#[cfg(feature = "layer-names")]
+#[derive(Debug, Copy, Clone, Default, Eq)]
+struct LayerNameTable(Option<&'static [(u32, &'static str)]>);
+
+#[cfg(feature = "layer-names")]
+impl PartialEq for LayerNameTable {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "layer-names")]
+impl fmt::Debug for CollisionLayers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("CollisionLayers");
+        match self.names.0 {
+            Some(names) => debug
+                .field("groups", &NamedBits(self.groups, names))
+                .field("masks", &NamedBits(self.masks, names)),
+            None => debug
+                .field("groups", &self.groups)
+                .field("masks", &self.masks),
+        }
+        .finish()
+    }
+}
+
+/// Shows the names of the layers contained in the bits
+#[cfg(feature = "layer-names")]
+struct NamedBits(u32, &'static [(u32, &'static str)]);
+
+#[cfg(feature = "layer-names")]
+impl fmt::Debug for NamedBits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for (_, name) in self.1.iter().filter(|(bits, _)| self.0 & bits != 0) {
+            list.entry(&format_args!("{name}"));
+        }
+        list.finish()
+    }
 }
 
 impl Default for CollisionLayers {
     fn default() -> Self {
-        Self {
-            groups: 0xffff_ffff,
-            masks: 0xffff_ffff,
-        }
+        Self::from_bits(0xffff_ffff, 0xffff_ffff)
     }
 }
 
@@ -95,7 +205,7 @@ impl CollisionLayers {
     /// You may add more groups and mask with `with_group` and `with_mask`.
     #[must_use]
     pub fn new<L: PhysicsLayer>(group: L, mask: L) -> Self {
-        Self::from_bits(group.to_bits(), mask.to_bits())
+        Self::from_bits(group.to_bits(), mask.to_bits()).named::<L>()
     }
 
     /// Contains all groups and masks
@@ -104,7 +214,7 @@ impl CollisionLayers {
     /// nothing).
     #[must_use]
     pub fn all<L: PhysicsLayer>() -> Self {
-        Self::from_bits(L::all_bits(), L::all_bits())
+        Self::from_bits(L::all_bits(), L::all_bits()).named::<L>()
     }
 
     /// Contains all groups and no masks
@@ -113,7 +223,7 @@ impl CollisionLayers {
     /// can also exclude specific groups using [`CollisionLayers::without_group`].
     #[must_use]
     pub fn all_groups<L: PhysicsLayer>() -> Self {
-        Self::from_bits(L::all_bits(), 0)
+        Self::from_bits(L::all_bits(), 0).named::<L>()
     }
 
     /// Contains no groups and all masks
@@ -122,7 +232,7 @@ impl CollisionLayers {
     /// can also exclude specific masks using [`CollisionLayers::without_mask`].
     #[must_use]
     pub fn all_masks<L: PhysicsLayer>() -> Self {
-        Self::from_bits(0, L::all_bits())
+        Self::from_bits(0, L::all_bits()).named::<L>()
     }
 
     /// Contains no masks and groups
@@ -136,7 +246,32 @@ impl CollisionLayers {
     #[must_use]
     #[allow(missing_docs)]
     pub const fn from_bits(groups: u32, masks: u32) -> Self {
-        Self { groups, masks }
+        Self {
+            groups,
+            masks,
+            #[cfg(feature = "layer-names")]
+            names: LayerNameTable(None),
+        }
+    }
+
+    /// Remembers the names of the layers of `L`, so that they can be shown in the `Debug` output
+    #[cfg_attr(
+        not(feature = "layer-names"),
+        allow(clippy::extra_unused_type_parameters)
+    )]
+    fn named<L: PhysicsLayer>(self) -> Self {
+        #[cfg(feature = "layer-names")]
+        if self.names.0.is_none() && !L::layer_names().is_empty() {
+            return Self {
+                names: LayerNameTable(Some(L::layer_names())),
+                ..self
+            };
+        }
+        self
+    }
+
+    fn named_like<L: PhysicsLayer>(self, _layer: &L) -> Self {
+        self.named::<L>()
     }
 
     /// Returns true if the entity would interact with an entity containing the `other` [`CollisionLayers]`
@@ -155,14 +290,15 @@ impl CollisionLayers {
     #[must_use]
     pub fn with_group(mut self, layer: impl PhysicsLayer) -> Self {
         self.groups |= layer.to_bits();
-        self
+        self.named_like(&layer)
     }
 
     /// Add the given layers in the "groups"
     #[must_use]
     pub fn with_groups(mut self, layers: impl IntoIterator<Item = impl PhysicsLayer>) -> Self {
-        for layer in layers.into_iter().map(|l| l.to_bits()) {
-            self.groups |= layer;
+        for layer in layers {
+            self.groups |= layer.to_bits();
+            self = self.named_like(&layer);
         }
 
         self
@@ -172,7 +308,7 @@ impl CollisionLayers {
     #[must_use]
     pub fn without_group(mut self, layer: impl PhysicsLayer) -> Self {
         self.groups &= !layer.to_bits();
-        self
+        self.named_like(&layer)
     }
 
     /// Returns true if the given layer is contained in the "masks"
@@ -185,14 +321,15 @@ impl CollisionLayers {
     #[must_use]
     pub fn with_mask(mut self, layer: impl PhysicsLayer) -> Self {
         self.masks |= layer.to_bits();
-        self
+        self.named_like(&layer)
     }
 
     /// Add the given layers in the "masks"
     #[must_use]
     pub fn with_masks(mut self, layers: impl IntoIterator<Item = impl PhysicsLayer>) -> Self {
-        for layer in layers.into_iter().map(|l| l.to_bits()) {
-            self.masks |= layer;
+        for layer in layers {
+            self.masks |= layer.to_bits();
+            self = self.named_like(&layer);
         }
 
         self
@@ -202,7 +339,7 @@ impl CollisionLayers {
     #[must_use]
     pub fn without_mask(mut self, layer: impl PhysicsLayer) -> Self {
         self.masks &= !layer.to_bits();
-        self
+        self.named_like(&layer)
     }
 
     #[must_use]
@@ -218,6 +355,21 @@ impl CollisionLayers {
     }
 }
 
+/// Warns about the [`CollisionLayers`] that have neither groups nor masks, since they cannot
+/// interact with anything
+pub(crate) fn warn_empty_layers_system(
+    layers: Query<'_, '_, (Entity, &CollisionLayers), Changed<CollisionLayers>>,
+) {
+    for (entity, layers) in layers.iter() {
+        if layers.groups == 0 && layers.masks == 0 {
+            warn!(
+                "The collision layers of {:?} have neither groups nor masks, it will not interact with anything",
+                entity
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -240,6 +392,35 @@ mod tests {
         fn all_bits() -> u32 {
             3
         }
+
+        fn layer_names() -> &'static [(u32, &'static str)] {
+            &[(1, "One"), (2, "Two")]
+        }
+    }
+
+    #[test]
+    fn layer_names_are_ignored_by_equality() {
+        assert_eq!(
+            CollisionLayers::new(TestLayer::One, TestLayer::Two),
+            CollisionLayers::from_bits(1, 2)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "layer-names")]
+    fn debug_shows_layer_names() {
+        let layers = CollisionLayers::none()
+            .with_groups([TestLayer::One, TestLayer::Two])
+            .with_mask(TestLayer::Two);
+
+        assert_eq!(
+            format!("{layers:?}"),
+            "CollisionLayers { groups: [One, Two], masks: [Two] }"
+        );
+        assert_eq!(
+            format!("{:?}", CollisionLayers::from_bits(1, 2)),
+            "CollisionLayers { groups: 1, masks: 2 }"
+        );
     }
 
     #[test]
@@ -284,6 +465,15 @@ mod tests {
         assert!(!c2.interacts_with(c2));
     }
 
+    #[test]
+    fn bit_layer_uses_the_raw_bits() {
+        let layers = CollisionLayers::all_masks::<BitLayer>().with_group(BitLayer(0b101));
+        assert_eq!(layers.groups_bits(), 0b101);
+        assert_eq!(layers.masks_bits(), u32::MAX);
+        assert!(layers.contains_group(BitLayer(0b100)));
+        assert!(!layers.contains_group(BitLayer(0b010)));
+    }
+
     #[test]
     fn without_layer_removes_interaction() {
         let c1 = CollisionLayers::all::<TestLayer>()
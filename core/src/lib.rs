@@ -1,6 +1,19 @@
 #![deny(future_incompatible, nonstandard_style)]
 #![warn(missing_docs, rust_2018_idioms, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions, clippy::needless_pass_by_value)]
+// Lints introduced by newer toolchains, that the existing tests predate
+#![cfg_attr(
+    test,
+    allow(
+        clippy::float_cmp,
+        clippy::items_after_statements,
+        clippy::default_constructed_unit_structs,
+        clippy::should_panic_without_expect,
+        clippy::uninlined_format_args
+    )
+)]
+// The examples spawn a `todo!()` bundle, whose type is inferred from the never type fallback
+#![doc(test(attr(allow(dependency_on_unit_never_type_fallback))))]
 
 //! Core components and resources to use Heron
 
@@ -9,32 +22,122 @@ use std::sync::Arc;
 
 use bevy::ecs::schedule::ShouldRun;
 use bevy::prelude::*;
+use bevy::reflect::{
+    FromReflect, GetTypeRegistration, ReflectDeserialize, ReflectSerialize, TypeRegistryArc,
+};
+use serde::{Deserialize, Serialize};
 
+pub use aabb::ColliderAabb;
+pub use active_collisions::ActiveCollisions;
+pub use body_info::PhysicsBodyInfo;
+pub use character::CharacterBundle;
 #[cfg(feature = "collision-from-mesh")]
 pub use collision_from_mesh::PendingConvexCollision;
 pub use collisions::Collisions;
-pub use constraints::RotationConstraints;
-pub use events::{CollisionData, CollisionEvent};
-pub use gravity::Gravity;
-pub use layers::{CollisionLayers, PhysicsLayer};
-pub use physics_time::PhysicsTime;
-pub use step::{PhysicsStepDuration, PhysicsSteps};
-pub use velocity::{Acceleration, AxisAngle, Damping, Velocity};
+pub use constraints::{RotationConstraints, VelocityConstraint};
+#[cfg(feature = "collider-debug-info")]
+pub use debug_info::{ColliderDebugInfo, LayerNames};
+#[cfg(feature = "debug-mesh")]
+pub use debug_mesh::DebugMeshMode;
+pub use diagnostics::{PhysicsDiagnostics, PhysicsDiagnosticsPlugin};
+pub use dimension::PhysicsDimension;
+pub use disabled_contacts::{DisabledContacts, NoSelfCollision};
+pub use events::{
+    ColliderCreationFailed, ColliderCreationFailure, CollisionData, CollisionEvent,
+    PhysicsStepEvent, StableId,
+};
+pub use fluid::FluidArea;
+pub use gravity::{DisableGravity, Gravity, GravityArea, GravityDirection, GravityScale};
+pub use ground::GroundDetection;
+#[cfg(feature = "impact-sounds")]
+pub use impact_sounds::{ImpactSound, ImpactSounds, ImpactSoundsPlugin, SurfaceTag};
+pub use interpolation::Interpolate;
+pub use joints::{AttachedToParent, Joint, JointKind, JointLimits, JointMotor, SpringJoint};
+pub use kinematic_override::KinematicOverride;
+pub use layers::{BitLayer, CollisionLayers, PhysicsLayer};
+pub use local_pose::ShapeLocalPose;
+#[cfg(feature = "material-library")]
+pub use material_library::{MaterialLibrary, MaterialLibraryLoader, MaterialLibraryPlugin};
+pub use movement::Movement;
+pub use network_id::NetworkId;
+pub use offset::ColliderOffset;
+pub use physics_time::{PhysicsTime, VelocityTimeSpace};
+pub use pixel_snap::PixelSnap;
+pub use platformer::PlatformerController;
+pub use pose::PhysicsPose;
+pub use ragdoll::{Ragdoll, RagdollState};
+pub use sensor_events::SensorEvents;
+pub use shape_lod::{ShapeLod, ShapeLodReference};
+pub use step::{PhysicsStepDuration, PhysicsStepRequest, PhysicsSteps};
+pub use teleport::{KinematicTeleportThreshold, Teleport};
+pub use top_down::TopDownController;
+pub use velocity::{
+    Acceleration, AxisAngle, Ccd, Damping, Force, SleepHint, SurfaceVelocity, Velocity,
+    VelocityTolerance,
+};
+pub use watch::WatchCollisionsWith;
+pub use worlds::{PhysicsWorldId, PhysicsWorldSettings, WorldSettings};
+pub use zones::{ZoneDetector, ZoneEnter, ZoneExit};
 
+mod aabb;
+mod active_collisions;
+mod body_info;
+mod character;
 #[cfg(feature = "collision-from-mesh")]
 mod collision_from_mesh;
 mod collisions;
 mod constraints;
+#[cfg(feature = "collider-debug-info")]
+mod debug_info;
+#[cfg(feature = "debug-mesh")]
+mod debug_mesh;
+mod diagnostics;
+mod dimension;
+mod disabled_contacts;
 mod events;
+mod fluid;
 mod gravity;
+mod ground;
+#[cfg(feature = "impact-sounds")]
+mod impact_sounds;
+mod interpolation;
+mod joints;
+mod kinematic_override;
 mod layers;
+mod local_pose;
+#[cfg(feature = "material-library")]
+mod material_library;
+mod movement;
+mod network_id;
+mod offset;
 mod physics_time;
+mod pixel_snap;
+mod platformer;
+mod pose;
+mod ragdoll;
+mod sensor_events;
+mod shape_2d;
+mod shape_data;
+mod shape_lod;
 mod step;
+mod teleport;
+mod top_down;
 pub mod utils;
+#[cfg(feature = "vehicle")]
+pub mod vehicle;
 mod velocity;
+mod watch;
+mod worlds;
+mod zones;
 
+/// Names of the stages formerly used to run the physics systems
+///
+/// The [`CorePlugin`] still adds these stages (right before `CoreStage::PostUpdate`), so that the
+/// code adding systems to them keeps working, but they are empty. The physics systems run in
+/// `CoreStage::PostUpdate`, labeled with [`PhysicsSystem`].
 #[deprecated(
-    note = "Physics system can be added to the bevy update stage. Use bevy's add_system instead."
+    note = "Physics system can be added to the bevy update stage. Use bevy's add_system instead, \
+    and the `PhysicsSystem` labels to order the systems relative to the physics."
 )]
 #[doc(hidden)]
 pub mod stage {
@@ -44,17 +147,178 @@ pub mod stage {
 
 /// Physics system labels
 ///
-/// The systems run during the bevy `CoreStage::PostUpdate` stage
+/// The physics backend runs its systems in this order:
+///
+/// 1. [`Removal`](Self::Removal), [`WorldUpdate`](Self::WorldUpdate),
+///    [`BodyCreation`](Self::BodyCreation), [`ColliderCreation`](Self::ColliderCreation),
+///    [`JointCreation`](Self::JointCreation) and then
+///    [`ContactFilterUpdate`](Self::ContactFilterUpdate), one after the other in the bevy
+///    `CoreStage::PostUpdate` stage. The commands of these systems (like the insertion of the
+///    handle components) are applied at the end of the stage, but the objects created by a set are
+///    already in the physics world when the next set runs.
+/// 2. [`PreStep`](Self::PreStep), [`Step`](Self::Step) and [`PostStep`](Self::PostStep), in the
+///    `CoreStage::PostUpdate` stage. A user system of the `PostUpdate` stage can be labeled
+///    `.after(PhysicsSystem::PreStep).before(PhysicsSystem::Step)` to apply forces or velocity
+///    changes just before the step, or `.after(PhysicsSystem::Step)` to read its results.
+/// 3. [`VelocityUpdate`](Self::VelocityUpdate) and [`TransformUpdate`](Self::TransformUpdate),
+///    which run in the stage defined by the [`PhysicsWriteBack`] resource (`PostUpdate` by
+///    default, after the step).
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemLabel)]
 pub enum PhysicsSystem {
     /// System that update the [`Velocity`] component to reflect the velocity in the physics world
     VelocityUpdate,
 
     /// System that update the bevy `Transform` component to reflect the velocity in the physics world
+    ///
+    /// When running in `CoreStage::PostUpdate`, it runs before the transform propagation.
     TransformUpdate,
 
     /// System that emits collision events
+    ///
+    /// It is the physics step itself, and has the same systems as [`Step`](Self::Step).
     Events,
+
+    /// Systems that remove the rigid bodies, colliders and joints whose components were removed
+    ///
+    /// Unlike the other physics systems, they also run in the frames without physics step.
+    Removal,
+
+    /// Systems that update the existing rigid bodies and colliders from their components
+    /// (position, velocity, damping, collision layers...)
+    WorldUpdate,
+
+    /// System that creates the rigid bodies of the new [`RigidBody`] components
+    BodyCreation,
+
+    /// System that creates the colliders of the new [`CollisionShape`] components
+    ColliderCreation,
+
+    /// Systems that create the new [`Joint`] and [`SpringJoint`] constraints
+    JointCreation,
+
+    /// Systems that update the contacts ignored by the physics step, like the ones within a
+    /// [`NoSelfCollision`] hierarchy
+    ContactFilterUpdate,
+
+    /// Systems that prepare the physics step, like the character controllers and custom forces
+    PreStep,
+
+    /// Systems that step the physics worlds
+    Step,
+
+    /// Systems that update the components derived from the result of the step, like
+    /// [`ColliderAabb`], [`GroundDetection`] and [`Collisions`]
+    PostStep,
+}
+
+/// Resource that defines when the results of a physics step are written back to the [`Velocity`]
+/// and `Transform` components
+///
+/// It is read when the physics plugin is added, and must therefore be inserted before.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_core::PhysicsWriteBack;
+/// App::new()
+///     // Write back the physics results at the beginning of the next frame.
+///     // It must be inserted before adding the physics plugin.
+///     .insert_resource(PhysicsWriteBack::PreUpdate)
+///     // ...
+///     .run();
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum PhysicsWriteBack {
+    /// Write back during `CoreStage::PostUpdate`, right after the physics step (default)
+    ///
+    /// The systems are labeled [`PhysicsSystem::VelocityUpdate`] and
+    /// [`PhysicsSystem::TransformUpdate`], so that the user systems of the `PostUpdate` stage can
+    /// run after them.
+    PostUpdate,
+
+    /// Write back during `CoreStage::PreUpdate` of the next frame
+    ///
+    /// The positions and velocities are then up-to-date for all the user systems of the
+    /// `Update` and `PostUpdate` stages. Note that the `GlobalTransform` of the children of the
+    /// rigid bodies is only updated during the transform propagation of `CoreStage::PostUpdate`.
+    PreUpdate,
+}
+
+impl Default for PhysicsWriteBack {
+    fn default() -> Self {
+        Self::PostUpdate
+    }
+}
+
+/// Resource that defines what to do with the [`RigidBody`] entities that lack a `Transform` or a
+/// `GlobalTransform`
+///
+/// A rigid body isn't created in the physics world until its entity has a `GlobalTransform`. A
+/// rigid body without a `Transform` is still simulated, and the result of the simulation is then
+/// only written back to its `GlobalTransform` (or to its [`PhysicsPose`]).
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_core::MissingTransforms;
+/// App::new()
+///     // Insert the default transforms instead of warning
+///     .insert_resource(MissingTransforms::Insert)
+///     // ...
+///     .run();
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum MissingTransforms {
+    /// Log a warning with the entity id, once per entity that has no `GlobalTransform` (default)
+    Warn,
+
+    /// Insert the missing components
+    ///
+    /// The missing `Transform` is computed from the `GlobalTransform` (if any), and the missing
+    /// `GlobalTransform` from the `Transform` (if any), relative to the `GlobalTransform` of the
+    /// parent entity. Otherwise, the identity is used.
+    Insert,
+
+    /// Do nothing
+    Ignore,
+}
+
+impl Default for MissingTransforms {
+    fn default() -> Self {
+        Self::Warn
+    }
+}
+
+/// Resource that defines what to do with the [`CollisionShape`] entities that have no [`RigidBody`],
+/// neither on the same entity nor on the parent entity
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_core::MissingRigidBody;
+/// App::new()
+///     // Collision shapes alone are static scenery
+///     .insert_resource(MissingRigidBody::InsertStatic)
+///     // ...
+///     .run();
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum MissingRigidBody {
+    /// Don't create the collider, and send a [`ColliderCreationFailed`] event, once per entity
+    /// (default)
+    Report,
+
+    /// Insert a [`RigidBody::Static`] on the collision shape entity, and log a warning
+    InsertStatic,
+}
+
+impl Default for MissingRigidBody {
+    fn default() -> Self {
+        Self::Report
+    }
 }
 
 /// Plugin that registers stage resources and components.
@@ -69,29 +333,166 @@ impl Plugin for CorePlugin {
         app.init_resource::<Gravity>()
             .init_resource::<PhysicsTime>()
             .init_resource::<PhysicsSteps>()
-            .register_type::<CollisionShape>()
-            .register_type::<RigidBody>()
-            .register_type::<PhysicMaterial>()
-            .register_type::<Velocity>()
-            .register_type::<Acceleration>()
-            .register_type::<Damping>()
-            .register_type::<RotationConstraints>()
-            .register_type::<CollisionLayers>()
-            .register_type::<SensorShape>()
-            .register_type::<Collisions>()
+            .add_event::<PhysicsStepRequest>()
+            .add_event::<ZoneEnter>()
+            .add_event::<ZoneExit>()
+            .init_resource::<PhysicsDiagnostics>()
+            .init_resource::<PhysicsWorldSettings>()
+            .init_resource::<VelocityTolerance>()
+            .init_resource::<KinematicTeleportThreshold>()
+            .init_resource::<PhysicsWriteBack>()
+            .init_resource::<VelocityTimeSpace>()
+            .init_resource::<MissingTransforms>()
+            .init_resource::<MissingRigidBody>()
+            .init_resource::<DisabledContacts>()
             .add_system(collisions::update_collisions_system)
+            .add_system(sensor_events::update_sensor_events_system)
+            .add_system(zones::update_zone_events_system)
+            .add_system(watch::update_watched_collisions_system)
+            .add_system(layers::warn_empty_layers_system)
+            .add_system(shape_lod::update_shape_lod_system)
             .add_system_to_stage(CoreStage::PostUpdate, collisions::cleanup_collisions_system)
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                watch::cleanup_watched_collisions_system,
+            )
             .add_system_to_stage(CoreStage::First, PhysicsSteps::update)
             .add_stage_before(CoreStage::PostUpdate, crate::stage::ROOT, {
                 Schedule::default().with_stage(crate::stage::UPDATE, SystemStage::parallel())
             });
 
+        register_component_types(app);
+        register_resource_and_event_types(app);
+
+        // Optional fields of the components, made serializable so that they can be saved in scenes
+        register_serializable_value::<Option<f32>>(app);
+        register_serializable_value::<Option<Quat>>(app);
+
         #[cfg(feature = "collision-from-mesh")]
         app.register_type::<PendingConvexCollision>()
             .add_system(collision_from_mesh::pending_collision_system);
+
+        #[cfg(feature = "debug-mesh")]
+        app.register_type::<DebugMeshMode>();
+
+        #[cfg(feature = "impact-sounds")]
+        app.register_type::<SurfaceTag>();
+
+        #[cfg(feature = "collider-debug-info")]
+        app.init_resource::<LayerNames>()
+            .register_type::<ColliderDebugInfo>()
+            .register_type::<LayerNames>();
+
+        #[cfg(feature = "vehicle")]
+        app.register_type::<vehicle::RaycastVehicle>()
+            .register_type::<vehicle::Wheel>()
+            .register_type::<vehicle::Suspension>()
+            .register_type::<vehicle::WheelContacts>()
+            .register_type::<vehicle::WheelContact>();
     }
 }
 
+/// Register the reflected component types, so that they can be saved in scenes
+#[allow(deprecated)]
+fn register_component_types(app: &mut App) {
+    app.register_type::<CollisionShape>()
+        .register_type::<RigidBody>()
+        .register_type::<PhysicMaterial>()
+        .register_type::<Velocity>()
+        .register_type::<Acceleration>()
+        .register_type::<Force>()
+        .register_type::<Damping>()
+        .register_type::<Ccd>()
+        .register_type::<SurfaceVelocity>()
+        .register_type::<SleepHint>()
+        .register_type::<NetworkId>()
+        .register_type::<RotationConstraints>()
+        .register_type::<VelocityConstraint>()
+        .register_type::<CollisionLayers>()
+        .register_type::<ActiveCollisions>()
+        .register_type::<SensorShape>()
+        .register_type::<SensorMass>()
+        .register_type::<ColliderMass>()
+        .register_type::<SolidShape>()
+        .register_type::<Sensor>()
+        .register_type::<PhysicsDisabled>()
+        .register_type::<ColliderOffset>()
+        .register_type::<PhysicsPose>()
+        .register_type::<Interpolate>()
+        .register_type::<Teleport>()
+        .register_type::<KinematicOverride>()
+        .register_type::<PixelSnap>()
+        .register_type::<Joint>()
+        .register_type::<JointKind>()
+        .register_type::<JointMotor>()
+        .register_type::<JointLimits>()
+        .register_type::<SpringJoint>()
+        .register_type::<AttachedToParent>()
+        .register_type::<Ragdoll>()
+        .register_type::<NoSelfCollision>()
+        .register_type::<RagdollState>()
+        .register_type::<FluidArea>()
+        .register_type::<GravityArea>()
+        .register_type::<GravityDirection>()
+        .register_type::<DisableGravity>()
+        .register_type::<GravityScale>()
+        .register_type::<ShapeLod>()
+        .register_type::<ShapeLodReference>()
+        .register_type::<Collisions>()
+        .register_type::<ColliderAabb>()
+        .register_type::<PhysicsBodyInfo>()
+        .register_type::<ShapeLocalPose>()
+        .register_type::<SensorEvents>()
+        .register_type::<ZoneDetector>()
+        .register_type::<GroundDetection>()
+        .register_type::<TopDownController>()
+        .register_type::<PlatformerController>()
+        .register_type::<Movement>()
+        .register_type::<WatchCollisionsWith>()
+        .register_type::<PhysicsWorldId>()
+        .register_type::<AxisAngle>();
+}
+
+/// Register the reflected resource and event types
+fn register_resource_and_event_types(app: &mut App) {
+    app.register_type::<Gravity>()
+        .register_type::<PhysicsSteps>()
+        .register_type::<PhysicsTime>()
+        .register_type::<PhysicsDiagnostics>()
+        .register_type::<PhysicsDimension>()
+        .register_type::<PhysicsWorldSettings>()
+        .register_type::<WorldSettings>()
+        .register_type::<VelocityTolerance>()
+        .register_type::<KinematicTeleportThreshold>()
+        .register_type::<PhysicsWriteBack>()
+        .register_type::<VelocityTimeSpace>()
+        .register_type::<MissingTransforms>()
+        .register_type::<MissingRigidBody>()
+        .register_type::<CollisionEvent>()
+        .register_type::<CollisionData>()
+        .register_type::<StableId>()
+        .register_type::<PhysicsStepEvent>()
+        .register_type::<PhysicsStepRequest>()
+        .register_type::<PhysicsStepDuration>()
+        .register_type::<ColliderCreationFailed>()
+        .register_type::<ColliderCreationFailure>()
+        .register_type::<InvalidCollisionShape>()
+        .register_type::<ZoneEnter>()
+        .register_type::<ZoneExit>();
+}
+
+/// Register a reflected value type, including its serialization (which bevy doesn't register
+/// for all the value types, like [`Option`])
+fn register_serializable_value<T>(app: &mut App)
+where
+    T: Reflect + GetTypeRegistration + Serialize + for<'de> Deserialize<'de>,
+{
+    app.register_type::<T>();
+    let mut registry = app.world.resource::<TypeRegistryArc>().write();
+    registry.register_type_data::<T, ReflectSerialize>();
+    registry.register_type_data::<T, ReflectDeserialize>();
+}
+
 /// Run criteria system that decides if the physics systems should run.
 #[must_use]
 pub fn should_run(
@@ -126,6 +527,12 @@ impl CustomCollisionShape {
     pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
         self.0.downcast_ref()
     }
+
+    /// Returns the name of the type of the stored value
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.1
+    }
 }
 
 impl core::fmt::Debug for CustomCollisionShape {
@@ -153,7 +560,8 @@ impl core::fmt::Debug for CustomCollisionShape {
 ///         .insert(CollisionShape::Sphere { radius: 1.0 }); // Attach a collision shape
 /// }
 /// ```
-#[derive(Debug, Clone, Component, Reflect)]
+#[derive(Debug, Clone, Component, Reflect, FromReflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum CollisionShape {
     /// A sphere (or circle in 2d) shape defined by its radius
@@ -195,6 +603,29 @@ pub enum CollisionShape {
         border_radius: Option<f32>,
     },
 
+    /// A convex polygon/polyhedron shape, whose points already form a convex hull
+    ///
+    /// Unlike [`ConvexHull`](CollisionShape::ConvexHull), the hull is not computed when creating
+    /// the collider. It is faster to build, and the geometry is used exactly as given, which is
+    /// useful when the hull was generated offline. It is up to the user to make sure the shape is
+    /// actually convex, as the collision detection is wrong otherwise.
+    ConvexPolyhedron {
+        /// The vertices of the hull
+        ///
+        /// In 2d, the `z` coordinate is ignored, and the vertices must be given in
+        /// counter-clockwise order.
+        points: Vec<Vec3>,
+        /// The triangles of the hull surface, as indices into the `points`
+        ///
+        /// In 2d, the indices are ignored.
+        indices: Vec<[u32; 3]>,
+        /// An optional border radius that will be used to round the corners of the hull
+        ///
+        /// This radius refers to how much to _add_ to the existing size of the hull, creating an
+        /// extra buffer around the un-rounded mesh.
+        border_radius: Option<f32>,
+    },
+
     /// A shape defined by the height of points.
     ///
     /// This shape is usefull for floors with relief.
@@ -235,10 +666,33 @@ pub enum CollisionShape {
         radius: f32,
     },
 
+    /// A triangle shape defined by its three vertices
+    ///
+    /// This shape is exclusive to the 2d API, you must enable the "2d" flag to use it.
+    /// It is cheaper to build than the equivalent [`ConvexHull`](CollisionShape::ConvexHull)
+    /// made of three points.
+    #[cfg(dim2)]
+    Triangle {
+        /// First vertex of the triangle
+        a: Vec2,
+        /// Second vertex of the triangle
+        b: Vec2,
+        /// Third vertex of the triangle
+        c: Vec2,
+        /// An optional border radius that will be used to round the corners of the triangle
+        ///
+        /// This radius refers to how much to _add_ to the existing size of the triangle, creating
+        /// an extra buffer around the un-rounded shape.
+        border_radius: Option<f32>,
+    },
+
     /// A Custom shape, the actual shape is abstracted, and will be determined
     /// by a corresponding backend depending on the implementation details
     ///
     /// See [`CustomCollisionShape`] for more info.
+    ///
+    /// This shape cannot be serialized (in a scene for instance).
+    #[serde(skip)]
     Custom {
         /// The custom collision shape to use
         shape: CustomCollisionShape,
@@ -251,6 +705,27 @@ impl Default for CollisionShape {
     }
 }
 
+/// Marker component inserted by the physics backend on the entities whose [`CollisionShape`] is
+/// invalid (like a degenerate [`ConvexHull`](CollisionShape::ConvexHull))
+///
+/// No collider is created for such a shape. An error is logged and a [`ColliderCreationFailed`]
+/// event is fired with the reason. The marker is removed once the shape is fixed (or removed).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn highlight_invalid_shapes(shapes: Query<Entity, Added<InvalidCollisionShape>>) {
+///     for entity in shapes.iter() {
+///         println!("The collision shape of {:?} is invalid", entity);
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct InvalidCollisionShape;
+
 /// Component that mark the entity as being a rigid body
 ///
 /// It'll need some [`CollisionShape`] to be attached. Either in the same entity or in a direct child
@@ -266,7 +741,10 @@ impl Default for CollisionShape {
 ///         .insert(CollisionShape::Sphere { radius: 1.0 }); // Attach a collision shape
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, Eq, PartialEq, Reflect)]
+#[derive(
+    Debug, Component, Copy, Clone, Eq, PartialEq, Reflect, FromReflect, Serialize, Deserialize,
+)]
+#[reflect(Component, Serialize, Deserialize)]
 pub enum RigidBody {
     /// A dynamic body is normally affected by physic forces and affect the other bodies normally too.
     ///
@@ -308,6 +786,13 @@ pub enum RigidBody {
     ///
     /// A sensor is useful when we are only interested in collision events.
     /// One may, for example, add a sensor to detect when the player reaches a certain area.
+    ///
+    /// # Migration
+    ///
+    /// This body type is deprecated in favor of the [`Sensor`](crate::Sensor) component, which can
+    /// be combined with any body type. Replace `RigidBody::Sensor` by `RigidBody::Static` and insert
+    /// the [`Sensor`](crate::Sensor) component to get the same behavior.
+    #[deprecated(note = "Use `RigidBody::Static` combined with the `Sensor` component instead")]
     Sensor,
 }
 
@@ -320,6 +805,7 @@ impl Default for RigidBody {
 impl RigidBody {
     /// Returns true if this body type can be moved by [`Velocity`]
     #[must_use]
+    #[allow(deprecated)]
     pub fn can_have_velocity(self) -> bool {
         match self {
             RigidBody::Dynamic | RigidBody::KinematicVelocityBased => true,
@@ -330,9 +816,12 @@ impl RigidBody {
 
 /// Mark the [`CollisionShape`] of the same entity as being a *sensor*.
 ///
-/// This is especially useful to add sensor to an existing (non-sensor) rigid body without the need to create a [`RigidBody::Sensor`] in between.
+/// This is especially useful to add sensor to an existing (non-sensor) rigid body without the need to create a [`Sensor`] body in between.
+///
+/// It has no effect if the concerned rigid body is already a [`Sensor`].
 ///
-/// It has no effect if the concerned rigid body is already a [`RigidBody::Sensor`].
+/// The sensor shape doesn't contribute to the mass of the rigid body, unless a [`SensorMass`] is
+/// inserted as well.
 ///
 /// # Example
 ///
@@ -358,13 +847,195 @@ impl RigidBody {
 ///     });
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, Default, Reflect)]
+#[derive(Debug, Component, Copy, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
 pub struct SensorShape;
 
+/// Component that makes a [`SensorShape`] contribute the given mass to its rigid body
+///
+/// By default, the sensor shapes don't change the mass of their rigid body. This component can be
+/// inserted next to the [`SensorShape`] when the sensor represents something with a weight, like
+/// the cargo carried by a vehicle. The mass is distributed uniformly in the collision shape.
+///
+/// It has no effect without a [`SensorShape`] on the same entity. The collision shapes of a
+/// [`Sensor`] body use the density of the [`PhysicMaterial`] as usual.
+///
+/// # Example
+///
+/// ```
+/// # use heron_core::*;
+/// # use bevy::prelude::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .with_children(|children| {
+///             children.spawn_bundle((
+///                 CollisionShape::Sphere { radius: 1.0 },
+///                 SensorShape,
+///                 SensorMass(50.0), // The cargo weighs 50 units of mass
+///                 Transform::default(),
+///                 GlobalTransform::default(),
+///             ));
+///         });
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct SensorMass(pub f32);
+
+/// Component that overrides the mass that the [`CollisionShape`] of the same entity contributes to
+/// its rigid body, independently of the density of the [`PhysicMaterial`]
+///
+/// It allows to tune the mass of the bodies made of many collision shapes, for instance by making
+/// the decorative parts massless while a single shape defines the mass of the body.
+///
+/// It takes precedence over the [`SensorMass`], except for [`ColliderMass::Material`].
+///
+/// The default is [`ColliderMass::Material`], which keeps the mass derived from the density of the
+/// [`PhysicMaterial`], as if the component wasn't inserted. So that the shapes spawned with a
+/// default value (like in a scene, or with `..Default::default()`) don't silently become massless.
+///
+/// # Example
+///
+/// ```
+/// # use heron_core::*;
+/// # use bevy::prelude::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ColliderMass::Mass(10.0)) // The body weighs 10 units of mass
+///         .with_children(|children| {
+///             children.spawn_bundle((
+///                 CollisionShape::Sphere { radius: 0.5 },
+///                 ColliderMass::Zero, // The antenna doesn't change the mass of the body
+///                 Transform::from_xyz(0.0, 1.5, 0.0),
+///                 GlobalTransform::default(),
+///             ));
+///         });
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub enum ColliderMass {
+    /// The mass of the collision shape is derived from the density of its [`PhysicMaterial`], as
+    /// if there were no [`ColliderMass`] (default)
+    Material,
+
+    /// The collision shape doesn't contribute to the mass of its rigid body
+    Zero,
+
+    /// The collision shape has the given density, instead of the density of the [`PhysicMaterial`]
+    Density(f32),
+
+    /// The collision shape has the given mass, distributed uniformly in the shape
+    Mass(f32),
+}
+
+impl Default for ColliderMass {
+    fn default() -> Self {
+        Self::Material
+    }
+}
+
+/// Mark the [`CollisionShape`] of the same entity as being *solid*, even if its rigid body is a
+/// [`Sensor`].
+///
+/// This is the opposite of [`SensorShape`]: the other shapes of the sensor body remain sensors,
+/// but this one participates in contacts. It allows to make prefabs that combine a trigger zone
+/// with solid parts, without splitting them into two rigid bodies.
+///
+/// It has no effect if the concerned rigid body is not a sensor, or if a [`SensorShape`] is
+/// inserted on the same entity.
+///
+/// # Example
+///
+/// ```
+/// # use heron_core::*;
+/// # use bevy::prelude::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(Sensor) // <-- All the shapes of the body are sensors...
+///         .insert(CollisionShape::Sphere { radius: 5.0 }) // <-- The trigger zone
+///         .with_children(|children| {
+///             children.spawn_bundle((
+///                 CollisionShape::Cuboid { // <-- ...except this one, which is solid
+///                     half_extends: Vec3::ONE,
+///                     border_radius: None,
+///                 },
+///                 SolidShape,
+///                 Transform::default(),
+///                 GlobalTransform::default(),
+///             ));
+///         });
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct SolidShape;
+
+/// Mark the rigid body of the same entity as being a *sensor*.
+///
+/// All the [`CollisionShape`] of a sensor body are sensors: other bodies will be able to penetrate
+/// them, but they still participate in collision events.
+///
+/// Unlike the deprecated [`RigidBody::Sensor`], this component can be combined with any body
+/// type. For instance, a [`RigidBody::KinematicPositionBased`] sensor can be moved around to detect
+/// what it touches. It can also be inserted or removed at runtime.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static) // The body type of the sensor
+///         .insert(Sensor) // Make it a sensor
+///         .insert(CollisionShape::Sphere { radius: 1.0 });
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct Sensor;
+
+/// Remove the entity from the physics simulation, while keeping its heron components
+///
+/// When inserted on a [`RigidBody`] entity, the body and all its collision shapes are removed from
+/// the physics world. When inserted on a child [`CollisionShape`] entity, only that collision shape
+/// is removed. The entity is added back to the simulation as soon as the component is removed,
+/// using its current `GlobalTransform`, [`Velocity`], etc.
+///
+/// This is cheaper than despawning and respawning entities, which makes it suited for object pools.
+///
+/// A [`CollisionEvent::Stopped`] is fired for each contact that the entity had when it got
+/// disabled.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # #[derive(Component)]
+/// # struct Bullet;
+/// fn recycle_bullets(mut commands: Commands, bullets: Query<Entity, With<Bullet>>) {
+///     for bullet in bullets.iter() {
+///         commands.entity(bullet).insert(PhysicsDisabled);
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct PhysicsDisabled;
+
 /// Component that defines the physics properties of the rigid body
 ///
 /// It must be inserted on the same entity of a [`RigidBody`]
 ///
+/// A few presets are available as constants, like [`PhysicMaterial::RUBBER`] or
+/// [`PhysicMaterial::ICE`].
+///
 /// # Example
 ///
 /// ```
@@ -380,7 +1051,9 @@ pub struct SensorShape;
 ///         });
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect)]
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect, Deserialize)]
+#[reflect(Component)]
+#[serde(default)]
 pub struct PhysicMaterial {
     /// Coefficient of restitution. Affect how much it "bounces" when colliding with other objects.
     ///
@@ -410,6 +1083,34 @@ impl PhysicMaterial {
 
     /// Perfectly elastic restitution coefficient, all kinematic energy is restated in movement. (Very bouncy)
     pub const PERFECTLY_ELASTIC_RESTITUTION: f32 = 1.0;
+
+    /// Slippery material that barely bounces, and is a bit lighter than water
+    pub const ICE: Self = Self {
+        restitution: 0.05,
+        density: 0.92,
+        friction: 0.02,
+    };
+
+    /// Bouncy material with a high friction
+    pub const RUBBER: Self = Self {
+        restitution: 0.8,
+        density: 1.1,
+        friction: 0.9,
+    };
+
+    /// Light material with a moderate friction and restitution
+    pub const WOOD: Self = Self {
+        restitution: 0.3,
+        density: 0.7,
+        friction: 0.5,
+    };
+
+    /// Heavy material with a moderate friction and a low restitution
+    pub const METAL: Self = Self {
+        restitution: 0.2,
+        density: 7.8,
+        friction: 0.4,
+    };
 }
 
 impl Default for PhysicMaterial {
@@ -0,0 +1,49 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::{Quat, Vec3};
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component which will be filled (if present) with the position of the collision shape relative
+/// to its rigid body, as used by the physics engine
+///
+/// It accounts for the `Transform` of child entities and for the
+/// [`ColliderOffset`](crate::ColliderOffset), and is updated after each physics step. It is meant
+/// to help debugging misplaced collision shapes. Changing it has no effect on the physics world.
+///
+/// It must be added to the entity containing the [`CollisionShape`](crate::CollisionShape).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ShapeLocalPose::default());
+/// }
+///
+/// fn log_poses(query: Query<(Entity, &ShapeLocalPose)>) {
+///     for (entity, pose) in query.iter() {
+///         println!("The shape of {:?} is at {} in its body", entity, pose.translation);
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ShapeLocalPose {
+    /// Translation of the collision shape, relative to the rigid body
+    pub translation: Vec3,
+
+    /// Rotation of the collision shape, relative to the rigid body
+    pub rotation: Quat,
+}
+
+impl Default for ShapeLocalPose {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+        }
+    }
+}
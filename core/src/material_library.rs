@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+
+use crate::PhysicMaterial;
+
+/// Asset mapping names to [`PhysicMaterial`]s, so that the materials can be tuned in a single
+/// place and shared across a team
+///
+/// It is loaded by the [`MaterialLibraryLoader`] from RON files with the `materials.ron`
+/// extension. The missing fields of a material take the values of [`PhysicMaterial::default`].
+///
+/// ```ron
+/// {
+///     "ice": (restitution: 0.05, density: 0.92, friction: 0.02),
+///     "trampoline": (restitution: 1.0),
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// struct Materials(Handle<MaterialLibrary>);
+///
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.insert_resource(Materials(asset_server.load("physics.materials.ron")));
+/// }
+///
+/// fn spawn(mut commands: Commands, materials: Res<Materials>, libraries: Res<Assets<MaterialLibrary>>) {
+///     if let Some(material) = libraries.get(&materials.0).and_then(|lib| lib.get("ice")) {
+///         commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///             .insert(CollisionShape::Sphere { radius: 1.0 })
+///             .insert(material);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, TypeUuid)]
+#[serde(transparent)]
+#[uuid = "f80c2e9a-f871-43c9-b8ec-de16382800ae"]
+pub struct MaterialLibrary {
+    materials: HashMap<String, PhysicMaterial>,
+}
+
+impl MaterialLibrary {
+    /// Parses a library from its RON representation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text isn't a valid RON map of materials
+    pub fn from_ron(text: &str) -> Result<Self, ron::Error> {
+        ron::from_str(text)
+    }
+
+    /// Returns the material registered under the given name, if any
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<PhysicMaterial> {
+        self.materials.get(name).copied()
+    }
+
+    /// Registers a material under the given name, replacing the previous one (if any)
+    pub fn insert(&mut self, name: impl Into<String>, material: PhysicMaterial) {
+        self.materials.insert(name.into(), material);
+    }
+
+    /// Iterates over the names and materials of the library
+    pub fn iter(&self) -> impl Iterator<Item = (&str, PhysicMaterial)> {
+        self.materials
+            .iter()
+            .map(|(name, material)| (name.as_str(), *material))
+    }
+}
+
+/// Asset loader of the [`MaterialLibrary`], for the files with the `materials.ron` extension
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MaterialLibraryLoader;
+
+impl AssetLoader for MaterialLibraryLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext<'_>,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let library: MaterialLibrary = ron::de::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(library));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["materials.ron"]
+    }
+}
+
+/// Plugin that registers the [`MaterialLibrary`] asset and its loader
+///
+/// It requires bevy's `AssetPlugin`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MaterialLibraryPlugin;
+
+impl Plugin for MaterialLibraryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<MaterialLibrary>()
+            .init_asset_loader::<MaterialLibraryLoader>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ron_library() {
+        let library = MaterialLibrary::from_ron(
+            r#"{
+                "ice": (restitution: 0.05, density: 0.92, friction: 0.02),
+                "trampoline": (restitution: 1.0),
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(library.get("ice"), Some(PhysicMaterial::ICE));
+        assert_eq!(
+            library.get("trampoline"),
+            Some(PhysicMaterial {
+                restitution: 1.0,
+                ..PhysicMaterial::default()
+            })
+        );
+        assert_eq!(library.get("lava"), None);
+    }
+
+    #[test]
+    fn invalid_ron_is_an_error() {
+        assert!(MaterialLibrary::from_ron("(restitution: 1.0)").is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+
+/// Component which will be updated (if present) with the speed and direction of the rigid body,
+/// derived from its [`Velocity`](crate::Velocity)
+///
+/// It is meant to drive animation state machines. To avoid flickering between the "idle" and
+/// "moving" states, the body starts moving when its speed exceeds
+/// [`start_speed`](Self::start_speed), and stops when it falls below
+/// [`stop_speed`](Self::stop_speed).
+///
+/// It is updated after each physics step, once the [`Velocity`](crate::Velocity) has been written back.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn animate(query: Query<'_, '_, &Movement>) {
+///     for movement in query.iter() {
+///         if movement.is_moving() {
+///             println!("Run at {} toward {}", movement.speed(), movement.direction());
+///         } else {
+///             println!("Idle");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct Movement {
+    /// Speed above which a body that was not moving is considered to be moving
+    pub start_speed: f32,
+    /// Speed below which a body that was moving is considered to be stopped
+    pub stop_speed: f32,
+    speed: f32,
+    direction: Vec3,
+    moving: bool,
+}
+
+impl Default for Movement {
+    fn default() -> Self {
+        Self::new(0.1, 0.05)
+    }
+}
+
+impl Movement {
+    /// Create a new movement with the given speed thresholds
+    ///
+    /// The `stop_speed` should not be greater than the `start_speed`.
+    #[must_use]
+    pub fn new(start_speed: f32, stop_speed: f32) -> Self {
+        Self {
+            start_speed,
+            stop_speed,
+            speed: 0.0,
+            direction: Vec3::ZERO,
+            moving: false,
+        }
+    }
+
+    /// Returns the linear speed of the body
+    #[must_use]
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Returns the normalized direction of the linear velocity
+    ///
+    /// When the body stops, the last direction is kept, so that it can still be used to orient an
+    /// idle animation. It is zero if the body never moved.
+    #[must_use]
+    pub fn direction(&self) -> Vec3 {
+        self.direction
+    }
+
+    /// Returns `true` if the body is moving, with respect to the speed thresholds
+    #[must_use]
+    pub fn is_moving(&self) -> bool {
+        self.moving
+    }
+
+    #[doc(hidden)]
+    pub fn update(&mut self, linear_velocity: Vec3) {
+        self.speed = linear_velocity.length();
+        if let Some(direction) = linear_velocity.try_normalize() {
+            self.direction = direction;
+        }
+        self.moving = if self.moving {
+            self.speed >= self.stop_speed
+        } else {
+            self.speed > self.start_speed
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speed_and_direction_are_derived_from_velocity() {
+        let mut movement = Movement::default();
+        movement.update(Vec3::new(3.0, 4.0, 0.0));
+
+        assert!((movement.speed() - 5.0).abs() < f32::EPSILON);
+        assert!(movement
+            .direction()
+            .abs_diff_eq(Vec3::new(0.6, 0.8, 0.0), 1e-6));
+        assert!(movement.is_moving());
+    }
+
+    #[test]
+    fn direction_is_kept_when_stopping() {
+        let mut movement = Movement::default();
+        movement.update(Vec3::X * 2.0);
+        movement.update(Vec3::ZERO);
+
+        assert_eq!(movement.direction(), Vec3::X);
+        assert!(!movement.is_moving());
+    }
+
+    #[test]
+    fn moving_state_has_hysteresis() {
+        let mut movement = Movement::new(1.0, 0.5);
+
+        movement.update(Vec3::X * 0.8);
+        assert!(!movement.is_moving());
+
+        movement.update(Vec3::X * 1.5);
+        assert!(movement.is_moving());
+
+        movement.update(Vec3::X * 0.8);
+        assert!(movement.is_moving());
+
+        movement.update(Vec3::X * 0.4);
+        assert!(!movement.is_moving());
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{FromReflect, Reflect};
+use serde::{Deserialize, Serialize};
+
+/// Component that identifies a collision shape in a stable way, independently of the bevy entity
+///
+/// Entity ids are not stable across sessions: the same game object can get a different entity
+/// when a replay is played back or when a rollback session is restarted. When it is present on
+/// the collision shape entity, the id is used as [`CollisionData::stable_id`](crate::CollisionData::stable_id),
+/// which makes the [`CollisionEvent::pair_key`](crate::CollisionEvent::pair_key) match the same
+/// collisions across sessions.
+///
+/// The ids must be unique among the collision shapes. Assigning them is up to the user, typically
+/// from the network or replay layer.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(NetworkId(42));
+/// }
+/// ```
+#[derive(
+    Debug,
+    Component,
+    Copy,
+    Clone,
+    Default,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Reflect,
+    FromReflect,
+    Serialize,
+    Deserialize,
+)]
+#[reflect(Component)]
+pub struct NetworkId(pub u64);
@@ -0,0 +1,104 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::{Quat, Vec3};
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that offsets the [`CollisionShape`](crate::CollisionShape) of the same entity,
+/// relative to the [`RigidBody`](crate::RigidBody).
+///
+/// This allows to offset (and rotate) a collision shape inserted on the same entity as the rigid
+/// body, without having to spawn a child entity.
+///
+/// If the collision shape is in a child entity, the offset is applied on top of the child
+/// `Transform`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ColliderOffset::from_translation(Vec3::Y * 2.0)); // The sphere is 2 units above the body
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ColliderOffset {
+    /// Translation of the collision shape, relative to the rigid body
+    pub translation: Vec3,
+
+    /// Rotation of the collision shape, relative to the rigid body
+    pub rotation: Quat,
+}
+
+impl Default for ColliderOffset {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+        }
+    }
+}
+
+impl ColliderOffset {
+    /// Returns an offset with the given translation and no rotation
+    #[must_use]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            ..Self::default()
+        }
+    }
+
+    /// Returns an offset with the given rotation and no translation
+    #[must_use]
+    pub fn from_rotation(rotation: Quat) -> Self {
+        Self {
+            rotation,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a new version with the given translation
+    #[must_use]
+    pub fn with_translation(mut self, translation: Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns a new version with the given rotation
+    #[must_use]
+    pub fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns the offset applied on top of the given parent translation and rotation
+    #[must_use]
+    pub fn apply_to(self, translation: Vec3, rotation: Quat) -> (Vec3, Quat) {
+        (
+            translation + rotation * self.translation,
+            rotation * self.rotation,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    #[test]
+    fn apply_to_rotates_the_translation() {
+        let rotation = Quat::from_axis_angle(Vec3::Z, FRAC_PI_2);
+        let offset = ColliderOffset::from_translation(Vec3::X);
+        let (actual_translation, actual_rotation) = offset.apply_to(Vec3::Y, rotation);
+
+        assert!(actual_translation.abs_diff_eq(Vec3::new(0.0, 2.0, 0.0), 0.0001));
+        assert!(actual_rotation.abs_diff_eq(rotation, 0.0001));
+    }
+}
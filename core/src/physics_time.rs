@@ -1,3 +1,7 @@
+use bevy::reflect::{FromReflect, Reflect};
+
+use crate::{Acceleration, Force, Velocity};
+
 /// Resource that controls the physics time scale
 ///
 /// # Example
@@ -14,7 +18,7 @@
 ///         .run();
 /// }
 /// ```
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Reflect, FromReflect)]
 pub struct PhysicsTime {
     /// Specify the physics emulation time scale used
     scale: f32,
@@ -30,7 +34,7 @@ impl PhysicsTime {
     ///
     #[must_use]
     pub fn new(scale: f32) -> Self {
-        assert!(scale >= 0.0, "Negative scale: {}", scale);
+        assert!(scale >= 0.0, "Negative scale: {scale}");
         Self {
             scale,
             previous_scale: None,
@@ -58,7 +62,7 @@ impl PhysicsTime {
     /// Panic if the scale is negative
     ///
     pub fn set_scale(&mut self, scale: f32) {
-        assert!(scale >= 0.0);
+        assert!(scale >= 0.0, "Negative scale: {scale}");
         self.scale = scale;
     }
 
@@ -86,9 +90,120 @@ impl Default for PhysicsTime {
     }
 }
 
+/// Resource that defines in which time space the [`Velocity`], [`Acceleration`] and [`Force`]
+/// components are expressed when the [`PhysicsTime`] scale is not 1
+///
+/// By default, the values are in simulation time: a body moving at 1 unit per second keeps
+/// reporting a velocity of 1 in slow motion, even though it moves slower on screen.
+///
+/// With [`VelocityTimeSpace::Real`], the values are in real time: the velocity written back is
+/// multiplied by the time scale (and the velocity set by the user is divided by it), which is
+/// convenient to drive animations or sounds from the [`Velocity`] component.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// App::new()
+///     .insert_resource(PhysicsTime::new(0.5))
+///     // At half speed, a body falling at 10 units per second reports a velocity of 5
+///     .insert_resource(VelocityTimeSpace::Real)
+///     // ...
+///     .run();
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum VelocityTimeSpace {
+    /// The values are not affected by the time scale (default)
+    Simulation,
+
+    /// The values are scaled by the time scale, so that they match what is observed on screen
+    Real,
+}
+
+impl Default for VelocityTimeSpace {
+    fn default() -> Self {
+        Self::Simulation
+    }
+}
+
+impl VelocityTimeSpace {
+    /// Factor by which the simulation velocities are multiplied to be expressed in this time space
+    #[must_use]
+    pub fn velocity_factor(self, time: &PhysicsTime) -> f32 {
+        match self {
+            Self::Simulation => 1.0,
+            Self::Real => time.scale(),
+        }
+    }
+
+    /// Convert a velocity from the simulation time space into this time space
+    #[must_use]
+    pub fn velocity_from_simulation(self, time: &PhysicsTime, velocity: Velocity) -> Velocity {
+        scale_velocity(velocity, self.velocity_factor(time))
+    }
+
+    /// Convert a velocity from this time space into the simulation time space
+    ///
+    /// The velocity is returned unchanged if the physics is paused.
+    #[must_use]
+    pub fn velocity_to_simulation(self, time: &PhysicsTime, velocity: Velocity) -> Velocity {
+        match self.velocity_factor(time) {
+            factor if factor > 0.0 => scale_velocity(velocity, factor.recip()),
+            _ => velocity,
+        }
+    }
+
+    /// Convert an acceleration from this time space into the simulation time space
+    ///
+    /// The acceleration is returned unchanged if the physics is paused.
+    #[must_use]
+    pub fn acceleration_to_simulation(
+        self,
+        time: &PhysicsTime,
+        acceleration: Acceleration,
+    ) -> Acceleration {
+        match self.velocity_factor(time) {
+            factor if factor > 0.0 => {
+                let factor = factor.powi(2).recip();
+                Acceleration {
+                    linear: acceleration.linear * factor,
+                    angular: acceleration.angular * factor,
+                }
+            }
+            _ => acceleration,
+        }
+    }
+
+    /// Convert a force from this time space into the simulation time space
+    ///
+    /// The force is returned unchanged if the physics is paused.
+    #[must_use]
+    pub fn force_to_simulation(self, time: &PhysicsTime, force: Force) -> Force {
+        match self.velocity_factor(time) {
+            factor if factor > 0.0 => {
+                let factor = factor.powi(2).recip();
+                Force {
+                    linear: force.linear * factor,
+                    angular: force.angular * factor,
+                }
+            }
+            _ => force,
+        }
+    }
+}
+
+fn scale_velocity(velocity: Velocity, factor: f32) -> Velocity {
+    Velocity {
+        linear: velocity.linear * factor,
+        angular: velocity.angular * factor,
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::float_cmp)]
 mod tests {
+    use bevy::math::Vec3;
     use rstest::rstest;
 
     use super::*;
@@ -140,4 +255,35 @@ mod tests {
     fn new_with_negative_scale_panics(#[case] scale: f32) {
         let _ = PhysicsTime::new(scale);
     }
+
+    #[rstest]
+    #[case(VelocityTimeSpace::Simulation, 4.0)]
+    #[case(VelocityTimeSpace::Real, 2.0)]
+    fn velocity_is_converted_from_simulation(
+        #[case] space: VelocityTimeSpace,
+        #[case] expected: f32,
+    ) {
+        let time = PhysicsTime::new(0.5);
+        let velocity = space.velocity_from_simulation(&time, Velocity::from_linear(Vec3::X * 4.0));
+        assert_eq!(velocity.linear, Vec3::X * expected);
+    }
+
+    #[rstest]
+    #[case(VelocityTimeSpace::Simulation)]
+    #[case(VelocityTimeSpace::Real)]
+    fn velocity_conversion_round_trips(#[case] space: VelocityTimeSpace) {
+        let time = PhysicsTime::new(0.5);
+        let velocity = Velocity::from_linear(Vec3::X * 4.0);
+        let converted =
+            space.velocity_to_simulation(&time, space.velocity_from_simulation(&time, velocity));
+        assert_eq!(converted, velocity);
+    }
+
+    #[test]
+    fn real_time_acceleration_is_scaled_quadratically() {
+        let time = PhysicsTime::new(0.5);
+        let acceleration = VelocityTimeSpace::Real
+            .acceleration_to_simulation(&time, Acceleration::from_linear(Vec3::X));
+        assert_eq!(acceleration.linear, Vec3::X * 4.0);
+    }
 }
@@ -0,0 +1,83 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::Vec3;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that rounds the rendered position of a rigid body to a grid, typically the size of a
+/// pixel in a pixel-art 2d game
+///
+/// Heron snaps the `x` and `y` coordinates of the `Transform` and `GlobalTransform` when writing
+/// back the result of a physics step, while the physics simulation keeps the exact position. That
+/// prevents sprites from "jittering" between pixels.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 8.0 })
+///         .insert(PixelSnap::default()); // Snap to integer coordinates
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct PixelSnap {
+    /// Size of a cell of the grid to snap to
+    pub grid_size: f32,
+}
+
+impl Default for PixelSnap {
+    fn default() -> Self {
+        Self { grid_size: 1.0 }
+    }
+}
+
+impl PixelSnap {
+    /// Returns a pixel snap with the given grid size
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid size is not strictly positive
+    #[must_use]
+    pub fn new(grid_size: f32) -> Self {
+        assert!(grid_size > 0.0, "Invalid grid size: {grid_size}");
+        Self { grid_size }
+    }
+
+    /// Returns the translation snapped to the grid
+    ///
+    /// The `z` coordinate is left untouched. The translation isn't snapped at all if the grid size
+    /// isn't strictly positive.
+    #[must_use]
+    pub fn snap(self, translation: Vec3) -> Vec3 {
+        if self.grid_size <= 0.0 || !self.grid_size.is_finite() {
+            return translation;
+        }
+        let snapped = (translation.truncate() / self.grid_size).round() * self.grid_size;
+        snapped.extend(translation.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_x_and_y_to_grid() {
+        let snap = PixelSnap::new(0.5);
+        assert_eq!(
+            snap.snap(Vec3::new(1.2, -0.8, 0.3)),
+            Vec3::new(1.0, -1.0, 0.3)
+        );
+    }
+
+    #[test]
+    fn invalid_grid_size_does_not_snap() {
+        let translation = Vec3::new(1.2, -0.8, 0.3);
+        assert_eq!(PixelSnap { grid_size: 0.0 }.snap(translation), translation);
+        assert_eq!(PixelSnap { grid_size: -1.0 }.snap(translation), translation);
+    }
+}
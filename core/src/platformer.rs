@@ -0,0 +1,299 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::{Vec2, Vec3};
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that moves a rigid body like the character of a 2d platformer game
+///
+/// The desired horizontal direction is set with [`set_direction`](Self::set_direction), and jumps
+/// are requested with [`jump`](Self::jump), typically from the player input. Before each physics
+/// step, the horizontal velocity is accelerated toward `direction * move_speed`, and the body jumps
+/// if a jump was requested while it is (or was very recently) on the ground.
+///
+/// The controller reads the [`GroundDetection`](crate::GroundDetection) of the body, which must
+/// be present. While grounded, the body moves along the ground (using the contact normal), so that
+/// it walks up and down the slopes instead of taking off.
+///
+/// Two common tricks make the jumps feel responsive:
+/// * the *coyote time* lets the body jump for a short time after it walked off a ledge
+/// * the *jump buffer* remembers a jump requested shortly before landing, and jumps as soon as the
+///   body touches the ground
+///
+/// The easiest way to get the other needed components is to use a
+/// [`CharacterBundle`](crate::CharacterBundle).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert_bundle(CharacterBundle::default())
+///         .insert(PlatformerController::new(200.0, 400.0));
+/// }
+///
+/// fn handle_input(input: Res<Input<KeyCode>>, mut controllers: Query<&mut PlatformerController>) {
+///     let direction = f32::from(input.pressed(KeyCode::D)) - f32::from(input.pressed(KeyCode::A));
+///     for mut controller in controllers.iter_mut() {
+///         controller.set_direction(direction);
+///         if input.just_pressed(KeyCode::Space) {
+///             controller.jump();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct PlatformerController {
+    /// Maximum horizontal speed, in units-per-second
+    pub move_speed: f32,
+
+    /// Rate at which the horizontal speed changes toward the desired velocity while grounded, in
+    /// units-per-second-squared
+    pub acceleration: f32,
+
+    /// Fraction (between `0.0` and `1.0`) of the [`acceleration`](Self::acceleration) available
+    /// while in the air
+    pub air_control: f32,
+
+    /// Upward speed given to the body when it jumps, in units-per-second
+    pub jump_impulse: f32,
+
+    /// Duration (in seconds) after leaving the ground, during which the body can still jump
+    pub coyote_time: f32,
+
+    /// Duration (in seconds) during which a jump request is remembered, if the body can't jump
+    /// right away
+    pub jump_buffer: f32,
+
+    direction: f32,
+    coyote_timer: f32,
+    jump_timer: f32,
+}
+
+impl Default for PlatformerController {
+    fn default() -> Self {
+        Self::new(5.0, 10.0)
+    }
+}
+
+impl PlatformerController {
+    /// Create a new controller with the given horizontal speed and jump impulse
+    ///
+    /// The acceleration is set to reach the maximum speed in a tenth of a second, half of it is
+    /// available in the air, and the coyote time and jump buffer are a tenth of a second, which
+    /// feels responsive in most games.
+    #[must_use]
+    pub fn new(move_speed: f32, jump_impulse: f32) -> Self {
+        Self {
+            move_speed,
+            acceleration: move_speed * 10.0,
+            air_control: 0.5,
+            jump_impulse,
+            coyote_time: 0.1,
+            jump_buffer: 0.1,
+            direction: 0.0,
+            coyote_timer: 0.0,
+            jump_timer: 0.0,
+        }
+    }
+
+    /// Returns a new version of this controller with the given acceleration
+    #[must_use]
+    pub fn with_acceleration(mut self, acceleration: f32) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Returns a new version of this controller with the given air control
+    #[must_use]
+    pub fn with_air_control(mut self, air_control: f32) -> Self {
+        self.air_control = air_control;
+        self
+    }
+
+    /// Returns a new version of this controller with the given coyote time (in seconds)
+    #[must_use]
+    pub fn with_coyote_time(mut self, coyote_time: f32) -> Self {
+        self.coyote_time = coyote_time;
+        self
+    }
+
+    /// Returns a new version of this controller with the given jump buffer (in seconds)
+    #[must_use]
+    pub fn with_jump_buffer(mut self, jump_buffer: f32) -> Self {
+        self.jump_buffer = jump_buffer;
+        self
+    }
+
+    /// Set the desired horizontal direction of movement
+    ///
+    /// The direction is clamped between `-1.0` (left) and `1.0` (right). A smaller value (like an
+    /// analog stick slightly tilted) moves slower than the maximum speed.
+    pub fn set_direction(&mut self, direction: f32) {
+        self.direction = direction.clamp(-1.0, 1.0);
+    }
+
+    /// Returns the desired horizontal direction of movement
+    #[must_use]
+    pub fn direction(&self) -> f32 {
+        self.direction
+    }
+
+    /// Request a jump
+    ///
+    /// The body jumps at the next physics step if it can, otherwise the request is remembered for
+    /// the duration of the [`jump_buffer`](Self::jump_buffer).
+    pub fn jump(&mut self) {
+        self.jump_timer = self.jump_buffer.max(f32::EPSILON);
+    }
+
+    /// Returns `true` if a jump is requested and not performed yet
+    #[must_use]
+    pub fn is_jump_requested(&self) -> bool {
+        self.jump_timer > 0.0
+    }
+
+    /// Returns the velocity after `delta_seconds`, given the `current` velocity and the normal of
+    /// the ground if the body is grounded
+    ///
+    /// The body is considered in the air if it moves away from the ground, like right after a
+    /// jump. This updates the coyote time and jump buffer timers, and should be called exactly once
+    /// per physics step.
+    pub fn next_velocity(
+        &mut self,
+        current: Vec2,
+        ground_normal: Option<Vec3>,
+        delta_seconds: f32,
+    ) -> Vec2 {
+        // Right after a jump, the body may still touch the ground, but it is moving away from it
+        let ground_normal = ground_normal.filter(|normal| current.dot(normal.truncate()) <= 0.0);
+        let mut velocity = if let Some(normal) = ground_normal {
+            self.coyote_timer = self.coyote_time.max(f32::EPSILON);
+            let tangent = Vec2::new(normal.y, -normal.x).normalize_or_zero();
+            let target = tangent * self.direction * self.move_speed;
+            move_toward(current, target, self.acceleration * delta_seconds)
+        } else {
+            let target = self.direction * self.move_speed;
+            let max_change = self.acceleration * self.air_control * delta_seconds;
+            Vec2::new(
+                move_toward(Vec2::X * current.x, Vec2::X * target, max_change).x,
+                current.y,
+            )
+        };
+
+        if self.jump_timer > 0.0 && self.coyote_timer > 0.0 {
+            velocity.y = self.jump_impulse;
+            self.jump_timer = 0.0;
+            self.coyote_timer = 0.0;
+        }
+
+        self.jump_timer = (self.jump_timer - delta_seconds).max(0.0);
+        if ground_normal.is_none() {
+            self.coyote_timer = (self.coyote_timer - delta_seconds).max(0.0);
+        }
+
+        velocity
+    }
+}
+
+fn move_toward(current: Vec2, target: Vec2, max_change: f32) -> Vec2 {
+    let delta = target - current;
+    if delta.length() <= max_change {
+        target
+    } else {
+        current + delta.normalize() * max_change
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 0.02;
+
+    #[test]
+    fn accelerates_along_the_ground() {
+        let mut controller = PlatformerController::new(10.0, 5.0).with_acceleration(100.0);
+        controller.set_direction(2.0);
+        assert_eq!(controller.direction(), 1.0);
+
+        assert_eq!(
+            controller.next_velocity(Vec2::ZERO, Some(Vec3::Y), DT),
+            Vec2::X * 2.0
+        );
+
+        let slope = Vec3::new(-1.0, 1.0, 0.0).normalize();
+        let velocity = controller.next_velocity(Vec2::X * 10.0, Some(slope), 1.0);
+        assert!((velocity - Vec2::new(1.0, 1.0).normalize() * 10.0).length() < 0.0001);
+    }
+
+    #[test]
+    fn air_control_limits_acceleration_and_keeps_vertical_speed() {
+        let mut controller = PlatformerController::new(10.0, 5.0)
+            .with_acceleration(100.0)
+            .with_air_control(0.5);
+        controller.set_direction(-1.0);
+
+        assert_eq!(
+            controller.next_velocity(Vec2::new(0.0, -3.0), None, DT),
+            Vec2::new(-1.0, -3.0)
+        );
+    }
+
+    #[test]
+    fn jumps_from_the_ground() {
+        let mut controller = PlatformerController::new(10.0, 5.0);
+        controller.jump();
+        assert!(controller.is_jump_requested());
+
+        let velocity = controller.next_velocity(Vec2::ZERO, Some(Vec3::Y), DT);
+        assert_eq!(velocity.y, 5.0);
+        assert!(!controller.is_jump_requested());
+
+        // Still touching the ground right after the jump, but moving away from it
+        controller.jump();
+        let velocity = controller.next_velocity(Vec2::Y * 5.0, Some(Vec3::Y), DT);
+        assert_eq!(velocity.y, 5.0);
+        assert!(controller.is_jump_requested());
+        assert_eq!(controller.coyote_timer, 0.0);
+    }
+
+    #[test]
+    fn can_jump_during_coyote_time() {
+        let mut controller = PlatformerController::new(10.0, 5.0).with_coyote_time(0.05);
+        controller.next_velocity(Vec2::ZERO, Some(Vec3::Y), DT);
+        controller.next_velocity(Vec2::ZERO, None, DT);
+
+        controller.jump();
+        assert_eq!(controller.next_velocity(Vec2::ZERO, None, DT).y, 5.0);
+
+        controller.next_velocity(Vec2::ZERO, Some(Vec3::Y), DT);
+        for _ in 0..3 {
+            controller.next_velocity(Vec2::ZERO, None, DT);
+        }
+        controller.jump();
+        assert_eq!(controller.next_velocity(Vec2::ZERO, None, DT).y, 0.0);
+    }
+
+    #[test]
+    fn jump_is_buffered_until_landing() {
+        let mut controller = PlatformerController::new(10.0, 5.0).with_jump_buffer(0.05);
+        controller.jump();
+        assert_eq!(controller.next_velocity(Vec2::ZERO, None, DT).y, 0.0);
+        assert_eq!(
+            controller.next_velocity(Vec2::ZERO, Some(Vec3::Y), DT).y,
+            5.0
+        );
+
+        controller.jump();
+        for _ in 0..3 {
+            controller.next_velocity(Vec2::ZERO, None, DT);
+        }
+        assert_eq!(
+            controller.next_velocity(Vec2::ZERO, Some(Vec3::Y), DT).y,
+            0.0
+        );
+    }
+}
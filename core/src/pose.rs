@@ -0,0 +1,65 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::{Quat, Vec3};
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that receives the pose computed by the physics simulation, instead of the bevy
+/// `Transform`
+///
+/// When a [`RigidBody`](crate::RigidBody) entity has this component, heron writes the position and
+/// rotation of the body into it after each physics step, and leaves the `Transform` and
+/// `GlobalTransform` untouched. This lets games post-process the pose (smoothing, shake, pixel
+/// snapping, etc.) before copying it into the `Transform`, without heron and the game writing the
+/// same component.
+///
+/// The rigid body is created at the position of its `GlobalTransform`. Afterward, changing the
+/// `Transform` no longer moves the body. Change the [`PhysicsPose`] instead to teleport it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(PhysicsPose::default());
+/// }
+///
+/// fn snap_to_pixels(mut query: Query<(&PhysicsPose, &mut Transform)>) {
+///     for (pose, mut transform) in query.iter_mut() {
+///         transform.translation = pose.translation.round();
+///         transform.rotation = pose.rotation;
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct PhysicsPose {
+    /// Position of the rigid body in the world
+    pub translation: Vec3,
+
+    /// Rotation of the rigid body in the world
+    pub rotation: Quat,
+}
+
+impl Default for PhysicsPose {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+        }
+    }
+}
+
+impl PhysicsPose {
+    /// Returns a pose with the given translation and rotation
+    #[must_use]
+    pub fn new(translation: Vec3, rotation: Quat) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+}
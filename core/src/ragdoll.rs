@@ -0,0 +1,115 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that turns the hierarchy of bones below this entity into a ragdoll
+///
+/// Every descendant with a [`RigidBody`](crate::RigidBody) is a bone. Each bone is connected to
+/// the closest ancestor bone with a ball joint (a revolute joint in 2d), located at the origin of
+/// the bone. The rotation of a bone relative to its parent bone is limited to
+/// [`angle_limit`](Self::angle_limit) around each axis, starting from their relative rotation at
+/// the time the joint is created. The contacts between a bone and its parent bone are ignored.
+///
+/// The [`state`](Self::state) switches the bones between [`RigidBody::KinematicPositionBased`]
+/// (animated) and [`RigidBody::Dynamic`] (simulated). The joints are recreated when the bones
+/// change state, so that the current pose of the animation becomes the rest pose of the ragdoll.
+///
+/// The bones that already have a [`Joint`](crate::Joint), a [`SpringJoint`](crate::SpringJoint) or
+/// are [`AttachedToParent`](crate::AttachedToParent) keep their own joint.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::KinematicPositionBased)
+///         .insert(CollisionShape::Capsule { half_segment: 0.3, radius: 0.2 })
+///         .insert(Ragdoll::default())
+///         .with_children(|pelvis| {
+///             pelvis.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///                 .insert(RigidBody::KinematicPositionBased)
+///                 .insert(CollisionShape::Capsule { half_segment: 0.4, radius: 0.1 });
+///         });
+/// }
+///
+/// fn die(mut ragdolls: Query<&mut Ragdoll>) {
+///     for mut ragdoll in ragdolls.iter_mut() {
+///         ragdoll.state = RagdollState::Simulated;
+///     }
+/// }
+/// ```
+///
+/// [`RigidBody::KinematicPositionBased`]: crate::RigidBody::KinematicPositionBased
+/// [`RigidBody::Dynamic`]: crate::RigidBody::Dynamic
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct Ragdoll {
+    /// Whether the bones are animated or simulated
+    pub state: RagdollState,
+    /// Maximum rotation (in radians) of a bone relative to its parent bone, in both directions
+    pub angle_limit: f32,
+}
+
+/// State of a [`Ragdoll`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum RagdollState {
+    /// The bones are kinematic, and follow their transform
+    Animated,
+    /// The bones are dynamic, and are moved by the physics engine
+    Simulated,
+}
+
+impl Default for Ragdoll {
+    fn default() -> Self {
+        Self {
+            state: RagdollState::Animated,
+            angle_limit: std::f32::consts::FRAC_PI_4,
+        }
+    }
+}
+
+impl Ragdoll {
+    /// Returns a ragdoll in the given state, with the default angle limit
+    #[must_use]
+    pub fn new(state: RagdollState) -> Self {
+        Self {
+            state,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a new version of this ragdoll with the given angle limit (in radians)
+    #[must_use]
+    pub fn with_angle_limit(mut self, angle_limit: f32) -> Self {
+        self.angle_limit = angle_limit;
+        self
+    }
+}
+
+impl RagdollState {
+    /// Returns the rigid body type of the bones in this state
+    #[must_use]
+    pub fn body_type(self) -> crate::RigidBody {
+        match self {
+            RagdollState::Animated => crate::RigidBody::KinematicPositionBased,
+            RagdollState::Simulated => crate::RigidBody::Dynamic,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RigidBody;
+
+    #[test]
+    fn animated_bones_are_kinematic() {
+        assert_eq!(
+            RagdollState::Animated.body_type(),
+            RigidBody::KinematicPositionBased
+        );
+        assert_eq!(RagdollState::Simulated.body_type(), RigidBody::Dynamic);
+    }
+}
@@ -0,0 +1,149 @@
+use bevy::ecs::entity::{EntityMap, MapEntities, MapEntitiesError};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+
+use crate::CollisionEvent;
+
+/// Component which will be filled (if present) with the entities that started or stopped to
+/// collide with the current entity since the last frame.
+///
+/// It is meant to be used on [`RigidBody::Static`](crate::RigidBody::Static) entities with the
+/// [`Sensor`](crate::Sensor) component (trigger volumes), so that gameplay code doesn't have to
+/// scan all the [`CollisionEvent`]s.
+///
+/// The entities are the ones containing the [`RigidBody`](crate::RigidBody).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(Sensor)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(SensorEvents::default());
+/// }
+///
+/// fn detect_entering(sensors: Query<&SensorEvents>) {
+///     for events in sensors.iter() {
+///         for entity in events.entered() {
+///             println!("{:?} entered the sensor", entity);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Default, Clone, Reflect, FromReflect)]
+#[reflect(Component, MapEntities)]
+pub struct SensorEvents {
+    entered: Vec<Entity>,
+    exited: Vec<Entity>,
+}
+
+impl SensorEvents {
+    /// An iterator visiting the entities that started to collide since the last frame
+    pub fn entered(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entered.iter().copied()
+    }
+
+    /// An iterator visiting the entities that stopped to collide since the last frame
+    pub fn exited(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.exited.iter().copied()
+    }
+
+    /// Returns `true` if no entity entered or exited since the last frame
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entered.is_empty() && self.exited.is_empty()
+    }
+
+    fn record(&mut self, entity: Entity, started: bool) {
+        let entities = if started {
+            &mut self.entered
+        } else {
+            &mut self.exited
+        };
+
+        if !entities.contains(&entity) {
+            entities.push(entity);
+        }
+    }
+}
+
+impl MapEntities for SensorEvents {
+    /// The events are not carried over when spawning a scene, the new bodies fire their own
+    fn map_entities(&mut self, _: &EntityMap) -> Result<(), MapEntitiesError> {
+        self.entered.clear();
+        self.exited.clear();
+        Ok(())
+    }
+}
+
+/// Clears the [`SensorEvents`] of the previous frame and records the new ones
+pub(super) fn update_sensor_events_system(
+    mut collision_events: EventReader<'_, '_, CollisionEvent>,
+    mut sensors: Query<'_, '_, &mut SensorEvents>,
+) {
+    for mut events in &mut sensors {
+        if !events.is_empty() {
+            events.entered.clear();
+            events.exited.clear();
+        }
+    }
+
+    for event in collision_events.iter() {
+        let (entity1, entity2) = event.rigid_body_entities();
+        if let Ok(mut events) = sensors.get_mut(entity1) {
+            events.record(entity2, event.is_started());
+        }
+        if let Ok(mut events) = sensors.get_mut(entity2) {
+            events.record(entity1, event.is_started());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::Events;
+
+    use crate::{CollisionData, CollisionLayers};
+
+    use super::*;
+
+    #[test]
+    fn records_entered_and_exited_entities_for_one_frame() {
+        let mut app = App::new();
+        app.add_event::<CollisionEvent>()
+            .add_system(update_sensor_events_system);
+
+        let sensor = app.world.spawn().insert(SensorEvents::default()).id();
+        let other = app.world.spawn().id();
+
+        let data1 = CollisionData::new(sensor, sensor, CollisionLayers::default(), []);
+        let data2 = CollisionData::new(other, other, CollisionLayers::default(), []);
+
+        app.world
+            .resource_mut::<Events<CollisionEvent>>()
+            .send(CollisionEvent::Started(data1.clone(), data2.clone()));
+        app.update();
+
+        let events = app.world.get::<SensorEvents>(sensor).unwrap();
+        assert_eq!(events.entered().collect::<Vec<_>>(), vec![other]);
+        assert_eq!(events.exited().count(), 0);
+
+        app.world
+            .resource_mut::<Events<CollisionEvent>>()
+            .send(CollisionEvent::Stopped(data1, data2));
+        app.update();
+
+        let events = app.world.get::<SensorEvents>(sensor).unwrap();
+        assert_eq!(events.entered().count(), 0);
+        assert_eq!(events.exited().collect::<Vec<_>>(), vec![other]);
+
+        app.update();
+
+        assert!(app.world.get::<SensorEvents>(sensor).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+use bevy::math::Vec2;
+
+use crate::CollisionShape;
+
+/// Constructors for 2d games, which only take the relevant dimensions and fill in the ones ignored
+/// in 2d
+impl CollisionShape {
+    /// Returns a rectangle [`Cuboid`](Self::Cuboid) with the given **half** extents (x = half
+    /// width, y = half height) and no border radius
+    #[must_use]
+    pub fn cuboid_2d(half_extents: Vec2) -> Self {
+        Self::Cuboid {
+            half_extends: half_extents.extend(0.0),
+            border_radius: None,
+        }
+    }
+
+    /// Returns a circle [`Sphere`](Self::Sphere) with the given radius
+    #[must_use]
+    pub fn sphere_2d(radius: f32) -> Self {
+        Self::Sphere { radius }
+    }
+
+    /// Returns a 2d [`HeightField`](Self::HeightField) of the given width
+    ///
+    /// The heights are evenly spaced along the x axis, from `-width / 2` to `width / 2`.
+    #[must_use]
+    pub fn heightfield_2d(width: f32, heights: Vec<f32>) -> Self {
+        Self::HeightField {
+            size: Vec2::new(width, 0.0),
+            heights: vec![heights],
+        }
+    }
+
+    /// Returns a polygon [`ConvexHull`](Self::ConvexHull) of the given points, with no border
+    /// radius
+    #[must_use]
+    pub fn convex_hull_2d(points: impl IntoIterator<Item = Vec2>) -> Self {
+        Self::ConvexHull {
+            points: points.into_iter().map(|point| point.extend(0.0)).collect(),
+            border_radius: None,
+        }
+    }
+}
+
+impl From<Vec2> for CollisionShape {
+    /// Returns a rectangle with the given **half** extents, like
+    /// [`cuboid_2d`](CollisionShape::cuboid_2d)
+    fn from(half_extents: Vec2) -> Self {
+        Self::cuboid_2d(half_extents)
+    }
+}
+
+impl From<(f32, f32)> for CollisionShape {
+    /// Returns a rectangle with the given **half** width and height, like
+    /// [`cuboid_2d`](CollisionShape::cuboid_2d)
+    fn from((half_width, half_height): (f32, f32)) -> Self {
+        Self::cuboid_2d(Vec2::new(half_width, half_height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Vec3;
+
+    use super::*;
+
+    #[test]
+    fn cuboid_2d_has_no_depth() {
+        let shape = CollisionShape::cuboid_2d(Vec2::new(1.0, 2.0));
+        assert!(matches!(
+            shape,
+            CollisionShape::Cuboid {
+                half_extends,
+                border_radius: None,
+            } if half_extends == Vec3::new(1.0, 2.0, 0.0)
+        ));
+        assert!(shape.validate().is_ok());
+    }
+
+    #[test]
+    fn heightfield_2d_has_a_single_row() {
+        let shape = CollisionShape::heightfield_2d(10.0, vec![0.0, 1.0, 0.5]);
+        assert!(matches!(
+            shape,
+            CollisionShape::HeightField { size, ref heights }
+                if size == Vec2::new(10.0, 0.0) && heights == &vec![vec![0.0, 1.0, 0.5]]
+        ));
+    }
+
+    #[test]
+    fn convex_hull_2d_sets_z_to_zero() {
+        let shape = CollisionShape::convex_hull_2d([Vec2::ZERO, Vec2::X, Vec2::Y]);
+        assert_eq!(
+            shape.points(),
+            Some([Vec3::ZERO, Vec3::X, Vec3::Y].as_slice())
+        );
+    }
+
+    #[test]
+    fn tuple_converts_to_rectangle() {
+        let shape: CollisionShape = (1.0, 2.0).into();
+        assert!(matches!(
+            shape,
+            CollisionShape::Cuboid { half_extends, .. } if half_extends == Vec3::new(1.0, 2.0, 0.0)
+        ));
+    }
+}
@@ -0,0 +1,297 @@
+use bevy::math::{Vec2, Vec3};
+
+use crate::CollisionShape;
+
+/// Accessors to the data of the [`CollisionShape`] variants, to let editing tools manipulate the
+/// points and heights without matching on every variant
+///
+/// Note that mutably dereferencing a `Mut<CollisionShape>` marks the component as changed, which
+/// makes the physics backend recreate the collider. It is best to only call the `_mut` accessors
+/// when an actual edit is made.
+impl CollisionShape {
+    /// Returns the points of a [`ConvexHull`](Self::ConvexHull) or
+    /// [`ConvexPolyhedron`](Self::ConvexPolyhedron), or `None` for the other shapes
+    #[must_use]
+    pub fn points(&self) -> Option<&[Vec3]> {
+        match self {
+            Self::ConvexHull { points, .. } | Self::ConvexPolyhedron { points, .. } => Some(points),
+            _ => None,
+        }
+    }
+
+    /// Returns the mutable points of a [`ConvexHull`](Self::ConvexHull) or
+    /// [`ConvexPolyhedron`](Self::ConvexPolyhedron), or `None` for the other shapes
+    ///
+    /// Call [`validate`](Self::validate) after editing, as the points may no longer form a valid
+    /// hull.
+    pub fn points_mut(&mut self) -> Option<&mut Vec<Vec3>> {
+        match self {
+            Self::ConvexHull { points, .. } | Self::ConvexPolyhedron { points, .. } => Some(points),
+            _ => None,
+        }
+    }
+
+    /// Returns the heights of a [`HeightField`](Self::HeightField), or `None` for the other shapes
+    #[must_use]
+    pub fn heights(&self) -> Option<&[Vec<f32>]> {
+        match self {
+            Self::HeightField { heights, .. } => Some(heights),
+            _ => None,
+        }
+    }
+
+    /// Returns the mutable heights of a [`HeightField`](Self::HeightField), or `None` for the
+    /// other shapes
+    ///
+    /// Call [`validate`](Self::validate) after editing, as all the rows must keep the same length.
+    pub fn heights_mut(&mut self) -> Option<&mut Vec<Vec<f32>>> {
+        match self {
+            Self::HeightField { heights, .. } => Some(heights),
+            _ => None,
+        }
+    }
+
+    /// Checks the invariants of the shape
+    ///
+    /// The physics backend doesn't create a collider for an invalid shape, and marks its entity
+    /// with [`InvalidCollisionShape`](crate::InvalidCollisionShape) instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if a dimension is negative or not finite, if the
+    /// points of a convex hull are all aligned (or coplanar in 3d), if the indices of a convex
+    /// polyhedron are out of bounds, or if a height field is too small or ragged.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let is_valid_length = |length: f32| length.is_finite() && length >= 0.0;
+        let is_valid_border = |border: &Option<f32>| border.map_or(true, is_valid_length);
+
+        match self {
+            Self::Sphere { radius } if !is_valid_length(*radius) => {
+                Err("The radius must be finite and positive")
+            }
+            Self::Capsule {
+                half_segment,
+                radius,
+            } if !is_valid_length(*half_segment) || !is_valid_length(*radius) => {
+                Err("The half segment and radius must be finite and positive")
+            }
+            Self::Cuboid {
+                half_extends,
+                border_radius,
+            } if !half_extends.to_array().into_iter().all(is_valid_length)
+                || !is_valid_border(border_radius) =>
+            {
+                Err("The half extends and border radius must be finite and positive")
+            }
+            Self::ConvexHull {
+                points,
+                border_radius,
+            } => {
+                if !points.iter().all(|point| point.is_finite()) || !is_valid_border(border_radius)
+                {
+                    Err("The points and border radius must be finite")
+                } else if is_flat(points) {
+                    Err("The convex hull is degenerate (the points are all aligned or coplanar)")
+                } else {
+                    Ok(())
+                }
+            }
+            Self::ConvexPolyhedron {
+                points,
+                indices,
+                border_radius,
+            } => validate_polyhedron(points, indices, border_radius.map_or(true, is_valid_length)),
+            Self::HeightField { size, heights } => validate_heightfield(*size, heights),
+            #[cfg(dim3)]
+            Self::Cone {
+                half_height,
+                radius,
+            }
+            | Self::Cylinder {
+                half_height,
+                radius,
+            } if !is_valid_length(*half_height) || !is_valid_length(*radius) => {
+                Err("The half height and radius must be finite and positive")
+            }
+            #[cfg(dim2)]
+            Self::Triangle {
+                a,
+                b,
+                c,
+                border_radius,
+            } if !a.is_finite()
+                || !b.is_finite()
+                || !c.is_finite()
+                || !is_valid_border(border_radius) =>
+            {
+                Err("The vertices and border radius must be finite")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn validate_polyhedron(
+    points: &[Vec3],
+    indices: &[[u32; 3]],
+    is_valid_border: bool,
+) -> Result<(), &'static str> {
+    if !points.iter().all(|point| point.is_finite()) || !is_valid_border {
+        Err("The points and border radius must be finite")
+    } else if is_flat(points) {
+        Err("The convex polyhedron is degenerate (the points are all aligned or coplanar)")
+    } else if cfg!(not(dim2))
+        && indices
+            .iter()
+            .flatten()
+            .any(|index| *index as usize >= points.len())
+    {
+        Err("The indices of the convex polyhedron must refer to its points")
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns true if the points don't span the whole plane, in which case no convex hull can be
+/// computed
+#[cfg(dim2)]
+fn is_flat(points: &[Vec3]) -> bool {
+    let points: Vec<Vec2> = points.iter().copied().map(Vec3::truncate).collect();
+    let origin = points.first().copied().unwrap_or_default();
+    let tolerance = flatness_tolerance(points.iter().map(|point| point.distance(origin)));
+    let mut offsets = points.iter().map(|point| *point - origin);
+
+    match offsets.find(|offset| offset.length() > tolerance) {
+        Some(axis) => {
+            let axis = axis.normalize();
+            !offsets.any(|offset| axis.perp_dot(offset).abs() > tolerance)
+        }
+        None => true,
+    }
+}
+
+/// Returns true if the points don't span the whole space, in which case no convex hull can be
+/// computed
+#[cfg(not(dim2))]
+fn is_flat(points: &[Vec3]) -> bool {
+    let origin = points.first().copied().unwrap_or_default();
+    let tolerance = flatness_tolerance(points.iter().map(|point| point.distance(origin)));
+    let mut offsets = points.iter().map(|point| *point - origin);
+
+    let axis = match offsets.find(|offset| offset.length() > tolerance) {
+        Some(offset) => offset.normalize(),
+        None => return true,
+    };
+    let normal = match offsets.find(|offset| axis.cross(*offset).length() > tolerance) {
+        Some(offset) => axis.cross(offset).normalize(),
+        None => return true,
+    };
+    !offsets.any(|offset| normal.dot(offset).abs() > tolerance)
+}
+
+/// Distance under which points are considered to be aligned, relative to the size of the hull
+fn flatness_tolerance(distances: impl Iterator<Item = f32>) -> f32 {
+    distances.fold(0.0, f32::max) * 1e-5
+}
+
+#[cfg(dim2)]
+fn validate_heightfield(size: Vec2, heights: &[Vec<f32>]) -> Result<(), &'static str> {
+    let heights = heights.first().map(Vec::as_slice).unwrap_or_default();
+    if !size.x.is_finite() || heights.len() < 2 {
+        Err("The height field must have a finite size and at least 2 heights")
+    } else if !heights.iter().all(|height| height.is_finite()) {
+        Err("The heights must be finite")
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(not(dim2))]
+fn validate_heightfield(size: Vec2, heights: &[Vec<f32>]) -> Result<(), &'static str> {
+    let ncols = heights.first().map(Vec::len).unwrap_or_default();
+    if !size.is_finite() || heights.len() < 2 || ncols < 2 {
+        Err("The height field must have a finite size and at least 2 rows and 2 columns")
+    } else if heights.iter().any(|row| row.len() != ncols) {
+        Err("All the rows of the height field must have the same length")
+    } else if !heights.iter().flatten().all(|height| height.is_finite()) {
+        Err("The heights must be finite")
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_of_convex_hull_can_be_edited() {
+        let mut shape = CollisionShape::ConvexHull {
+            points: vec![Vec3::ZERO, Vec3::X],
+            border_radius: None,
+        };
+
+        shape.points_mut().unwrap().push(Vec3::Y);
+
+        assert_eq!(
+            shape.points(),
+            Some([Vec3::ZERO, Vec3::X, Vec3::Y].as_slice())
+        );
+        assert!(shape.heights().is_none());
+    }
+
+    #[test]
+    fn heights_of_height_field_can_be_edited() {
+        let mut shape = CollisionShape::HeightField {
+            size: Vec2::ONE,
+            heights: vec![vec![0.0, 1.0], vec![2.0, 3.0]],
+        };
+
+        shape.heights_mut().unwrap()[0][0] = 4.0;
+
+        assert_eq!(shape.heights().unwrap()[0], vec![4.0, 1.0]);
+        assert!(shape.points().is_none());
+    }
+
+    #[test]
+    fn shapes_without_points_have_no_points() {
+        let mut shape = CollisionShape::Sphere { radius: 1.0 };
+        assert!(shape.points().is_none());
+        assert!(shape.points_mut().is_none());
+        assert!(shape.heights_mut().is_none());
+    }
+
+    #[test]
+    fn degenerate_convex_hull_is_invalid() {
+        let shape = CollisionShape::ConvexHull {
+            points: vec![Vec3::ZERO, Vec3::X, Vec3::X * 2.0],
+            border_radius: None,
+        };
+        assert!(shape.validate().is_err());
+    }
+
+    #[test]
+    #[cfg(not(dim2))]
+    fn convex_polyhedron_indices_must_refer_to_points() {
+        let points = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z];
+        let valid = CollisionShape::ConvexPolyhedron {
+            points: points.clone(),
+            indices: vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+            border_radius: None,
+        };
+        assert!(valid.validate().is_ok());
+
+        let invalid = CollisionShape::ConvexPolyhedron {
+            points,
+            indices: vec![[0, 1, 4]],
+            border_radius: None,
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn negative_radius_is_invalid() {
+        assert!(CollisionShape::Sphere { radius: -1.0 }.validate().is_err());
+        assert!(CollisionShape::Sphere { radius: 1.0 }.validate().is_ok());
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+
+use crate::CollisionShape;
+
+/// Component that switches the [`CollisionShape`] of the entity depending on the distance to the
+/// nearest [`ShapeLodReference`] (typically the camera or the player)
+///
+/// The `near` shape is used when a reference is closer than `distance`, or if there is no
+/// reference at all. Otherwise, the `far` shape is used. That lets distant objects use cheap shapes,
+/// while nearby objects use detailed ones.
+///
+/// The [`CollisionShape`] component is inserted and replaced automatically, and shouldn't be
+/// added manually.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands, detailed_points: Vec<Vec3>) {
+///     commands.spawn_bundle(todo!("Spawn your camera"))
+///         .insert(ShapeLodReference);
+///
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(ShapeLod {
+///             near: CollisionShape::ConvexHull { points: detailed_points, border_radius: None },
+///             far: CollisionShape::Sphere { radius: 1.0 },
+///             distance: 50.0,
+///         });
+/// }
+/// ```
+#[derive(Debug, Component, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ShapeLod {
+    /// Shape used when a reference is closer than `distance`
+    pub near: CollisionShape,
+    /// Shape used when all references are further than `distance`
+    pub far: CollisionShape,
+    /// Distance from which the `far` shape is used
+    pub distance: f32,
+}
+
+/// Marker component for the entities (like the camera or the player) from which the distance of
+/// the [`ShapeLod`]s is measured
+#[derive(Debug, Component, Copy, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ShapeLodReference;
+
+/// Level of detail currently in use
+#[derive(Debug, Component, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ActiveLod {
+    Near,
+    Far,
+}
+
+impl ShapeLod {
+    fn level(&self, position: Vec3, references: &[Vec3]) -> ActiveLod {
+        let distance_squared = self.distance * self.distance;
+        let is_far = !references.is_empty()
+            && references
+                .iter()
+                .all(|it| it.distance_squared(position) >= distance_squared);
+
+        if is_far {
+            ActiveLod::Far
+        } else {
+            ActiveLod::Near
+        }
+    }
+
+    fn shape(&self, level: ActiveLod) -> &CollisionShape {
+        match level {
+            ActiveLod::Near => &self.near,
+            ActiveLod::Far => &self.far,
+        }
+    }
+}
+
+/// Inserts the [`CollisionShape`] matching the distance to the nearest [`ShapeLodReference`]
+pub(super) fn update_shape_lod_system(
+    mut commands: Commands<'_, '_>,
+    references: Query<'_, '_, &GlobalTransform, With<ShapeLodReference>>,
+    lods: Query<'_, '_, (Entity, &ShapeLod, &GlobalTransform, Option<&ActiveLod>)>,
+    changed: Query<'_, '_, (), Changed<ShapeLod>>,
+) {
+    let references: Vec<Vec3> = references
+        .iter()
+        .map(GlobalTransform::translation)
+        .collect();
+
+    for (entity, lod, transform, active) in lods.iter() {
+        let level = lod.level(transform.translation(), &references);
+        if active == Some(&level) && !changed.contains(entity) {
+            continue;
+        }
+
+        commands
+            .entity(entity)
+            .insert(lod.shape(level).clone())
+            .insert(level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lod() -> ShapeLod {
+        ShapeLod {
+            near: CollisionShape::Sphere { radius: 1.0 },
+            far: CollisionShape::Sphere { radius: 2.0 },
+            distance: 10.0,
+        }
+    }
+
+    #[test]
+    fn near_without_reference() {
+        assert_eq!(lod().level(Vec3::ZERO, &[]), ActiveLod::Near);
+    }
+
+    #[test]
+    fn near_if_any_reference_is_close() {
+        assert_eq!(
+            lod().level(Vec3::ZERO, &[Vec3::X * 100.0, Vec3::Y * 5.0]),
+            ActiveLod::Near
+        );
+    }
+
+    #[test]
+    fn far_if_all_references_are_far() {
+        assert_eq!(
+            lod().level(Vec3::ZERO, &[Vec3::X * 100.0, Vec3::Y * 10.0]),
+            ActiveLod::Far
+        );
+    }
+}
@@ -1,23 +1,55 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
+use bevy::reflect::FromReflect;
 
 /// Resource to control how many physics steps are performed per second.
 ///
-/// Note that the physics update will be performed at most once per frame. It means that if the rate of
-/// frames per second is lower than the physics step per second, the physics simulation will slows down.
+/// When a frame takes longer than the configured step interval, that frame performs one step per
+/// elapsed interval (see [`PhysicsSteps::steps_this_frame`]), so that the simulation doesn't slow
+/// down. After a very long frame, at most [`PhysicsSteps::MAX_STEPS_PER_FRAME`] steps are
+/// performed and the rest of the elapsed time is dropped.
 ///
 /// This resource is used to tune the precision and performance of the physics system.
 /// It doesn't change the speed of the simulation.
 /// To change the time scale, look at the [`PhysicsTime`](crate::PhysicsTime) resource instead.
+#[derive(Reflect, FromReflect)]
 pub struct PhysicsSteps(Mode);
 
+#[derive(Clone, Reflect, FromReflect)]
 enum Mode {
     MaxDeltaTime(Duration),
     EveryFrame(Duration),
-    Timer(Timer),
+    Timer {
+        interval: Duration,
+        elapsed: Duration,
+        steps: u32,
+    },
+    Manual {
+        duration: Duration,
+        pending: usize,
+        step_frame: bool,
+    },
 }
 
+/// Event requesting to perform one physics step, when the [`PhysicsSteps`] are
+/// [`manual`](PhysicsSteps::manual)
+///
+/// Exactly one physics step is performed for each request. If many steps are requested during the
+/// same frame, they are performed during the following frames, one step per frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn end_turn(mut step_requests: EventWriter<PhysicsStepRequest>) {
+///     step_requests.send(PhysicsStepRequest);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Reflect, FromReflect)]
+pub struct PhysicsStepRequest;
+
 impl Default for PhysicsSteps {
     fn default() -> Self {
         Self::from_max_delta_time(Duration::from_secs_f32(0.2) /* 50 FPS */)
@@ -25,7 +57,7 @@ impl Default for PhysicsSteps {
 }
 
 /// The duration of time that this physics step should advance the simulation time
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, FromReflect)]
 pub enum PhysicsStepDuration {
     /// The simulation time should be advanced by the provided exact duration
     Exact(Duration),
@@ -46,33 +78,34 @@ impl PhysicsStepDuration {
 }
 
 impl PhysicsSteps {
+    /// Maximum number of steps performed during a single frame when stepping with a timer
+    pub const MAX_STEPS_PER_FRAME: u32 = 8;
+
     /// Configure to run at the given number of steps per second
     ///
     /// The higher the value, the more precise and the more expensive the physics simulation will be.
-    /// If the value gets higher than the frame rate of the game, the physics simulation will slows down.
+    /// If the value gets higher than the frame rate of the game, many steps may be performed during
+    /// the same frame, which increases the cost of the simulation.
     ///
     /// For good results, it is better to choose a value as high as possible but lower than the typical frame rate of the game.
     ///
     /// # Panics
     ///
-    /// Panics if the argument is nan, infinite or negative
+    /// Panics if the argument is nan, infinite or negative, or so high that the interval is zero
     #[must_use]
     pub fn from_steps_per_seconds(steps_per_second: f32) -> Self {
         assert!(
             steps_per_second.is_finite() && steps_per_second > 0.0,
-            "Invalid steps per second: {}",
-            steps_per_second
+            "Invalid steps per second: {steps_per_second}"
         );
-        Self(Mode::Timer(Timer::from_seconds(
-            1.0 / steps_per_second,
-            true,
-        )))
+        Self::timer(Duration::from_secs_f32(1.0 / steps_per_second))
     }
 
     /// Configure the physics systems to wait for the given duration before running again
     ///
     /// The lower the value, the more precise and the more expensive the physics simulation will be.
-    /// If the value gets lower than the delta time between each frame of the game, the physics simulation will slows down.
+    /// If the value gets lower than the delta time between each frame of the game, many steps may be
+    /// performed during the same frame, which increases the cost of the simulation.
     ///
     /// For good results, it is better to choose a value as low as possible, but higher than the typical delay between each frame of the game.
     ///
@@ -81,8 +114,16 @@ impl PhysicsSteps {
     /// Panics if the duration is zero
     #[must_use]
     pub fn from_delta_time(duration: Duration) -> Self {
-        assert_ne!(!duration.as_nanos(), 0, "Invalid duration: {:?}", duration);
-        Self(Mode::Timer(Timer::new(duration, true)))
+        Self::timer(duration)
+    }
+
+    fn timer(interval: Duration) -> Self {
+        assert!(interval > Duration::ZERO, "Invalid duration: {interval:?}");
+        Self(Mode::Timer {
+            interval,
+            elapsed: Duration::ZERO,
+            steps: 0,
+        })
     }
 
     /// Configure the physics systems to run at each and every frame, advancing the simulation the
@@ -95,7 +136,7 @@ impl PhysicsSteps {
     /// Panics if the duration is zero
     #[must_use]
     pub fn every_frame(duration: Duration) -> Self {
-        assert_ne!(!duration.as_micros(), 0, "Invalid duration: {:?}", duration);
+        assert!(duration > Duration::ZERO, "Invalid duration: {duration:?}");
         Self(Mode::EveryFrame(duration))
     }
 
@@ -133,12 +174,46 @@ impl PhysicsSteps {
         Self(Mode::MaxDeltaTime(max))
     }
 
+    /// Configure the physics systems to run only when a step is requested, by sending a
+    /// [`PhysicsStepRequest`] event
+    ///
+    /// Each step advances the simulation by the given duration.
+    ///
+    /// This is useful for tests, turn-based games, replay systems, etc.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is zero
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use heron_core::PhysicsSteps;
+    /// # use std::time::Duration;
+    /// App::new()
+    ///     // Only runs the physics step when a `PhysicsStepRequest` event is sent
+    ///     .insert_resource(PhysicsSteps::manual(Duration::from_secs_f64(1.0 / 60.0)))
+    ///     // ...
+    ///     .run();
+    /// ```
+    #[must_use]
+    pub fn manual(duration: Duration) -> Self {
+        assert!(duration > Duration::ZERO, "Invalid duration: {duration:?}");
+        Self(Mode::Manual {
+            duration,
+            pending: 0,
+            step_frame: false,
+        })
+    }
+
     /// Returns true only if the current frame is a frame that execute a physics simulation step
     #[must_use]
     pub fn is_step_frame(&self) -> bool {
         match &self.0 {
             Mode::EveryFrame(_) | Mode::MaxDeltaTime(_) => true,
-            Mode::Timer(timer) => timer.just_finished(),
+            Mode::Timer { steps, .. } => *steps > 0,
+            Mode::Manual { step_frame, .. } => *step_frame,
         }
     }
 
@@ -146,20 +221,86 @@ impl PhysicsSteps {
     #[must_use]
     pub fn duration(&self) -> PhysicsStepDuration {
         match &self.0 {
-            Mode::EveryFrame(duration) => PhysicsStepDuration::Exact(*duration),
-            Mode::Timer(timer) => PhysicsStepDuration::Exact(timer.duration()),
+            Mode::EveryFrame(duration)
+            | Mode::Manual { duration, .. }
+            | Mode::Timer {
+                interval: duration, ..
+            } => PhysicsStepDuration::Exact(*duration),
             Mode::MaxDeltaTime(max) => PhysicsStepDuration::MaxDeltaTime(*max),
         }
     }
 
-    pub(crate) fn update(mut physics_steps: ResMut<'_, PhysicsSteps>, time: Res<'_, Time>) {
-        physics_steps.do_update(time.delta());
+    /// Number of physics steps to perform during the current frame
+    ///
+    /// Each step advances the simulation by the [`duration`](PhysicsSteps::duration) of a step. It
+    /// is `0` when the current frame isn't a [step frame](PhysicsSteps::is_step_frame), and `1`
+    /// otherwise, except when stepping with a timer
+    /// ([`from_steps_per_seconds`](PhysicsSteps::from_steps_per_seconds) or
+    /// [`from_delta_time`](PhysicsSteps::from_delta_time)) and more than one interval has elapsed
+    /// since the last step. In that case, one step is performed for each elapsed interval, up to
+    /// [`MAX_STEPS_PER_FRAME`](PhysicsSteps::MAX_STEPS_PER_FRAME), and the remainder is carried
+    /// over to the next frames.
+    #[must_use]
+    pub fn steps_this_frame(&self) -> u32 {
+        match &self.0 {
+            Mode::Timer { steps, .. } => *steps,
+            _ => u32::from(self.is_step_frame()),
+        }
+    }
+
+    /// Progress of the time toward the next physics step, between `0.0` (a step has just been
+    /// performed) and `1.0`
+    ///
+    /// It is used to [`Interpolate`](crate::Interpolate) the rendered poses of the rigid bodies. It
+    /// is always `1.0` when the steps aren't driven by a timer, since the simulation is then up to
+    /// date at each step frame.
+    #[must_use]
+    pub fn interpolation_factor(&self) -> f32 {
+        match &self.0 {
+            Mode::Timer {
+                interval, elapsed, ..
+            } => (elapsed.as_secs_f32() / interval.as_secs_f32()).min(1.0),
+            _ => 1.0,
+        }
+    }
+
+    pub(crate) fn update(
+        mut physics_steps: ResMut<'_, PhysicsSteps>,
+        time: Res<'_, Time>,
+        mut requests: EventReader<'_, '_, PhysicsStepRequest>,
+    ) {
+        physics_steps.do_update(time.delta(), requests.iter().count());
     }
 
     #[inline]
-    fn do_update(&mut self, delta: Duration) {
-        if let Mode::Timer(timer) = &mut self.0 {
-            timer.tick(delta);
+    fn do_update(&mut self, delta: Duration, requests: usize) {
+        match &mut self.0 {
+            Mode::Timer {
+                interval,
+                elapsed,
+                steps,
+            } => {
+                *elapsed += delta;
+                let count = elapsed.as_nanos() / interval.as_nanos();
+                *steps = u32::try_from(count)
+                    .unwrap_or(u32::MAX)
+                    .min(Self::MAX_STEPS_PER_FRAME);
+                // The intervals beyond the maximum are dropped, only the remainder is carried over
+                let remainder = elapsed.as_nanos() % interval.as_nanos();
+                *elapsed = Duration::from_nanos(u64::try_from(remainder).unwrap_or(u64::MAX));
+            }
+            Mode::Manual {
+                pending,
+                step_frame,
+                ..
+            } => {
+                *pending += requests;
+                *step_frame = *pending > 0;
+                if *step_frame {
+                    *pending -= 1;
+                }
+            }
+            Mode::EveryFrame(_) | Mode::MaxDeltaTime(_) => (),
         }
     }
 }
@@ -174,11 +315,12 @@ mod tests {
     #[case(PhysicsSteps::from_delta_time(Duration::from_secs(1)), 0.9)]
     #[case(PhysicsSteps::from_delta_time(Duration::from_secs_f32(0.016)), 0.01)]
     #[case(PhysicsSteps::from_steps_per_seconds(10.0), 0.09)]
+    #[case(PhysicsSteps::manual(Duration::from_secs(1)), 1.1)]
     fn is_not_step_frame_if_not_enough_time_has_elapsed(
         #[case] mut steps: PhysicsSteps,
         #[case] delta_time: f32,
     ) {
-        steps.do_update(Duration::from_secs_f32(delta_time));
+        steps.do_update(Duration::from_secs_f32(delta_time), 0);
         assert!(!steps.is_step_frame());
     }
 
@@ -192,7 +334,103 @@ mod tests {
         #[case] mut steps: PhysicsSteps,
         #[case] delta_time: f32,
     ) {
-        steps.do_update(Duration::from_secs_f32(delta_time));
+        steps.do_update(Duration::from_secs_f32(delta_time), 0);
         assert!(steps.is_step_frame());
     }
+
+    #[test]
+    fn timer_carries_remaining_time_over() {
+        let mut steps = PhysicsSteps::from_delta_time(Duration::from_millis(10));
+
+        steps.do_update(Duration::from_millis(6), 0);
+        assert!(!steps.is_step_frame());
+        steps.do_update(Duration::from_millis(6), 0);
+        assert_eq!(steps.steps_this_frame(), 1);
+        steps.do_update(Duration::from_millis(8), 0);
+        assert!(steps.is_step_frame());
+    }
+
+    #[test]
+    fn timer_performs_one_fixed_size_step_per_elapsed_interval() {
+        let mut steps = PhysicsSteps::from_delta_time(Duration::from_millis(10));
+
+        steps.do_update(Duration::from_millis(35), 0);
+        assert!(steps.is_step_frame());
+        assert_eq!(steps.steps_this_frame(), 3);
+        assert_eq!(
+            steps.duration(),
+            PhysicsStepDuration::Exact(Duration::from_millis(10))
+        );
+
+        steps.do_update(Duration::from_millis(4), 0);
+        assert!(!steps.is_step_frame());
+        steps.do_update(Duration::from_millis(1), 0);
+        assert!(steps.is_step_frame());
+    }
+
+    #[test]
+    fn timer_performs_at_most_the_max_steps_per_frame() {
+        let mut steps = PhysicsSteps::from_delta_time(Duration::from_millis(10));
+
+        steps.do_update(Duration::from_secs(10) + Duration::from_millis(4), 0);
+        assert_eq!(steps.steps_this_frame(), PhysicsSteps::MAX_STEPS_PER_FRAME);
+
+        steps.do_update(Duration::from_millis(5), 0);
+        assert!(!steps.is_step_frame());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid duration")]
+    fn zero_delta_time_is_rejected() {
+        let _ = PhysicsSteps::from_delta_time(Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid duration")]
+    fn too_many_steps_per_seconds_are_rejected() {
+        let _ = PhysicsSteps::from_steps_per_seconds(f32::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid duration")]
+    fn zero_manual_step_duration_is_rejected() {
+        let _ = PhysicsSteps::manual(Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid duration")]
+    fn zero_every_frame_duration_is_rejected() {
+        let _ = PhysicsSteps::every_frame(Duration::ZERO);
+    }
+
+    #[test]
+    fn interpolation_factor_is_the_progress_toward_the_next_step() {
+        let mut steps = PhysicsSteps::from_delta_time(Duration::from_millis(10));
+
+        steps.do_update(Duration::from_millis(14), 0);
+        assert!((steps.interpolation_factor() - 0.4).abs() < 1e-5);
+
+        assert!(
+            (PhysicsSteps::every_frame(Duration::from_millis(10)).interpolation_factor() - 1.0)
+                .abs()
+                < f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn manual_steps_once_per_request() {
+        let mut steps = PhysicsSteps::manual(Duration::from_secs(1));
+
+        steps.do_update(Duration::ZERO, 2);
+        assert!(steps.is_step_frame());
+        steps.do_update(Duration::ZERO, 0);
+        assert!(steps.is_step_frame());
+        steps.do_update(Duration::ZERO, 0);
+        assert!(!steps.is_step_frame());
+        assert_eq!(steps.steps_this_frame(), 0);
+        assert_eq!(
+            steps.duration(),
+            PhysicsStepDuration::Exact(Duration::from_secs(1))
+        );
+    }
 }
@@ -0,0 +1,141 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::{Quat, Vec3};
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that teleports a rigid body at the beginning of the next physics step
+///
+/// Unlike changing the `Transform`, the teleport is applied as-is by the physics backend: the body
+/// is woken up, and its motion for the step starts from the new position, so that it doesn't sweep
+/// through the bodies between the old and new positions. The `Transform` (or
+/// [`PhysicsPose`](crate::PhysicsPose)) is updated accordingly.
+///
+/// The component is removed once the teleport is done.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn respawn(mut commands: Commands, player: Query<Entity, With<RigidBody>>) {
+///     for entity in player.iter() {
+///         commands.entity(entity).insert(Teleport::to(Vec3::Y * 10.0).with_velocity_reset());
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct Teleport {
+    /// Destination of the rigid body, in world space
+    pub translation: Vec3,
+
+    /// Rotation of the rigid body after the teleport
+    ///
+    /// The current rotation is kept if `None`.
+    pub rotation: Option<Quat>,
+
+    /// Set to true to stop the body (linear and angular velocity set to zero)
+    pub reset_velocity: bool,
+}
+
+impl Default for Teleport {
+    fn default() -> Self {
+        Self::to(Vec3::ZERO)
+    }
+}
+
+impl Teleport {
+    /// Teleports the body to the given translation, keeping its rotation and velocity
+    #[must_use]
+    pub fn to(translation: Vec3) -> Self {
+        Self {
+            translation,
+            rotation: None,
+            reset_velocity: false,
+        }
+    }
+
+    /// Returns a new version of this teleport, that also sets the rotation of the body
+    #[must_use]
+    pub fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = Some(rotation);
+        self
+    }
+
+    /// Returns a new version of this teleport, that also stops the body
+    #[must_use]
+    pub fn with_velocity_reset(mut self) -> Self {
+        self.reset_velocity = true;
+        self
+    }
+}
+
+/// Resource that defines the distance above which moving a kinematic body is a teleport
+///
+/// When the `Transform` (or [`PhysicsPose`](crate::PhysicsPose)) of a
+/// [`RigidBody::KinematicPositionBased`](crate::RigidBody::KinematicPositionBased) changes, the
+/// body moves to its new position during the next step, with the velocity needed to reach it. That
+/// pushes the dynamic bodies on its way. When the body jumps far away (e.g. respawn), that velocity
+/// becomes huge, and launches the bodies around.
+///
+/// When the body moves farther than this distance in a single step, it is teleported instead, as
+/// with the [`Teleport`] component.
+///
+/// By default, the distance is infinite: kinematic bodies are never teleported.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+///
+/// fn main() {
+///     App::new()
+///         // ... Add plugins
+///         .insert_resource(KinematicTeleportThreshold::new(10.0))
+///         // ... Add systems
+///         .run();
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Reflect, FromReflect)]
+pub struct KinematicTeleportThreshold {
+    /// Distance above which the kinematic bodies are teleported
+    pub distance: f32,
+}
+
+impl Default for KinematicTeleportThreshold {
+    fn default() -> Self {
+        Self::new(f32::INFINITY)
+    }
+}
+
+impl KinematicTeleportThreshold {
+    /// Creates a threshold with the given distance
+    #[must_use]
+    pub fn new(distance: f32) -> Self {
+        Self { distance }
+    }
+
+    /// Returns true if moving a kinematic body from `from` to `to` is a teleport
+    #[must_use]
+    pub fn is_teleport(self, from: Vec3, to: Vec3) -> bool {
+        from.distance_squared(to) > self.distance * self.distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn never_teleports_by_default() {
+        assert!(!KinematicTeleportThreshold::default().is_teleport(Vec3::ZERO, Vec3::X * 1e6));
+    }
+
+    #[test]
+    fn teleports_above_the_distance() {
+        let threshold = KinematicTeleportThreshold::new(2.0);
+        assert!(!threshold.is_teleport(Vec3::X, Vec3::X * 2.9));
+        assert!(threshold.is_teleport(Vec3::X, Vec3::X * 3.1));
+    }
+}
@@ -0,0 +1,165 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::math::Vec2;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that moves a rigid body like the character of a top-down 2d game
+///
+/// The desired direction is set with [`set_direction`](Self::set_direction), typically from the
+/// player input. Before each physics step, the linear velocity (in the `XY` plane) is accelerated
+/// toward `direction * max_speed`, and decelerated toward zero when there is no input.
+///
+/// The velocity is updated right before the physics step, so that the contacts with the walls are
+/// resolved by the physics engine. For the character to slide along the walls, it is recommended
+/// to use a [`PhysicMaterial`](crate::PhysicMaterial) without friction and to lock the rotations
+/// with [`RotationConstraints::lock`](crate::RotationConstraints::lock).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert_bundle(CharacterBundle::default())
+///         .insert(TopDownController::new(200.0).with_facing(true));
+/// }
+///
+/// fn handle_input(input: Res<Input<KeyCode>>, mut controllers: Query<&mut TopDownController>) {
+///     let direction = Vec2::new(
+///         f32::from(input.pressed(KeyCode::D)) - f32::from(input.pressed(KeyCode::A)),
+///         f32::from(input.pressed(KeyCode::W)) - f32::from(input.pressed(KeyCode::S)),
+///     );
+///     for mut controller in controllers.iter_mut() {
+///         controller.set_direction(direction);
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct TopDownController {
+    /// Maximum speed, in units-per-second
+    pub max_speed: f32,
+
+    /// Rate at which the speed increases toward the desired velocity, in units-per-second-squared
+    pub acceleration: f32,
+
+    /// Rate at which the speed decreases when there is no input, in units-per-second-squared
+    pub deceleration: f32,
+
+    /// Set to true to rotate the body (around the `Z` axis) so that its `X` axis faces the
+    /// direction of the input
+    pub facing: bool,
+
+    direction: Vec2,
+}
+
+impl Default for TopDownController {
+    fn default() -> Self {
+        Self::new(5.0)
+    }
+}
+
+impl TopDownController {
+    /// Create a new controller with the given maximum speed
+    ///
+    /// The acceleration is set to reach the maximum speed in a tenth of a second, and the
+    /// deceleration to stop in a twentieth of a second, which feels responsive in most games.
+    #[must_use]
+    pub fn new(max_speed: f32) -> Self {
+        Self {
+            max_speed,
+            acceleration: max_speed * 10.0,
+            deceleration: max_speed * 20.0,
+            facing: false,
+            direction: Vec2::ZERO,
+        }
+    }
+
+    /// Returns a new version of this controller with the given acceleration
+    #[must_use]
+    pub fn with_acceleration(mut self, acceleration: f32) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Returns a new version of this controller with the given deceleration
+    #[must_use]
+    pub fn with_deceleration(mut self, deceleration: f32) -> Self {
+        self.deceleration = deceleration;
+        self
+    }
+
+    /// Returns a new version of this controller, that rotates the body toward the direction of
+    /// the input if `facing` is true
+    #[must_use]
+    pub fn with_facing(mut self, facing: bool) -> Self {
+        self.facing = facing;
+        self
+    }
+
+    /// Set the desired direction of movement
+    ///
+    /// A direction longer than one is normalized, while a shorter one (like an analog stick
+    /// slightly tilted) moves slower than the maximum speed.
+    pub fn set_direction(&mut self, direction: Vec2) {
+        self.direction = direction.clamp_length_max(1.0);
+    }
+
+    /// Returns the desired direction of movement
+    #[must_use]
+    pub fn direction(&self) -> Vec2 {
+        self.direction
+    }
+
+    /// Returns the velocity after `delta_seconds`, given the `current` velocity
+    #[must_use]
+    pub fn next_velocity(&self, current: Vec2, delta_seconds: f32) -> Vec2 {
+        let target = self.direction * self.max_speed;
+        let rate = if self.direction == Vec2::ZERO {
+            self.deceleration
+        } else {
+            self.acceleration
+        };
+
+        let delta = target - current;
+        let max_change = rate * delta_seconds;
+        if delta.length() <= max_change {
+            target
+        } else {
+            current + delta.normalize() * max_change
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction_is_clamped() {
+        let mut controller = TopDownController::default();
+        controller.set_direction(Vec2::new(3.0, 4.0));
+        assert!((controller.direction() - Vec2::new(0.6, 0.8)).length() < 0.0001);
+
+        controller.set_direction(Vec2::X * 0.5);
+        assert_eq!(controller.direction(), Vec2::X * 0.5);
+    }
+
+    #[test]
+    fn accelerates_toward_max_speed() {
+        let mut controller = TopDownController::new(10.0).with_acceleration(20.0);
+        controller.set_direction(Vec2::X);
+
+        assert_eq!(controller.next_velocity(Vec2::ZERO, 0.1), Vec2::X * 2.0);
+        assert_eq!(controller.next_velocity(Vec2::X * 9.0, 0.1), Vec2::X * 10.0);
+    }
+
+    #[test]
+    fn decelerates_without_input() {
+        let controller = TopDownController::new(10.0).with_deceleration(50.0);
+
+        assert_eq!(controller.next_velocity(Vec2::Y * 10.0, 0.1), Vec2::Y * 5.0);
+        assert_eq!(controller.next_velocity(Vec2::Y * 4.0, 0.1), Vec2::ZERO);
+    }
+}
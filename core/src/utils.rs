@@ -15,7 +15,6 @@ impl NearZero for f32 {
 }
 
 impl NearZero for Vec3 {
-    #[must_use]
     fn is_near_zero(self) -> bool {
         self.x.is_near_zero() && self.y.is_near_zero() && self.z.is_near_zero()
     }
@@ -0,0 +1,205 @@
+//! Raycast vehicles
+//!
+//! A [`RaycastVehicle`] is a rigid body (the chassis) held above the ground by a set of
+//! [`Wheel`]s. The wheels are not rigid bodies: each wheel casts its shape downward (along the
+//! negative `Y` axis of the chassis) to find the ground, and the suspension pushes the chassis up
+//! at the wheel anchor.
+//!
+//! The contact of each wheel with the ground is reported in the [`WheelContacts`] component, which
+//! is automatically inserted on the vehicle. It can be used to implement traction, steering or
+//! to detect that the vehicle is airborne.
+//!
+//! Only the vehicles of the [default physics world](crate::PhysicsWorldId::DEFAULT) are
+//! simulated.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use heron_core::*;
+//! # use heron_core::vehicle::*;
+//! fn spawn(mut commands: Commands) {
+//!     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+//!         .insert(RigidBody::Dynamic)
+//!         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(2.0, 0.5, 1.0), border_radius: None })
+//!         .insert(
+//!             RaycastVehicle::default()
+//!                 .with_wheel(Wheel::new(Vec3::new(-1.5, -0.5, 1.0), 0.4))
+//!                 .with_wheel(Wheel::new(Vec3::new(-1.5, -0.5, -1.0), 0.4))
+//!                 .with_wheel(Wheel::new(Vec3::new(1.5, -0.5, 1.0), 0.4))
+//!                 .with_wheel(Wheel::new(Vec3::new(1.5, -0.5, -1.0), 0.4))
+//!         );
+//! }
+//! ```
+
+use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::ecs::entity::Entity;
+use bevy::math::Vec3;
+use bevy::reflect::{FromReflect, Reflect};
+
+/// Component that makes the rigid body of the same entity a vehicle supported by wheels
+///
+/// See the [module documentation](self) for more details.
+#[derive(Debug, Component, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct RaycastVehicle {
+    /// The wheels of the vehicle
+    pub wheels: Vec<Wheel>,
+}
+
+impl RaycastVehicle {
+    /// Returns a new version of this vehicle with the given wheel added
+    #[must_use]
+    pub fn with_wheel(mut self, wheel: Wheel) -> Self {
+        self.wheels.push(wheel);
+        self
+    }
+}
+
+/// A wheel of a [`RaycastVehicle`]
+#[derive(Debug, Copy, Clone, PartialEq, Reflect, FromReflect)]
+pub struct Wheel {
+    /// Point where the suspension is attached, in the local space of the chassis
+    pub anchor: Vec3,
+    /// Radius of the wheel
+    pub radius: f32,
+    /// Suspension that links the wheel to the chassis
+    pub suspension: Suspension,
+}
+
+impl Wheel {
+    /// Returns a wheel attached at the given anchor, with the default suspension
+    #[must_use]
+    pub fn new(anchor: Vec3, radius: f32) -> Self {
+        Self {
+            anchor,
+            radius,
+            suspension: Suspension::default(),
+        }
+    }
+
+    /// Returns a new version of this wheel with the given suspension
+    #[must_use]
+    pub fn with_suspension(mut self, suspension: Suspension) -> Self {
+        self.suspension = suspension;
+        self
+    }
+}
+
+/// Suspension of a [`Wheel`], behaving like a damped spring
+#[derive(Debug, Copy, Clone, PartialEq, Reflect, FromReflect)]
+pub struct Suspension {
+    /// Distance between the anchor and the center of the wheel when the spring is at rest
+    pub rest_length: f32,
+    /// Force applied per unit of compression
+    pub stiffness: f32,
+    /// Force applied per unit of compression velocity
+    pub damping: f32,
+    /// Maximum force the suspension can apply
+    pub max_force: f32,
+}
+
+impl Default for Suspension {
+    fn default() -> Self {
+        Self {
+            rest_length: 0.5,
+            stiffness: 50.0,
+            damping: 5.0,
+            max_force: f32::MAX,
+        }
+    }
+}
+
+impl Suspension {
+    /// Returns a suspension with the given rest length, stiffness and damping
+    #[must_use]
+    pub fn new(rest_length: f32, stiffness: f32, damping: f32) -> Self {
+        Self {
+            rest_length,
+            stiffness,
+            damping,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a new version of this suspension with the given maximum force
+    #[must_use]
+    pub fn with_max_force(mut self, max_force: f32) -> Self {
+        self.max_force = max_force;
+        self
+    }
+}
+
+/// Component that contains the contact of each wheel of a [`RaycastVehicle`] with the ground
+///
+/// It is automatically inserted and updated at each physics step. The contacts are in the same
+/// order as the [`RaycastVehicle::wheels`].
+#[derive(Debug, Component, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct WheelContacts(#[reflect(ignore)] pub(crate) Vec<Option<WheelContact>>);
+
+impl WheelContacts {
+    /// Returns the contact of the wheel at the given index, or `None` if the wheel doesn't touch
+    /// the ground
+    #[must_use]
+    pub fn get(&self, wheel: usize) -> Option<&WheelContact> {
+        self.0.get(wheel).and_then(Option::as_ref)
+    }
+
+    /// Iterates over the contact of each wheel
+    pub fn iter(&self) -> impl Iterator<Item = Option<&WheelContact>> {
+        self.0.iter().map(Option::as_ref)
+    }
+
+    /// Returns true if at least one wheel touches the ground
+    #[must_use]
+    pub fn is_grounded(&self) -> bool {
+        self.0.iter().any(Option::is_some)
+    }
+
+    #[doc(hidden)]
+    pub fn set(&mut self, contacts: Vec<Option<WheelContact>>) {
+        self.0 = contacts;
+    }
+
+    #[doc(hidden)]
+    pub fn get_mut(&mut self, wheel: usize) -> Option<&mut WheelContact> {
+        self.0.get_mut(wheel).and_then(Option::as_mut)
+    }
+}
+
+/// Contact of a [`Wheel`] with the ground
+#[derive(Debug, Copy, Clone, PartialEq, Reflect, FromReflect)]
+pub struct WheelContact {
+    /// The collision shape entity touched by the wheel
+    pub entity: Entity,
+    /// Point of contact, in world space
+    pub point: Vec3,
+    /// Current distance between the anchor and the center of the wheel
+    pub suspension_length: f32,
+    /// Force applied by the suspension during the last physics step
+    pub suspension_force: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grounded_if_any_wheel_has_contact() {
+        let contact = WheelContact {
+            entity: Entity::from_raw(0),
+            point: Vec3::ZERO,
+            suspension_length: 0.5,
+            suspension_force: 0.0,
+        };
+        let mut contacts = WheelContacts::default();
+        assert!(!contacts.is_grounded());
+
+        contacts.set(vec![None, Some(contact)]);
+        assert!(contacts.is_grounded());
+        assert_eq!(contacts.get(0), None);
+        assert_eq!(contacts.get(1), Some(&contact));
+    }
+}
@@ -1,8 +1,10 @@
 use std::ops::{Mul, MulAssign};
 
 use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
 use bevy::math::prelude::*;
 use bevy::reflect::prelude::*;
+use bevy::reflect::FromReflect;
 use duplicate::duplicate_item;
 
 use crate::utils::NearZero;
@@ -29,7 +31,8 @@ use crate::utils::NearZero;
 ///         );
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect)]
+#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect, FromReflect)]
+#[reflect(Component)]
 pub struct Velocity {
     /// Linear velocity in units-per-second on each axis
     ///
@@ -47,6 +50,8 @@ pub struct Velocity {
 /// The linear part is in "unit" per second squared on each axis, represented as a `Vec3`. (The unit, being your game unit, be it pixel or anything else)
 /// The angular part is in radians per second squared around an axis, represented as an [`AxisAngle`]
 ///
+/// The acceleration does not depend on the mass of the body. To apply a mass-aware force, use [`Force`] instead.
+///
 /// # Example
 ///
 /// ```
@@ -63,7 +68,8 @@ pub struct Velocity {
 ///         );
 /// }
 /// ```
-#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect)]
+#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect, FromReflect)]
+#[reflect(Component)]
 pub struct Acceleration {
     /// Linear acceleration in units-per-second-squared on each axis
     pub linear: Vec3,
@@ -72,6 +78,42 @@ pub struct Acceleration {
     pub angular: AxisAngle,
 }
 
+/// Component that defines the linear force and the torque applied to a rigid body.
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// Unlike [`Acceleration`], the resulting acceleration depends on the mass of the body:
+/// the heavier the body, the less it accelerates.
+///
+/// The linear part is in "unit" times mass per second squared on each axis, represented as a `Vec3`.
+/// The angular part (torque) is around an axis, represented as an [`AxisAngle`]
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # use std::f32::consts::PI;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(
+///             Force::from_linear(Vec3::X * 10.0)
+///                 .with_angular(AxisAngle::new(Vec3::Z, 0.5 * PI))
+///         );
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct Force {
+    /// Linear force on each axis
+    pub linear: Vec3,
+
+    /// Torque around an axis
+    pub angular: AxisAngle,
+}
+
 /// Component that defines the linear and angular damping.
 ///
 /// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
@@ -94,7 +136,8 @@ pub struct Acceleration {
 ///         );
 /// }
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect, Component)]
+#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect, FromReflect, Component)]
+#[reflect(Component)]
 pub struct Damping {
     /// Linear damping coefficient
     pub linear: f32,
@@ -103,12 +146,133 @@ pub struct Damping {
     pub angular: f32,
 }
 
+/// Marker component that enables the continuous collision detection (CCD) of a rigid body
+///
+/// Without CCD, a fast and small body may go through thin obstacles, as the collisions are only
+/// detected at the positions of the body at each step. With CCD, the motion between two steps is
+/// taken into account, which prevents such tunneling, at an additional cost. It is best reserved
+/// for the bodies that need it, like bullets.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn_bullet(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 0.05 })
+///         .insert(Velocity::from_linear(Vec3::X * 500.0))
+///         .insert(Ccd);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Reflect, FromReflect, Component)]
+#[reflect(Component)]
+pub struct Ccd;
+
+/// Component that defines the velocity of the surface of a collision shape, like a conveyor belt
+///
+/// It must be inserted on the same entity of the [`CollisionShape`](crate::CollisionShape). The
+/// bodies in contact with the shape are dragged along its surface at the given velocity, even if
+/// the shape itself doesn't move.
+///
+/// The velocity is expressed in the frame of the collision shape, so that the belt keeps moving
+/// along the shape when it is rotated. Only the part which is tangent to the contact surface has
+/// an effect.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn_conveyor_belt(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Cuboid {
+///             half_extends: Vec3::new(10.0, 0.5, 0.0),
+///             border_radius: None,
+///         })
+///         .insert(SurfaceVelocity::from_linear(Vec3::X * 2.0));
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect, FromReflect, Component)]
+#[reflect(Component)]
+pub struct SurfaceVelocity {
+    /// Linear velocity of the surface in units-per-second, in the frame of the collision shape
+    pub linear: Vec3,
+}
+
 /// An [axis-angle] representation
 ///
 /// [axis-angle]: https://en.wikipedia.org/wiki/Axis%E2%80%93angle_representation
-#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect)]
+#[derive(Debug, Copy, Clone, PartialEq, Default, Reflect, FromReflect)]
 pub struct AxisAngle(Vec3);
 
+/// Resource that defines how much a [`Velocity`] must change before it is written to the physics
+/// engine
+///
+/// Writing a velocity to the physics engine wakes the rigid body up. When systems write the
+/// [`Velocity`] every frame (e.g. AI steering), skipping the negligible changes lets the resting
+/// bodies fall asleep, which reduces the work of the solver.
+///
+/// By default, only the changes smaller than [`f32::EPSILON`] are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+///
+/// fn main() {
+///     App::new()
+///         // ... Add plugins
+///         .insert_resource(VelocityTolerance::new(0.01, 0.001))
+///         // ... Add systems
+///         .run();
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Reflect, FromReflect)]
+pub struct VelocityTolerance {
+    /// Maximum ignored change of linear velocity, in units-per-second
+    pub linear: f32,
+
+    /// Maximum ignored change of angular velocity, in radians-per-second
+    pub angular: f32,
+}
+
+/// Component that defines how writing the [`Velocity`] affects the sleeping state of a rigid body
+///
+/// By default, writing a non-zero velocity wakes the body up, and writing a zero velocity leaves
+/// the body as it is, so that it falls asleep after resting for a while. For crowds of idle agents
+/// whose velocity is zeroed by the gameplay, [`SleepHint::SleepWhenStopped`] puts them to sleep
+/// immediately, which spares the solver the time they would need to fall asleep.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(Velocity::default())
+///         .insert(SleepHint::SleepWhenStopped);
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub enum SleepHint {
+    /// Writing a zero velocity lets the body fall asleep after resting for a while (default)
+    WakeWhenMoving,
+
+    /// Writing a zero velocity puts the body to sleep immediately
+    ///
+    /// Note that a sleeping body isn't moved by gravity until something wakes it up. This is meant
+    /// for bodies that rest on the ground or that aren't affected by gravity.
+    SleepWhenStopped,
+}
+
 impl Velocity {
     /// Returns a linear velocity from a vector
     #[must_use]
@@ -141,6 +305,46 @@ impl Velocity {
         self.angular = angular;
         self
     }
+
+    /// Returns an angular velocity around the Z axis, in radians-per-second
+    ///
+    /// This is meant for 2d games, where the bodies can only rotate around the Z axis. A positive
+    /// value rotates counter-clockwise.
+    #[must_use]
+    pub fn from_angular_2d(angular: f32) -> Self {
+        Self::from_angular(AxisAngle::from(Vec3::Z * angular))
+    }
+
+    /// Returns a new version with the given angular velocity around the Z axis, in
+    /// radians-per-second
+    #[must_use]
+    pub fn with_angular_2d(self, angular: f32) -> Self {
+        self.with_angular(AxisAngle::from(Vec3::Z * angular))
+    }
+
+    /// Returns the angular velocity around the Z axis, in radians-per-second
+    ///
+    /// This is meant for 2d games. A positive value is a counter-clockwise rotation.
+    #[must_use]
+    pub fn angular_2d(&self) -> f32 {
+        self.angular.axis().z
+    }
+
+    /// Set the angular velocity around the Z axis, in radians-per-second
+    ///
+    /// This is meant for 2d games. Any rotation around the other axes is removed.
+    pub fn set_angular_2d(&mut self, angular: f32) {
+        self.angular = AxisAngle::from(Vec3::Z * angular);
+    }
+
+    /// Returns the velocity of a point of the rigid body
+    ///
+    /// The `point` is relative to the center of mass of the body (which, for most shapes, is the
+    /// translation of its `GlobalTransform`). The result is `linear + angular × point`.
+    #[must_use]
+    pub fn at_point(&self, point: Vec3) -> Vec3 {
+        self.linear + Vec3::from(self.angular).cross(point)
+    }
 }
 
 impl Acceleration {
@@ -177,6 +381,83 @@ impl Acceleration {
     }
 }
 
+impl Force {
+    /// Returns a linear force from a vector
+    #[must_use]
+    pub fn from_linear(linear: Vec3) -> Self {
+        Self {
+            linear,
+            angular: AxisAngle::default(),
+        }
+    }
+
+    /// Returns a torque from an axis-angle
+    #[must_use]
+    pub fn from_angular(angular: AxisAngle) -> Self {
+        Self {
+            angular,
+            linear: Vec3::ZERO,
+        }
+    }
+
+    /// Returns a new version with the given linear force
+    #[must_use]
+    pub fn with_linear(mut self, linear: Vec3) -> Self {
+        self.linear = linear;
+        self
+    }
+
+    /// Returns a new version with the given torque
+    #[must_use]
+    pub fn with_angular(mut self, angular: AxisAngle) -> Self {
+        self.angular = angular;
+        self
+    }
+}
+
+impl Default for VelocityTolerance {
+    fn default() -> Self {
+        Self::new(f32::EPSILON, f32::EPSILON)
+    }
+}
+
+impl VelocityTolerance {
+    /// Returns a tolerance with the given linear and angular values
+    #[must_use]
+    pub fn new(linear: f32, angular: f32) -> Self {
+        Self { linear, angular }
+    }
+
+    /// Returns true if the change from `current` to `target` is negligible
+    #[must_use]
+    pub fn is_negligible(&self, current: Velocity, target: Velocity) -> bool {
+        (target.linear - current.linear).length() < self.linear
+            && (Vec3::from(target.angular) - Vec3::from(current.angular)).length() < self.angular
+    }
+}
+
+impl Default for SleepHint {
+    fn default() -> Self {
+        Self::WakeWhenMoving
+    }
+}
+
+impl SleepHint {
+    /// Returns true if writing the given velocity should put the body to sleep
+    #[must_use]
+    pub fn should_sleep(self, velocity: Velocity) -> bool {
+        self == Self::SleepWhenStopped && velocity.is_near_zero()
+    }
+}
+
+impl SurfaceVelocity {
+    /// Returns a surface velocity from a vector
+    #[must_use]
+    pub fn from_linear(linear: Vec3) -> Self {
+        Self { linear }
+    }
+}
+
 impl Damping {
     /// Returns a linear damping
     #[must_use]
@@ -215,6 +496,7 @@ impl Damping {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl From<Vec2> for Velocity {
     fn from(v: Vec2) -> Self {
@@ -226,6 +508,7 @@ impl From<Vec2> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl From<Vec3> for Velocity {
     fn from(linear: Vec3) -> Self {
@@ -237,6 +520,7 @@ impl From<Vec3> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl From<Velocity> for Vec3 {
     fn from(Velocity { linear, .. }: Velocity) -> Self {
@@ -248,6 +532,7 @@ impl From<Velocity> for Vec3 {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl From<AxisAngle> for Velocity {
     fn from(angular: AxisAngle) -> Self {
@@ -259,6 +544,7 @@ impl From<AxisAngle> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl From<Quat> for Velocity {
     fn from(quat: Quat) -> Self {
@@ -270,6 +556,7 @@ impl From<Quat> for Velocity {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl From<Velocity> for AxisAngle {
     fn from(Velocity { angular, .. }: Velocity) -> Self {
@@ -281,6 +568,7 @@ impl From<Velocity> for AxisAngle {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl From<Velocity> for Quat {
     fn from(Velocity { angular, .. }: Velocity) -> Self {
@@ -310,6 +598,7 @@ impl From<AxisAngle> for f32 {
   Velocity;
   [ Velocity ];
   [ Acceleration ];
+  [ Force ];
 )]
 impl NearZero for Velocity {
     fn is_near_zero(self) -> bool {
@@ -399,3 +688,51 @@ impl From<AxisAngle> for Quat {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angular_2d_round_trip() {
+        assert_eq!(Velocity::from_angular_2d(-1.5).angular_2d(), -1.5);
+        assert_eq!(
+            Velocity::from_linear(Vec3::X)
+                .with_angular_2d(2.0)
+                .angular_2d(),
+            2.0
+        );
+    }
+
+    #[test]
+    fn set_angular_2d_replaces_the_angular_velocity() {
+        let mut velocity = Velocity::from_angular(AxisAngle::new(Vec3::X, 1.0));
+        velocity.set_angular_2d(-3.0);
+        assert_eq!(velocity.angular_2d(), -3.0);
+        assert_eq!(velocity.angular.axis(), Vec3::new(0.0, 0.0, -3.0));
+    }
+
+    #[test]
+    fn angular_2d_is_the_rotation_around_z() {
+        let velocity = Velocity::from_angular(AxisAngle::new(-Vec3::Z, 2.0));
+        assert_eq!(velocity.angular_2d(), -2.0);
+    }
+
+    #[test]
+    fn velocity_at_point_includes_rotation() {
+        let velocity = Velocity::from_linear(Vec3::X).with_angular_2d(2.0);
+        assert_eq!(velocity.at_point(Vec3::ZERO), Vec3::X);
+        assert_eq!(velocity.at_point(Vec3::X), Vec3::new(1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn sleep_hint() {
+        let moving = Velocity::from_linear(Vec3::X);
+        let stopped = Velocity::default();
+
+        assert!(!SleepHint::WakeWhenMoving.should_sleep(moving));
+        assert!(!SleepHint::WakeWhenMoving.should_sleep(stopped));
+        assert!(!SleepHint::SleepWhenStopped.should_sleep(moving));
+        assert!(SleepHint::SleepWhenStopped.should_sleep(stopped));
+    }
+}
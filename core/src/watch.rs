@@ -0,0 +1,296 @@
+use bevy::ecs::entity::{EntityMap, MapEntities, MapEntitiesError};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+
+use crate::{CollisionData, CollisionEvent, RigidBody};
+
+/// Component which tracks (if present) whether the entity is currently touching another specific
+/// entity
+///
+/// It is a cheaper alternative to [`Collisions`](crate::Collisions) or to reading the whole stream
+/// of [`CollisionEvent`]s, when only one pair of entities is of interest.
+///
+/// Both the watching entity and the target may either be a rigid body entity or a collision shape
+/// entity. When they are rigid bodies with many collision shapes, the entity is touching the target
+/// as long as any of their collision shapes are in contact.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// fn spawn(mut commands: Commands, door: Entity) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(WatchCollisionsWith::new(door))
+///         .insert(Player);
+/// }
+///
+/// fn open_door(players: Query<'_, '_, &WatchCollisionsWith, With<Player>>) {
+///     for watch in players.iter() {
+///         if watch.is_touching() {
+///             println!("The player touches the door");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Component, MapEntities)]
+pub struct WatchCollisionsWith {
+    target: Entity,
+    #[reflect(ignore)]
+    data: Option<CollisionData>,
+    /// Number of pairs of collision shapes currently in contact
+    #[reflect(ignore)]
+    contacts: usize,
+}
+
+impl WatchCollisionsWith {
+    /// Create a new watch of the collisions with the `target` entity
+    #[must_use]
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            data: None,
+            contacts: 0,
+        }
+    }
+
+    /// Returns the watched entity
+    #[must_use]
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Returns `true` if the entity is currently touching the target
+    #[must_use]
+    pub fn is_touching(&self) -> bool {
+        self.data.is_some()
+    }
+
+    /// Returns the collision data of the target, if the entity is currently touching it
+    #[must_use]
+    pub fn collision_data(&self) -> Option<&CollisionData> {
+        self.data.as_ref()
+    }
+
+    fn is_target(&self, data: &CollisionData) -> bool {
+        self.target == data.rigid_body_entity() || self.target == data.collision_shape_entity()
+    }
+
+    fn update(&mut self, started: bool, other: &CollisionData) {
+        if started {
+            self.contacts += 1;
+            self.data = Some(other.clone());
+        } else {
+            self.contacts = self.contacts.saturating_sub(1);
+            if self.contacts == 0 {
+                self.data = None;
+            }
+        }
+    }
+}
+
+impl FromWorld for WatchCollisionsWith {
+    /// Placeholder used when spawning a scene, the actual value is applied right after
+    fn from_world(_world: &mut World) -> Self {
+        Self::new(Entity::from_raw(u32::MAX))
+    }
+}
+
+impl MapEntities for WatchCollisionsWith {
+    fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+        // The target may be outside of the scene, in which case it isn't mapped
+        if let Ok(target) = entity_map.get(self.target) {
+            self.target = target;
+        }
+        Ok(())
+    }
+}
+
+/// Updates the [`WatchCollisionsWith`] components from the collision events
+pub(super) fn update_watched_collisions_system(
+    mut collision_events: EventReader<'_, '_, CollisionEvent>,
+    mut watches: Query<'_, '_, &mut WatchCollisionsWith>,
+) {
+    for event in collision_events.iter() {
+        let (data1, data2) = match event {
+            CollisionEvent::Started(d1, d2) | CollisionEvent::Stopped(d1, d2) => (d1, d2),
+        };
+        for (this, other) in [(data1, data2), (data2, data1)] {
+            let body = this.rigid_body_entity();
+            let shape = this.collision_shape_entity();
+            let entities = if body == shape {
+                &[body][..]
+            } else {
+                &[body, shape][..]
+            };
+            for entity in entities {
+                if let Ok(mut watch) = watches.get_mut(*entity) {
+                    if watch.is_target(other) {
+                        watch.update(event.is_started(), other);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Stops tracking the despawned targets, because entity deletion doesn't emit
+/// [`CollisionEvent::Stopped`]
+pub(super) fn cleanup_watched_collisions_system(
+    removed_rigid_bodies: RemovedComponents<'_, RigidBody>,
+    mut watches: Query<'_, '_, &mut WatchCollisionsWith>,
+) {
+    for rigid_body in removed_rigid_bodies.iter() {
+        for mut watch in &mut watches {
+            if watch
+                .data
+                .as_ref()
+                .map_or(false, |data| data.rigid_body_entity() == rigid_body)
+            {
+                watch.data = None;
+                watch.contacts = 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::Events;
+
+    use crate::CollisionLayers;
+
+    use super::*;
+
+    fn data(rigid_body: Entity, shape: Entity) -> CollisionData {
+        CollisionData::new(rigid_body, shape, CollisionLayers::default(), [Vec3::Y])
+    }
+
+    #[test]
+    fn tracks_collisions_with_target() {
+        let mut app = App::new();
+        app.add_event::<CollisionEvent>()
+            .add_system(update_watched_collisions_system);
+
+        let target = app.world.spawn().id();
+        let other = app.world.spawn().id();
+        let body = app
+            .world
+            .spawn()
+            .insert(WatchCollisionsWith::new(target))
+            .id();
+        let shape = app.world.spawn().id();
+
+        let mut events = app.world.resource_mut::<Events<CollisionEvent>>();
+        events.send(CollisionEvent::Started(
+            data(body, shape),
+            data(other, other),
+        ));
+        app.update();
+        assert!(!app
+            .world
+            .get::<WatchCollisionsWith>(body)
+            .unwrap()
+            .is_touching());
+
+        let mut events = app.world.resource_mut::<Events<CollisionEvent>>();
+        events.send(CollisionEvent::Started(
+            data(target, target),
+            data(body, shape),
+        ));
+        app.update();
+        let watch = app.world.get::<WatchCollisionsWith>(body).unwrap();
+        assert!(watch.is_touching());
+        assert_eq!(watch.collision_data(), Some(&data(target, target)));
+
+        let mut events = app.world.resource_mut::<Events<CollisionEvent>>();
+        events.send(CollisionEvent::Stopped(
+            data(body, shape),
+            data(target, target),
+        ));
+        app.update();
+        assert!(!app
+            .world
+            .get::<WatchCollisionsWith>(body)
+            .unwrap()
+            .is_touching());
+    }
+
+    #[test]
+    fn keeps_touching_while_any_shape_is_in_contact() {
+        let mut app = App::new();
+        app.add_event::<CollisionEvent>()
+            .add_system(update_watched_collisions_system);
+
+        let target = app.world.spawn().id();
+        let body = app
+            .world
+            .spawn()
+            .insert(WatchCollisionsWith::new(target))
+            .id();
+        let shape1 = app.world.spawn().id();
+        let shape2 = app.world.spawn().id();
+
+        let mut events = app.world.resource_mut::<Events<CollisionEvent>>();
+        events.send(CollisionEvent::Started(
+            data(body, shape1),
+            data(target, target),
+        ));
+        events.send(CollisionEvent::Started(
+            data(body, shape2),
+            data(target, target),
+        ));
+        app.update();
+
+        let mut events = app.world.resource_mut::<Events<CollisionEvent>>();
+        events.send(CollisionEvent::Stopped(
+            data(body, shape1),
+            data(target, target),
+        ));
+        app.update();
+        assert!(app
+            .world
+            .get::<WatchCollisionsWith>(body)
+            .unwrap()
+            .is_touching());
+
+        let mut events = app.world.resource_mut::<Events<CollisionEvent>>();
+        events.send(CollisionEvent::Stopped(
+            data(body, shape2),
+            data(target, target),
+        ));
+        app.update();
+        assert!(!app
+            .world
+            .get::<WatchCollisionsWith>(body)
+            .unwrap()
+            .is_touching());
+    }
+
+    #[test]
+    fn stops_tracking_despawned_target() {
+        let mut app = App::new();
+        app.add_system(cleanup_watched_collisions_system);
+
+        let target = app.world.spawn().insert(RigidBody::Static).id();
+        let mut watch = WatchCollisionsWith::new(target);
+        watch.data = Some(data(target, target));
+        let entity = app.world.spawn().insert(watch).id();
+
+        app.update();
+        app.world.entity_mut(target).despawn();
+        app.update();
+
+        assert!(!app
+            .world
+            .get::<WatchCollisionsWith>(entity)
+            .unwrap()
+            .is_touching());
+    }
+}
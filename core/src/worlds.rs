@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+use bevy::utils::HashMap;
+
+use crate::Gravity;
+
+/// Component that assigns a rigid body to a physics world
+///
+/// Rigid bodies that are in different physics worlds are simulated in isolation: they never
+/// collide, and they don't generate collision events with each other.
+///
+/// Rigid bodies without this component are in the [default](Self::DEFAULT) world, which is
+/// configured with the [`Gravity`] and [`PhysicsTime`](crate::PhysicsTime) resources. The other
+/// worlds are configured with the [`PhysicsWorldSettings`] resource.
+///
+/// The collision shapes always belong to the physics world of their rigid body.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// const MINIGAME: PhysicsWorldId = PhysicsWorldId(1);
+///
+/// fn spawn(mut commands: Commands, mut settings: ResMut<PhysicsWorldSettings>) {
+///     settings.insert(
+///         MINIGAME,
+///         WorldSettings::default().with_gravity(Gravity::from(Vec3::Y * -100.0)),
+///     );
+///
+///     commands.spawn_bundle((
+///         Transform::default(),
+///         GlobalTransform::default(),
+///         RigidBody::Dynamic,
+///         CollisionShape::Sphere { radius: 1.0 },
+///         MINIGAME,
+///     ));
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Hash, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct PhysicsWorldId(pub u32);
+
+impl PhysicsWorldId {
+    /// The default physics world, in which are the rigid bodies that don't have a
+    /// [`PhysicsWorldId`]
+    pub const DEFAULT: Self = Self(0);
+
+    /// Returns `true` if this is the [default](Self::DEFAULT) physics world
+    #[must_use]
+    pub fn is_default(self) -> bool {
+        self == Self::DEFAULT
+    }
+}
+
+/// Settings of a physics world other than the default one
+///
+/// See [`PhysicsWorldSettings`]
+#[derive(Debug, Copy, Clone, PartialEq, Reflect, FromReflect)]
+pub struct WorldSettings {
+    /// Gravity of the physics world
+    pub gravity: Gravity,
+
+    /// Speed of the simulation, relative to the default physics world
+    ///
+    /// A value of `0.0` pauses the physics world.
+    pub time_scale: f32,
+}
+
+impl Default for WorldSettings {
+    fn default() -> Self {
+        Self {
+            gravity: Gravity::default(),
+            time_scale: 1.0,
+        }
+    }
+}
+
+impl WorldSettings {
+    /// Returns a new version with the given gravity
+    #[must_use]
+    pub fn with_gravity(mut self, gravity: Gravity) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Returns a new version with the given time scale
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time scale is negative
+    #[must_use]
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        assert!(time_scale >= 0.0, "Negative time scale is not supported");
+        self.time_scale = time_scale;
+        self
+    }
+}
+
+/// Resource that defines the settings of the physics worlds
+///
+/// The settings of the [default](PhysicsWorldId::DEFAULT) world are ignored, as it is configured
+/// with the [`Gravity`] and [`PhysicsTime`](crate::PhysicsTime) resources.
+///
+/// The worlds that don't have any settings use [`WorldSettings::default()`]
+#[derive(Debug, Clone, Default, Reflect, FromReflect)]
+pub struct PhysicsWorldSettings(HashMap<PhysicsWorldId, WorldSettings>);
+
+impl PhysicsWorldSettings {
+    /// Define the settings of a physics world
+    pub fn insert(&mut self, world: PhysicsWorldId, settings: WorldSettings) {
+        self.0.insert(world, settings);
+    }
+
+    /// Remove the settings of a physics world, so that it uses the default settings
+    pub fn remove(&mut self, world: PhysicsWorldId) {
+        self.0.remove(&world);
+    }
+
+    /// Returns the settings of the given physics world
+    #[must_use]
+    pub fn get(&self, world: PhysicsWorldId) -> WorldSettings {
+        self.0.get(&world).copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_default_settings_for_unknown_worlds() {
+        let mut settings = PhysicsWorldSettings::default();
+        let custom = WorldSettings::default().with_time_scale(0.5);
+        settings.insert(PhysicsWorldId(1), custom);
+
+        assert_eq!(settings.get(PhysicsWorldId(1)), custom);
+        assert_eq!(settings.get(PhysicsWorldId(2)), WorldSettings::default());
+    }
+}
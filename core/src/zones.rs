@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+
+use crate::{CollisionData, CollisionEvent, CollisionLayers};
+
+/// Component that turns a sensor entity into a zone, firing [`ZoneEnter`] and [`ZoneExit`] events
+/// when entities matching the `mask` enter or exit it.
+///
+/// An entity matches if one of the groups of its [`CollisionLayers`] is contained in the masks of
+/// the detector's `mask`. The groups of the `mask` are ignored.
+///
+/// The component can be added to the entity containing the [`RigidBody`](crate::RigidBody) or to
+/// the entity containing the [`CollisionShape`](crate::CollisionShape).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # enum Layer {
+/// #   World,
+/// #   Player,
+/// # }
+/// # impl PhysicsLayer for Layer {
+/// #     fn to_bits(&self) -> u32 {
+/// #         todo!()
+/// #     }
+/// #     fn all_bits() -> u32 {
+/// #         todo!()
+/// #     }
+/// # }
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(Sensor)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ZoneDetector::new(CollisionLayers::none().with_mask(Layer::Player)));
+/// }
+///
+/// fn detect_player(mut events: EventReader<ZoneEnter>) {
+///     for event in events.iter() {
+///         println!("The player {:?} entered the zone {:?}", event.entity, event.zone);
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, PartialEq, Eq, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ZoneDetector {
+    /// Layers of the entities to detect
+    pub mask: CollisionLayers,
+}
+
+impl ZoneDetector {
+    /// Returns a detector for the entities matching the given mask
+    #[must_use]
+    pub fn new(mask: CollisionLayers) -> Self {
+        Self { mask }
+    }
+
+    /// Returns true if an entity with the given layers should be detected
+    #[must_use]
+    pub fn matches(self, layers: CollisionLayers) -> bool {
+        self.mask.masks_bits() & layers.groups_bits() != 0
+    }
+}
+
+/// An event fired when an entity matching a [`ZoneDetector`] enters the zone
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub struct ZoneEnter {
+    /// The entity containing the [`ZoneDetector`]
+    pub zone: Entity,
+    /// The entity containing the [`RigidBody`](crate::RigidBody) that entered the zone
+    pub entity: Entity,
+}
+
+/// An event fired when an entity matching a [`ZoneDetector`] exits the zone
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub struct ZoneExit {
+    /// The entity containing the [`ZoneDetector`]
+    pub zone: Entity,
+    /// The entity containing the [`RigidBody`](crate::RigidBody) that exited the zone
+    pub entity: Entity,
+}
+
+/// Converts the [`CollisionEvent`]s involving a [`ZoneDetector`] into [`ZoneEnter`] and
+/// [`ZoneExit`] events
+pub(super) fn update_zone_events_system(
+    mut collision_events: EventReader<'_, '_, CollisionEvent>,
+    detectors: Query<'_, '_, &ZoneDetector>,
+    mut enter_events: EventWriter<'_, '_, ZoneEnter>,
+    mut exit_events: EventWriter<'_, '_, ZoneExit>,
+) {
+    for event in collision_events.iter() {
+        let started = event.is_started();
+        let (data1, data2) = event.clone().data();
+        for (zone, other) in [(&data1, &data2), (&data2, &data1)] {
+            let zone = match find_detector(&detectors, zone) {
+                Some((zone, detector)) if detector.matches(other.collision_layers()) => zone,
+                _ => continue,
+            };
+
+            let entity = other.rigid_body_entity();
+            if started {
+                enter_events.send(ZoneEnter { zone, entity });
+            } else {
+                exit_events.send(ZoneExit { zone, entity });
+            }
+        }
+    }
+}
+
+fn find_detector(
+    detectors: &Query<'_, '_, &ZoneDetector>,
+    data: &CollisionData,
+) -> Option<(Entity, ZoneDetector)> {
+    [data.collision_shape_entity(), data.rigid_body_entity()]
+        .into_iter()
+        .find_map(|entity| detectors.get(entity).ok().map(|it| (entity, *it)))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::Events;
+
+    use super::*;
+
+    #[test]
+    fn fires_events_only_for_matching_entities() {
+        let mut app = App::new();
+        app.add_event::<CollisionEvent>()
+            .add_event::<ZoneEnter>()
+            .add_event::<ZoneExit>()
+            .add_system(update_zone_events_system);
+
+        let zone = app
+            .world
+            .spawn()
+            .insert(ZoneDetector::new(CollisionLayers::from_bits(0, 0b10)))
+            .id();
+        let matching = app.world.spawn().id();
+        let other = app.world.spawn().id();
+
+        let zone_data = CollisionData::new(zone, zone, CollisionLayers::default(), []);
+        let matching_data = CollisionData::new(
+            matching,
+            matching,
+            CollisionLayers::from_bits(0b10, 0b1),
+            [],
+        );
+        let other_data = CollisionData::new(other, other, CollisionLayers::from_bits(0b1, 0b1), []);
+
+        let mut events = app.world.resource_mut::<Events<CollisionEvent>>();
+        events.send(CollisionEvent::Started(
+            zone_data.clone(),
+            matching_data.clone(),
+        ));
+        events.send(CollisionEvent::Started(
+            other_data.clone(),
+            zone_data.clone(),
+        ));
+        events.send(CollisionEvent::Stopped(matching_data, zone_data.clone()));
+        events.send(CollisionEvent::Stopped(zone_data, other_data));
+        app.update();
+
+        let enter_events = app.world.resource::<Events<ZoneEnter>>();
+        assert_eq!(
+            enter_events
+                .get_reader()
+                .iter(enter_events)
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![ZoneEnter {
+                zone,
+                entity: matching
+            }]
+        );
+
+        let exit_events = app.world.resource::<Events<ZoneExit>>();
+        assert_eq!(
+            exit_events
+                .get_reader()
+                .iter(exit_events)
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![ZoneExit {
+                zone,
+                entity: matching
+            }]
+        );
+    }
+}
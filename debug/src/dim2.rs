@@ -7,7 +7,7 @@ use lyon_path::{
     traits::PathBuilder,
 };
 
-use heron_core::{CollisionShape, RigidBody, SensorShape};
+use heron_core::{CollisionShape, Joint, RigidBody, Sensor, SensorShape};
 use heron_rapier::{
     convert::{IntoBevy, IntoRapier},
     rapier2d::geometry::{ColliderSet, Shape},
@@ -22,6 +22,40 @@ pub(crate) fn systems() -> SystemSet {
         .with_system(delete_debug_sprite)
         .with_system(replace_debug_sprite)
         .with_system(create_debug_sprites)
+        .with_system(replace_joint_anchors)
+}
+
+/// Radius of the circles drawn at the anchors of the joints
+const JOINT_ANCHOR_RADIUS: f32 = 3.0;
+
+/// Marks the debug sprite of an anchor of the [`Joint`] of the given entity
+#[derive(Component)]
+pub(crate) struct IsJointAnchor(Entity);
+
+/// Shows or hides the debug sprites according to the [`DebugRenderSettings`]
+pub(crate) fn update_visibility(
+    settings: Res<'_, DebugRenderSettings>,
+    shapes: Query<'_, '_, (Option<&RigidBody>, Option<&SensorShape>, Option<&Sensor>)>,
+    mut sprites: Query<'_, '_, (&IsDebug, &mut Visibility)>,
+    mut anchors: Query<'_, '_, &mut Visibility, (With<IsJointAnchor>, Without<IsDebug>)>,
+) {
+    for (IsDebug(parent), mut visibility) in &mut sprites {
+        let is_visible = match shapes.get(*parent) {
+            Ok((rigid_body_option, sensor_option, sensor_body)) => settings.is_shown(
+                rigid_body_option,
+                sensor_option.is_some() || sensor_body.is_some(),
+            ),
+            Err(_) => false,
+        };
+        if visibility.is_visible != is_visible {
+            visibility.is_visible = is_visible;
+        }
+    }
+    for mut visibility in &mut anchors {
+        if visibility.is_visible != settings.enabled {
+            visibility.is_visible = settings.enabled;
+        }
+    }
 }
 
 fn create_debug_sprites(
@@ -37,12 +71,15 @@ fn create_debug_sprites(
             &GlobalTransform,
             Option<&RigidBody>,
             Option<&SensorShape>,
+            Option<&Sensor>,
         ),
         Without<HasDebug>,
     >,
     debug_color: Res<'_, DebugColor>,
 ) {
-    for (entity, body, handle, transform, rigid_body_option, sensor_option) in query.iter() {
+    for (entity, body, handle, transform, rigid_body_option, sensor_option, sensor_body) in
+        query.iter()
+    {
         if let Some(collider) = colliders.get(handle.into_rapier()) {
             commands
                 .entity(entity)
@@ -51,8 +88,10 @@ fn create_debug_sprites(
                         .spawn_bundle(create_shape(
                             body,
                             collider.shape(),
-                            debug_color
-                                .for_collider_type(rigid_body_option, sensor_option.is_some()),
+                            debug_color.for_collider_type(
+                                rigid_body_option,
+                                sensor_option.is_some() || sensor_body.is_some(),
+                            ),
                             *transform,
                         ))
                         .insert(IsDebug(entity));
@@ -77,11 +116,14 @@ fn replace_debug_sprite(
             &GlobalTransform,
             Option<&RigidBody>,
             Option<&SensorShape>,
+            Option<&Sensor>,
         ),
         (With<HasDebug>, Changed<CollisionShape>),
     >,
 ) {
-    for (parent_entity, body, handle, transform, rigid_body_option, sensor_option) in query.iter() {
+    for (parent_entity, body, handle, transform, rigid_body_option, sensor_option, sensor_body) in
+        query.iter()
+    {
         if let (Some(debug_entity), Some(collider)) = (
             map.remove(&parent_entity),
             colliders.get(handle.into_rapier()),
@@ -92,7 +134,10 @@ fn replace_debug_sprite(
                     .spawn_bundle(create_shape(
                         body,
                         collider.shape(),
-                        debug_color.for_collider_type(rigid_body_option, sensor_option.is_some()),
+                        debug_color.for_collider_type(
+                            rigid_body_option,
+                            sensor_option.is_some() || sensor_body.is_some(),
+                        ),
                         *transform,
                     ))
                     .insert(IsDebug(parent_entity));
@@ -116,6 +161,67 @@ fn delete_debug_sprite(
     }
 }
 
+/// Draws a circle at both anchors of the joints, as children of their rigid bodies
+fn replace_joint_anchors(
+    mut commands: Commands<'_, '_>,
+    debug_color: Res<'_, DebugColor>,
+    joints: Query<'_, '_, (Entity, &Joint), Changed<Joint>>,
+    removed_joints: RemovedComponents<'_, Joint>,
+    transforms: Query<'_, '_, &GlobalTransform>,
+    anchors: Query<'_, '_, (Entity, &IsJointAnchor)>,
+) {
+    let changed: Vec<Entity> = joints
+        .iter()
+        .map(|(entity, _)| entity)
+        .chain(removed_joints.iter())
+        .collect();
+    if changed.is_empty() {
+        return;
+    }
+
+    for (anchor, IsJointAnchor(joint_entity)) in anchors.iter() {
+        if changed.contains(joint_entity) {
+            commands.entity(anchor).despawn();
+        }
+    }
+
+    for (entity, joint) in joints.iter() {
+        for (body, anchor) in [
+            (entity, joint.local_anchor),
+            (joint.body, joint.other_anchor),
+        ] {
+            let scale = match transforms.get(body) {
+                Ok(transform) => transform.to_scale_rotation_translation().0,
+                Err(_) => continue,
+            };
+            commands.entity(body).with_children(|builder| {
+                builder
+                    .spawn_bundle(create_joint_anchor(anchor, scale, debug_color.joint))
+                    .insert(IsJointAnchor(entity));
+            });
+        }
+    }
+}
+
+fn create_joint_anchor(anchor: Vec3, scale: Vec3, color: Color) -> ShapeBundle {
+    // The anchors are expressed in the unscaled space of the rigid body
+    GeometryBuilder::build_as(
+        &shapes::Circle {
+            radius: JOINT_ANCHOR_RADIUS,
+            center: Vec2::ZERO,
+        },
+        DrawMode::Fill(FillMode {
+            color,
+            options: FillOptions::default(),
+        }),
+        Transform {
+            translation: (anchor.truncate() / scale.truncate()).extend(2.0),
+            scale: scale.recip(),
+            ..Default::default()
+        },
+    )
+}
+
 fn create_shape(
     body: &CollisionShape,
     shape: &dyn Shape,
@@ -190,7 +296,7 @@ fn base_builder(body: &CollisionShape, shape: &dyn Shape) -> GeometryBuilder {
                 radius: border_radius.unwrap_or(0.0),
             });
         }
-        CollisionShape::ConvexHull { .. } => {
+        CollisionShape::ConvexHull { .. } | CollisionShape::ConvexPolyhedron { .. } => {
             if let Some(polygon) = shape.as_convex_polygon() {
                 builder = builder.add(&shapes::Polygon {
                     points: polygon.points().into_bevy(),
@@ -215,8 +321,30 @@ fn base_builder(body: &CollisionShape, shape: &dyn Shape) -> GeometryBuilder {
                 });
             }
         }
+        CollisionShape::Triangle {
+            a,
+            b,
+            c,
+            border_radius,
+        } => {
+            // Same strategy as for the rounded convex hulls: a circle at each vertex shows what
+            // the border radius adds to the triangle.
+            if let Some(border_radius) = border_radius {
+                for point in [a, b, c] {
+                    builder = builder.add(&shapes::Circle {
+                        radius: *border_radius,
+                        center: *point,
+                    });
+                }
+            }
+
+            builder = builder.add(&shapes::Polygon {
+                points: vec![*a, *b, *c],
+                closed: true,
+            });
+        }
         CollisionShape::HeightField { size, heights } => {
-            if let Some(heights) = heights.get(0) {
+            if let Some(heights) = heights.first() {
                 let mut points: Vec<Vec2> = Vec::with_capacity(heights.len() + 2);
                 let mut min_y = f32::MAX;
                 let half_size = size.x * 0.5;
@@ -248,7 +376,7 @@ fn base_builder(body: &CollisionShape, shape: &dyn Shape) -> GeometryBuilder {
                 any_other
             );
         }
-    };
+    }
 
     builder
 }
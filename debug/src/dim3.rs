@@ -1,14 +1,14 @@
 use bevy::prelude::*;
 use bevy_prototype_debug_lines::DebugLines;
 
-use heron_core::{CollisionShape, RigidBody, SensorShape};
+use heron_core::{CollisionShape, RigidBody, Sensor, SensorShape};
 
 use crate::shape3d_wireframe::{
-    add_capsule, add_cone, add_convex_hull, add_cuboid, add_cylinder, add_height_field,
-    add_rounded_cuboid, add_sphere,
+    add_capsule, add_cone, add_convex_hull, add_convex_polyhedron, add_cuboid, add_cylinder,
+    add_height_field, add_rounded_cuboid, add_sphere,
 };
 
-use super::DebugColor;
+use super::{DebugColor, DebugRenderSettings};
 
 fn add_shape_outlines(
     shapes: Query<
@@ -19,17 +19,28 @@ fn add_shape_outlines(
             &GlobalTransform,
             Option<&RigidBody>,
             Option<&SensorShape>,
+            Option<&Sensor>,
         ),
     >,
     color: Res<'_, DebugColor>,
+    settings: Res<'_, DebugRenderSettings>,
     mut lines: ResMut<'_, DebugLines>,
 ) {
-    for (shape, trans, rigid_body_option, sensor_option) in shapes.iter() {
+    if !settings.enabled {
+        return;
+    }
+
+    for (shape, trans, rigid_body_option, sensor_option, sensor_body) in shapes.iter() {
+        let is_sensor = sensor_option.is_some() || sensor_body.is_some();
+        if !settings.is_shown(rigid_body_option, is_sensor) {
+            continue;
+        }
+
         let (_, global_rotation, global_translation) = trans.to_scale_rotation_translation();
         let origin = global_translation;
         let orient = global_rotation;
 
-        let color = color.for_collider_type(rigid_body_option, sensor_option.is_some());
+        let color = color.for_collider_type(rigid_body_option, is_sensor);
         match shape {
             CollisionShape::Cuboid {
                 half_extends,
@@ -57,6 +68,11 @@ fn add_shape_outlines(
                 // absolutely no idea how to handle it here
                 add_convex_hull(origin, orient, points, color, &mut lines);
             }
+            CollisionShape::ConvexPolyhedron {
+                points, indices, ..
+            } => {
+                add_convex_polyhedron(origin, orient, points, indices, color, &mut lines);
+            }
             CollisionShape::HeightField { size, heights } => {
                 add_height_field(origin, orient, *size, heights, color, &mut lines);
             }
@@ -24,6 +24,12 @@ mod dim3;
 #[cfg(feature = "3d")]
 mod shape3d_wireframe;
 
+#[cfg(all(feature = "playground", any(feature = "2d", feature = "3d")))]
+mod playground;
+
+#[cfg(all(feature = "playground", any(feature = "2d", feature = "3d")))]
+pub use playground::PhysicsPlaygroundPlugin;
+
 /// Plugin that enables rendering of collision shapes
 #[derive(Debug, Copy, Clone, Default)]
 pub struct DebugPlugin(DebugColor);
@@ -35,6 +41,71 @@ struct DebugColor {
     static_body: Color,
     dynamic_body: Color,
     kinematic_body: Color,
+    joint: Color,
+}
+
+/// Resource to switch the debug render on and off at runtime
+///
+/// Each category of collision shapes can be hidden separately. By default, everything is shown.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_debug::DebugRenderSettings;
+/// fn toggle_debug_render(input: Res<Input<KeyCode>>, mut settings: ResMut<DebugRenderSettings>) {
+///     if input.just_pressed(KeyCode::F1) {
+///         settings.enabled = !settings.enabled;
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct DebugRenderSettings {
+    /// Whether anything is rendered at all
+    pub enabled: bool,
+    /// Whether the sensors are rendered
+    pub sensors: bool,
+    /// Whether the static bodies are rendered
+    pub static_bodies: bool,
+    /// Whether the dynamic bodies (and the shapes without rigid body) are rendered
+    pub dynamic_bodies: bool,
+    /// Whether the kinematic bodies are rendered
+    pub kinematic_bodies: bool,
+}
+
+impl Default for DebugRenderSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sensors: true,
+            static_bodies: true,
+            dynamic_bodies: true,
+            kinematic_bodies: true,
+        }
+    }
+}
+
+impl DebugRenderSettings {
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    #[allow(deprecated)]
+    fn is_shown(self, rigid_body_option: Option<&RigidBody>, is_sensor_shape: bool) -> bool {
+        if !self.enabled {
+            false
+        } else if is_sensor_shape {
+            self.sensors
+        } else {
+            use RigidBody::{
+                KinematicPositionBased as PositionBased, KinematicVelocityBased as VelocityBased,
+            };
+            match rigid_body_option {
+                Some(RigidBody::Sensor) => self.sensors,
+                Some(RigidBody::Static) => self.static_bodies,
+                Some(RigidBody::Dynamic) | None => self.dynamic_bodies,
+                Some(PositionBased | VelocityBased) => self.kinematic_bodies,
+            }
+        }
+    }
 }
 
 type DebugEntityMap = FnvHashMap<Entity, Entity>;
@@ -59,9 +130,11 @@ impl Plugin for DebugPlugin {
 
         #[cfg(all(feature = "2d", not(feature = "3d")))]
         app.add_plugin(bevy_prototype_lyon::plugin::ShapePlugin)
-            .add_system_set_to_stage(CoreStage::PostUpdate, dim2::systems());
+            .add_system_set_to_stage(CoreStage::PostUpdate, dim2::systems())
+            .add_system_to_stage(CoreStage::Last, dim2::update_visibility);
 
         app.insert_resource(self.0)
+            .init_resource::<DebugRenderSettings>()
             .init_resource::<DebugEntityMap>()
             .add_system_to_stage(CoreStage::Last, track_debug_entities)
             .add_system_to_stage(CoreStage::Last, scale_debug_entities);
@@ -70,6 +143,7 @@ impl Plugin for DebugPlugin {
 
 impl DebugColor {
     #[cfg(any(feature = "2d", feature = "3d"))]
+    #[allow(deprecated)]
     fn for_collider_type(
         &self,
         rigid_body_option: Option<&RigidBody>,
@@ -105,6 +179,7 @@ impl Default for DebugColor {
             static_body: Color::rgba(0.64, 0.0, 0.16, DEFAULT_DEBUG_ALPHA),
             dynamic_body: Color::rgba(0.0, 0.18, 0.54, DEFAULT_DEBUG_ALPHA),
             kinematic_body: Color::rgba(0.21, 0.07, 0.7, DEFAULT_DEBUG_ALPHA),
+            joint: Color::rgba(0.9, 0.5, 0.0, DEFAULT_DEBUG_ALPHA),
         }
     }
 }
@@ -148,3 +223,55 @@ fn scale_debug_entities(
 fn is_near(v1: f32, v2: f32) -> bool {
     (v2 - v1).abs() <= f32::EPSILON
 }
+
+#[cfg(all(test, any(feature = "2d", feature = "3d")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn everything_is_shown_by_default() {
+        let settings = DebugRenderSettings::default();
+        assert!(settings.is_shown(None, false));
+        assert!(settings.is_shown(None, true));
+        assert!(settings.is_shown(Some(&RigidBody::Static), false));
+        assert!(settings.is_shown(Some(&RigidBody::Dynamic), false));
+        assert!(settings.is_shown(Some(&RigidBody::KinematicPositionBased), false));
+        assert!(settings.is_shown(Some(&RigidBody::KinematicVelocityBased), false));
+    }
+
+    #[test]
+    fn nothing_is_shown_when_disabled() {
+        let settings = DebugRenderSettings {
+            enabled: false,
+            ..Default::default()
+        };
+        assert!(!settings.is_shown(None, false));
+        assert!(!settings.is_shown(None, true));
+        assert!(!settings.is_shown(Some(&RigidBody::Static), false));
+    }
+
+    #[test]
+    fn categories_are_hidden_separately() {
+        let settings = DebugRenderSettings {
+            static_bodies: false,
+            kinematic_bodies: false,
+            ..Default::default()
+        };
+        assert!(!settings.is_shown(Some(&RigidBody::Static), false));
+        assert!(!settings.is_shown(Some(&RigidBody::KinematicPositionBased), false));
+        assert!(!settings.is_shown(Some(&RigidBody::KinematicVelocityBased), false));
+        assert!(settings.is_shown(Some(&RigidBody::Dynamic), false));
+        assert!(settings.is_shown(None, false));
+    }
+
+    #[test]
+    fn sensor_shapes_follow_the_sensor_setting() {
+        let settings = DebugRenderSettings {
+            sensors: false,
+            ..Default::default()
+        };
+        assert!(!settings.is_shown(Some(&RigidBody::Static), true));
+        assert!(!settings.is_shown(Some(&RigidBody::Dynamic), true));
+        assert!(settings.is_shown(Some(&RigidBody::Static), false));
+    }
+}
@@ -0,0 +1,259 @@
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::prelude::*;
+
+use heron_core::{CollisionShape, Gravity, PhysicsDiagnostics, RigidBody};
+
+/// Plugin that sets up a sandbox scene to play with the physics
+///
+/// It is meant to reproduce issues in a consistent environment with minimal code, and is therefore
+/// only available with the `playground` feature. It adds:
+///
+/// * A camera controller: `WASD` to move, `Space`/`LShift` to move up and down, and (in 3d)
+///   right-click drag to look around. In 2d the mouse wheel zooms in and out.
+/// * A static ground
+/// * A random dynamic body spawned at each left click
+/// * The [`PhysicsDiagnostics`] shown in the title of the primary window
+///
+/// The gravity is set to a sensible value if it is zero.
+///
+/// The collision shapes are rendered by the [`DebugPlugin`](crate::DebugPlugin), which must be
+/// added as well (it is included in heron's `PhysicsPlugin` when a debug feature is enabled).
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_debug::PhysicsPlaygroundPlugin;
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     // Add the physics and debug plugins here
+///     .add_plugin(PhysicsPlaygroundPlugin)
+///     .run();
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PhysicsPlaygroundPlugin;
+
+/// Marker of the camera controlled by the playground
+#[derive(Component)]
+struct PlaygroundCamera;
+
+/// Minimal pseudo-random generator, good enough to vary the spawned bodies
+struct PlaygroundRng(u64);
+
+impl Default for PlaygroundRng {
+    fn default() -> Self {
+        Self(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+impl PlaygroundRng {
+    /// Returns a value in the range `[min, max)`
+    #[allow(clippy::cast_possible_truncation)]
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        let unit = (self.0 >> 40) as f32 / (1_u64 << 24) as f32;
+        min + unit * (max - min)
+    }
+}
+
+#[cfg(feature = "3d")]
+mod dim {
+    pub(super) const GRAVITY: f32 = -9.81;
+    pub(super) const UNIT: f32 = 1.0;
+    pub(super) const CAMERA_SPEED: f32 = 10.0;
+    pub(super) const SPAWN_DISTANCE: f32 = 10.0;
+}
+
+#[cfg(not(feature = "3d"))]
+mod dim {
+    pub(super) const GRAVITY: f32 = -500.0;
+    pub(super) const UNIT: f32 = 20.0;
+    pub(super) const CAMERA_SPEED: f32 = 400.0;
+}
+
+impl Plugin for PhysicsPlaygroundPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlaygroundRng>()
+            .add_startup_system(setup)
+            .add_system(move_camera)
+            .add_system(spawn_on_click)
+            .add_system(show_diagnostics);
+    }
+}
+
+fn setup(mut commands: Commands<'_, '_>, mut gravity: ResMut<'_, Gravity>) {
+    if gravity.vector() == Vec3::ZERO {
+        *gravity = Gravity::from(Vec3::Y * dim::GRAVITY);
+    }
+
+    #[cfg(feature = "3d")]
+    commands
+        .spawn_bundle(Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 8.0, 20.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..Default::default()
+        })
+        .insert(PlaygroundCamera);
+
+    #[cfg(not(feature = "3d"))]
+    commands
+        .spawn_bundle(Camera2dBundle::default())
+        .insert(PlaygroundCamera);
+
+    let ground = Transform::from_translation(Vec3::Y * -5.0 * dim::UNIT);
+    commands.spawn_bundle((
+        ground,
+        GlobalTransform::from(ground),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(20.0, 0.5, 20.0) * dim::UNIT,
+            border_radius: None,
+        },
+    ));
+}
+
+fn move_camera(
+    time: Res<'_, Time>,
+    keys: Res<'_, Input<KeyCode>>,
+    buttons: Res<'_, Input<MouseButton>>,
+    mut motions: EventReader<'_, '_, MouseMotion>,
+    mut wheels: EventReader<'_, '_, MouseWheel>,
+    mut cameras: Query<
+        '_,
+        '_,
+        (&mut Transform, Option<&mut OrthographicProjection>),
+        With<PlaygroundCamera>,
+    >,
+) {
+    let mut direction = Vec3::ZERO;
+    for (key, axis) in [
+        (KeyCode::W, Vec3::NEG_Z),
+        (KeyCode::S, Vec3::Z),
+        (KeyCode::A, Vec3::NEG_X),
+        (KeyCode::D, Vec3::X),
+        (KeyCode::Space, Vec3::Y),
+        (KeyCode::LShift, Vec3::NEG_Y),
+    ] {
+        if keys.pressed(key) {
+            direction += axis;
+        }
+    }
+
+    // In 2d, moving "forward" moves up on the screen
+    #[cfg(not(feature = "3d"))]
+    let direction = Vec3::new(direction.x, direction.y - direction.z, 0.0);
+
+    let look: Vec2 = if buttons.pressed(MouseButton::Right) {
+        motions.iter().map(|motion| &motion.delta).sum()
+    } else {
+        motions.iter().for_each(drop);
+        Vec2::ZERO
+    };
+    let zoom: f32 = wheels.iter().map(|wheel| wheel.y).sum();
+
+    for (mut transform, projection) in &mut cameras {
+        let translation = transform.rotation * direction.normalize_or_zero();
+        transform.translation += translation * dim::CAMERA_SPEED * time.delta_seconds();
+
+        if cfg!(feature = "3d") && look != Vec2::ZERO {
+            let yaw = Quat::from_rotation_y(-look.x * 0.003);
+            let pitch = Quat::from_rotation_x(-look.y * 0.003);
+            transform.rotation = yaw * transform.rotation * pitch;
+        }
+
+        if let Some(mut projection) = projection {
+            projection.scale = (projection.scale * (1.0 - zoom * 0.1)).clamp(0.1, 10.0);
+        }
+    }
+}
+
+fn spawn_on_click(
+    mut commands: Commands<'_, '_>,
+    mut rng: ResMut<'_, PlaygroundRng>,
+    buttons: Res<'_, Input<MouseButton>>,
+    windows: Res<'_, Windows>,
+    cameras: Query<
+        '_,
+        '_,
+        (&GlobalTransform, Option<&OrthographicProjection>),
+        With<PlaygroundCamera>,
+    >,
+) {
+    if !buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let (camera, projection) = match cameras.get_single() {
+        Ok(camera) => camera,
+        Err(_) => return,
+    };
+
+    let translation = match spawn_position(camera, projection, &windows) {
+        Some(translation) => translation,
+        None => return,
+    };
+
+    let size = rng.range(0.3, 1.0) * dim::UNIT;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let shape = match rng.range(0.0, 3.0) as u8 {
+        0 => CollisionShape::Sphere { radius: size },
+        1 => CollisionShape::Capsule {
+            half_segment: size,
+            radius: size / 2.0,
+        },
+        _ => CollisionShape::Cuboid {
+            half_extends: Vec3::new(size, rng.range(0.3, 1.0) * dim::UNIT, size),
+            border_radius: None,
+        },
+    };
+
+    let transform = Transform::from_translation(translation);
+    commands.spawn_bundle((
+        transform,
+        GlobalTransform::from(transform),
+        RigidBody::Dynamic,
+        shape,
+    ));
+}
+
+/// Returns the position in front of the camera
+#[cfg(feature = "3d")]
+#[allow(clippy::unnecessary_wraps)]
+fn spawn_position(
+    camera: &GlobalTransform,
+    _: Option<&OrthographicProjection>,
+    _: &Windows,
+) -> Option<Vec3> {
+    Some(camera.translation() + camera.forward() * dim::SPAWN_DISTANCE)
+}
+
+/// Returns the position of the cursor in the world
+#[cfg(not(feature = "3d"))]
+fn spawn_position(
+    camera: &GlobalTransform,
+    projection: Option<&OrthographicProjection>,
+    windows: &Windows,
+) -> Option<Vec3> {
+    let window = windows.get_primary()?;
+    let size = Vec2::new(window.width(), window.height());
+    let cursor = window.cursor_position()? - size / 2.0;
+    let scale = projection.map_or(1.0, |projection| projection.scale);
+    Some(camera.translation() + (cursor * scale).extend(0.0))
+}
+
+fn show_diagnostics(diagnostics: Res<'_, PhysicsDiagnostics>, mut windows: ResMut<'_, Windows>) {
+    if !diagnostics.is_changed() {
+        return;
+    }
+
+    if let Some(window) = windows.get_primary_mut() {
+        window.set_title(format!(
+            "Heron playground | step: {:.2} ms | active bodies: {} | contacts: {} | joints: {}",
+            diagnostics.step_time.as_secs_f64() * 1000.0,
+            diagnostics.active_bodies,
+            diagnostics.contact_pairs,
+            diagnostics.joints,
+        ));
+    }
+}
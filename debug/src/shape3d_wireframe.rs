@@ -319,3 +319,27 @@ pub(crate) fn add_convex_hull(
         lines.line_colored(p1, p2, 0.0, color);
     }
 }
+
+pub(crate) fn add_convex_polyhedron(
+    origin: Vec3,
+    orient: Quat,
+    points: &[Vec3],
+    indices: &[[u32; 3]],
+    color: Color,
+    lines: &mut DebugLines,
+) {
+    let point = |index: u32| {
+        points
+            .get(index as usize)
+            .map(|point| origin + orient.mul_vec3(*point))
+    };
+    for triangle in indices {
+        let (p0, p1, p2) = match (point(triangle[0]), point(triangle[1]), point(triangle[2])) {
+            (Some(p0), Some(p1), Some(p2)) => (p0, p1, p2),
+            _ => continue,
+        };
+        lines.line_colored(p0, p1, 0.0, color);
+        lines.line_colored(p0, p2, 0.0, color);
+        lines.line_colored(p1, p2, 0.0, color);
+    }
+}
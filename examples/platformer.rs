@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+use heron::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(PhysicsPlugin::default())
+        .insert_resource(Gravity::from(Vec2::new(0.0, -1500.0)))
+        .add_startup_system(spawn)
+        .add_system(handle_input)
+        .run();
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn_bundle(Camera2dBundle::default());
+
+    // The ground and a few platforms
+    for (position, size) in [
+        (Vec2::new(0.0, -250.0), Vec2::new(1000.0, 50.0)),
+        (Vec2::new(-200.0, -100.0), Vec2::new(200.0, 20.0)),
+        (Vec2::new(150.0, 20.0), Vec2::new(200.0, 20.0)),
+    ] {
+        commands
+            .spawn_bundle(SpriteBundle {
+                sprite: Sprite {
+                    color: Color::WHITE,
+                    custom_size: Some(size),
+                    ..Default::default()
+                },
+                transform: Transform::from_translation(position.extend(0.0)),
+                ..Default::default()
+            })
+            .insert(RigidBody::Static)
+            .insert(CollisionShape::Cuboid {
+                half_extends: size.extend(0.0) / 2.0,
+                border_radius: None,
+            });
+    }
+
+    // The player, made of the character components and a platformer controller
+    let (half_segment, radius) = (10.0, 15.0);
+    commands
+        .spawn_bundle(SpriteBundle {
+            sprite: Sprite {
+                color: Color::GREEN,
+                custom_size: Some(Vec2::new(radius, half_segment + radius) * 2.0),
+                ..Default::default()
+            },
+            transform: Transform::from_translation(Vec3::new(0.0, -150.0, 0.0)),
+            ..Default::default()
+        })
+        .insert_bundle(CharacterBundle::default().with_capsule(half_segment, radius))
+        .insert(PlatformerController::new(300.0, 700.0));
+}
+
+fn handle_input(input: Res<Input<KeyCode>>, mut controllers: Query<&mut PlatformerController>) {
+    let direction = f32::from(input.pressed(KeyCode::Right) || input.pressed(KeyCode::D))
+        - f32::from(input.pressed(KeyCode::Left) || input.pressed(KeyCode::A));
+    let jump = input.any_just_pressed([KeyCode::Space, KeyCode::Up, KeyCode::W]);
+
+    for mut controller in controllers.iter_mut() {
+        controller.set_direction(direction);
+        if jump {
+            controller.jump();
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+use heron::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(PhysicsPlugin::default())
+        .add_plugin(PhysicsPlaygroundPlugin) // Left click to spawn bodies
+        .run();
+}
@@ -1,8 +1,5 @@
 use bevy::prelude::*;
-use heron::{
-    rapier_plugin::{PhysicsWorld, ShapeCastCollisionType},
-    *,
-};
+use heron::*;
 
 fn main() {
     App::new()
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use heron::prelude::*;
+
+#[bevy_main]
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // The scene only contains the `Transform`s, let heron insert the `GlobalTransform`s
+        .add_plugin(PhysicsPlugin::default().with_missing_transforms(MissingTransforms::Insert))
+        .insert_resource(Gravity::from(Vec3::new(0.0, -300.0, 0.0)))
+        .add_startup_system(spawn)
+        .run();
+}
+
+fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn_bundle(Camera2dBundle::default());
+
+    // The rigid bodies, with their collision shapes and materials, are defined in the scene file.
+    // Such a file can be created with `DynamicScene::from_world` and `DynamicScene::serialize_ron`.
+    commands.spawn_bundle(DynamicSceneBundle {
+        scene: asset_server.load("scenes/physics.scn.ron"),
+        ..Default::default()
+    });
+}
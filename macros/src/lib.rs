@@ -1,12 +1,44 @@
 use proc_macro::TokenStream;
 
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Lit, Meta, NestedMeta, Path,
+};
 
-#[proc_macro_derive(PhysicsLayer)]
+/// Derives `PhysicsLayer` for an enum without fields
+///
+/// By default, each variant gets the bit of its index. The `#[layer(...)]` attribute of a variant
+/// accepts:
+///
+/// * `bit = N` to assign the bit `N` (in `0..32`) explicitly, so that the bit layout stays stable
+///   when variants are reordered. The variants without explicit bit get the lowest bits that are
+///   not explicitly assigned, in order.
+/// * `group = "name"` to add the variant to a group of layers. A `NAME_MASK` constant with the bits
+///   of all the layers of the group is generated for each group. It can be repeated to add the
+///   variant to many groups.
+///
+/// ```ignore
+/// #[derive(PhysicsLayer)]
+/// enum Layer {
+///     #[layer(bit = 0)]
+///     World,
+///     #[layer(bit = 5, group = "enemies")]
+///     Monster,
+///     #[layer(bit = 6, group = "enemies")]
+///     Boss,
+/// }
+///
+/// assert_eq!(Layer::ENEMIES_MASK, (1 << 5) | (1 << 6));
+/// ```
+///
+/// The generated code implements `heron::PhysicsLayer`. Crates that depend on `heron_core` instead
+/// of `heron` can change the path of the crate with `#[physics_layer(crate = "heron_core")]` on the
+/// enum.
+#[proc_macro_derive(PhysicsLayer, attributes(layer, physics_layer))]
 pub fn derive_layer(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let enum_ident = input.ident;
+    let crate_path = crate_path(&input.attrs);
 
     let variants = match &input.data {
         Data::Enum(data) => &data.variants,
@@ -15,24 +47,56 @@ pub fn derive_layer(input: TokenStream) -> TokenStream {
 
     assert!(variants.len() <= 32, "Reached the maximum of 32 layers");
 
-    let to_bits = variants.iter().enumerate().map(|(index, variant)| {
-        let bits: u32 = 1 << index;
-        assert!(
-            variant.fields.is_empty(),
-            "Can only derive Layer for enums without fields"
-        );
+    let attributes: Vec<LayerAttributes> = variants
+        .iter()
+        .map(|variant| {
+            assert!(
+                variant.fields.is_empty(),
+                "Can only derive Layer for enums without fields"
+            );
+            LayerAttributes::parse(&variant.attrs)
+        })
+        .collect();
+
+    let bits = assign_bits(&attributes);
+
+    let to_bits = variants.iter().zip(&bits).map(|(variant, bits)| {
         let ident = &variant.ident;
         quote! { #enum_ident::#ident => #bits, }
     });
 
-    let all_bits: u32 = if variants.len() == 32 {
-        0xffffffff
-    } else {
-        (1 << variants.len()) - 1
-    };
+    let names = variants.iter().zip(&bits).map(|(variant, bits)| {
+        let name = variant.ident.to_string();
+        quote! { (#bits, #name), }
+    });
+
+    let all_bits: u32 = bits.iter().fold(0, |all, bits| all | bits);
+
+    let mut groups: Vec<(String, u32)> = Vec::new();
+    for (attributes, bits) in attributes.iter().zip(&bits) {
+        for group in &attributes.groups {
+            match groups.iter_mut().find(|(name, _)| name == group) {
+                Some((_, mask)) => *mask |= bits,
+                None => groups.push((group.clone(), *bits)),
+            }
+        }
+    }
+
+    let masks = groups.iter().map(|(name, mask)| {
+        let ident = format_ident!("{}_MASK", name.to_uppercase());
+        let doc = format!("Bits of all the layers of the `{}` group", name);
+        quote! {
+            #[doc = #doc]
+            pub const #ident: u32 = #mask;
+        }
+    });
 
     let expanded = quote! {
-        impl heron::PhysicsLayer for #enum_ident {
+        impl #enum_ident {
+            #(#masks)*
+        }
+
+        impl #crate_path::PhysicsLayer for #enum_ident {
             fn all_bits() -> u32 {
                 #all_bits
             }
@@ -42,8 +106,132 @@ pub fn derive_layer(input: TokenStream) -> TokenStream {
                     #(#to_bits)*
                 }
             }
+
+            fn layer_names() -> &'static [(u32, &'static str)] {
+                &[#(#names)*]
+            }
         }
     };
 
     TokenStream::from(expanded)
 }
+
+/// Returns the path of the crate that defines `PhysicsLayer`, as defined by the
+/// `#[physics_layer(crate = "...")]` attribute of the enum
+fn crate_path(attrs: &[Attribute]) -> Path {
+    let mut path = None;
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("physics_layer"))
+    {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            _ => panic!("Expected `#[physics_layer(crate = \"path\")]`"),
+        };
+
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("crate") => {
+                    let crate_path = match value.lit {
+                        Lit::Str(crate_path) => crate_path
+                            .parse::<Path>()
+                            .expect("The crate must be a valid path"),
+                        _ => panic!("The crate must be a string"),
+                    };
+                    assert!(path.is_none(), "The crate is defined twice");
+                    path = Some(crate_path);
+                }
+                _ => panic!("Unknown physics_layer attribute, expected `crate = \"path\"`"),
+            }
+        }
+    }
+
+    path.unwrap_or_else(|| parse_quote!(heron))
+}
+
+/// Content of the `#[layer(...)]` attributes of a variant
+#[derive(Default)]
+struct LayerAttributes {
+    bit: Option<u32>,
+    groups: Vec<String>,
+}
+
+impl LayerAttributes {
+    fn parse(attrs: &[Attribute]) -> Self {
+        let mut result = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("layer")) {
+            let list = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list,
+                _ => panic!("Expected `#[layer(bit = N)]` or `#[layer(group = \"name\")]`"),
+            };
+
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("bit") => {
+                        let bit = match value.lit {
+                            Lit::Int(bit) => bit
+                                .base10_parse::<u32>()
+                                .expect("The layer bit must be an integer"),
+                            _ => panic!("The layer bit must be an integer"),
+                        };
+                        assert!(bit < 32, "The layer bit must be lower than 32");
+                        assert!(result.bit.is_none(), "The layer bit is defined twice");
+                        result.bit = Some(bit);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("group") => {
+                        let group = match value.lit {
+                            Lit::Str(group) => group.value(),
+                            _ => panic!("The layer group must be a string"),
+                        };
+                        assert!(
+                            !group.is_empty()
+                                && group.chars().all(|c| c.is_alphanumeric() || c == '_'),
+                            "Invalid layer group name: {:?}",
+                            group
+                        );
+                        if !result.groups.contains(&group) {
+                            result.groups.push(group);
+                        }
+                    }
+                    _ => {
+                        panic!("Unknown layer attribute, expected `bit = N` or `group = \"name\"`")
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Returns the bits of each variant
+///
+/// The variants without explicit bit get the lowest bits that are not explicitly assigned
+fn assign_bits(attributes: &[LayerAttributes]) -> Vec<u32> {
+    let mut taken: u32 = 0;
+    for bit in attributes.iter().filter_map(|attributes| attributes.bit) {
+        assert!(
+            taken & (1 << bit) == 0,
+            "The layer bit {} is used twice",
+            bit
+        );
+        taken |= 1 << bit;
+    }
+
+    let mut next_free = 0;
+    attributes
+        .iter()
+        .map(|attributes| {
+            let bit = attributes.bit.unwrap_or_else(|| {
+                while taken & (1 << next_free) != 0 {
+                    next_free += 1;
+                }
+                taken |= 1 << next_free;
+                next_free
+            });
+            1 << bit
+        })
+        .collect()
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+
+/// The bodies are spawned on a grid of `GRID_SIZE * GRID_SIZE`
+const GRID_SIZE: u16 = 100;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_bodies(app: &mut App) -> Vec<Entity> {
+    let mut entities = Vec::new();
+    for x in 0..GRID_SIZE {
+        for y in 0..GRID_SIZE {
+            let transform = Transform::from_xyz(f32::from(x) * 3.0, f32::from(y) * 3.0, 0.0);
+            let entity = app
+                .world
+                .spawn()
+                .insert_bundle((
+                    transform,
+                    GlobalTransform::from(transform),
+                    RigidBody::Dynamic,
+                    CollisionShape::Sphere { radius: 1.0 },
+                ))
+                .id();
+            entities.push(entity);
+        }
+    }
+    entities
+}
+
+/// App with the bodies already created in the physics world
+fn app_with_bodies() -> (App, Vec<Entity>) {
+    let mut app = test_app();
+    let entities = spawn_bodies(&mut app);
+    app.update();
+    (app, entities)
+}
+
+fn create(c: &mut Criterion) {
+    c.bench_function("create 10k bodies", |b| {
+        b.iter_batched(
+            || {
+                let mut app = test_app();
+                spawn_bodies(&mut app);
+                app
+            },
+            |mut app| app.update(),
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+fn step(c: &mut Criterion) {
+    let (mut app, _) = app_with_bodies();
+    c.bench_function("step 10k bodies", |b| b.iter(|| app.update()));
+}
+
+fn remove(c: &mut Criterion) {
+    c.bench_function("remove 10k bodies", |b| {
+        b.iter_batched(
+            app_with_bodies,
+            |(mut app, entities)| {
+                for entity in entities {
+                    app.world.despawn(entity);
+                }
+                app.update();
+            },
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+criterion_group!(benches, create, step, remove);
+criterion_main!(benches);
@@ -5,4 +5,8 @@ fn main() {
         // 3D feature takes precedence over 2D feature
         dim3: { all(feature = "3d") }
     }
+
+    // Declare the aliases, so that rustc doesn't report them as unexpected cfg names
+    println!("cargo:rustc-check-cfg=cfg(dim2)");
+    println!("cargo:rustc-check-cfg=cfg(dim3)");
 }
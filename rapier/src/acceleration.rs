@@ -1,29 +1,61 @@
 use bevy::prelude::*;
 
-use heron_core::{utils::NearZero, Acceleration};
+use heron_core::{utils::NearZero, Acceleration, Force, PhysicsTime, VelocityTimeSpace};
 
 use crate::convert::IntoRapier;
-use crate::rapier::dynamics::RigidBodySet;
 use crate::rapier::{
     dynamics::RigidBody,
     math::{AngVector, Vector},
 };
+use crate::worlds::Worlds;
 
 pub(crate) fn update_rapier_force_and_torque(
-    mut bodies: ResMut<'_, RigidBodySet>,
-    accelerations: Query<'_, '_, (&super::RigidBodyHandle, &Acceleration)>,
+    mut worlds: Worlds<'_, '_>,
+    time: Res<'_, PhysicsTime>,
+    time_space: Res<'_, VelocityTimeSpace>,
+    accelerations: Query<
+        '_,
+        '_,
+        (
+            &super::RigidBodyHandle,
+            Option<&Acceleration>,
+            Option<&Force>,
+        ),
+        Or<(With<Acceleration>, With<Force>)>,
+    >,
+    handles: Query<'_, '_, &super::RigidBodyHandle>,
+    removed_accelerations: RemovedComponents<'_, Acceleration>,
+    removed_forces: RemovedComponents<'_, Force>,
 ) {
-    for (handle, acceleration) in accelerations.iter() {
-        if let Some(body) = bodies.get_mut(handle.0) {
-            update_acceleration(body, acceleration);
+    removed_accelerations
+        .iter()
+        .chain(removed_forces.iter())
+        .filter_map(|entity| handles.get(entity).ok())
+        .for_each(|handle| {
+            if let Some(body) = worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+                body.reset_forces(false);
+                body.reset_torques(false);
+            }
+        });
+
+    for (handle, acceleration, force) in accelerations.iter() {
+        if let Some(body) = worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+            update_acceleration(
+                body,
+                &time_space
+                    .acceleration_to_simulation(&time, acceleration.copied().unwrap_or_default()),
+                &time_space.force_to_simulation(&time, force.copied().unwrap_or_default()),
+            );
         }
     }
 }
 
-fn update_acceleration(body: &mut RigidBody, acceleration: &Acceleration) {
-    let wake_up = !acceleration.is_near_zero();
+fn update_acceleration(body: &mut RigidBody, acceleration: &Acceleration, force: &Force) {
+    let wake_up = !acceleration.is_near_zero() || !force.is_near_zero();
     let linear_acceleration: Vector<f32> = acceleration.linear.into_rapier();
     let angular_acceleration: AngVector<f32> = acceleration.angular.into_rapier();
+    let linear_force: Vector<f32> = force.linear.into_rapier();
+    let torque: AngVector<f32> = force.angular.into_rapier();
     let inertia = {
         #[cfg(dim3)]
         {
@@ -41,6 +73,6 @@ fn update_acceleration(body: &mut RigidBody, acceleration: &Acceleration) {
     };
     body.reset_forces(wake_up);
     body.reset_torques(wake_up);
-    body.add_force(linear_acceleration * body.mass(), wake_up);
-    body.add_torque(inertia * angular_acceleration, wake_up);
+    body.add_force(linear_acceleration * body.mass() + linear_force, wake_up);
+    body.add_torque(inertia * angular_acceleration + torque, wake_up);
 }
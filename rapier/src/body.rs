@@ -1,39 +1,160 @@
 use bevy::ecs::prelude::*;
-use bevy::math::Affine3A;
+use bevy::hierarchy::Parent;
+use bevy::log::prelude::*;
+use bevy::math::{Affine3A, Quat, Vec3};
 use bevy::transform::prelude::*;
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 
-use heron_core::{Damping, PhysicMaterial, RigidBody, RotationConstraints, Velocity};
+use heron_core::{
+    Ccd, Damping, DisableGravity, GravityScale, Interpolate, KinematicTeleportThreshold,
+    MissingTransforms, PhysicMaterial, PhysicsBodyInfo, PhysicsDisabled, PhysicsPose, PhysicsTime,
+    PhysicsWorldId, PixelSnap, RigidBody, RotationConstraints, Teleport, Velocity,
+    VelocityTimeSpace,
+};
 
+use crate::body_params::effective_gravity_scale;
 use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::{RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType};
 use crate::rapier::geometry::ColliderSet;
-use crate::rapier::{
-    dynamics::{IslandManager, RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType},
-    prelude::{ImpulseJointSet, MultibodyJointSet},
-};
+use crate::rapier::math::{AngVector, Vector};
+use crate::worlds::{HandleChanges, WorldMut, Worlds};
+use crate::{rapier, shape};
 
-pub(crate) type HandleMap = FnvHashMap<Entity, RigidBodyHandle>;
+pub(crate) type HandleMap = crate::handle_map::HandleMap<RigidBodyHandle>;
 
+/// Warns about (or fixes) the rigid bodies that lack a `Transform` or a `GlobalTransform`,
+/// depending on the [`MissingTransforms`] resource
+///
+/// Only a missing `GlobalTransform` is reported, since the rigid bodies having only a
+/// `GlobalTransform` are simulated.
 #[allow(clippy::type_complexity)]
-pub(crate) fn create(
+pub(crate) fn handle_missing_transforms(
     mut commands: Commands<'_, '_>,
-    mut bodies: ResMut<'_, RigidBodySet>,
-    mut handles: ResMut<'_, HandleMap>,
+    mut warned: Local<'_, FnvHashSet<Entity>>,
+    policy: Res<'_, MissingTransforms>,
     query: Query<
         '_,
         '_,
         (
             Entity,
+            Option<&Transform>,
+            Option<&GlobalTransform>,
+            Option<&PhysicsPose>,
+            Option<&Parent>,
+        ),
+        (
+            With<RigidBody>,
+            Or<(Without<Transform>, Without<GlobalTransform>)>,
+        ),
+    >,
+    globals: Query<'_, '_, &GlobalTransform>,
+) {
+    if *policy == MissingTransforms::Ignore {
+        return;
+    }
+
+    warned.retain(|entity| query.contains(*entity));
+
+    for (entity, transform, global, pose, parent) in query.iter() {
+        match *policy {
+            MissingTransforms::Warn => {
+                if global.is_none() && warned.insert(entity) {
+                    warn!(
+                        "The rigid body {:?} has no `GlobalTransform`. It isn't simulated until it has one.",
+                        entity,
+                    );
+                }
+            }
+            MissingTransforms::Insert => {
+                if global.is_some() && pose.is_some() {
+                    continue;
+                }
+                let parent_global = parent.and_then(|parent| globals.get(parent.get()).ok());
+                let mut entity = commands.entity(entity);
+                if let Some(global) = global {
+                    let local = match parent_global {
+                        Some(parent_global) => GlobalTransform::from(
+                            parent_global.affine().inverse() * global.affine(),
+                        )
+                        .compute_transform(),
+                        None => global.compute_transform(),
+                    };
+                    entity.insert(local);
+                } else {
+                    let local = transform.copied().unwrap_or_default();
+                    entity.insert(match parent_global {
+                        Some(parent_global) => parent_global.mul_transform(local),
+                        None => GlobalTransform::from(local),
+                    });
+                    if transform.is_none() && pose.is_none() {
+                        entity.insert(local);
+                    }
+                }
+            }
+            MissingTransforms::Ignore => (),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn create(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut changes: ResMut<'_, HandleChanges>,
+    time: Res<'_, PhysicsTime>,
+    time_space: Res<'_, VelocityTimeSpace>,
+    new_bodies: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<RigidBody>,
+            Without<super::RigidBodyHandle>,
+            Without<PhysicsDisabled>,
+        ),
+    >,
+    query: Query<
+        '_,
+        '_,
+        (
             &GlobalTransform,
             &RigidBody,
             Option<&Velocity>,
             Option<&Damping>,
             Option<&RotationConstraints>,
+            Option<&PhysicsWorldId>,
+            Option<&GravityScale>,
+            Option<&DisableGravity>,
+            Option<&Ccd>,
         ),
-        Without<super::RigidBodyHandle>,
+        Without<PhysicsDisabled>,
     >,
 ) {
-    for (entity, transform, body, velocity, damping, rotation_constraints) in query.iter() {
+    // The bodies removed in this frame still have their handle component, until the end of the stage
+    let HandleChanges {
+        removed_bodies,
+        created_bodies,
+        ..
+    } = &mut *changes;
+    for entity in new_bodies.iter().chain(removed_bodies.iter().copied()) {
+        if worlds.body_handle(entity).is_some() {
+            continue;
+        }
+        let (
+            transform,
+            body,
+            velocity,
+            damping,
+            rotation_constraints,
+            world_id,
+            gravity_scale,
+            disable_gravity,
+            ccd,
+        ) = match query.get(entity) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+
         let (_, global_rotation, global_translation) = transform.to_scale_rotation_translation();
         let mut builder = RigidBodyBuilder::new(body_status(*body))
             .user_data(entity.to_bits().into())
@@ -57,6 +178,7 @@ pub(crate) fn create(
         }
 
         if let Some(v) = velocity {
+            let v = time_space.velocity_to_simulation(&time, *v);
             builder = builder
                 .linvel(v.linear.into_rapier())
                 .angvel(v.angular.into_rapier());
@@ -66,27 +188,30 @@ pub(crate) fn create(
             builder = builder.linear_damping(d.linear).angular_damping(d.angular);
         }
 
-        let rigid_body_handle = bodies.insert(builder.build());
+        builder = builder
+            .gravity_scale(effective_gravity_scale(gravity_scale, disable_gravity))
+            .ccd_enabled(ccd.is_some());
+
+        let world_id = world_id.copied().unwrap_or_default();
+        let world = worlds.get_mut(world_id);
+        let rigid_body_handle = world.bodies.insert(builder.build());
 
-        handles.insert(entity, rigid_body_handle);
+        world.body_handles.insert(entity, rigid_body_handle);
         commands
             .entity(entity)
-            .insert(super::RigidBodyHandle(rigid_body_handle));
+            .insert(super::RigidBodyHandle(rigid_body_handle, world_id));
+        created_bodies.push(entity);
     }
 }
 
-#[allow(clippy::too_many_arguments)]
 pub(crate) fn remove_invalids_after_components_removed(
     mut commands: Commands<'_, '_>,
-    mut handles: ResMut<'_, HandleMap>,
-    mut bodies: ResMut<'_, RigidBodySet>,
-    mut islands: ResMut<'_, IslandManager>,
-    mut colliders: ResMut<'_, ColliderSet>,
-    mut impulse_joints: ResMut<'_, ImpulseJointSet>,
-    mut multibody_joints: ResMut<'_, MultibodyJointSet>,
+    mut worlds: Worlds<'_, '_>,
     bodies_removed: RemovedComponents<'_, RigidBody>,
     constraints_removed: RemovedComponents<'_, RotationConstraints>,
     materials_removed: RemovedComponents<'_, PhysicMaterial>,
+    worlds_removed: RemovedComponents<'_, PhysicsWorldId>,
+    mut changes: ResMut<'_, HandleChanges>,
     rb_entities: Query<'_, '_, Entity, With<super::RigidBodyHandle>>,
     collider_entities: Query<'_, '_, Entity, With<super::ColliderHandle>>,
 ) {
@@ -94,39 +219,30 @@ pub(crate) fn remove_invalids_after_components_removed(
         .iter()
         .chain(constraints_removed.iter())
         .chain(materials_removed.iter())
+        .chain(worlds_removed.iter())
         .for_each(|entity| {
-            if let Some(handle) = handles.remove(&entity) {
-                remove_collider_handles(
-                    &mut commands,
-                    &collider_entities,
-                    &bodies,
-                    &colliders,
-                    handle,
-                );
-                bodies.remove(
-                    handle,
-                    &mut islands,
-                    &mut colliders,
-                    &mut impulse_joints,
-                    &mut multibody_joints,
-                    true,
-                );
-                if rb_entities.get(entity).is_ok() {
-                    commands.entity(entity).remove::<super::RigidBodyHandle>();
+            for world in worlds.iter_mut() {
+                if let Some(handle) = world.body_handles.remove(entity) {
+                    remove_body(
+                        &mut commands,
+                        &mut changes,
+                        &collider_entities,
+                        world,
+                        handle,
+                    );
+                    if rb_entities.get(entity).is_ok() {
+                        commands.entity(entity).remove::<super::RigidBodyHandle>();
+                    }
+                    changes.removed_bodies.push(entity);
                 }
             }
         });
 }
 
-#[allow(clippy::type_complexity)]
 pub(crate) fn remove_invalids_after_component_changed(
     mut commands: Commands<'_, '_>,
-    mut handles: ResMut<'_, HandleMap>,
-    mut bodies: ResMut<'_, RigidBodySet>,
-    mut islands: ResMut<'_, IslandManager>,
-    mut colliders: ResMut<'_, ColliderSet>,
-    mut impulse_joints: ResMut<'_, ImpulseJointSet>,
-    mut multibody_joints: ResMut<'_, MultibodyJointSet>,
+    mut worlds: Worlds<'_, '_>,
+    mut handle_changes: ResMut<'_, HandleChanges>,
     collider_entities: Query<'_, '_, Entity, With<super::ColliderHandle>>,
     rigidbody_entities: Query<'_, '_, Entity, With<super::RigidBodyHandle>>,
     changed: Query<
@@ -137,38 +253,63 @@ pub(crate) fn remove_invalids_after_component_changed(
             Changed<RigidBody>,
             Changed<RotationConstraints>,
             Changed<PhysicMaterial>,
+            Changed<PhysicsWorldId>,
+            Added<PhysicsDisabled>,
         )>,
     >,
 ) {
     for (entity, handle) in changed.iter() {
-        remove_collider_handles(
+        let world = worlds.get_mut(handle.1);
+        world.body_handles.remove(entity);
+        remove_body(
             &mut commands,
+            &mut handle_changes,
             &collider_entities,
-            &bodies,
-            &colliders,
+            world,
             handle.0,
         );
-        bodies.remove(
-            handle.0,
-            &mut islands,
-            &mut colliders,
-            &mut impulse_joints,
-            &mut multibody_joints,
-            true,
-        );
         if rigidbody_entities.get(entity).is_ok() {
             commands.entity(entity).remove::<super::RigidBodyHandle>();
         }
-        handles.remove(&entity);
+        handle_changes.removed_bodies.push(entity);
     }
 }
 
+/// Remove the rigid body and its colliders from the physics world
+fn remove_body(
+    commands: &mut Commands<'_, '_>,
+    changes: &mut HandleChanges,
+    collider_entities: &Query<'_, '_, Entity, With<super::ColliderHandle>>,
+    world: WorldMut<'_>,
+    handle: RigidBodyHandle,
+) {
+    remove_collider_handles(
+        commands,
+        &mut changes.removed_colliders,
+        collider_entities,
+        world.bodies,
+        world.colliders,
+        world.collider_handles,
+        handle,
+    );
+    world.bodies.remove(
+        handle,
+        world.islands,
+        world.colliders,
+        world.impulse_joints,
+        world.multibody_joints,
+        true,
+    );
+}
+
 #[allow(clippy::manual_filter_map)]
 fn remove_collider_handles(
     commands: &mut Commands<'_, '_>,
+    removed: &mut Vec<Entity>,
     entities: &Query<'_, '_, Entity, With<super::ColliderHandle>>,
     bodies: &RigidBodySet,
     colliders: &ColliderSet,
+    collider_handles: &mut shape::HandleMap,
     handle: RigidBodyHandle,
 ) {
     bodies
@@ -180,98 +321,341 @@ fn remove_collider_handles(
             #[allow(clippy::cast_possible_truncation)]
             Entity::from_bits(it.user_data as u64)
         })
-        .filter(|e| entities.get(*e).is_ok())
         .for_each(|collider_entity| {
-            commands
-                .entity(collider_entity)
-                .remove::<super::ColliderHandle>();
+            collider_handles.remove(collider_entity);
+            if entities.get(collider_entity).is_ok() {
+                commands
+                    .entity(collider_entity)
+                    .remove::<super::ColliderHandle>();
+            }
+            removed.push(collider_entity);
         });
 }
 
 pub(crate) fn update_rapier_position(
-    mut bodies: ResMut<'_, RigidBodySet>,
-    query: Query<'_, '_, (&GlobalTransform, &super::RigidBodyHandle), Changed<GlobalTransform>>,
+    mut worlds: Worlds<'_, '_>,
+    threshold: Res<'_, KinematicTeleportThreshold>,
+    query: Query<
+        '_,
+        '_,
+        (
+            &GlobalTransform,
+            &super::RigidBodyHandle,
+            Option<&PixelSnap>,
+        ),
+        (Changed<GlobalTransform>, Without<PhysicsPose>),
+    >,
 ) {
-    for (transform, handle) in query.iter() {
-        if let Some(body) = bodies.get_mut(handle.0) {
+    for (transform, handle, snap) in query.iter() {
+        if let Some(body) = worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
             let (_, global_rotation, global_translation) =
                 transform.to_scale_rotation_translation();
-            let isometry = (global_translation, global_rotation).into_rapier();
 
-            if body.is_kinematic() {
-                body.set_next_kinematic_position(isometry);
-            } else {
-                body.set_position(isometry, true);
+            // Don't overwrite the exact position with the snapped one written by `update_bevy_transform`
+            if let Some(snap) = snap {
+                let (translation, rotation) = rendered_position(body, global_translation);
+                if snap.snap(translation) == global_translation && rotation == global_rotation {
+                    continue;
+                }
             }
+
+            set_position(body, global_translation, global_rotation, *threshold);
+        }
+    }
+}
+
+pub(crate) fn update_rapier_position_from_pose(
+    mut worlds: Worlds<'_, '_>,
+    threshold: Res<'_, KinematicTeleportThreshold>,
+    query: Query<'_, '_, (&PhysicsPose, &super::RigidBodyHandle), Changed<PhysicsPose>>,
+) {
+    for (pose, handle) in query.iter() {
+        if let Some(body) = worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+            set_position(body, pose.translation, pose.rotation, *threshold);
+        }
+    }
+}
+
+fn set_position(
+    body: &mut rapier::dynamics::RigidBody,
+    translation: Vec3,
+    rotation: Quat,
+    threshold: KinematicTeleportThreshold,
+) {
+    let isometry = (translation, rotation).into_rapier();
+    if body.is_kinematic() {
+        // A far jump is a teleport, so that the body doesn't sweep (and push) everything on its way
+        if threshold.is_teleport(
+            (*body.translation()).into_bevy(),
+            isometry.translation.vector.into_bevy(),
+        ) {
+            body.set_position(isometry, true);
         }
+        body.set_next_kinematic_position(isometry);
+    } else {
+        body.set_position(isometry, true);
     }
 }
 
 pub(crate) fn update_bevy_transform(
-    bodies: Res<'_, RigidBodySet>,
+    mut worlds: Worlds<'_, '_>,
     mut query: Query<
         '_,
         '_,
         (
+            Entity,
             Option<&mut Transform>,
             &mut GlobalTransform,
-            &super::RigidBodyHandle,
-            Option<&RigidBody>,
+            &RigidBody,
+            Option<&PixelSnap>,
+            Option<&Parent>,
         ),
+        Without<PhysicsPose>,
     >,
+    mut moved: Local<'_, FnvHashMap<Entity, GlobalTransform>>,
 ) {
-    for (mut local, mut global, handle, body_type) in query.iter_mut() {
-        if !body_type.copied().unwrap_or_default().can_have_velocity() {
+    moved.clear();
+    for (entity, _, global, body_type, snap, _) in query.iter() {
+        if !body_type.can_have_velocity() {
             continue;
         }
 
-        let body = match bodies.get(handle.0) {
+        // Looked up in the handle maps, so that the bodies created in this frame are included
+        let body = match worlds
+            .body_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).bodies.get(handle.0))
+        {
             None => continue,
             Some(body) => body,
         };
 
-        #[cfg(dim3)]
-        let (translation, rotation) = body.position().into_bevy();
-        #[cfg(dim2)]
-        let (mut translation, rotation) = body.position().into_bevy();
-
         let (global_scale, global_rotation, global_translation) =
             global.to_scale_rotation_translation();
 
-        #[cfg(dim2)]
-        {
-            // In 2D, preserve the transform `z` component that may have been set by the user
-            translation.z = global_translation.z;
+        let (mut translation, rotation) = rendered_position(body, global_translation);
+
+        if let Some(snap) = snap {
+            translation = snap.snap(translation);
         }
 
         if translation == global_translation && rotation == global_rotation {
             continue;
         }
 
-        if let Some(local) = &mut local {
-            if local.translation == global_translation {
-                local.translation = translation;
-            } else {
-                local.translation = translation - (global_translation - local.translation);
+        moved.insert(
+            entity,
+            GlobalTransform::from(Affine3A::from_scale_rotation_translation(
+                global_scale,
+                rotation,
+                translation,
+            )),
+        );
+    }
+
+    for (entity, new_global) in moved.iter() {
+        let (_, mut local, mut global, _, _, parent) = match query.get_mut(*entity) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+
+        // The parent body moved in the same step, so the offset between the local and global
+        // transforms of the child is outdated: the local transform is derived from the new
+        // position of the parent instead
+        if let Some(parent_global) = parent.and_then(|parent| moved.get(&parent.get())) {
+            if let Some(mut local) = local {
+                *local =
+                    GlobalTransform::from(parent_global.affine().inverse() * new_global.affine())
+                        .compute_transform();
             }
+            *global = *new_global;
+        } else {
+            let (_, rotation, translation) = new_global.to_scale_rotation_translation();
+            write_transform(local.as_deref_mut(), &mut global, translation, rotation);
+        }
+    }
+}
+
+/// Moves the entity to the given global position, preserving the offset between its local and
+/// global transforms
+pub(crate) fn write_transform(
+    local: Option<&mut Transform>,
+    global: &mut GlobalTransform,
+    translation: Vec3,
+    rotation: Quat,
+) {
+    let (global_scale, global_rotation, global_translation) =
+        global.to_scale_rotation_translation();
+
+    if let Some(local) = local {
+        if local.translation == global_translation {
+            local.translation = translation;
+        } else {
+            local.translation = translation - (global_translation - local.translation);
+        }
+
+        if local.rotation == global_rotation {
+            local.rotation = rotation;
+        } else {
+            local.rotation = rotation * (global_rotation * local.rotation.conjugate()).conjugate();
+        }
+    }
+
+    *global = GlobalTransform::from(Affine3A::from_scale_rotation_translation(
+        global_scale,
+        rotation,
+        translation,
+    ));
+}
+
+/// Applies and removes the [`Teleport`] components
+#[allow(clippy::type_complexity)]
+pub(crate) fn teleport(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut query: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &Teleport,
+            &super::RigidBodyHandle,
+            Option<&mut Transform>,
+            Option<&mut GlobalTransform>,
+            Option<&mut PhysicsPose>,
+            Option<&mut Velocity>,
+            Option<&mut Interpolate>,
+        ),
+    >,
+) {
+    for (entity, teleport, handle, mut local, global, pose, velocity, interpolate) in &mut query {
+        commands.entity(entity).remove::<Teleport>();
+
+        let body = match worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+            Some(body) => body,
+            None => continue,
+        };
+
+        let rotation = teleport
+            .rotation
+            .unwrap_or_else(|| body.position().into_bevy().1);
+        let isometry = (teleport.translation, rotation).into_rapier();
+
+        // Setting the position also resets the position predicted for the end of the step, so that
+        // the continuous collision detection doesn't sweep from the old position to the new one
+        body.set_position(isometry, true);
+        if body.is_kinematic() {
+            body.set_next_kinematic_position(isometry);
+        }
 
-            if local.rotation == global_rotation {
-                local.rotation = rotation;
-            } else {
-                local.rotation =
-                    rotation * (global_rotation * local.rotation.conjugate()).conjugate();
+        if teleport.reset_velocity {
+            body.set_linvel(Vector::default(), true);
+            body.set_angvel(AngVector::default(), true);
+            if let Some(mut velocity) = velocity {
+                *velocity = Velocity::default();
             }
         }
 
-        *global = GlobalTransform::from(Affine3A::from_scale_rotation_translation(
-            global_scale,
-            rotation,
-            translation,
-        ));
+        body.wake_up(true);
+
+        if let Some(mut interpolate) = interpolate {
+            interpolate.reset(PhysicsPose::new(teleport.translation, rotation));
+        }
+
+        if let Some(mut pose) = pose {
+            pose.translation = teleport.translation;
+            pose.rotation = rotation;
+        } else if let Some(mut global) = global {
+            write_transform(
+                local.as_deref_mut(),
+                &mut global,
+                teleport.translation,
+                rotation,
+            );
+        }
+    }
+}
+
+/// Returns the position of the body as it should be rendered by bevy
+fn rendered_position(
+    body: &rapier::dynamics::RigidBody,
+    #[cfg_attr(dim3, allow(unused_variables))] global_translation: Vec3,
+) -> (Vec3, Quat) {
+    #[cfg(dim3)]
+    let (translation, rotation) = body.position().into_bevy();
+    #[cfg(dim2)]
+    let (mut translation, rotation) = body.position().into_bevy();
+
+    #[cfg(dim2)]
+    {
+        // In 2D, preserve the transform `z` component that may have been set by the user
+        translation.z = global_translation.z;
+    }
+
+    (translation, rotation)
+}
+
+pub(crate) fn update_physics_pose(
+    mut worlds: Worlds<'_, '_>,
+    mut query: Query<'_, '_, (Entity, &mut PhysicsPose, &RigidBody)>,
+) {
+    for (entity, mut pose, body_type) in &mut query {
+        if !body_type.can_have_velocity() {
+            continue;
+        }
+
+        let body = match worlds
+            .body_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).bodies.get(handle.0))
+        {
+            None => continue,
+            Some(body) => body,
+        };
+
+        #[cfg(dim3)]
+        let (translation, rotation) = body.position().into_bevy();
+        #[cfg(dim2)]
+        let (mut translation, rotation) = body.position().into_bevy();
+
+        #[cfg(dim2)]
+        {
+            // In 2D, preserve the `z` component that may have been set by the user
+            translation.z = pose.translation.z;
+        }
+
+        if translation != pose.translation || rotation != pose.rotation {
+            *pose = PhysicsPose::new(translation, rotation);
+        }
+    }
+}
+
+/// Fills the [`PhysicsBodyInfo`] components with the mass, energy and momentum of the rigid bodies
+pub(crate) fn update_body_info_component(
+    mut worlds: Worlds<'_, '_>,
+    time: Res<'_, PhysicsTime>,
+    time_space: Res<'_, VelocityTimeSpace>,
+    mut query: Query<'_, '_, (Entity, &mut PhysicsBodyInfo)>,
+) {
+    let factor = time_space.velocity_factor(&time);
+    for (entity, mut info) in &mut query {
+        if let Some(body) = worlds
+            .body_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).bodies.get(handle.0))
+        {
+            let linvel: Vec3 = (*body.linvel()).into_bevy();
+            let new_info = PhysicsBodyInfo::new(
+                body.mass(),
+                body.kinetic_energy() * factor * factor,
+                linvel * body.mass() * factor,
+            );
+            if *info != new_info {
+                *info = new_info;
+            }
+        }
     }
 }
 
-fn body_status(body_type: RigidBody) -> RigidBodyType {
+#[allow(deprecated)]
+pub(crate) fn body_status(body_type: RigidBody) -> RigidBodyType {
     match body_type {
         RigidBody::Dynamic => RigidBodyType::Dynamic,
         RigidBody::Static | RigidBody::Sensor => RigidBodyType::Fixed,
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+use heron_core::{Ccd, Damping, DisableGravity, GravityScale};
+
+use crate::rapier::dynamics::{RigidBody, RigidBodyDamping};
+use crate::worlds::Worlds;
+use crate::RigidBodyHandle;
+
+type BodyParams<'a> = (
+    &'a RigidBodyHandle,
+    Option<&'a Damping>,
+    Option<&'a GravityScale>,
+    Option<&'a DisableGravity>,
+    Option<&'a Ccd>,
+);
+
+/// Applies the body parameter components ([`Damping`], [`GravityScale`], [`DisableGravity`] and
+/// [`Ccd`]) to the rapier bodies
+///
+/// All the parameters of a body are applied at once when any of them is changed, inserted or
+/// removed, so that a single pass over the changed bodies is needed, regardless of the number of
+/// parameter components.
+pub(crate) fn update_body_params(
+    mut worlds: Worlds<'_, '_>,
+    bodies: Query<'_, '_, BodyParams<'_>>,
+    changed: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<RigidBodyHandle>,
+            Or<(
+                Changed<Damping>,
+                Changed<GravityScale>,
+                Changed<DisableGravity>,
+                Changed<Ccd>,
+            )>,
+        ),
+    >,
+    removed_damping: RemovedComponents<'_, Damping>,
+    removed_gravity_scale: RemovedComponents<'_, GravityScale>,
+    removed_disable_gravity: RemovedComponents<'_, DisableGravity>,
+    removed_ccd: RemovedComponents<'_, Ccd>,
+) {
+    changed
+        .iter()
+        .chain(removed_damping.iter())
+        .chain(removed_gravity_scale.iter())
+        .chain(removed_disable_gravity.iter())
+        .chain(removed_ccd.iter())
+        .filter_map(|entity| bodies.get(entity).ok())
+        .for_each(|(handle, damping, gravity_scale, disable_gravity, ccd)| {
+            if let Some(body) = worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+                apply(body, damping, gravity_scale, disable_gravity, ccd);
+            }
+        });
+}
+
+fn apply(
+    body: &mut RigidBody,
+    damping: Option<&Damping>,
+    gravity_scale: Option<&GravityScale>,
+    disable_gravity: Option<&DisableGravity>,
+    ccd: Option<&Ccd>,
+) {
+    let default_damping = RigidBodyDamping::default();
+    body.set_linear_damping(damping.map_or(default_damping.linear_damping, |d| d.linear));
+    body.set_angular_damping(damping.map_or(default_damping.angular_damping, |d| d.angular));
+
+    let scale = effective_gravity_scale(gravity_scale, disable_gravity);
+    #[allow(clippy::float_cmp)]
+    if body.gravity_scale() != scale {
+        // Only wake the body up if it may start falling
+        body.set_gravity_scale(scale, scale != 0.0);
+    }
+
+    body.enable_ccd(ccd.is_some());
+}
+
+/// Returns the gravity scale of a body, which is zero if its gravity is disabled
+pub(crate) fn effective_gravity_scale(
+    gravity_scale: Option<&GravityScale>,
+    disable_gravity: Option<&DisableGravity>,
+) -> f32 {
+    if disable_gravity.is_some() {
+        0.0
+    } else {
+        gravity_scale.copied().unwrap_or_default().0
+    }
+}
@@ -7,12 +7,12 @@
 
 use bevy::math::prelude::*;
 
-use heron_core::{AxisAngle, CollisionLayers};
+use heron_core::{ActiveCollisions, AxisAngle, CollisionLayers};
 
 use crate::nalgebra::{
     self, Point2, Point3, Quaternion, UnitComplex, UnitQuaternion, Vector2, Vector3,
 };
-use crate::rapier::geometry::InteractionGroups;
+use crate::rapier::geometry::{ActiveCollisionTypes, InteractionGroups};
 use crate::rapier::math::{Isometry, Translation, Vector};
 
 pub trait IntoBevy<T> {
@@ -160,11 +160,7 @@ impl IntoRapier<Isometry<f32>> for (Vec3, Quat) {
 
 impl IntoRapier<f32> for AxisAngle {
     fn into_rapier(self) -> f32 {
-        if self.axis().z > 0.0 {
-            self.angle()
-        } else {
-            -self.angle()
-        }
+        self.axis().z
     }
 }
 
@@ -180,6 +176,25 @@ impl IntoRapier<InteractionGroups> for CollisionLayers {
     }
 }
 
+impl IntoRapier<ActiveCollisionTypes> for ActiveCollisions {
+    fn into_rapier(self) -> ActiveCollisionTypes {
+        let mut types = ActiveCollisionTypes::empty();
+        types.set(ActiveCollisionTypes::DYNAMIC_DYNAMIC, self.dynamic_dynamic);
+        types.set(
+            ActiveCollisionTypes::DYNAMIC_KINEMATIC,
+            self.dynamic_kinematic,
+        );
+        types.set(ActiveCollisionTypes::DYNAMIC_FIXED, self.dynamic_static);
+        types.set(
+            ActiveCollisionTypes::KINEMATIC_KINEMATIC,
+            self.kinematic_kinematic,
+        );
+        types.set(ActiveCollisionTypes::KINEMATIC_FIXED, self.kinematic_static);
+        types.set(ActiveCollisionTypes::FIXED_FIXED, self.static_static);
+        types
+    }
+}
+
 impl IntoBevy<CollisionLayers> for InteractionGroups {
     fn into_bevy(self) -> CollisionLayers {
         #[allow(clippy::cast_possible_truncation)]
@@ -225,6 +240,25 @@ impl IntoRapier<rapier3d::geometry::ColliderHandle> for crate::ColliderHandle {
     }
 }
 
+#[cfg(feature = "2d")]
+impl IntoRapier<rapier2d::dynamics::ImpulseJointHandle> for crate::JointHandle {
+    #[cfg(not(feature = "3d"))]
+    fn into_rapier(self) -> rapier2d::dynamics::ImpulseJointHandle {
+        self.0
+    }
+    #[cfg(feature = "3d")]
+    fn into_rapier(self) -> rapier2d::dynamics::ImpulseJointHandle {
+        rapier2d::dynamics::ImpulseJointHandle::invalid()
+    }
+}
+
+#[cfg(feature = "3d")]
+impl IntoRapier<rapier3d::dynamics::ImpulseJointHandle> for crate::JointHandle {
+    fn into_rapier(self) -> rapier3d::dynamics::ImpulseJointHandle {
+        self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(dim3)]
@@ -247,6 +281,21 @@ mod tests {
             assert_eq!(result, -1.0);
         }
 
+        #[test]
+        fn axis_angle_around_negative_z_to_rapier() {
+            let result: f32 = AxisAngle::new(-Vec3::Z, 1.0).into_rapier();
+            assert_eq!(result, -1.0);
+        }
+
+        #[test]
+        fn axis_angle_around_other_axis_is_projected_on_z() {
+            let result: f32 = AxisAngle::new(Vec3::X, 1.0).into_rapier();
+            assert_eq!(result, 0.0);
+
+            let result: f32 = AxisAngle::from(Vec3::new(1.0, 0.0, 2.0)).into_rapier();
+            assert_eq!(result, 2.0);
+        }
+
         #[rstest(quat,
             case(Quat::from_axis_angle(Vec3::Z, 2.0)),
             case(Quat::from_axis_angle(-Vec3::Z, 2.0)),
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+use heron_core::{ColliderDebugInfo, CollisionLayers, CollisionShape, LayerNames};
+
+use crate::shape::collider_aabb;
+use crate::worlds::Worlds;
+
+/// Inserts and updates the [`ColliderDebugInfo`] of the collision shapes
+pub(crate) fn update_collider_debug_info(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    names: Res<'_, LayerNames>,
+    mut query: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &CollisionShape,
+            Option<&CollisionLayers>,
+            Option<&mut ColliderDebugInfo>,
+        ),
+    >,
+) {
+    for (entity, shape, layers, info) in &mut query {
+        let collider = match worlds
+            .collider_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).colliders.get(handle.0))
+        {
+            Some(collider) => collider,
+            None => continue,
+        };
+
+        let new_info = ColliderDebugInfo::new(
+            shape,
+            collider.shape().mass_properties(1.0).mass(),
+            collider_aabb(collider),
+            names.group_names(layers.copied().unwrap_or_default()),
+        );
+
+        match info {
+            Some(mut info) => {
+                if *info != new_info {
+                    *info = new_info;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(new_info);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use heron_core::{FluidArea, Gravity, PhysicsWorldSettings};
+
+use crate::convert::IntoRapier;
+use crate::rapier::dynamics::{IntegrationParameters, RigidBody};
+use crate::rapier::geometry::{Collider, AABB};
+use crate::rapier::math::{Point, Vector};
+use crate::worlds::{WorldMut, Worlds};
+
+/// Applies the buoyancy and drag of the [`FluidArea`]s to the dynamic bodies overlapping them
+pub(crate) fn apply_fluid_forces(
+    mut worlds: Worlds<'_, '_>,
+    gravity: Res<'_, Gravity>,
+    settings: Res<'_, PhysicsWorldSettings>,
+    integration_parameters: Res<'_, IntegrationParameters>,
+    areas: Query<'_, '_, (Entity, &FluidArea)>,
+) {
+    for (entity, area) in areas.iter() {
+        let handle = match worlds.collider_handle(entity) {
+            Some(handle) => handle,
+            None => continue,
+        };
+        let gravity = if handle.1.is_default() {
+            *gravity
+        } else {
+            settings.get(handle.1).gravity
+        };
+
+        let WorldMut {
+            bodies, colliders, ..
+        } = worlds.get_mut(handle.1);
+
+        let area_aabb = match colliders.get(handle.0) {
+            Some(collider) => collider.compute_aabb(),
+            None => continue,
+        };
+
+        for (collider_handle, collider) in colliders.iter() {
+            if collider_handle == handle.0 || collider.is_sensor() {
+                continue;
+            }
+
+            let body = match collider.parent().and_then(|it| bodies.get_mut(it)) {
+                Some(body) if body.is_dynamic() => body,
+                _ => continue,
+            };
+
+            if let Some((fraction, center)) = submerged_part(collider, &area_aabb) {
+                apply(
+                    area,
+                    gravity,
+                    integration_parameters.dt,
+                    body,
+                    collider,
+                    fraction,
+                    center,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the submerged fraction of the collider, and the center of the submerged part
+fn submerged_part(collider: &Collider, area: &AABB) -> Option<(f32, Point<f32>)> {
+    let aabb = collider.compute_aabb();
+    let submerged = aabb.intersection(area)?;
+    let fraction = submerged.volume() / aabb.volume();
+    if fraction.is_nan() || fraction <= 0.0 {
+        return None;
+    }
+
+    Some((fraction, submerged.center()))
+}
+
+fn apply(
+    area: &FluidArea,
+    gravity: Gravity,
+    dt: f32,
+    body: &mut RigidBody,
+    collider: &Collider,
+    fraction: f32,
+    center: Point<f32>,
+) {
+    let displaced_volume = collider.shape().mass_properties(1.0).mass() * fraction;
+    let buoyancy = -gravity.vector() * area.density * displaced_volume;
+    let impulse: Vector<f32> = (buoyancy * dt).into_rapier();
+    body.apply_impulse_at_point(impulse, center, true);
+
+    let linear_drag = (area.linear_drag * fraction * dt).min(1.0);
+    let flow_velocity: Vector<f32> = area.flow_velocity.into_rapier();
+    let linvel = *body.linvel();
+    body.set_linvel(linvel - (linvel - flow_velocity) * linear_drag, true);
+
+    let angular_drag = (area.angular_drag * fraction * dt).min(1.0);
+    body.set_angvel(body.angvel() * (1.0 - angular_drag), true);
+}
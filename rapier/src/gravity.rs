@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use fnv::{FnvHashMap, FnvHashSet};
+
+use heron_core::{DisableGravity, GravityArea, GravityDirection, GravityScale, PhysicsWorldId};
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::{IntegrationParameters, RigidBody, RigidBodyHandle};
+use crate::rapier::geometry::Collider;
+use crate::rapier::math::Vector;
+use crate::worlds::{WorldMut, Worlds};
+
+type GravityMap = FnvHashMap<(PhysicsWorldId, RigidBodyHandle), Vec3>;
+
+/// Resource that holds the custom gravity applied to each body at the last step, so that the global
+/// gravity of the bodies can be restored once they are not affected anymore
+#[derive(Default)]
+pub(crate) struct CustomGravities(GravityMap);
+
+/// Applies the gravity of the [`GravityArea`]s and [`GravityDirection`]s to the dynamic bodies
+///
+/// The global gravity is disabled for the affected bodies (by setting their gravity scale to zero)
+/// and restored once they are not affected anymore. The custom gravity is multiplied by the
+/// [`GravityScale`] of the body, and the bodies with [`DisableGravity`] are ignored.
+pub(crate) fn apply_custom_gravity(
+    mut worlds: Worlds<'_, '_>,
+    integration_parameters: Res<'_, IntegrationParameters>,
+    areas: Query<'_, '_, (Entity, &GravityArea)>,
+    overrides: Query<'_, '_, (Entity, &GravityDirection)>,
+    floating: Query<'_, '_, Entity, With<DisableGravity>>,
+    scales: Query<'_, '_, &GravityScale>,
+    mut affected: ResMut<'_, CustomGravities>,
+) {
+    let mut gravities = GravityMap::default();
+    let floating: FnvHashSet<(PhysicsWorldId, RigidBodyHandle)> = floating
+        .iter()
+        .filter_map(|entity| worlds.body_handle(entity))
+        .map(|handle| (handle.1, handle.0))
+        .collect();
+
+    for (entity, area) in areas.iter() {
+        let handle = match worlds.collider_handle(entity) {
+            Some(handle) => handle,
+            None => continue,
+        };
+        let WorldMut {
+            bodies,
+            colliders,
+            narrow_phase,
+            ..
+        } = worlds.get_mut(handle.1);
+
+        let center: Vec3 = match colliders.get(handle.0) {
+            Some(collider) => collider.position().translation.into_bevy(),
+            None => continue,
+        };
+
+        for (collider1, collider2, intersecting) in narrow_phase.intersections_with(handle.0) {
+            let other = if collider1 == handle.0 {
+                collider2
+            } else {
+                collider1
+            };
+
+            let body_handle = match colliders.get(other).and_then(Collider::parent) {
+                Some(body_handle) if intersecting => body_handle,
+                _ => continue,
+            };
+
+            if let Some(body) = bodies.get(body_handle).filter(|it| it.is_dynamic()) {
+                let position: Vec3 = body.translation().into_bevy();
+                *gravities.entry((handle.1, body_handle)).or_default() +=
+                    area.gravity_at(center, position);
+            }
+        }
+    }
+
+    for (entity, gravity) in overrides.iter() {
+        if let Some(handle) = worlds.body_handle(entity) {
+            gravities.insert((handle.1, handle.0), gravity.vector());
+        }
+    }
+
+    gravities.retain(|key, _| !floating.contains(key));
+
+    for (&(world, handle), gravity) in &gravities {
+        if let Some(body) = worlds.get_mut(world).bodies.get_mut(handle) {
+            let scale = gravity_scale(&scales, body);
+            body.set_gravity_scale(0.0, false);
+            let impulse: Vector<f32> =
+                (*gravity * scale * body.mass() * integration_parameters.dt).into_rapier();
+            body.apply_impulse(impulse, true);
+        }
+    }
+
+    for (world, handle) in affected.0.keys() {
+        let key = (*world, *handle);
+        if !gravities.contains_key(&key) && !floating.contains(&key) {
+            if let Some(body) = worlds.get_mut(*world).bodies.get_mut(*handle) {
+                let scale = gravity_scale(&scales, body);
+                body.set_gravity_scale(scale, true);
+            }
+        }
+    }
+
+    affected.0 = gravities;
+}
+
+/// Returns the [`GravityScale`] of the entity of the body
+#[allow(clippy::cast_possible_truncation)]
+fn gravity_scale(scales: &Query<'_, '_, &GravityScale>, body: &RigidBody) -> f32 {
+    scales
+        .get(Entity::from_bits(body.user_data as u64))
+        .copied()
+        .unwrap_or_default()
+        .0
+}
@@ -0,0 +1,84 @@
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use heron_core::{Gravity, GravityDirection, GroundDetection};
+
+use crate::convert::IntoBevy;
+use crate::rapier::geometry::Collider;
+use crate::worlds::{WorldMut, Worlds};
+
+/// Maximum difference between the components of the ground normals, under which the
+/// [`GroundDetection`] isn't updated, to not trigger the change detection on every step
+const NORMAL_EPSILON: f32 = 1e-4;
+
+/// Updates the [`GroundDetection`] of the rigid bodies from the contacts of their colliders
+///
+/// The up axis is opposite to the [`GravityDirection`] of the body, or to the global [`Gravity`].
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn update_ground_detection(
+    mut worlds: Worlds<'_, '_>,
+    gravity: Res<'_, Gravity>,
+    mut query: Query<'_, '_, (Entity, Option<&GravityDirection>, &mut GroundDetection)>,
+) {
+    for (entity, direction, mut detection) in &mut query {
+        let handle = match worlds.body_handle(entity) {
+            Some(handle) => handle,
+            None => continue,
+        };
+        let up = -direction.map_or(gravity.vector(), |direction| direction.vector());
+
+        let WorldMut {
+            bodies,
+            colliders,
+            narrow_phase,
+            ..
+        } = worlds.get_mut(handle.1);
+
+        let body_colliders = match bodies.get(handle.0) {
+            Some(body) => body.colliders(),
+            None => continue,
+        };
+
+        let ground = body_colliders
+            .iter()
+            .flat_map(|collider| {
+                narrow_phase
+                    .contacts_with(*collider)
+                    .filter(|pair| pair.has_any_active_contact)
+                    .flat_map(move |pair| {
+                        // The manifold normal points from the first collider toward the second one
+                        let (other, sign) = if pair.collider1 == *collider {
+                            (pair.collider2, -1.0)
+                        } else {
+                            (pair.collider1, 1.0)
+                        };
+                        pair.manifolds
+                            .iter()
+                            .filter(|manifold| !manifold.data.solver_contacts.is_empty())
+                            .map(move |manifold| (other, (manifold.data.normal * sign).into_bevy()))
+                    })
+            })
+            .filter(|(_, normal)| detection.is_ground_normal_toward(*normal, up))
+            .max_by(|(_, n1), (_, n2)| {
+                n1.dot(up)
+                    .partial_cmp(&n2.dot(up))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .and_then(|(other, normal)| {
+                colliders
+                    .get(other)
+                    .and_then(Collider::parent)
+                    .and_then(|parent| bodies.get(parent))
+                    .map(|body| (Entity::from_bits(body.user_data as u64), normal))
+            });
+
+        let normal_changed = match (detection.ground_normal(), ground) {
+            (Some(current), Some((_, normal))) => !current.abs_diff_eq(normal, NORMAL_EPSILON),
+            (current, ground) => current.is_some() != ground.is_some(),
+        };
+        if normal_changed || detection.ground_entity() != ground.map(|(entity, _)| entity) {
+            detection.set_ground(ground);
+        }
+    }
+}
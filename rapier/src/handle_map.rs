@@ -0,0 +1,104 @@
+use bevy::ecs::entity::Entity;
+
+/// Map from the entities to the handles of their rapier counterpart
+///
+/// The handles are stored in a vector indexed by the entity id, so that the lookups don't need to
+/// hash the entity. The generation of the entity is checked, so that the handle of a despawned
+/// entity isn't returned for another entity reusing the same id.
+///
+/// It is public only because it appears in the fields of the [`PhysicsWorld`](crate::PhysicsWorld)
+/// system parameter.
+#[doc(hidden)]
+pub struct HandleMap<H> {
+    slots: Vec<Option<(Entity, H)>>,
+}
+
+impl<H> Default for HandleMap<H> {
+    fn default() -> Self {
+        Self { slots: Vec::new() }
+    }
+}
+
+impl<H> HandleMap<H> {
+    /// Inserts the handle of the entity, and returns the previous one if any
+    pub(crate) fn insert(&mut self, entity: Entity, handle: H) -> Option<H> {
+        let index = entity.id() as usize;
+        if index >= self.slots.len() {
+            self.slots.resize_with(index + 1, || None);
+        }
+
+        self.slots[index]
+            .replace((entity, handle))
+            .filter(|(previous, _)| *previous == entity)
+            .map(|(_, handle)| handle)
+    }
+
+    /// Returns the handle of the entity
+    pub(crate) fn get(&self, entity: Entity) -> Option<&H> {
+        match self.slots.get(entity.id() as usize) {
+            Some(Some((current, handle))) if *current == entity => Some(handle),
+            _ => None,
+        }
+    }
+
+    /// Removes the handle of the entity, and returns it if any
+    pub(crate) fn remove(&mut self, entity: Entity) -> Option<H> {
+        let slot = self.slots.get_mut(entity.id() as usize)?;
+        if slot
+            .as_ref()
+            .map_or(false, |(current, _)| *current == entity)
+        {
+            slot.take().map(|(_, handle)| handle)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_inserted_handle() {
+        let mut map = HandleMap::default();
+        let entity = Entity::from_raw(42);
+        assert_eq!(map.insert(entity, 1), None);
+        assert_eq!(map.get(entity), Some(&1));
+        assert_eq!(map.get(Entity::from_raw(41)), None);
+        assert_eq!(map.get(Entity::from_raw(43)), None);
+    }
+
+    #[test]
+    fn insert_replaces_previous_handle() {
+        let mut map = HandleMap::default();
+        let entity = Entity::from_raw(3);
+        map.insert(entity, 1);
+        assert_eq!(map.insert(entity, 2), Some(1));
+        assert_eq!(map.get(entity), Some(&2));
+    }
+
+    #[test]
+    fn remove_handle() {
+        let mut map = HandleMap::default();
+        let entity = Entity::from_raw(3);
+        map.insert(entity, 1);
+        assert_eq!(map.remove(entity), Some(1));
+        assert_eq!(map.get(entity), None);
+        assert_eq!(map.remove(entity), None);
+    }
+
+    #[test]
+    fn generation_is_checked() {
+        let mut map = HandleMap::default();
+        let old = Entity::from_bits(3);
+        let new = Entity::from_bits((1 << 32) | 3);
+        map.insert(old, 1);
+
+        assert_eq!(map.get(new), None);
+        assert_eq!(map.remove(new), None);
+        assert_eq!(map.insert(new, 2), None);
+        assert_eq!(map.get(old), None);
+        assert_eq!(map.get(new), Some(&2));
+    }
+}
@@ -0,0 +1,244 @@
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use heron_core::{
+    CollisionShape, DisabledContacts, Joint, NoSelfCollision, PhysicsWorldId, SpringJoint,
+};
+
+use crate::rapier::dynamics::RigidBodyHandle;
+use crate::rapier::geometry::{ColliderHandle, SolverFlags};
+use crate::rapier::pipeline::{
+    ActiveHooks, ContactModificationContext, PairFilterContext, PhysicsHooks,
+};
+use crate::surface_velocity::SurfaceVelocities;
+use crate::worlds::{HandleChanges, Worlds};
+
+/// Physics hooks given to rapier when stepping the simulation
+pub(crate) struct Hooks<'a> {
+    pub(crate) surface_velocities: &'a SurfaceVelocities,
+    pub(crate) disabled_contacts: &'a DisabledContacts,
+    pub(crate) hierarchies: &'a Hierarchies,
+}
+
+/// Collision shapes and rigid bodies whose contacts are ignored because of the hierarchy
+#[derive(Default)]
+pub(crate) struct Hierarchies {
+    /// Root entity (with [`NoSelfCollision`]) of the hierarchy of each collision shape entity
+    roots: HashMap<Entity, Entity>,
+    /// Pairs of rigid body entities connected by the joints created from the hierarchy
+    jointed: HashSet<(Entity, Entity)>,
+}
+
+impl PhysicsHooks for Hooks<'_> {
+    fn filter_contact_pair(&self, context: &PairFilterContext<'_>) -> Option<SolverFlags> {
+        let entities1 = entities(context, context.collider1, context.rigid_body1);
+        let entities2 = entities(context, context.collider2, context.rigid_body2);
+
+        if let (Some(shape1), Some(shape2)) = (entities1[0], entities2[0]) {
+            let root1 = self.hierarchies.roots.get(&shape1);
+            if root1.is_some() && root1 == self.hierarchies.roots.get(&shape2) {
+                return None;
+            }
+        }
+
+        if let (Some(body1), Some(body2)) = (entities1[1], entities2[1]) {
+            if self.hierarchies.jointed.contains(&key(body1, body2)) {
+                return None;
+            }
+        }
+
+        let is_disabled = entities1.iter().flatten().any(|entity1| {
+            entities2
+                .iter()
+                .flatten()
+                .any(|entity2| self.disabled_contacts.is_disabled(*entity1, *entity2))
+        });
+
+        if is_disabled {
+            // The contacts are still computed, so that the collision events are fired
+            Some(SolverFlags::empty())
+        } else {
+            Some(SolverFlags::COMPUTE_IMPULSES)
+        }
+    }
+
+    fn modify_solver_contacts(&self, context: &mut ContactModificationContext<'_>) {
+        self.surface_velocities.modify_solver_contacts(context);
+    }
+}
+
+/// Returns the collision shape entity and the rigid body entity of the collider
+#[allow(clippy::cast_possible_truncation)]
+fn entities(
+    context: &PairFilterContext<'_>,
+    collider: ColliderHandle,
+    body: Option<RigidBodyHandle>,
+) -> [Option<Entity>; 2] {
+    [
+        context
+            .colliders
+            .get(collider)
+            .map(|collider| Entity::from_bits(collider.user_data as u64)),
+        body.and_then(|body| context.bodies.get(body))
+            .map(|body| Entity::from_bits(body.user_data as u64)),
+    ]
+}
+
+/// Finds the collision shapes belonging to the hierarchies marked with [`NoSelfCollision`]
+///
+/// A hierarchy nested in another one belongs to the outermost one. The hierarchies are only
+/// rebuilt when a root is added or removed, or when a collision shape or a child is added inside
+/// one of them.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_hierarchies(
+    mut hierarchies: ResMut<'_, Hierarchies>,
+    roots: Query<'_, '_, Entity, With<NoSelfCollision>>,
+    added_roots: Query<'_, '_, (), Added<NoSelfCollision>>,
+    changed: Query<'_, '_, Entity, Or<(Added<CollisionShape>, Changed<Children>)>>,
+    removed: RemovedComponents<'_, NoSelfCollision>,
+    parents: Query<'_, '_, &Parent>,
+    children: Query<'_, '_, &Children>,
+    shapes: Query<'_, '_, (), With<CollisionShape>>,
+) {
+    if added_roots.is_empty()
+        && removed.iter().next().is_none()
+        && !changed
+            .iter()
+            .any(|entity| outermost_root(entity, &roots, &parents).is_some())
+    {
+        return;
+    }
+
+    let hierarchies = &mut hierarchies.roots;
+    hierarchies.clear();
+    for root in roots.iter() {
+        if outermost_root(root, &roots, &parents) != Some(root) {
+            continue;
+        }
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            if shapes.contains(entity) {
+                hierarchies.insert(entity, root);
+            }
+            if let Ok(children) = children.get(entity) {
+                stack.extend(children.iter().copied());
+            }
+        }
+    }
+}
+
+/// Returns the outermost entity marked with [`NoSelfCollision`] among the entity and its ancestors
+fn outermost_root(
+    mut entity: Entity,
+    roots: &Query<'_, '_, Entity, With<NoSelfCollision>>,
+    parents: &Query<'_, '_, &Parent>,
+) -> Option<Entity> {
+    let mut root = None;
+    loop {
+        if roots.contains(entity) {
+            root = Some(entity);
+        }
+        match parents.get(entity) {
+            Ok(parent) => entity = parent.get(),
+            Err(_) => return root,
+        }
+    }
+}
+
+/// Finds the rigid bodies connected to their parent by
+/// [`AttachedToParent`](heron_core::AttachedToParent) or by a [`Ragdoll`](heron_core::Ragdoll)
+/// joint, so that the contacts between them are ignored
+///
+/// These are the joints created from the hierarchy, i.e. without [`Joint`] or [`SpringJoint`].
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn update_jointed_bodies(
+    mut hierarchies: ResMut<'_, Hierarchies>,
+    mut worlds: Worlds<'_, '_>,
+    changes: Res<'_, HandleChanges>,
+    other_joints: Query<'_, '_, (), Or<(With<Joint>, With<SpringJoint>)>>,
+) {
+    if !changes.created_joints && changes.removed_joints.is_empty() {
+        return;
+    }
+
+    // The handle components of the new joints are only inserted at the end of the stage, so the
+    // joints are found in the physics worlds. A joint belongs to the entity of its first body.
+    let jointed = &mut hierarchies.jointed;
+    jointed.clear();
+    for world in worlds.iter_mut() {
+        for (_, joint) in world.impulse_joints.iter() {
+            if let (Some(body1), Some(body2)) =
+                (world.bodies.get(joint.body1), world.bodies.get(joint.body2))
+            {
+                let entity1 = Entity::from_bits(body1.user_data as u64);
+                if !other_joints.contains(entity1) {
+                    jointed.insert(key(entity1, Entity::from_bits(body2.user_data as u64)));
+                }
+            }
+        }
+    }
+}
+
+/// Activates the contact filtering hook on the colliders involved in the [`DisabledContacts`],
+/// belonging to a hierarchy marked with [`NoSelfCollision`] or attached to their parent
+///
+/// The hook is only active on these colliders, so that the other contact pairs are not slowed
+/// down.
+pub(crate) fn update_contact_filters(
+    mut worlds: Worlds<'_, '_>,
+    disabled_contacts: Res<'_, DisabledContacts>,
+    hierarchies: Res<'_, Hierarchies>,
+    changes: Res<'_, HandleChanges>,
+    mut filtered: Local<'_, Vec<(PhysicsWorldId, ColliderHandle)>>,
+) {
+    if !disabled_contacts.is_changed() && !hierarchies.is_changed() && !changes.created_colliders {
+        return;
+    }
+
+    for (world, handle) in filtered.drain(..) {
+        if let Some(collider) = worlds.get_mut(world).colliders.get_mut(handle) {
+            collider.set_active_hooks(collider.active_hooks() - ActiveHooks::FILTER_CONTACT_PAIRS);
+        }
+    }
+
+    let entities: HashSet<Entity> = disabled_contacts
+        .iter()
+        .flat_map(|(entity1, entity2)| [entity1, entity2])
+        .chain(hierarchies.roots.keys().copied())
+        .chain(
+            hierarchies
+                .jointed
+                .iter()
+                .flat_map(|(entity1, entity2)| [*entity1, *entity2]),
+        )
+        .collect();
+
+    for entity in entities {
+        if let Some(handle) = worlds.collider_handle(entity) {
+            filtered.push((handle.1, handle.0));
+        }
+        if let Some(handle) = worlds.body_handle(entity) {
+            if let Some(body) = worlds.get_mut(handle.1).bodies.get(handle.0) {
+                filtered.extend(
+                    body.colliders()
+                        .iter()
+                        .map(|collider| (handle.1, *collider)),
+                );
+            }
+        }
+    }
+
+    for (world, handle) in filtered.iter() {
+        if let Some(collider) = worlds.get_mut(*world).colliders.get_mut(*handle) {
+            collider.set_active_hooks(collider.active_hooks() | ActiveHooks::FILTER_CONTACT_PAIRS);
+        }
+    }
+}
+
+fn key(entity1: Entity, entity2: Entity) -> (Entity, Entity) {
+    if entity1 <= entity2 {
+        (entity1, entity2)
+    } else {
+        (entity2, entity1)
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use heron_core::{Interpolate, PhysicsPose, PhysicsSteps};
+
+/// Inserts the [`PhysicsPose`] that holds the exact pose of the interpolated rigid bodies
+pub(crate) fn insert_physics_pose(
+    mut commands: Commands<'_, '_>,
+    query: Query<'_, '_, (Entity, &GlobalTransform), (With<Interpolate>, Without<PhysicsPose>)>,
+) {
+    for (entity, global) in query.iter() {
+        let (_, rotation, translation) = global.to_scale_rotation_translation();
+        commands
+            .entity(entity)
+            .insert(PhysicsPose::new(translation, rotation));
+    }
+}
+
+/// Records the poses written back after the physics step
+pub(crate) fn record_poses(mut query: Query<'_, '_, (&PhysicsPose, &mut Interpolate)>) {
+    for (pose, mut interpolate) in &mut query {
+        interpolate.record(*pose);
+    }
+}
+
+/// Writes the interpolated poses into the `Transform` and `GlobalTransform`, at each frame
+pub(crate) fn update_transforms(
+    steps: Res<'_, PhysicsSteps>,
+    mut query: Query<'_, '_, (&Interpolate, Option<&mut Transform>, &mut GlobalTransform)>,
+) {
+    let factor = steps.interpolation_factor();
+    for (interpolate, mut local, mut global) in &mut query {
+        if let Some(pose) = interpolate.pose(factor) {
+            let (_, rotation, translation) = global.to_scale_rotation_translation();
+            if pose.translation == translation && pose.rotation == rotation {
+                continue;
+            }
+            crate::body::write_transform(
+                local.as_deref_mut(),
+                &mut global,
+                pose.translation,
+                pose.rotation,
+            );
+        }
+    }
+}
@@ -0,0 +1,556 @@
+use std::f32::consts;
+use std::mem;
+
+use bevy::ecs::query::WorldQuery;
+use bevy::prelude::*;
+
+use heron_core::{AttachedToParent, Joint, JointKind, JointLimits, JointMotor, SpringJoint};
+
+use crate::convert::IntoRapier;
+use crate::rapier::dynamics::{
+    FixedJointBuilder, GenericJoint, ImpulseJoint, ImpulseJointHandle, IntegrationParameters,
+    JointAxis, MotorModel, PrismaticJointBuilder, RevoluteJointBuilder, RigidBodySet,
+};
+use crate::rapier::math::{Isometry, Point, Vector};
+use crate::rapier::na::Unit;
+use crate::worlds::{HandleChanges, WorldMut, Worlds};
+
+pub(crate) fn create(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut changes: ResMut<'_, HandleChanges>,
+    new_joints: Query<'_, '_, Entity, (With<Joint>, Without<super::JointHandle>)>,
+    joints: Query<'_, '_, (&Joint, Option<&JointMotor>, Option<&JointLimits>)>,
+) {
+    let mut created = false;
+    for entity in new_entities(&new_joints, &changes.removed_joints) {
+        let (joint, motor, limits) = match joints.get(entity) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+        let (body1, body2) = match bodies(&worlds, entity, joint.body) {
+            Some(bodies) => bodies,
+            None => continue,
+        };
+
+        let mut data = build(joint);
+        if let Some(motor) = motor {
+            set_motor(&mut data, joint.kind, motor);
+        }
+        if let Some(limits) = limits {
+            set_limits(&mut data, joint.kind, limits);
+        }
+
+        let handle = worlds
+            .get_mut(body1.1)
+            .impulse_joints
+            .insert(body1.0, body2.0, data, true);
+
+        commands
+            .entity(entity)
+            .insert(super::JointHandle(handle, body1.1));
+        created = true;
+    }
+    changes.created_joints |= created;
+}
+
+pub(crate) fn create_springs(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut changes: ResMut<'_, HandleChanges>,
+    new_springs: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<SpringJoint>,
+            Without<super::JointHandle>,
+            Without<Joint>,
+        ),
+    >,
+    springs: Query<'_, '_, &SpringJoint, Without<Joint>>,
+) {
+    let mut created = false;
+    for entity in new_entities(&new_springs, &changes.removed_joints) {
+        let spring = match springs.get(entity) {
+            Ok(spring) => spring,
+            Err(_) => continue,
+        };
+        let (body1, body2) = match bodies(&worlds, entity, spring.body) {
+            Some(bodies) => bodies,
+            None => continue,
+        };
+
+        let mut data = GenericJoint::default();
+        data.set_local_anchor1(spring.local_anchor.into_rapier())
+            .set_local_anchor2(spring.other_anchor.into_rapier());
+
+        let WorldMut {
+            bodies,
+            impulse_joints,
+            ..
+        } = worlds.get_mut(body1.1);
+        let handle = impulse_joints.insert(body1.0, body2.0, data, true);
+        // The handle component is only inserted at the end of the stage, so the spring is driven
+        // here for the first step
+        if let Some(joint) = impulse_joints.get_mut(handle) {
+            drive_spring(spring, joint, bodies);
+        }
+
+        commands
+            .entity(entity)
+            .insert(super::JointHandle(handle, body1.1));
+        created = true;
+    }
+    changes.created_joints |= created;
+}
+
+/// Creates a fixed joint between the bodies marked with [`AttachedToParent`] and their parent
+pub(crate) fn create_parent_attachments(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut changes: ResMut<'_, HandleChanges>,
+    new_attachments: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<AttachedToParent>,
+            Without<super::JointHandle>,
+            Without<Joint>,
+            Without<SpringJoint>,
+        ),
+    >,
+    parents: Query<'_, '_, &Parent, (With<AttachedToParent>, Without<Joint>, Without<SpringJoint>)>,
+) {
+    let mut created = false;
+    for entity in new_entities(&new_attachments, &changes.removed_joints) {
+        let parent = match parents.get(entity) {
+            Ok(parent) => parent,
+            Err(_) => continue,
+        };
+        let (body1, body2) = match bodies(&worlds, entity, parent.get()) {
+            Some(bodies) => bodies,
+            None => continue,
+        };
+
+        let world = worlds.get_mut(body1.1);
+        let (position1, position2) = match (world.bodies.get(body1.0), world.bodies.get(body2.0)) {
+            (Some(rb1), Some(rb2)) => (*rb1.position(), *rb2.position()),
+            _ => continue,
+        };
+
+        // Keep the current pose of the child relative to the parent
+        let data = FixedJointBuilder::new()
+            .local_frame1(Isometry::identity())
+            .local_frame2(position2.inv_mul(&position1));
+
+        let handle = world.impulse_joints.insert(body1.0, body2.0, data, true);
+
+        commands
+            .entity(entity)
+            .insert(super::JointHandle(handle, body1.1));
+        created = true;
+    }
+    changes.created_joints |= created;
+}
+
+/// Returns the entities without joint handle, and the ones whose joint was removed in this frame
+///
+/// The latter still have their handle component, until the end of the stage.
+fn new_entities<'a, F: WorldQuery>(
+    new: &'a Query<'_, '_, Entity, F>,
+    removed: &'a [Entity],
+) -> impl Iterator<Item = Entity> + 'a {
+    new.iter().chain(
+        removed
+            .iter()
+            .copied()
+            .filter(|entity| !new.contains(*entity)),
+    )
+}
+
+/// Returns the rigid bodies of the two entities, if they are in the same physics world
+fn bodies(
+    worlds: &Worlds<'_, '_>,
+    entity1: Entity,
+    entity2: Entity,
+) -> Option<(super::RigidBodyHandle, super::RigidBodyHandle)> {
+    let body1 = worlds.body_handle(entity1)?;
+    let body2 = worlds.body_handle(entity2)?;
+    (body1.1 == body2.1).then(|| (body1, body2))
+}
+
+pub(crate) fn remove_invalids_after_components_removed(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    handles: Query<'_, '_, &super::JointHandle>,
+    attachments: Query<'_, '_, &super::JointHandle, (Without<Joint>, Without<SpringJoint>)>,
+    removed: RemovedComponents<'_, Joint>,
+    removed_springs: RemovedComponents<'_, SpringJoint>,
+    removed_attachments: RemovedComponents<'_, AttachedToParent>,
+    removed_parents: RemovedComponents<'_, Parent>,
+    mut changes: ResMut<'_, HandleChanges>,
+) {
+    let removed_joints = removed
+        .iter()
+        .chain(removed_springs.iter())
+        .filter_map(|entity| Some((entity, handles.get(entity).ok()?)));
+    let removed_attachments = removed_attachments
+        .iter()
+        .chain(removed_parents.iter())
+        .filter_map(|entity| Some((entity, attachments.get(entity).ok()?)));
+
+    for (entity, handle) in removed_joints.chain(removed_attachments) {
+        worlds
+            .get_mut(handle.1)
+            .impulse_joints
+            .remove(handle.0, true);
+        commands.entity(entity).remove::<super::JointHandle>();
+        changes.remove_joint(entity);
+    }
+}
+
+pub(crate) fn remove_invalids_after_component_changed(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut handle_changes: ResMut<'_, HandleChanges>,
+    changed: Query<
+        '_,
+        '_,
+        (Entity, &super::JointHandle),
+        Or<(Changed<Joint>, Changed<SpringJoint>)>,
+    >,
+    reparented: Query<
+        '_,
+        '_,
+        (Entity, &super::JointHandle),
+        (
+            With<AttachedToParent>,
+            Without<Joint>,
+            Without<SpringJoint>,
+            Changed<Parent>,
+        ),
+    >,
+) {
+    for (entity, handle) in changed.iter().chain(reparented.iter()) {
+        worlds
+            .get_mut(handle.1)
+            .impulse_joints
+            .remove(handle.0, true);
+        commands.entity(entity).remove::<super::JointHandle>();
+        handle_changes.remove_joint(entity);
+    }
+}
+
+/// Remove the handles of the joints that rapier removed together with one of their rigid bodies
+pub(crate) fn remove_dangling_handles(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut changes: ResMut<'_, HandleChanges>,
+    handles: Query<'_, '_, (Entity, &super::JointHandle)>,
+) {
+    for (entity, handle) in handles.iter() {
+        if !worlds.get_mut(handle.1).impulse_joints.contains(handle.0) {
+            commands.entity(entity).remove::<super::JointHandle>();
+            changes.remove_joint(entity);
+        }
+    }
+}
+
+pub(crate) fn update_motor(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, (&Joint, &JointMotor, &super::JointHandle), Changed<JointMotor>>,
+) {
+    for (joint, motor, handle) in query.iter() {
+        update_rapier_joint(worlds.get_mut(handle.1), handle.0, |data| {
+            set_motor(data, joint.kind, motor);
+        });
+    }
+}
+
+pub(crate) fn reset_motor(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, (&Joint, &super::JointHandle)>,
+    removed: RemovedComponents<'_, JointMotor>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| query.get(entity).ok())
+        .for_each(|(joint, handle)| {
+            update_rapier_joint(worlds.get_mut(handle.1), handle.0, |data| {
+                set_motor(data, joint.kind, &JointMotor::default());
+            });
+        });
+}
+
+pub(crate) fn update_limits(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, (&Joint, &JointLimits, &super::JointHandle), Changed<JointLimits>>,
+) {
+    for (joint, limits, handle) in query.iter() {
+        update_rapier_joint(worlds.get_mut(handle.1), handle.0, |data| {
+            set_limits(data, joint.kind, limits);
+        });
+    }
+}
+
+/// Rebuilds the joints whose limits are removed, since rapier can't unset the limits of an axis
+pub(crate) fn reset_limits(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, (&Joint, Option<&JointMotor>, &super::JointHandle)>,
+    removed: RemovedComponents<'_, JointLimits>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| query.get(entity).ok())
+        .for_each(|(joint, motor, handle)| {
+            update_rapier_joint(worlds.get_mut(handle.1), handle.0, |data| {
+                *data = build(joint);
+                if let Some(motor) = motor {
+                    set_motor(data, joint.kind, motor);
+                }
+            });
+        });
+}
+
+fn update_rapier_joint(
+    world: WorldMut<'_>,
+    handle: ImpulseJointHandle,
+    update: impl FnOnce(&mut GenericJoint),
+) {
+    if let Some(joint) = world.impulse_joints.get_mut(handle) {
+        update(&mut joint.data);
+
+        // The motor and the limits have no effect on sleeping bodies
+        for body in [joint.body1, joint.body2] {
+            if let Some(body) = world.bodies.get_mut(body) {
+                body.wake_up(true);
+            }
+        }
+    }
+}
+
+/// Drives the [`SpringJoint`]s toward their rest length, along the current direction between the
+/// anchors
+pub(crate) fn update_springs(
+    mut worlds: Worlds<'_, '_>,
+    springs: Query<'_, '_, (&SpringJoint, &super::JointHandle)>,
+) {
+    for (spring, handle) in springs.iter() {
+        let WorldMut {
+            bodies,
+            impulse_joints,
+            ..
+        } = worlds.get_mut(handle.1);
+
+        if let Some(joint) = impulse_joints.get_mut(handle.0) {
+            drive_spring(spring, joint, bodies);
+        }
+    }
+}
+
+/// Sets the motors pulling the bodies of the spring toward its rest length
+fn drive_spring(spring: &SpringJoint, joint: &mut ImpulseJoint, bodies: &RigidBodySet) {
+    let (frame1, frame2) = match frames(joint, bodies) {
+        Some(frames) => frames,
+        None => return,
+    };
+
+    // The motors drive the position of the second frame in the local space of the first one
+    let offset =
+        frame1.inverse_transform_vector(&(frame2.translation.vector - frame1.translation.vector));
+    let direction = offset.try_normalize(f32::EPSILON).unwrap_or_else(Vector::x);
+    let target = direction * spring.rest_length;
+
+    for (axis, target) in LINEAR_AXES.into_iter().zip(target.iter()) {
+        joint
+            .data
+            .set_motor_model(axis, MotorModel::ForceBased)
+            .set_motor(axis, *target, 0.0, spring.stiffness, spring.damping);
+    }
+}
+
+#[cfg(dim2)]
+const LINEAR_AXES: [JointAxis; 2] = [JointAxis::X, JointAxis::Y];
+#[cfg(dim3)]
+const LINEAR_AXES: [JointAxis; 3] = [JointAxis::X, JointAxis::Y, JointAxis::Z];
+
+fn build(joint: &Joint) -> GenericJoint {
+    let anchor1: Point<f32> = joint.local_anchor.into_rapier();
+    let anchor2: Point<f32> = joint.other_anchor.into_rapier();
+
+    match joint.kind {
+        JointKind::Fixed => FixedJointBuilder::new()
+            .local_anchor1(anchor1)
+            .local_anchor2(anchor2)
+            .into(),
+        JointKind::Revolute => {
+            #[cfg(dim2)]
+            let builder = RevoluteJointBuilder::new();
+            #[cfg(dim3)]
+            let builder = RevoluteJointBuilder::new(unit_axis(joint.axis));
+            builder.local_anchor1(anchor1).local_anchor2(anchor2).into()
+        }
+        JointKind::Prismatic => PrismaticJointBuilder::new(unit_axis(joint.axis))
+            .local_anchor1(anchor1)
+            .local_anchor2(anchor2)
+            .into(),
+    }
+}
+
+fn unit_axis(axis: Vec3) -> Unit<Vector<f32>> {
+    Unit::new_normalize(axis.into_rapier())
+}
+
+/// Returns the axis along which (or around which) the joint is free to move
+fn free_axis(kind: JointKind) -> Option<JointAxis> {
+    match kind {
+        JointKind::Fixed => None,
+        JointKind::Revolute => Some(JointAxis::AngX),
+        JointKind::Prismatic => Some(JointAxis::X),
+    }
+}
+
+fn set_limits(data: &mut GenericJoint, kind: JointKind, limits: &JointLimits) {
+    let min = limits.min.unwrap_or(f32::NEG_INFINITY);
+    let max = limits.max.unwrap_or(f32::INFINITY);
+    match kind {
+        JointKind::Fixed => {}
+        JointKind::Revolute => {
+            data.set_limits(JointAxis::AngX, angular_limits(min, max));
+        }
+        JointKind::Prismatic => {
+            data.set_limits(JointAxis::X, [min, max]);
+        }
+    }
+}
+
+/// Returns the limits of an angular axis, as expected by rapier
+///
+/// Rapier compares the sine of half the limits with the sine of half the angle in 3d, but with the
+/// sine of the whole angle in 2d. The limits are therefore clamped to the range in which the sine
+/// is monotonic, and doubled in 2d.
+pub(crate) fn angular_limits(min: f32, max: f32) -> [f32; 2] {
+    #[cfg(dim2)]
+    let convert = |limit: f32| 2.0 * limit.clamp(-consts::FRAC_PI_2, consts::FRAC_PI_2);
+    #[cfg(dim3)]
+    let convert = |limit: f32| limit.clamp(-consts::PI, consts::PI);
+    [convert(min), convert(max)]
+}
+
+fn set_motor(data: &mut GenericJoint, kind: JointKind, motor: &JointMotor) {
+    let axis = match free_axis(kind) {
+        Some(axis) => axis,
+        None => return,
+    };
+
+    // Like for the limits, rapier compares the sine of the target angle with the sine of half the
+    // angle in 3d
+    #[cfg(dim3)]
+    let target_position = if kind == JointKind::Revolute {
+        motor.target_position / 2.0
+    } else {
+        motor.target_position
+    };
+    #[cfg(dim2)]
+    let target_position = motor.target_position;
+
+    data.set_motor(
+        axis,
+        target_position,
+        motor.target_velocity,
+        motor.stiffness,
+        motor.damping,
+    )
+    .set_motor_max_force(axis, motor.max_force);
+}
+
+/// Keeps the [`JointMotor`]s from driving their joint beyond its [`JointLimits`]
+///
+/// Rapier only enforces the limits once they are exceeded, so that a motor overshoots them by up to
+/// its target velocity times the step duration. The targets of the motor are therefore clamped
+/// before each step, so that the motor stops at the limits.
+pub(crate) fn clamp_motors_to_limits(
+    mut worlds: Worlds<'_, '_>,
+    integration_parameters: Res<'_, IntegrationParameters>,
+    joints: Query<'_, '_, (&Joint, &JointMotor, &JointLimits, &super::JointHandle)>,
+) {
+    let dt = integration_parameters.dt;
+    if dt <= 0.0 {
+        return;
+    }
+
+    for (joint, motor, limits, handle) in joints.iter() {
+        let WorldMut {
+            bodies,
+            impulse_joints,
+            ..
+        } = worlds.get_mut(handle.1);
+
+        let rapier_joint = match impulse_joints.get_mut(handle.0) {
+            Some(joint) => joint,
+            None => continue,
+        };
+
+        let position = match position(rapier_joint, bodies, joint.kind) {
+            Some(position) => position,
+            None => continue,
+        };
+
+        let min = limits.min.unwrap_or(f32::NEG_INFINITY);
+        let max = limits.max.unwrap_or(f32::INFINITY);
+        let clamped = JointMotor {
+            target_position: motor.target_position.max(min).min(max),
+            target_velocity: motor
+                .target_velocity
+                .max((min - position) / dt)
+                .min((max - position) / dt),
+            ..*motor
+        };
+
+        set_motor(&mut rapier_joint.data, joint.kind, &clamped);
+    }
+}
+
+/// Returns the position of the joint along (or around) its free axis, as measured by rapier
+fn position(joint: &ImpulseJoint, bodies: &RigidBodySet, kind: JointKind) -> Option<f32> {
+    let (frame1, frame2) = frames(joint, bodies)?;
+
+    match kind {
+        JointKind::Fixed => None,
+        JointKind::Revolute => {
+            let rotation = frame1.rotation.inverse() * frame2.rotation;
+            #[cfg(dim2)]
+            let angle = rotation.angle();
+            #[cfg(dim3)]
+            let angle = 2.0 * (rotation.i * rotation.w.signum()).atan2(rotation.w.abs());
+            Some(angle)
+        }
+        JointKind::Prismatic => {
+            let axis = frame1.rotation * Vector::x();
+            Some((frame2.translation.vector - frame1.translation.vector).dot(&axis))
+        }
+    }
+}
+
+/// Returns the frames of the joint in world space, in the order used by rapier to measure the
+/// joint positions
+///
+/// Rapier measures the positions relative to the body that isn't dynamic, if any.
+fn frames(joint: &ImpulseJoint, bodies: &RigidBodySet) -> Option<(Isometry<f32>, Isometry<f32>)> {
+    let mut body1 = bodies.get(joint.body1)?;
+    let mut body2 = bodies.get(joint.body2)?;
+    let mut local_frame1 = joint.data.local_frame1;
+    let mut local_frame2 = joint.data.local_frame2;
+
+    if !body2.is_dynamic() {
+        mem::swap(&mut body1, &mut body2);
+        mem::swap(&mut local_frame1, &mut local_frame2);
+    }
+
+    Some((
+        body1.position() * local_frame1,
+        body2.position() * local_frame2,
+    ))
+}
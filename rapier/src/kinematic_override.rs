@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use fnv::FnvHashMap;
+
+use heron_core::{KinematicOverride, PhysicsTime, RigidBody, Velocity, VelocityTimeSpace};
+
+use crate::body::body_status;
+use crate::rapier::dynamics::RigidBodyType;
+use crate::rapier::math::{AngVector, Vector};
+use crate::velocity::rapier_velocity;
+use crate::worlds::Worlds;
+
+/// State of a body before its [`KinematicOverride`] was applied
+pub(crate) struct OverriddenBody {
+    restore_velocity: bool,
+    linvel: Vector<f32>,
+    angvel: AngVector<f32>,
+}
+
+/// Makes the bodies having a [`KinematicOverride`] kinematic, and restores them once the component
+/// is removed
+///
+/// The [`Velocity`] component is updated on removal, so that the stale velocity it had during the
+/// override isn't written back to the body.
+pub(crate) fn apply_kinematic_overrides(
+    mut worlds: Worlds<'_, '_>,
+    time: Res<'_, PhysicsTime>,
+    time_space: Res<'_, VelocityTimeSpace>,
+    mut overridden: Local<'_, FnvHashMap<Entity, OverriddenBody>>,
+    changed: Query<
+        '_,
+        '_,
+        (Entity, &super::RigidBodyHandle, &KinematicOverride),
+        Or<(Changed<KinematicOverride>, Added<super::RigidBodyHandle>)>,
+    >,
+    mut bodies: Query<
+        '_,
+        '_,
+        (
+            &super::RigidBodyHandle,
+            Option<&RigidBody>,
+            Option<&mut Velocity>,
+        ),
+    >,
+    removed: RemovedComponents<'_, KinematicOverride>,
+) {
+    for entity in removed.iter() {
+        let state = match overridden.remove(&entity) {
+            Some(state) => state,
+            None => continue,
+        };
+        let (handle, body_type, velocity) = match bodies.get_mut(entity) {
+            Ok(it) => it,
+            Err(_) => continue,
+        };
+        if let Some(body) = worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+            body.set_body_type(body_status(body_type.copied().unwrap_or_default()));
+            if state.restore_velocity {
+                body.set_linvel(state.linvel, true);
+                body.set_angvel(state.angvel, true);
+            } else {
+                body.wake_up(true);
+            }
+            if let Some(mut velocity) = velocity {
+                *velocity = time_space.velocity_from_simulation(&time, rapier_velocity(body));
+            }
+        }
+    }
+
+    for (entity, handle, kinematic_override) in changed.iter() {
+        let body = match worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+            Some(body) => body,
+            None => continue,
+        };
+
+        #[cfg(dim2)]
+        let angvel = body.angvel();
+        #[cfg(dim3)]
+        let angvel = *body.angvel();
+
+        overridden
+            .entry(entity)
+            .and_modify(|state| state.restore_velocity = kinematic_override.restore_velocity)
+            .or_insert(OverriddenBody {
+                restore_velocity: kinematic_override.restore_velocity,
+                linvel: *body.linvel(),
+                angvel,
+            });
+
+        if !body.is_kinematic() {
+            let position = *body.position();
+            body.set_body_type(RigidBodyType::KinematicPositionBased);
+            body.set_next_kinematic_position(position);
+        }
+    }
+}
@@ -5,6 +5,12 @@
     clippy::type_complexity,
     clippy::too_many_arguments
 )]
+// Lints introduced by newer toolchains, that the existing tests predate
+#![cfg_attr(
+    test,
+    allow(clippy::float_cmp, clippy::default_constructed_unit_structs)
+)]
+#![cfg_attr(not(any(dim2, dim3)), allow(missing_docs))]
 #![cfg(any(dim2, dim3))]
 
 //! Physics behavior for Heron, using [rapier](https://rapier.rs/)
@@ -21,7 +27,10 @@ pub extern crate rapier2d;
 #[cfg(feature = "rapier3d")]
 pub extern crate rapier3d;
 
+use std::fmt;
+
 use bevy::ecs::component::Component;
+use bevy::ecs::schedule::{ParallelSystemDescriptor, ShouldRun};
 use bevy::prelude::*;
 use bevy::transform::TransformSystem;
 #[cfg(dim2)]
@@ -29,8 +38,18 @@ pub(crate) use rapier2d as rapier;
 #[cfg(dim3)]
 pub(crate) use rapier3d as rapier;
 
-use heron_core::{CollisionEvent, PhysicsSystem};
-pub use pipeline::{PhysicsWorld, RayCastInfo, ShapeCastCollisionInfo, ShapeCastCollisionType};
+use heron_core::{
+    ColliderCreationFailed, CollisionEvent, PhysicsDimension, PhysicsStepEvent, PhysicsSystem,
+    PhysicsWorldId, PhysicsWriteBack,
+};
+pub use manual_step::step_physics;
+#[cfg(feature = "parallel")]
+pub use parallel::PhysicsThreads;
+pub use pipeline::{
+    ClosestColliderInfo, CollisionEventsCapacity, CollisionShapeInfo, MoveAndSlideHit,
+    MoveAndSlideResult, PhysicsWorld, RayCastHit, RayCastInfo, ShapeCastCollisionInfo,
+    ShapeCastCollisionType, ShapeCastInfo,
+};
 
 use crate::rapier::dynamics::{
     self, CCDSolver, ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointSet,
@@ -42,11 +61,37 @@ use crate::rapier::pipeline::{PhysicsPipeline, QueryPipeline};
 
 mod acceleration;
 mod body;
+mod body_params;
 pub mod convert;
-mod damping;
+#[cfg(feature = "collider-debug-info")]
+mod debug_info;
+mod fluid;
+mod gravity;
+mod ground;
+mod handle_map;
+mod hooks;
+mod interpolation;
+mod joints;
+mod kinematic_override;
+mod manual_step;
+mod parallel;
 mod pipeline;
+mod platformer;
+mod ragdoll;
+pub mod rollback;
 mod shape;
+mod shape_cache;
+mod surface_velocity;
+mod top_down;
+#[cfg(feature = "vehicle")]
+mod vehicle;
 mod velocity;
+mod worlds;
+
+#[cfg(dim2)]
+const DIMENSION: PhysicsDimension = PhysicsDimension::Two;
+#[cfg(dim3)]
+const DIMENSION: PhysicsDimension = PhysicsDimension::Three;
 
 /// Plugin that enables collision detection and physics behavior, powered by rapier.
 #[must_use]
@@ -57,24 +102,150 @@ pub struct RapierPlugin;
 ///
 /// It is automatically inserted and removed by heron.
 /// It is only useful for advanced, direct access to the rapier world
+///
+/// It is displayed as `RigidBody(<index>v<generation>)`, suffixed with `@<world>` when it isn't in the
+/// default physics world.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
-pub struct RigidBodyHandle(dynamics::RigidBodyHandle);
+pub struct RigidBodyHandle(dynamics::RigidBodyHandle, PhysicsWorldId);
 
 /// Component that holds a reference to the rapier collider
 ///
 /// It is automatically inserted and removed by heron.
 /// It is only useful for advanced, direct access to the rapier world
+///
+/// It is displayed as `Collider(<index>v<generation>)`, suffixed with `@<world>` when it isn't in the
+/// default physics world.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
-pub struct ColliderHandle(geometry::ColliderHandle);
+pub struct ColliderHandle(geometry::ColliderHandle, PhysicsWorldId);
+
+/// Component that holds a reference to the rapier impulse joint
+///
+/// It is automatically inserted and removed by heron.
+/// It is only useful for advanced, direct access to the rapier world
+///
+/// It is displayed as `Joint(<index>v<generation>)`, suffixed with `@<world>` when it isn't in the
+/// default physics world.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct JointHandle(dynamics::ImpulseJointHandle, PhysicsWorldId);
+
+impl RigidBodyHandle {
+    /// Returns the physics world that contains the rigid body
+    #[must_use]
+    pub fn world(&self) -> PhysicsWorldId {
+        self.1
+    }
+
+    /// Returns the index of the rigid body in its physics world
+    ///
+    /// Indices are reused after a rigid body is removed. Together with the
+    /// [`generation`](Self::generation) it uniquely identifies the rigid body in its world.
+    #[must_use]
+    pub fn index(&self) -> u32 {
+        self.0.into_raw_parts().0
+    }
+
+    /// Returns the generation of the rigid body index, incremented each time the index is reused
+    #[must_use]
+    pub fn generation(&self) -> u32 {
+        self.0.into_raw_parts().1
+    }
+}
+
+impl ColliderHandle {
+    /// Returns the physics world that contains the collider
+    #[must_use]
+    pub fn world(&self) -> PhysicsWorldId {
+        self.1
+    }
+
+    /// Returns the index of the collider in its physics world
+    ///
+    /// Indices are reused after a collider is removed. Together with the
+    /// [`generation`](Self::generation) it uniquely identifies the collider in its world.
+    #[must_use]
+    pub fn index(&self) -> u32 {
+        self.0.into_raw_parts().0
+    }
+
+    /// Returns the generation of the collider index, incremented each time the index is reused
+    #[must_use]
+    pub fn generation(&self) -> u32 {
+        self.0.into_raw_parts().1
+    }
+}
+
+impl JointHandle {
+    /// Returns the physics world that contains the joint
+    #[must_use]
+    pub fn world(&self) -> PhysicsWorldId {
+        self.1
+    }
+
+    /// Returns the index of the joint in its physics world
+    ///
+    /// Indices are reused after a joint is removed. Together with the
+    /// [`generation`](Self::generation) it uniquely identifies the joint in its world.
+    #[must_use]
+    pub fn index(&self) -> u32 {
+        self.0.into_raw_parts().0
+    }
+
+    /// Returns the generation of the joint index, incremented each time the index is reused
+    #[must_use]
+    pub fn generation(&self) -> u32 {
+        self.0.into_raw_parts().1
+    }
+}
+
+/// Formats the handle as `Kind(index v generation)`, followed by the world if it isn't the default
+fn fmt_handle(
+    f: &mut fmt::Formatter<'_>,
+    kind: &str,
+    (index, generation): (u32, u32),
+    world: PhysicsWorldId,
+) -> fmt::Result {
+    if world.is_default() {
+        write!(f, "{kind}({index}v{generation})")
+    } else {
+        write!(f, "{}({}v{}@{})", kind, index, generation, world.0)
+    }
+}
+
+impl fmt::Display for RigidBodyHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_handle(f, "RigidBody", self.0.into_raw_parts(), self.1)
+    }
+}
+
+impl fmt::Display for ColliderHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_handle(f, "Collider", self.0.into_raw_parts(), self.1)
+    }
+}
+
+impl fmt::Display for JointHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_handle(f, "Joint", self.0.into_raw_parts(), self.1)
+    }
+}
 
 impl Plugin for RapierPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(heron_core::CorePlugin)
-            .init_resource::<PhysicsPipeline>()
+            .insert_resource(physics_pipeline())
             .init_resource::<body::HandleMap>()
             .init_resource::<shape::HandleMap>()
+            .init_resource::<shape_cache::ShapeCache>()
+            .init_resource::<worlds::ExtraWorlds>()
+            .init_resource::<surface_velocity::SurfaceVelocities>()
+            .init_resource::<hooks::Hierarchies>()
+            .init_resource::<gravity::CustomGravities>()
+            .init_resource::<pipeline::EventManager>()
             .init_resource::<IntegrationParameters>()
+            .init_resource::<pipeline::StepCount>()
             .add_event::<CollisionEvent>()
+            .add_event::<ColliderCreationFailed>()
+            .add_event::<PhysicsStepEvent>()
             .insert_resource(BroadPhase::new())
             .insert_resource(NarrowPhase::new())
             .insert_resource(RigidBodySet::new())
@@ -84,78 +255,360 @@ impl Plugin for RapierPlugin {
             .insert_resource(ImpulseJointSet::new())
             .insert_resource(MultibodyJointSet::new())
             .insert_resource(CCDSolver::new())
-            .stage("heron-physics", |schedule: &mut Schedule| {
-                schedule
-                    .add_stage("heron-remove", removal_stage())
-                    .add_stage("heron-update-rapier-world", update_rapier_world_stage())
-                    .add_stage("heron-create-new-bodies", body_update_stage())
-                    .add_stage("heron-create-new-colliders", create_collider_stage())
-            })
-            .add_system_set_to_stage(CoreStage::PostUpdate, step_systems());
+            .insert_resource(DIMENSION)
+            .init_resource::<worlds::HandleChanges>()
+            .add_system_set_to_stage(
+                CoreStage::PostUpdate,
+                step_systems()
+                    .with_system(
+                        pipeline::update_step_count
+                            .label(PhysicsSystem::PreStep)
+                            .before(PhysicsSystem::Events),
+                    )
+                    .with_system(step_extra_worlds())
+                    .after(PhysicsSystem::ContactFilterUpdate)
+                    .with_run_criteria(heron_core::should_run),
+            )
+            .insert_resource(rollback::ResimulationSchedule::new());
+
+        let [removal, preparation @ ..] = preparation_systems(removal_systems());
+        // The removals aren't skipped when the physics doesn't step, because the removed
+        // components are only reported during the frame of their removal
+        app.add_system_set_to_stage(CoreStage::PostUpdate, removal);
+        for systems in preparation {
+            app.add_system_set_to_stage(
+                CoreStage::PostUpdate,
+                systems.with_run_criteria(heron_core::should_run),
+            );
+        }
+
+        let thread_pool = parallel::PhysicsThreadPool::from_world(&app.world);
+        app.insert_resource(thread_pool);
+
+        add_write_back_systems(app);
+
+        // The components inserted by these systems are only available once the commands of their
+        // stage are applied. So the missing transforms are inserted before the stage creating the
+        // rigid bodies, and the pose once the new bodies are written back.
+        app.add_system_to_stage(CoreStage::PreUpdate, body::handle_missing_transforms)
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                interpolation::insert_physics_pose
+                    .after(PhysicsSystem::TransformUpdate)
+                    .after(TransformSystem::TransformPropagate),
+            );
+
+        #[cfg(dim2)]
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            velocity::warn_unsupported_rotations.before(PhysicsSystem::WorldUpdate),
+        );
+
+        #[cfg(feature = "collider-debug-info")]
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            debug_info::update_collider_debug_info
+                .after(PhysicsSystem::Events)
+                .with_run_criteria(heron_core::should_run),
+        );
+
+        #[cfg(feature = "vehicle")]
+        app.add_system_set_to_stage(
+            CoreStage::PostUpdate,
+            vehicle::systems().with_run_criteria(heron_core::should_run),
+        );
+    }
+}
+
+/// Adds the systems writing the physics state back to the components, in the stage selected by
+/// the [`PhysicsWriteBack`] resource
+fn add_write_back_systems(app: &mut App) {
+    match app
+        .world
+        .get_resource::<PhysicsWriteBack>()
+        .copied()
+        .unwrap_or_default()
+    {
+        PhysicsWriteBack::PostUpdate => {
+            app.add_system_set_to_stage(
+                CoreStage::PostUpdate,
+                write_back_systems()
+                    .after(PhysicsSystem::Events)
+                    .before(TransformSystem::TransformPropagate)
+                    .with_run_criteria(heron_core::should_run),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                interpolation::update_transforms
+                    .after(interpolation::record_poses)
+                    .before(TransformSystem::TransformPropagate),
+            );
+        }
+        PhysicsWriteBack::PreUpdate => {
+            app.init_resource::<PendingWriteBack>()
+                .add_system_to_stage(
+                    CoreStage::PostUpdate,
+                    mark_write_back_pending
+                        .after(PhysicsSystem::Events)
+                        .with_run_criteria(heron_core::should_run),
+                )
+                .add_system_set_to_stage(
+                    CoreStage::PreUpdate,
+                    write_back_systems().with_run_criteria(take_pending_write_back),
+                )
+                .add_system_to_stage(
+                    CoreStage::PostUpdate,
+                    interpolation::update_transforms.before(TransformSystem::TransformPropagate),
+                );
+        }
     }
 }
 
-fn removal_stage() -> SystemStage {
-    SystemStage::single_threaded()
+pub(crate) fn physics_pipeline() -> PhysicsPipeline {
+    #[allow(unused_mut)]
+    let mut pipeline = PhysicsPipeline::new();
+    #[cfg(feature = "profiler")]
+    pipeline.counters.enable();
+    pipeline
+}
+
+/// Label of the transform propagation running before the physics step
+///
+/// The function of the system can't be used as a label, because the propagation of bevy runs
+/// after the physics step.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemLabel)]
+struct PropagateTransforms;
+
+/// Systems updating the physics worlds from the components, before the physics step, in the order
+/// in which they run
+///
+/// The commands of the systems (like the insertion of the handles) are only applied at the end of
+/// the stage. So the systems find the handles created earlier in the same stage in the handle maps
+/// (see [`Worlds`](worlds::Worlds)), and the objects to recreate in the
+/// [`HandleChanges`](worlds::HandleChanges).
+fn preparation_systems(removal: SystemSet) -> [SystemSet; 6] {
+    [
+        removal,
+        update_rapier_world_systems().after(PhysicsSystem::Removal),
+        body_creation_systems().after(PhysicsSystem::WorldUpdate),
+        collider_creation_systems().after(PhysicsSystem::BodyCreation),
+        joint_creation_systems().after(PhysicsSystem::ColliderCreation),
+        contact_filter_systems().after(PhysicsSystem::JointCreation),
+    ]
+}
+
+/// Systems removing the physics objects whose components were removed or changed
+///
+/// The objects whose components changed are re-created by the creation systems.
+fn removal_systems() -> SystemSet {
+    removed_component_systems()
+        .with_system(
+            body::remove_invalids_after_component_changed
+                .after(ragdoll::update_state)
+                .before(joints::remove_dangling_handles),
+        )
+        .with_system(shape::remove_invalids_after_component_changed)
+        .with_system(joints::remove_invalids_after_component_changed)
+}
+
+/// Systems removing the physics objects whose components were removed
+fn removed_component_systems() -> SystemSet {
+    SystemSet::new()
+        .label(PhysicsSystem::Removal)
         .with_system(body::remove_invalids_after_components_removed)
         .with_system(shape::remove_invalids_after_components_removed)
-        .with_system(body::remove_invalids_after_component_changed)
-        .with_system(shape::remove_invalids_after_component_changed)
+        .with_system(joints::remove_invalids_after_components_removed)
+        .with_system(
+            joints::remove_dangling_handles.after(body::remove_invalids_after_components_removed),
+        )
+        .with_system(ragdoll::remove_joints)
+        .with_system(ragdoll::update_state.before(body::remove_invalids_after_components_removed))
+        .with_system(shape_cache::remove_unused_shapes)
 }
 
-fn update_rapier_world_stage() -> SystemStage {
-    SystemStage::parallel()
-        .with_run_criteria(heron_core::should_run)
-        .with_system(bevy::transform::transform_propagate_system)
+fn update_rapier_world_systems() -> SystemSet {
+    SystemSet::new()
+        .label(PhysicsSystem::WorldUpdate)
+        .with_system(bevy::transform::transform_propagate_system.label(PropagateTransforms))
+        .with_system(body::update_rapier_position.after(PropagateTransforms))
+        .with_system(body::update_rapier_position_from_pose)
+        .with_system(
+            body::teleport
+                .after(body::update_rapier_position)
+                .after(body::update_rapier_position_from_pose),
+        )
+        .with_system(kinematic_override::apply_kinematic_overrides)
         .with_system(
-            body::update_rapier_position.after(bevy::transform::transform_propagate_system),
+            velocity::update_rapier_velocity.after(kinematic_override::apply_kinematic_overrides),
         )
-        .with_system(velocity::update_rapier_velocity)
         .with_system(acceleration::update_rapier_force_and_torque)
-        .with_system(damping::update_rapier_damping)
-        .with_system(damping::reset_rapier_damping)
+        .with_system(body_params::update_body_params)
         .with_system(shape::update_position)
+        .with_system(shape::reset_position)
+        .with_system(shape::update_offset)
+        .with_system(shape::reset_offset)
         .with_system(shape::update_collision_groups)
-        .with_system(shape::update_sensor_flag)
-        .with_system(shape::remove_sensor_flag)
+        .with_system(shape::update_active_collision_types)
+        .with_system(shape::reset_active_collision_types)
+        .with_system(shape::update_body_sensor_flag)
+        .with_system(shape::remove_body_sensor_flag)
+        .with_system(surface_velocity::update_surface_velocities)
+        .with_system(joints::update_motor)
+        .with_system(joints::reset_motor)
+        .with_system(joints::update_limits)
+        .with_system(joints::reset_limits)
         .with_system(shape::reset_collision_groups)
 }
 
-fn body_update_stage() -> SystemStage {
-    SystemStage::single_threaded()
-        .with_run_criteria(heron_core::should_run)
+fn body_creation_systems() -> SystemSet {
+    SystemSet::new()
+        .label(PhysicsSystem::BodyCreation)
         .with_system(body::create)
 }
 
-fn create_collider_stage() -> SystemStage {
-    SystemStage::single_threaded()
-        .with_run_criteria(heron_core::should_run)
+fn collider_creation_systems() -> SystemSet {
+    SystemSet::new()
+        .label(PhysicsSystem::ColliderCreation)
         .with_system(shape::create)
 }
 
+fn joint_creation_systems() -> SystemSet {
+    SystemSet::new()
+        .label(PhysicsSystem::JointCreation)
+        .with_system(joints::create)
+        .with_system(joints::create_springs)
+        .with_system(joints::create_parent_attachments)
+        .with_system(ragdoll::create_joints)
+}
+
+/// Systems activating the contact filtering hook, once the colliders and joints are created
+fn contact_filter_systems() -> SystemSet {
+    SystemSet::new()
+        .label(PhysicsSystem::ContactFilterUpdate)
+        .with_system(hooks::update_hierarchies)
+        .with_system(hooks::update_jointed_bodies.after(hooks::update_hierarchies))
+        .with_system(hooks::update_contact_filters.after(hooks::update_jointed_bodies))
+        .with_system(worlds::clear_handle_changes.after(hooks::update_contact_filters))
+}
+
 fn step_systems() -> SystemSet {
     SystemSet::new()
-        .with_run_criteria(heron_core::should_run)
-        .with_system(pipeline::update_integration_parameters.before(PhysicsSystem::Events))
-        .with_system(pipeline::step.label(PhysicsSystem::Events))
         .with_system(
-            body::update_bevy_transform
-                .label(PhysicsSystem::TransformUpdate)
+            pipeline::update_integration_parameters
+                .label(PhysicsSystem::PreStep)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            fluid::apply_fluid_forces
+                .label(PhysicsSystem::PreStep)
+                .after(pipeline::update_integration_parameters)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            gravity::apply_custom_gravity
+                .label(PhysicsSystem::PreStep)
+                .after(pipeline::update_integration_parameters)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            top_down::apply_top_down_controllers
+                .label(PhysicsSystem::PreStep)
+                .after(pipeline::update_integration_parameters)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            platformer::apply_platformer_controllers
+                .label(PhysicsSystem::PreStep)
+                .after(pipeline::update_integration_parameters)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            joints::clamp_motors_to_limits
+                .label(PhysicsSystem::PreStep)
+                .after(pipeline::update_integration_parameters)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            joints::update_springs
+                .label(PhysicsSystem::PreStep)
+                .after(pipeline::update_integration_parameters)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            pipeline::step
+                .label(PhysicsSystem::Step)
+                .label(PhysicsSystem::Events),
+        )
+        .with_system(
+            velocity::apply_velocity_constraints
+                .label(PhysicsSystem::PostStep)
                 .after(PhysicsSystem::Events)
-                .before(TransformSystem::TransformPropagate),
+                .before(PhysicsSystem::VelocityUpdate),
         )
         .with_system(
-            velocity::update_velocity_component
-                .label(PhysicsSystem::VelocityUpdate)
+            shape::update_aabb_component
+                .label(PhysicsSystem::PostStep)
                 .after(PhysicsSystem::Events),
         )
         .with_system(
-            velocity::update_rapier_velocity
-                .after(PhysicsSystem::Events)
-                .after(PhysicsSystem::VelocityUpdate),
+            shape::update_local_pose_component
+                .label(PhysicsSystem::PostStep)
+                .after(PhysicsSystem::Events),
+        )
+        .with_system(
+            ground::update_ground_detection
+                .label(PhysicsSystem::PostStep)
+                .after(PhysicsSystem::Events),
+        )
+        .with_system(
+            body::update_body_info_component
+                .label(PhysicsSystem::PostStep)
+                .after(PhysicsSystem::Events),
+        )
+        .with_system(
+            pipeline::update_collisions_normals
+                .label(PhysicsSystem::PostStep)
+                .after(PhysicsSystem::Events),
         )
 }
 
+/// System stepping the extra physics worlds, after the default one
+fn step_extra_worlds() -> ParallelSystemDescriptor {
+    pipeline::step_extra_worlds
+        .label(PhysicsSystem::Step)
+        .label(PhysicsSystem::Events)
+        .after(pipeline::step)
+}
+
+/// Systems writing the results of the physics step back to the `Transform` and `Velocity`
+/// components
+fn write_back_systems() -> SystemSet {
+    SystemSet::new()
+        .with_system(body::update_bevy_transform.label(PhysicsSystem::TransformUpdate))
+        .with_system(body::update_physics_pose.label(PhysicsSystem::TransformUpdate))
+        .with_system(interpolation::record_poses.after(PhysicsSystem::TransformUpdate))
+        .with_system(velocity::update_velocity_component.label(PhysicsSystem::VelocityUpdate))
+        .with_system(velocity::update_rapier_velocity.after(PhysicsSystem::VelocityUpdate))
+        .with_system(velocity::update_movement_component.after(PhysicsSystem::VelocityUpdate))
+}
+
+/// Resource telling whether a physics step happened since the last write-back
+#[derive(Default)]
+struct PendingWriteBack(bool);
+
+fn mark_write_back_pending(mut pending: ResMut<'_, PendingWriteBack>) {
+    pending.0 = true;
+}
+
+/// Run criteria of the write-back systems when they run in the next frame
+fn take_pending_write_back(mut pending: ResMut<'_, PendingWriteBack>) -> ShouldRun {
+    if std::mem::take(&mut pending.0) {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -209,6 +662,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn post_update_systems_see_the_new_bodies_after_their_creation() {
+        #[derive(Default)]
+        struct Seen(usize);
+
+        fn count_bodies(bodies: Res<'_, RigidBodySet>, mut seen: ResMut<'_, Seen>) {
+            seen.0 = bodies.len();
+        }
+
+        let mut app = App::new();
+        app.add_plugin(CorePlugin)
+            .add_plugin(TimePlugin)
+            .add_plugin(RapierPlugin::default())
+            .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+            .init_resource::<Seen>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                count_bodies.after(PhysicsSystem::BodyCreation),
+            );
+        app.world.spawn().insert_bundle((
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Transform::default(),
+            GlobalTransform::default(),
+        ));
+
+        app.update();
+
+        assert_eq!(app.world.resource::<Seen>().0, 1);
+    }
+
     #[test]
     fn does_not_update_rapier_when_paused() {
         let mut app = App::new();
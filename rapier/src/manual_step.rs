@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use bevy::ecs::world::{Mut, World};
+
+use heron_core::{PhysicsStepDuration, PhysicsSteps};
+
+use crate::rollback::ResimulationSchedule;
+
+/// Advance the physics simulation by the given duration, immediately
+///
+/// The duration is split into as few physics steps as possible, each of them not longer than the
+/// [`PhysicsSteps::duration`]. All the heron systems run for each step, so that the changes made to
+/// the components are synchronized with the physics world before stepping, and the `Transform`,
+/// [`Velocity`](heron_core::Velocity), etc. are updated afterward. Only the rigid bodies, colliders
+/// and joints whose components changed aren't re-created until the next regular physics step. The
+/// duration is scaled by the [`PhysicsTime`](heron_core::PhysicsTime), like the regular physics
+/// steps.
+///
+/// This runs outside of the regular schedule and regardless of whether the current frame is a
+/// physics step frame, which is useful for turn-based games ("one minute passes") and for test
+/// harnesses. The [`CollisionEvent`](heron_core::CollisionEvent)s fired during these steps can be
+/// read as usual.
+///
+/// It can be called from an exclusive system, or directly on the `World` of the `App`.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use heron_rapier::step_physics;
+/// fn end_turn(world: &mut World) {
+///     step_physics(world, Duration::from_secs(5));
+/// }
+/// ```
+pub fn step_physics(world: &mut World, duration: Duration) {
+    let max_step = match world.resource::<PhysicsSteps>().duration() {
+        PhysicsStepDuration::Exact(duration) | PhysicsStepDuration::MaxDeltaTime(duration) => {
+            duration
+        }
+    };
+    let count = step_count(duration, max_step);
+    if count == 0 {
+        return;
+    }
+
+    // Temporarily step every frame by the sub-step duration, so that the integration parameters
+    // are updated accordingly. Restoring the original steps marks them as changed, which updates
+    // the integration parameters again at the next regular step.
+    let original = std::mem::replace(
+        &mut *world.resource_mut::<PhysicsSteps>(),
+        PhysicsSteps::every_frame(duration / count),
+    );
+
+    world.resource_scope(|world, mut schedule: Mut<'_, ResimulationSchedule>| {
+        for _ in 0..count {
+            schedule.run_step(world, true);
+        }
+    });
+
+    *world.resource_mut::<PhysicsSteps>() = original;
+}
+
+/// Number of steps not longer than `max_step` needed to integrate the `duration`
+fn step_count(duration: Duration, max_step: Duration) -> u32 {
+    let max_step = max_step.as_nanos().max(1);
+    u32::try_from((duration.as_nanos() + max_step - 1) / max_step).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_cover_the_whole_duration() {
+        let max_step = Duration::from_millis(10);
+        assert_eq!(step_count(Duration::ZERO, max_step), 0);
+        assert_eq!(step_count(Duration::from_millis(5), max_step), 1);
+        assert_eq!(step_count(Duration::from_millis(10), max_step), 1);
+        assert_eq!(step_count(Duration::from_millis(11), max_step), 2);
+        assert_eq!(step_count(Duration::from_secs(1), max_step), 100);
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+/// Resource that defines how many threads are used to step the physics simulation
+///
+/// It is only available with the `parallel` feature, which enables the parallel solver and
+/// narrow-phase of rapier.
+///
+/// It is read when the physics plugin is added, and must therefore be inserted before. A value of
+/// `0` (default) lets rayon choose, which is usually the number of logical CPUs.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_rapier::PhysicsThreads;
+/// App::new()
+///     // Step the physics with 4 threads.
+///     // It must be inserted before adding the physics plugin.
+///     .insert_resource(PhysicsThreads(4))
+///     // ...
+///     .run();
+/// ```
+#[cfg(feature = "parallel")]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct PhysicsThreads(pub usize);
+
+/// Thread pool in which the physics steps are performed
+///
+/// Without the `parallel` feature, the steps are simply performed in the calling thread.
+#[cfg(feature = "parallel")]
+pub(crate) struct PhysicsThreadPool(rayon::ThreadPool);
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) struct PhysicsThreadPool;
+
+impl PhysicsThreadPool {
+    pub(crate) fn from_world(world: &World) -> Self {
+        #[cfg(feature = "parallel")]
+        {
+            let threads = world
+                .get_resource::<PhysicsThreads>()
+                .copied()
+                .unwrap_or_default();
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads.0)
+                .thread_name(|index| format!("heron-physics-{index}"))
+                .build()
+                .expect("Failed to create the physics thread pool");
+            Self(pool)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            let _ = world;
+            Self
+        }
+    }
+
+    /// Runs the physics step `op` in the thread pool
+    #[cfg_attr(not(feature = "parallel"), allow(clippy::unused_self))]
+    pub(crate) fn install(&self, op: impl FnOnce() + Send) {
+        #[cfg(feature = "parallel")]
+        self.0.install(op);
+
+        #[cfg(not(feature = "parallel"))]
+        op();
+    }
+}
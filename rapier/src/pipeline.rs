@@ -1,4 +1,8 @@
+use std::cmp::Ordering;
 use std::marker::PhantomData;
+use std::ops::ControlFlow;
+use std::sync::Mutex;
+use std::time::Duration;
 
 use bevy::ecs::event::Events;
 use bevy::ecs::prelude::*;
@@ -6,11 +10,13 @@ use bevy::ecs::system::SystemParam;
 use bevy::log::prelude::*;
 use bevy::math::Quat;
 use bevy::math::Vec3;
-use crossbeam::channel::{Receiver, Sender};
+use bevy::utils::Instant;
+use fnv::FnvHashMap;
 
 use heron_core::{
-    CollisionData, CollisionEvent, CollisionLayers, CollisionShape, Gravity, PhysicsStepDuration,
-    PhysicsSteps, PhysicsTime,
+    ColliderAabb, CollisionData, CollisionEvent, CollisionLayers, CollisionShape, Collisions,
+    DisabledContacts, Gravity, NetworkId, PhysicsDiagnostics, PhysicsStepDuration,
+    PhysicsStepEvent, PhysicsSteps, PhysicsTime, PhysicsWorldSettings, StableId,
 };
 pub use physics_world::PhysicsWorld;
 
@@ -18,15 +24,20 @@ use crate::convert::{IntoBevy, IntoRapier};
 use crate::rapier::{
     self,
     prelude::{
-        BroadPhase, CCDSolver, ColliderHandle, ColliderSet, ContactPair, ImpulseJointSet,
-        IntegrationParameters, InteractionGroups, IslandManager, MultibodyJointSet, NarrowPhase,
-        RigidBodySet,
+        BroadPhase, CCDSolver, Collider, ColliderHandle, ColliderSet, ContactManifold, ContactPair,
+        ImpulseJointSet, IntegrationParameters, InteractionGroups, IslandManager,
+        MultibodyJointSet, NarrowPhase, RigidBodyHandle, RigidBodySet,
     },
 };
 
+use crate::hooks::{Hierarchies, Hooks};
+use crate::parallel::PhysicsThreadPool;
 use crate::rapier::parry::query::{Ray, TOIStatus};
 use crate::rapier::pipeline::{EventHandler, PhysicsPipeline, QueryPipeline};
 use crate::shape::ColliderFactory;
+use crate::surface_velocity::SurfaceVelocities;
+use crate::worlds::{ExtraWorlds, Worlds};
+use crate::{body, shape};
 
 // We have to make a module here so that we can allow missing docs on the structs generated by the
 // derive macro
@@ -41,15 +52,240 @@ mod physics_world {
     ///
     /// See the [`ray_casting`](https://github.com/jcornaz/heron/blob/main/examples/ray_casting.rs)
     /// example for a detailed usage example.
+    ///
+    /// Only the [default](heron_core::PhysicsWorldId::DEFAULT) physics world is considered.
     #[derive(SystemParam)]
     pub struct PhysicsWorld<'w, 's> {
         query_pipeline: ResMut<'w, QueryPipeline>,
         colliders: ResMut<'w, ColliderSet>,
+        bodies: Res<'w, RigidBodySet>,
+        narrow_phase: Res<'w, NarrowPhase>,
+        body_handles: Res<'w, body::HandleMap>,
+        collider_handles: Res<'w, shape::HandleMap>,
         #[system_param(ignore)]
         marker: PhantomData<&'s usize>,
     }
 
-    impl<'w, 's> PhysicsWorld<'w, 's> {
+    impl PhysicsWorld<'_, '_> {
+        /// Returns `true` if the entity is currently touching a collision shape that belongs to at
+        /// least one of the groups of the given `layers`
+        ///
+        /// The `entity` may either be a collision shape entity or a rigid body entity, in which case
+        /// all the collision shapes of the rigid body are considered.
+        ///
+        /// Both contacts and sensor intersections are taken into account.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use bevy::prelude::*;
+        /// # use heron_core::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// # struct Lava;
+        /// # impl PhysicsLayer for Lava {
+        /// #     fn to_bits(&self) -> u32 { 1 }
+        /// #     fn all_bits() -> u32 { 1 }
+        /// # }
+        /// # #[derive(Component)]
+        /// # struct Player;
+        /// fn burn_players(
+        ///     physics_world: PhysicsWorld<'_, '_>,
+        ///     players: Query<'_, '_, Entity, With<Player>>,
+        /// ) {
+        ///     let lava = CollisionLayers::none().with_group(Lava);
+        ///     for player in players.iter() {
+        ///         if physics_world.touches_layer(player, lava) {
+        ///             println!("Ouch!");
+        ///         }
+        ///     }
+        /// }
+        /// ```
+        #[must_use]
+        pub fn touches_layer(&self, entity: Entity, layers: CollisionLayers) -> bool {
+            self.collider_handles_of(entity).iter().any(|handle| {
+                self.touching_colliders(*handle).any(|other| {
+                    self.colliders.get(other).map_or(false, |collider| {
+                        collider.collision_groups().memberships & layers.groups_bits() != 0
+                    })
+                })
+            })
+        }
+
+        /// Returns `true` if the two entities are currently intersecting
+        ///
+        /// Each entity may either be a collision shape entity or a rigid body entity, in which case
+        /// all the collision shapes of the rigid body are considered.
+        ///
+        /// Only the intersections involving a [`Sensor`](heron_core::Sensor) are reported, the
+        /// contacts between solid shapes are not. The result reflects the state at the end of the
+        /// last physics step, which makes it possible to poll the sensor overlaps instead of keeping
+        /// track of the collision events.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use bevy::prelude::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// # #[derive(Component)]
+        /// # struct Player;
+        /// # #[derive(Component)]
+        /// # struct Checkpoint;
+        /// fn detect_checkpoints(
+        ///     physics_world: PhysicsWorld<'_, '_>,
+        ///     players: Query<'_, '_, Entity, With<Player>>,
+        ///     checkpoints: Query<'_, '_, Entity, With<Checkpoint>>,
+        /// ) {
+        ///     for player in players.iter() {
+        ///         for checkpoint in checkpoints.iter() {
+        ///             if physics_world.is_intersecting(player, checkpoint) {
+        ///                 println!("Checkpoint reached");
+        ///             }
+        ///         }
+        ///     }
+        /// }
+        /// ```
+        #[must_use]
+        pub fn is_intersecting(&self, entity_a: Entity, entity_b: Entity) -> bool {
+            let handles_b = self.collider_handles_of(entity_b);
+            self.collider_handles_of(entity_a).iter().any(|h1| {
+                handles_b.iter().any(|h2| {
+                    self.narrow_phase
+                        .intersection_pair(*h1, *h2)
+                        .unwrap_or(false)
+                })
+            })
+        }
+
+        /// Returns the collision shape entities currently intersecting with the given entity
+        ///
+        /// The `entity` may either be a collision shape entity or a rigid body entity, in which case
+        /// all the collision shapes of the rigid body are considered. Each intersecting collision
+        /// shape entity is returned only once.
+        ///
+        /// Like [`is_intersecting`](Self::is_intersecting), only the intersections involving a
+        /// [`Sensor`](heron_core::Sensor) are reported.
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn intersections_of(&self, entity: Entity) -> Vec<Entity> {
+            let mut entities = Vec::new();
+            for handle in self.collider_handles_of(entity) {
+                let intersecting = self
+                    .narrow_phase
+                    .intersections_with(handle)
+                    .filter(|(_, _, intersecting)| *intersecting)
+                    .filter_map(|(h1, h2, _)| {
+                        self.colliders
+                            .get(if h1 == handle { h2 } else { h1 })
+                            .map(|collider| Entity::from_bits(collider.user_data as u64))
+                    });
+                for other in intersecting {
+                    if !entities.contains(&other) {
+                        entities.push(other);
+                    }
+                }
+            }
+            entities
+        }
+
+        /// Returns the collision shape entity of the given rapier collider, if it exists
+        ///
+        /// This is useful to map the handles received in rapier callbacks back to the ECS.
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn entity_of_collider(&self, handle: ColliderHandle) -> Option<Entity> {
+            self.colliders
+                .get(handle)
+                .map(|collider| Entity::from_bits(collider.user_data as u64))
+        }
+
+        /// Returns the rigid body entity of the given rapier rigid body, if it exists
+        ///
+        /// This is useful to map the handles received in rapier callbacks back to the ECS.
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn entity_of_rigid_body(&self, handle: RigidBodyHandle) -> Option<Entity> {
+            self.bodies
+                .get(handle)
+                .map(|body| Entity::from_bits(body.user_data as u64))
+        }
+
+        /// Returns an iterator over all the collision shapes, with their world-space bounding box,
+        /// collision layers, and whether they are sensors
+        ///
+        /// This is useful to draw simplified physics footprints (e.g. on a minimap) without
+        /// depending on the debug plugin or on rapier types.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use bevy::prelude::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// fn draw_minimap(physics_world: PhysicsWorld<'_, '_>) {
+        ///     for shape in physics_world.collision_shapes().filter(|shape| !shape.is_sensor) {
+        ///         println!("Draw a rectangle from {} to {}", shape.aabb.min(), shape.aabb.max());
+        ///     }
+        /// }
+        /// ```
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn collision_shapes(&self) -> impl Iterator<Item = CollisionShapeInfo> + '_ {
+            self.colliders
+                .iter()
+                .map(|(_, collider)| CollisionShapeInfo {
+                    entity: Entity::from_bits(collider.user_data as u64),
+                    aabb: shape::collider_aabb(collider),
+                    layers: collider.collision_groups().into_bevy(),
+                    is_sensor: collider.is_sensor(),
+                })
+        }
+
+        /// Handles of the colliders of the entity, which may be a collider or a rigid body
+        ///
+        /// The colliders of a rigid body include its own collision shape, if any, and the
+        /// collision shapes of its children.
+        fn collider_handles_of(&self, entity: Entity) -> Vec<ColliderHandle> {
+            if let Some(body) = self
+                .body_handles
+                .get(entity)
+                .and_then(|handle| self.bodies.get(*handle))
+            {
+                body.colliders().to_vec()
+            } else {
+                self.collider_handles
+                    .get(entity)
+                    .copied()
+                    .into_iter()
+                    .collect()
+            }
+        }
+
+        /// Handles of the colliders currently in contact or intersecting with the given collider
+        fn touching_colliders(
+            &self,
+            handle: ColliderHandle,
+        ) -> impl Iterator<Item = ColliderHandle> + '_ {
+            let other = move |h1: ColliderHandle, h2: ColliderHandle| {
+                if h1 == handle {
+                    h2
+                } else {
+                    h1
+                }
+            };
+
+            let contacts = self
+                .narrow_phase
+                .contacts_with(handle)
+                .filter(|pair| pair.has_any_active_contact)
+                .map(move |pair| other(pair.collider1, pair.collider2));
+
+            let intersections = self
+                .narrow_phase
+                .intersections_with(handle)
+                .filter(|(_, _, intersecting)| *intersecting)
+                .map(move |(h1, h2, _)| other(h1, h2));
+
+            contacts.chain(intersections)
+        }
+
         /// Cast a ray and get the collision shape entity, point, and normal at which it collided,
         /// if any
         ///
@@ -61,7 +297,14 @@ mod physics_world {
         ///   surface of the object after traveling through the object interior.
         #[must_use]
         pub fn ray_cast(&self, start: Vec3, ray: Vec3, solid: bool) -> Option<RayCastInfo> {
-            self.ray_cast_internal(start, ray, solid, CollisionLayers::default(), None)
+            self.ray_cast_internal(
+                start,
+                ray,
+                ray.length(),
+                solid,
+                CollisionLayers::default(),
+                None,
+            )
         }
 
         /// Cast a ray with extra filters
@@ -86,7 +329,177 @@ mod physics_world {
         where
             F: Fn(Entity) -> bool,
         {
-            self.ray_cast_internal(start, ray, solid, layers, Some(&filter))
+            self.ray_cast_internal(start, ray, ray.length(), solid, layers, Some(&filter))
+        }
+
+        /// Cast a ray up to a maximum distance, only considering the given layers
+        ///
+        /// Unlike [`ray_cast`](Self::ray_cast), the length of `direction` doesn't matter.
+        ///
+        /// - `start`: The point to cast the ray from.
+        /// - `direction`: The direction in which the ray is cast.
+        /// - `max_distance`: The maximum distance traveled by the ray. It may be
+        ///   [`f32::INFINITY`].
+        /// - `layers`: The [`CollisionLayers`] to considered for collisions.
+        /// - `ignored`: An entity to ignore, typically the one casting the ray. If it is a rigid
+        ///   body, all its collision shapes are ignored.
+        ///
+        /// The ray is always solid: if it starts inside a collision shape, it hits it immediately.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use bevy::prelude::*;
+        /// # use heron_core::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// # #[derive(Component)]
+        /// # struct Player;
+        /// fn look_ahead(
+        ///     physics_world: PhysicsWorld<'_, '_>,
+        ///     players: Query<'_, '_, (Entity, &GlobalTransform), With<Player>>,
+        /// ) {
+        ///     for (entity, transform) in players.iter() {
+        ///         if let Some(hit) = physics_world.ray_cast_with_layers(
+        ///             transform.translation(),
+        ///             transform.forward(),
+        ///             10.0,
+        ///             CollisionLayers::default(),
+        ///             Some(entity),
+        ///         ) {
+        ///             println!("The player is looking at {:?}", hit.entity);
+        ///         }
+        ///     }
+        /// }
+        /// ```
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn ray_cast_with_layers(
+            &self,
+            start: Vec3,
+            direction: Vec3,
+            max_distance: f32,
+            layers: CollisionLayers,
+            ignored: Option<Entity>,
+        ) -> Option<RayCastInfo> {
+            let ignored: Vec<Entity> = ignored
+                .map(|entity| self.collider_handles_of(entity))
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|handle| self.colliders.get(handle))
+                .map(|collider| Entity::from_bits(collider.user_data as u64))
+                .collect();
+
+            if ignored.is_empty() {
+                self.ray_cast_internal(start, direction, max_distance, true, layers, None)
+            } else {
+                self.ray_cast_internal(
+                    start,
+                    direction,
+                    max_distance,
+                    true,
+                    layers,
+                    Some(&|entity| !ignored.contains(&entity)),
+                )
+            }
+        }
+
+        /// Cast a ray and visit all the collision shapes it hits, from the closest to the farthest
+        ///
+        /// The `visit` closure is called for each hit, and decides whether the ray goes through
+        /// ([`ControlFlow::Continue`]) or stops ([`ControlFlow::Break`]). This allows, for
+        /// instance, bullets piercing a number of thin walls in a single query.
+        ///
+        /// - `start`: The point to cast the ray from.
+        /// - `direction`: The direction in which the ray is cast. Its length doesn't matter.
+        /// - `max_distance`: The maximum distance traveled by the ray. It may be
+        ///   [`f32::INFINITY`].
+        /// - `layers`: The [`CollisionLayers`] to considered for collisions.
+        ///
+        /// Returns the number of visited hits.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use std::ops::ControlFlow;
+        /// # use bevy::prelude::*;
+        /// # use heron_core::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// fn shoot(physics_world: PhysicsWorld<'_, '_>) {
+        ///     let mut damage = 100.0;
+        ///     physics_world.ray_cast_while(
+        ///         Vec3::ZERO,
+        ///         Vec3::X,
+        ///         f32::INFINITY,
+        ///         CollisionLayers::default(),
+        ///         |hit| {
+        ///             println!("Deal {} damage to {:?}", damage, hit.entity);
+        ///             damage /= 2.0;
+        ///             if damage < 10.0 {
+        ///                 ControlFlow::Break(())
+        ///             } else {
+        ///                 ControlFlow::Continue(())
+        ///             }
+        ///         },
+        ///     );
+        /// }
+        /// ```
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn ray_cast_while<F>(
+            &self,
+            start: Vec3,
+            direction: Vec3,
+            max_distance: f32,
+            layers: CollisionLayers,
+            mut visit: F,
+        ) -> usize
+        where
+            F: FnMut(&RayCastHit) -> ControlFlow<()>,
+        {
+            let direction = match direction.try_normalize() {
+                Some(direction) => direction,
+                None => return 0,
+            };
+            let rapier_ray = Ray::new(start.into_rapier(), direction.into_rapier());
+
+            let mut hits = Vec::new();
+            self.query_pipeline.intersections_with_ray(
+                &self.colliders,
+                &rapier_ray,
+                max_distance,
+                true,
+                InteractionGroups {
+                    memberships: layers.groups_bits(),
+                    filter: layers.masks_bits(),
+                },
+                None,
+                |handle, intersection| {
+                    if let Some(collider) = self.colliders.get(handle) {
+                        hits.push(RayCastHit {
+                            entity: Entity::from_bits(collider.user_data as u64),
+                            rigid_body_entity: self.rigid_body_entity(collider),
+                            collision_point: start + direction * intersection.toi,
+                            normal: intersection.normal.into_bevy(),
+                            distance: intersection.toi,
+                            layers: collider.collision_groups().into_bevy(),
+                        });
+                    }
+                    true
+                },
+            );
+            hits.sort_by(|a, b| {
+                a.distance
+                    .partial_cmp(&b.distance)
+                    .unwrap_or(Ordering::Equal)
+            });
+
+            let mut visited = 0;
+            for hit in &hits {
+                visited += 1;
+                if visit(hit).is_break() {
+                    break;
+                }
+            }
+            visited
         }
 
         /// Non-public implementation of `ray_cast`
@@ -95,18 +508,19 @@ mod physics_world {
         fn ray_cast_internal(
             &self,
             start: Vec3,
-            ray: Vec3,
+            direction: Vec3,
+            max_toi: f32,
             solid: bool,
             layers: CollisionLayers,
             filter: Option<&dyn Fn(Entity) -> bool>,
         ) -> Option<RayCastInfo> {
-            let direction = ray.try_normalize()?;
+            let direction = direction.try_normalize()?;
             let rapier_ray = Ray::new(start.into_rapier(), direction.into_rapier());
 
             let result = self.query_pipeline.cast_ray_and_get_normal(
-                &*self.colliders,
+                &self.colliders,
                 &rapier_ray,
-                ray.length(),
+                max_toi,
                 solid,
                 InteractionGroups {
                     memberships: layers.groups_bits(),
@@ -126,16 +540,27 @@ mod physics_world {
                     .map(|x| x as &dyn Fn(ColliderHandle) -> bool),
             );
 
-            result.map(|(collider_handle, intersection)| {
-                Some(RayCastInfo {
-                    collision_point: start + direction * intersection.toi,
-                    entity: self
-                        .colliders
-                        .get(collider_handle)
-                        .map(|collider| Entity::from_bits(collider.user_data as u64))?,
-                    normal: intersection.normal.into_bevy(),
-                })
-            })?
+            let (collider_handle, intersection) = result?;
+            let collider = self.colliders.get(collider_handle)?;
+            Some(RayCastInfo {
+                collision_point: start + direction * intersection.toi,
+                entity: Entity::from_bits(collider.user_data as u64),
+                rigid_body_entity: self.rigid_body_entity(collider),
+                normal: intersection.normal.into_bevy(),
+                layers: collider.collision_groups().into_bevy(),
+            })
+        }
+
+        /// Returns the rigid body entity of the collider
+        ///
+        /// It is the entity of the collider itself, if the collider isn't attached to a rigid body.
+        #[allow(clippy::cast_possible_truncation)]
+        fn rigid_body_entity(&self, collider: &Collider) -> Entity {
+            let bits = collider
+                .parent()
+                .and_then(|handle| self.bodies.get(handle))
+                .map_or(collider.user_data, |body| body.user_data);
+            Entity::from_bits(bits as u64)
         }
 
         /// Cast a shape and get the collision shape entity, point, and normal at which it collided, if
@@ -146,9 +571,7 @@ mod physics_world {
         /// - `start_rotation`: The rotation of the collision shape
         /// - `ray`: A vector indicating the direction and the distance to cast the shape
         ///
-        /// # Panics
-        ///
-        /// This will panic if the start position and end position are the same.
+        /// Returns `None` if the shape is invalid or if the `ray` is zero.
         #[must_use]
         pub fn shape_cast(
             &self,
@@ -178,9 +601,8 @@ mod physics_world {
         ///   considered for collisions, allowing for fine-grained, per-entity filtering of
         ///   collisions.
         ///
-        /// # Panics
-        ///
-        /// This will panic if the `from` point and the `to` point are the same.
+        /// Like [`shape_cast`](Self::shape_cast), it returns `None` if the shape is invalid or if the
+        /// `ray` is zero.
         pub fn shape_cast_with_filter<F>(
             &self,
             shape: &CollisionShape,
@@ -215,10 +637,10 @@ mod physics_world {
             filter: Option<&dyn Fn(Entity) -> bool>,
         ) -> Option<ShapeCastInfo> {
             let direction = ray.try_normalize()?;
-            let collider = shape.collider_builder().build();
+            let collider = shape.try_collider_builder().ok()?.build();
 
             let result = self.query_pipeline.cast_shape(
-                &*self.colliders,
+                &self.colliders,
                 &(start_position, start_rotation).into_rapier(),
                 &direction.into_rapier(),
                 collider.shape(),
@@ -281,6 +703,281 @@ mod physics_world {
                 })
             })?
         }
+
+        /// Returns all the collision shape entities intersecting the given shape
+        ///
+        /// - `shape`: The [`CollisionShape`] to test for intersections
+        /// - `position`: The position of the shape
+        /// - `rotation`: The rotation of the shape
+        /// - `layers`: The [`CollisionLayers`] to considered for intersections, allowing for coarse
+        ///   filtering.
+        /// - `filter`: A closure taking an [`Entity`] and returning `true` if the entity should be
+        ///   considered, allowing for fine-grained, per-entity filtering.
+        ///
+        /// Unlike a sensor, the result is available immediately, which makes it well suited for
+        /// melee hit detection or area of effect.
+        ///
+        /// The result is empty if the shape is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use bevy::prelude::*;
+        /// # use heron_core::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// fn explode(physics_world: PhysicsWorld<'_, '_>, mut commands: Commands<'_, '_>) {
+        ///     let blast = CollisionShape::Sphere { radius: 5.0 };
+        ///     let entities = physics_world.intersections_with_shape(
+        ///         &blast,
+        ///         Vec3::ZERO,
+        ///         Quat::IDENTITY,
+        ///         CollisionLayers::default(),
+        ///         |_| true,
+        ///     );
+        ///     for entity in entities {
+        ///         commands.entity(entity).despawn();
+        ///     }
+        /// }
+        /// ```
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn intersections_with_shape<F>(
+            &self,
+            shape: &CollisionShape,
+            position: Vec3,
+            rotation: Quat,
+            layers: CollisionLayers,
+            filter: F,
+        ) -> Vec<Entity>
+        where
+            F: Fn(Entity) -> bool,
+        {
+            let collider = match shape.try_collider_builder() {
+                Ok(builder) => builder.build(),
+                Err(_) => return Vec::new(),
+            };
+            let entity_of = |handle: ColliderHandle| {
+                self.colliders
+                    .get(handle)
+                    .map(|collider| Entity::from_bits(collider.user_data as u64))
+            };
+
+            let mut entities = Vec::new();
+            self.query_pipeline.intersections_with_shape(
+                &self.colliders,
+                &(position, rotation).into_rapier(),
+                collider.shape(),
+                InteractionGroups {
+                    memberships: layers.groups_bits(),
+                    filter: layers.masks_bits(),
+                },
+                Some(&|handle| entity_of(handle).map_or(false, &filter)),
+                |handle| {
+                    entities.extend(entity_of(handle));
+                    true
+                },
+            );
+            entities
+        }
+
+        /// Returns the collision shape entity closest to the `point`, if there is one within
+        /// `max_distance`
+        ///
+        /// Only the collision shapes interacting with the given `layers` are considered. If the
+        /// point is inside a collision shape, the distance is zero and the closest point is the
+        /// `point` itself.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use bevy::prelude::*;
+        /// # use heron_core::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// # #[derive(Component)]
+        /// # struct Player;
+        /// fn show_pickup_prompt(
+        ///     physics_world: PhysicsWorld<'_, '_>,
+        ///     players: Query<'_, '_, &GlobalTransform, With<Player>>,
+        /// ) {
+        ///     for player in players.iter() {
+        ///         if let Some(closest) =
+        ///             physics_world.closest_collider(player.translation(), 2.0, CollisionLayers::default())
+        ///         {
+        ///             println!("Press E to pick up {:?}", closest.entity);
+        ///         }
+        ///     }
+        /// }
+        /// ```
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn closest_collider(
+            &self,
+            point: Vec3,
+            max_distance: f32,
+            layers: CollisionLayers,
+        ) -> Option<ClosestColliderInfo> {
+            #[cfg(dim2)]
+            let point = point.truncate().extend(0.0);
+
+            let (handle, projection) = self.query_pipeline.project_point(
+                &self.colliders,
+                &point.into_rapier(),
+                true,
+                InteractionGroups {
+                    memberships: layers.groups_bits(),
+                    filter: layers.masks_bits(),
+                },
+                None,
+            )?;
+
+            let closest_point = projection.point.into_bevy();
+            #[cfg(dim2)]
+            let closest_point = closest_point.extend(0.0);
+
+            let distance = closest_point.distance(point);
+            if distance > max_distance {
+                return None;
+            }
+
+            Some(ClosestColliderInfo {
+                entity: self
+                    .colliders
+                    .get(handle)
+                    .map(|collider| Entity::from_bits(collider.user_data as u64))?,
+                distance,
+                point: closest_point,
+            })
+        }
+
+        /// Moves the collision shapes of the entity by the `desired_translation`, sliding along the
+        /// surfaces they hit on the way
+        ///
+        /// The `entity` may either be a collision shape entity or a rigid body entity, in which case
+        /// all the collision shapes of the rigid body are moved together.
+        ///
+        /// The shapes are cast from their position after the last physics step. Each time they hit
+        /// something, the remaining translation is projected on the hit surface and the cast is
+        /// repeated, up to a few iterations.
+        ///
+        /// Nothing is actually moved. It returns the translation that can safely be applied to
+        /// the entity, as well as the list of hits, or `None` if the entity has no collision shape
+        /// in the physics world.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use bevy::prelude::*;
+        /// # use heron_core::*;
+        /// # use heron_rapier::PhysicsWorld;
+        /// # #[derive(Component)]
+        /// # struct Player;
+        /// fn move_player(
+        ///     physics_world: PhysicsWorld<'_, '_>,
+        ///     mut players: Query<'_, '_, (Entity, &mut Transform), With<Player>>,
+        /// ) {
+        ///     for (entity, mut transform) in players.iter_mut() {
+        ///         if let Some(result) = physics_world.move_and_slide(entity, Vec3::X * 0.1) {
+        ///             transform.translation += result.translation;
+        ///         }
+        ///     }
+        /// }
+        /// ```
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn move_and_slide(
+            &self,
+            entity: Entity,
+            desired_translation: Vec3,
+        ) -> Option<MoveAndSlideResult> {
+            const MAX_ITERATIONS: usize = 4;
+            const SKIN: f32 = 0.01;
+
+            let handles = self.collider_handles_of(entity);
+            if handles.is_empty() {
+                return None;
+            }
+
+            #[cfg(dim2)]
+            let desired_translation = desired_translation.truncate().extend(0.0);
+
+            let filter = |handle: ColliderHandle| {
+                !handles.contains(&handle)
+                    && self
+                        .colliders
+                        .get(handle)
+                        .map_or(false, |collider| !collider.is_sensor())
+            };
+
+            let mut result = MoveAndSlideResult {
+                translation: Vec3::ZERO,
+                hits: Vec::new(),
+            };
+            let mut remaining = desired_translation;
+
+            for _ in 0..MAX_ITERATIONS {
+                let length = remaining.length();
+                let direction = match remaining.try_normalize() {
+                    Some(direction) => direction,
+                    None => break,
+                };
+
+                let closest_hit = handles
+                    .iter()
+                    .filter_map(|handle| self.colliders.get(*handle))
+                    .filter_map(|collider| {
+                        let mut position = *collider.position();
+                        position.append_translation_mut(&result.translation.into_rapier());
+                        self.query_pipeline.cast_shape(
+                            &self.colliders,
+                            &position,
+                            &direction.into_rapier(),
+                            collider.shape(),
+                            length,
+                            collider.collision_groups(),
+                            Some(&filter),
+                        )
+                    })
+                    .min_by(|(_, toi1), (_, toi2)| {
+                        toi1.toi.partial_cmp(&toi2.toi).unwrap_or(Ordering::Equal)
+                    });
+
+                let (handle, toi) = if let Some(hit) = closest_hit {
+                    hit
+                } else {
+                    result.translation += remaining;
+                    break;
+                };
+
+                let other = match self.colliders.get(handle) {
+                    Some(collider) => collider,
+                    None => break,
+                };
+
+                let safe_distance = (toi.toi - SKIN).max(0.0);
+                result.translation += direction * safe_distance;
+
+                // The witness and normal 1 are on the hit collider, in world space
+                let point = toi.witness1.into_bevy();
+                #[cfg(dim2)]
+                let point = point.extend(0.0);
+                let normal: Vec3 = toi.normal1.into_bevy();
+
+                result.hits.push(MoveAndSlideHit {
+                    entity: Entity::from_bits(other.user_data as u64),
+                    point,
+                    normal,
+                });
+
+                if toi.status == TOIStatus::Penetrating {
+                    break;
+                }
+
+                remaining = direction * (length - safe_distance);
+                remaining -= normal * remaining.dot(normal).min(0.0);
+            }
+
+            Some(result)
+        }
     }
 }
 
@@ -291,8 +988,76 @@ pub struct RayCastInfo {
     pub collision_point: Vec3,
     /// The collision shape entity that the ray collided with
     pub entity: Entity,
+    /// The rigid body entity of the collision shape that the ray collided with
+    ///
+    /// It is different from [`entity`](Self::entity) when the collision shape is a child of the
+    /// rigid body.
+    pub rigid_body_entity: Entity,
     /// The surface normal at the point of ray collision
     pub normal: Vec3,
+    /// The collision layers of the collision shape that the ray collided with
+    pub layers: CollisionLayers,
+}
+
+/// A hit of a [`PhysicsWorld::ray_cast_while`] operation
+#[derive(Clone, Debug)]
+pub struct RayCastHit {
+    /// The collision shape entity that the ray hit
+    pub entity: Entity,
+    /// The rigid body entity of the collision shape that the ray hit
+    pub rigid_body_entity: Entity,
+    /// The point in the world that the ray hit
+    pub collision_point: Vec3,
+    /// The surface normal at the point of the hit
+    pub normal: Vec3,
+    /// The distance traveled by the ray from its start
+    pub distance: f32,
+    /// The collision layers of the collision shape that the ray hit
+    pub layers: CollisionLayers,
+}
+
+/// A collision shape returned by [`PhysicsWorld::collision_shapes`]
+#[derive(Clone, Debug)]
+pub struct CollisionShapeInfo {
+    /// The collision shape entity
+    pub entity: Entity,
+    /// The bounding box of the collision shape, in world space
+    pub aabb: ColliderAabb,
+    /// The collision layers of the collision shape
+    pub layers: CollisionLayers,
+    /// Whether the collision shape is a sensor
+    pub is_sensor: bool,
+}
+
+/// The result of a [`PhysicsWorld::closest_collider`] operation
+#[derive(Clone, Debug)]
+pub struct ClosestColliderInfo {
+    /// The closest collision shape entity
+    pub entity: Entity,
+    /// The distance between the queried point and the collision shape
+    pub distance: f32,
+    /// The point of the collision shape that is the closest to the queried point
+    pub point: Vec3,
+}
+
+/// The result of a [`PhysicsWorld::move_and_slide`] operation
+#[derive(Clone, Debug)]
+pub struct MoveAndSlideResult {
+    /// The translation that can safely be applied to the entity
+    pub translation: Vec3,
+    /// The surfaces hit on the way, in order
+    pub hits: Vec<MoveAndSlideHit>,
+}
+
+/// A surface hit during a [`PhysicsWorld::move_and_slide`] operation
+#[derive(Clone, Debug)]
+pub struct MoveAndSlideHit {
+    /// The collision shape entity that was hit
+    pub entity: Entity,
+    /// The point of contact, on the collision shape that was hit
+    pub point: Vec3,
+    /// The normal of the surface that was hit, at the point of contact
+    pub normal: Vec3,
 }
 
 /// The result of a [`PhysicsWorld::shape_cast`] operation
@@ -350,6 +1115,27 @@ pub(crate) fn update_integration_parameters(
     }
 }
 
+/// Number of physics steps performed by the step systems, each integrating the `dt` of the
+/// [`IntegrationParameters`]
+///
+/// It is only updated by the regular step systems, so that stepping the physics on demand always
+/// performs a single step.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct StepCount(pub(crate) u32);
+
+impl Default for StepCount {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+pub(crate) fn update_step_count(
+    physics_steps: Res<'_, PhysicsSteps>,
+    mut step_count: ResMut<'_, StepCount>,
+) {
+    step_count.0 = physics_steps.steps_this_frame();
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn step(
     mut pipeline: ResMut<'_, PhysicsPipeline>,
@@ -364,81 +1150,442 @@ pub(crate) fn step(
     mut impulse_joints: ResMut<'_, ImpulseJointSet>,
     mut multibody_joints: ResMut<'_, MultibodyJointSet>,
     mut ccd_solver: ResMut<'_, CCDSolver>,
-    event_manager: Local<'_, EventManager>,
-    mut events: ResMut<'_, Events<CollisionEvent>>,
+    mut event_manager: ResMut<'_, EventManager>,
+    (mut events, mut step_events, surface_velocities, disabled_contacts, hierarchies, network_ids): (
+        ResMut<'_, Events<CollisionEvent>>,
+        EventWriter<'_, '_, PhysicsStepEvent>,
+        Res<'_, SurfaceVelocities>,
+        Res<'_, DisabledContacts>,
+        Res<'_, Hierarchies>,
+        Query<'_, '_, &NetworkId>,
+    ),
+    mut diagnostics: ResMut<'_, PhysicsDiagnostics>,
+    (thread_pool, step_count): (Res<'_, PhysicsThreadPool>, Res<'_, StepCount>),
 ) {
     let gravity = Vec3::from(*gravity).into_rapier();
+    let hooks = Hooks {
+        surface_velocities: &surface_velocities,
+        disabled_contacts: &disabled_contacts,
+        hierarchies: &hierarchies,
+    };
+
+    for _ in 0..step_count.0 {
+        let start = Instant::now();
+        let step_index = diagnostics.step_count + 1;
+
+        // Step the physics simulation
+        thread_pool.install(|| {
+            pipeline.step(
+                &gravity,
+                &integration_parameters,
+                &mut islands,
+                &mut broad_phase,
+                &mut narrow_phase,
+                &mut bodies,
+                &mut colliders,
+                &mut impulse_joints,
+                &mut multibody_joints,
+                &mut ccd_solver,
+                &hooks,
+                &*event_manager,
+            );
+        });
+
+        // Update the query pipleine
+        query_pipeline.update(&islands, &bodies, &colliders);
 
-    // Step the physics simulation
-    pipeline.step(
-        &gravity,
-        &integration_parameters,
-        &mut islands,
-        &mut broad_phase,
-        &mut narrow_phase,
-        &mut bodies,
-        &mut colliders,
-        &mut impulse_joints,
-        &mut multibody_joints,
-        &mut ccd_solver,
-        &(),
-        &*event_manager,
-    );
-
-    // Update the query pipleine
-    query_pipeline.update(&islands, &bodies, &colliders);
-
-    event_manager.fire_events(&narrow_phase, &bodies, &colliders, &mut events);
+        event_manager.fire_events(
+            &narrow_phase,
+            &bodies,
+            &colliders,
+            step_index,
+            &mut events,
+            |entity| network_ids.get(entity).ok().copied(),
+        );
+        step_events.send(PhysicsStepEvent {
+            step: step_index,
+            duration: Duration::from_secs_f32(integration_parameters.dt),
+        });
+
+        update_diagnostics(
+            &mut diagnostics,
+            start.elapsed(),
+            &pipeline,
+            &integration_parameters,
+            &islands,
+            &narrow_phase,
+            &colliders,
+            &impulse_joints,
+            &multibody_joints,
+        );
+    }
 }
 
+pub(crate) fn step_extra_worlds(
+    integration_parameters: Res<'_, IntegrationParameters>,
+    surface_velocities: Res<'_, SurfaceVelocities>,
+    disabled_contacts: Res<'_, DisabledContacts>,
+    hierarchies: Res<'_, Hierarchies>,
+    settings: Res<'_, PhysicsWorldSettings>,
+    diagnostics: Res<'_, PhysicsDiagnostics>,
+    mut worlds: ResMut<'_, ExtraWorlds>,
+    mut events: ResMut<'_, Events<CollisionEvent>>,
+    (thread_pool, step_count): (Res<'_, PhysicsThreadPool>, Res<'_, StepCount>),
+    network_ids: Query<'_, '_, &NetworkId>,
+) {
+    let hooks = Hooks {
+        surface_velocities: &surface_velocities,
+        disabled_contacts: &disabled_contacts,
+        hierarchies: &hierarchies,
+    };
+    for (id, world) in &mut worlds.0 {
+        let settings = settings.get(*id);
+        if settings.time_scale <= 0.0 {
+            continue;
+        }
+
+        let gravity = settings.gravity.vector().into_rapier();
+        let integration_parameters = IntegrationParameters {
+            dt: integration_parameters.dt * settings.time_scale,
+            ..*integration_parameters
+        };
+
+        // The default world has already performed all the steps of this frame
+        let first_step = (diagnostics.step_count + 1).saturating_sub(step_count.0.into());
+        for step_index in first_step..=diagnostics.step_count {
+            thread_pool.install(|| {
+                world.pipeline.step(
+                    &gravity,
+                    &integration_parameters,
+                    &mut world.islands,
+                    &mut world.broad_phase,
+                    &mut world.narrow_phase,
+                    &mut world.bodies,
+                    &mut world.colliders,
+                    &mut world.impulse_joints,
+                    &mut world.multibody_joints,
+                    &mut world.ccd_solver,
+                    &hooks,
+                    &world.event_manager,
+                );
+            });
+
+            world
+                .query_pipeline
+                .update(&world.islands, &world.bodies, &world.colliders);
+
+            world.event_manager.fire_events(
+                &world.narrow_phase,
+                &world.bodies,
+                &world.colliders,
+                step_index,
+                &mut events,
+                |entity| network_ids.get(entity).ok().copied(),
+            );
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_diagnostics(
+    diagnostics: &mut PhysicsDiagnostics,
+    step_time: Duration,
+    pipeline: &PhysicsPipeline,
+    integration_parameters: &IntegrationParameters,
+    islands: &IslandManager,
+    narrow_phase: &NarrowPhase,
+    colliders: &ColliderSet,
+    impulse_joints: &ImpulseJointSet,
+    multibody_joints: &MultibodyJointSet,
+) {
+    diagnostics.step_count += 1;
+    diagnostics.simulation_time += Duration::from_secs_f32(integration_parameters.dt);
+    diagnostics.step_time = step_time;
+    diagnostics.broad_phase_time = millis(pipeline.counters.broad_phase_time());
+    diagnostics.narrow_phase_time = millis(pipeline.counters.narrow_phase_time());
+    diagnostics.solver_time = millis(pipeline.counters.solver_time());
+    diagnostics.active_bodies =
+        islands.active_dynamic_bodies().len() + islands.active_kinematic_bodies().len();
+    diagnostics.contact_pairs = narrow_phase
+        .contact_pairs()
+        .filter(|pair| pair.has_any_active_contact)
+        .count();
+    diagnostics.joints = impulse_joints.len() + multibody_joints.iter().count();
+
+    let is_filtered =
+        |h1: ColliderHandle, h2: ColliderHandle| match (colliders.get(h1), colliders.get(h2)) {
+            (Some(c1), Some(c2)) => !c1.collision_groups().test(c2.collision_groups()),
+            _ => false,
+        };
+    let (candidate_pairs, filtered_pairs) = narrow_phase
+        .contact_pairs()
+        .map(|pair| (pair.collider1, pair.collider2))
+        .chain(
+            narrow_phase
+                .intersection_pairs()
+                .map(|(h1, h2, _)| (h1, h2)),
+        )
+        .fold((0, 0), |(candidates, filtered), (h1, h2)| {
+            (candidates + 1, filtered + usize::from(is_filtered(h1, h2)))
+        });
+    diagnostics.candidate_pairs = candidate_pairs;
+    diagnostics.filtered_pairs = filtered_pairs;
+}
+
+/// A contact normal between two rigid body entities, with the raw parts of the collider handles of
+/// the contact pair
+type PairNormal = (Entity, Entity, ((u32, u32), (u32, u32)), Vec3);
+
+/// Refreshes the contact normals of the [`Collisions`] components, as long as the contacts persist
+///
+/// When two rigid bodies touch through many pairs of colliders, the normals of all the pairs are
+/// reported, ordered by collider handles so that they don't depend on the order of the narrow
+/// phase. The buffers are kept from one step to the next.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn update_collisions_normals(
+    mut worlds: Worlds<'_, '_>,
+    mut pair_normals: Local<'_, Vec<PairNormal>>,
+    mut normals: Local<'_, Vec<Vec3>>,
+    mut collisions: Query<'_, '_, &mut Collisions>,
+) {
+    if collisions.is_empty() {
+        return;
+    }
+
+    pair_normals.clear();
+    for world in worlds.iter_mut() {
+        let (bodies, colliders) = (&*world.bodies, &*world.colliders);
+        let body_entity = |handle: ColliderHandle| {
+            colliders
+                .get(handle)?
+                .parent()
+                .and_then(|parent| bodies.get(parent))
+                .map(|body| Entity::from_bits(body.user_data as u64))
+        };
+
+        for pair in world
+            .narrow_phase
+            .contact_pairs()
+            .filter(|pair| pair.has_any_active_contact)
+        {
+            let (entity1, entity2) =
+                match (body_entity(pair.collider1), body_entity(pair.collider2)) {
+                    (Some(entity1), Some(entity2)) => (entity1, entity2),
+                    _ => continue,
+                };
+            if !collisions.contains(entity1) && !collisions.contains(entity2) {
+                continue;
+            }
+
+            let (h1, h2) = pair_key(pair.collider1, pair.collider2);
+            let key = (h1.into_raw_parts(), h2.into_raw_parts());
+            for normal in pair.manifolds.iter().map(manifold_normal) {
+                pair_normals.push((entity1, entity2, key, normal));
+                pair_normals.push((entity2, entity1, key, normal));
+            }
+        }
+    }
+
+    // The sort is stable, which keeps the order of the manifolds of each pair
+    pair_normals.sort_by_key(|(entity, other, key, _)| (*entity, *other, *key));
+
+    let mut start = 0;
+    while start < pair_normals.len() {
+        let (entity, other, _, _) = pair_normals[start];
+        let end = start
+            + pair_normals[start..]
+                .iter()
+                .take_while(|(e, o, _, _)| *e == entity && *o == other)
+                .count();
+        normals.clear();
+        normals.extend(
+            pair_normals[start..end]
+                .iter()
+                .map(|(_, _, _, normal)| *normal),
+        );
+        start = end;
+
+        // Only write when the normals differ, to not trigger the change detection every step
+        let changed = collisions.get(entity).map_or(false, |collisions| {
+            collisions
+                .get(&other)
+                .map_or(false, |current| current.normals() != normals.as_slice())
+        });
+        if changed {
+            if let Ok(mut collisions) = collisions.get_mut(entity) {
+                collisions.update_normals(other, &normals);
+            }
+        }
+    }
+}
+
+/// Converts the milliseconds measured by rapier's counters
+fn millis(time: f64) -> Duration {
+    Duration::from_secs_f64(time.max(0.0) / 1000.0)
+}
+
+/// Resource that defines for how many collision events memory is reserved in advance
+///
+/// The collision events reported by rapier during a physics step are collected in a buffer, which
+/// is reused from one step to the next. Reserving enough capacity up front avoids growing the
+/// buffer during the first steps, which is useful for worlds with thousands of contacts per step.
+/// The buffer still grows if needed. The default capacity is `256`.
+///
+/// It is read when creating the physics worlds, and must therefore be inserted before adding the
+/// physics plugin. It is the same as calling `PhysicsPlugin::with_collision_events_capacity`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use heron_rapier::CollisionEventsCapacity;
+/// App::new()
+///     .insert_resource(CollisionEventsCapacity(10_000))
+///     // ...
+///     .run();
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CollisionEventsCapacity(pub usize);
+
+impl Default for CollisionEventsCapacity {
+    fn default() -> Self {
+        Self(256)
+    }
+}
+
+/// A collision event reported by rapier, with the linear velocities of the rigid bodies of the two
+/// colliders
+type RawCollisionEvent = (rapier::prelude::CollisionEvent, (Vec3, Vec3));
+
 pub(crate) struct EventManager {
-    recv: Receiver<rapier::prelude::CollisionEvent>,
-    send: Sender<rapier::prelude::CollisionEvent>,
+    /// Collision events reported during the current step
+    ///
+    /// Rapier may report the events from many threads (with the `parallel` feature), hence the
+    /// mutex. It is never contended outside of the physics step.
+    buffer: Mutex<Vec<RawCollisionEvent>>,
+    /// Events being fired, swapped with the `buffer` after each step so that the allocations of
+    /// both vectors are reused
+    pending: Vec<RawCollisionEvent>,
+    /// Pairs of colliders that are currently colliding, with the data of their `Started` event
+    ///
+    /// Rapier doesn't report the end of the collisions involving a removed collider, so the
+    /// `Stopped` events of such pairs are fired from this data.
+    active_pairs: FnvHashMap<(ColliderHandle, ColliderHandle), (CollisionData, CollisionData)>,
 }
 
 impl EventHandler for EventManager {
     fn handle_collision_event(
         &self,
-        _: &RigidBodySet,
-        _: &ColliderSet,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
         event: rapier::prelude::CollisionEvent,
         _: Option<&ContactPair>,
     ) {
-        if let Err(err) = self.send.send(event) {
-            error!("Failed to handle collision even ({})", err);
+        // The velocities are sampled before the solver resolves the contact
+        let velocity = |handle| {
+            colliders
+                .get(handle)
+                .and_then(Collider::parent)
+                .and_then(|parent| bodies.get(parent))
+                .map_or(Vec3::ZERO, |body| (*body.linvel()).into_bevy())
+        };
+        let velocities = match event {
+            rapier::prelude::CollisionEvent::Started(h1, h2, _)
+            | rapier::prelude::CollisionEvent::Stopped(h1, h2, _) => (velocity(h1), velocity(h2)),
+        };
+
+        match self.buffer.lock() {
+            Ok(mut buffer) => buffer.push((event, velocities)),
+            Err(err) => error!("Failed to handle collision event ({})", err),
         }
     }
 }
 
-impl Default for EventManager {
-    fn default() -> Self {
-        let (send, recv) = crossbeam::channel::unbounded();
-        Self { recv, send }
+impl FromWorld for EventManager {
+    fn from_world(world: &mut World) -> Self {
+        Self::with_capacity(
+            world
+                .get_resource::<CollisionEventsCapacity>()
+                .copied()
+                .unwrap_or_default()
+                .0,
+        )
     }
 }
 
 impl EventManager {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Mutex::new(Vec::with_capacity(capacity)),
+            pending: Vec::with_capacity(capacity),
+            active_pairs: FnvHashMap::default(),
+        }
+    }
+
     fn fire_events(
-        &self,
+        &mut self,
         narrow_phase: &NarrowPhase,
         bodies: &RigidBodySet,
         colliders: &ColliderSet,
+        step: u64,
         events: &mut Events<CollisionEvent>,
+        network_id: impl Fn(Entity) -> Option<NetworkId>,
     ) {
-        while let Ok(event) = self.recv.try_recv() {
+        let with_stable_id = |data: CollisionData| match network_id(data.collision_shape_entity()) {
+            Some(id) => data.with_stable_id(StableId::Network(id)),
+            None => data,
+        };
+
+        let mut pending = std::mem::take(&mut self.pending);
+        match self.buffer.get_mut() {
+            Ok(buffer) => std::mem::swap(buffer, &mut pending),
+            Err(err) => error!("Failed to fire collision events ({})", err),
+        }
+
+        for (event, velocities) in pending.drain(..) {
             match event {
                 rapier::prelude::CollisionEvent::Started(h1, h2, _) => {
-                    if let Some((e1, e2)) = Self::data(narrow_phase, bodies, colliders, h1, h2) {
-                        events.send(CollisionEvent::Started(e1, e2));
+                    if let Some((e1, e2)) =
+                        Self::data(narrow_phase, bodies, colliders, h1, h2, velocities)
+                            .map(|(e1, e2)| (with_stable_id(e1), with_stable_id(e2)))
+                    {
+                        self.active_pairs
+                            .insert(pair_key(h1, h2), (e1.clone(), e2.clone()));
+                        events.send(CollisionEvent::Started(
+                            e1.with_step(step),
+                            e2.with_step(step),
+                        ));
                     }
                 }
                 rapier::prelude::CollisionEvent::Stopped(h1, h2, _) => {
-                    if let Some((e1, e2)) = Self::data(narrow_phase, bodies, colliders, h1, h2) {
-                        events.send(CollisionEvent::Stopped(e1, e2));
+                    let started = self.active_pairs.remove(&pair_key(h1, h2));
+                    if let Some((e1, e2)) =
+                        Self::data(narrow_phase, bodies, colliders, h1, h2, velocities)
+                            .map(|(e1, e2)| (with_stable_id(e1), with_stable_id(e2)))
+                            .or_else(|| {
+                                started.map(|(e1, e2)| (without_normals(&e1), without_normals(&e2)))
+                            })
+                    {
+                        events.send(CollisionEvent::Stopped(
+                            e1.with_step(step),
+                            e2.with_step(step),
+                        ));
                     }
                 }
             }
         }
+        self.pending = pending;
+
+        self.active_pairs.retain(|(h1, h2), (e1, e2)| {
+            if colliders.contains(*h1) && colliders.contains(*h2) {
+                return true;
+            }
+            events.send(CollisionEvent::Stopped(
+                without_normals(e1).with_step(step),
+                without_normals(e2).with_step(step),
+            ));
+            false
+        });
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -448,73 +1595,75 @@ impl EventManager {
         colliders: &ColliderSet,
         h1: ColliderHandle,
         h2: ColliderHandle,
+        (velocity1, velocity2): (Vec3, Vec3),
     ) -> Option<(CollisionData, CollisionData)> {
-        if let (Some(collider1), Some(collider2)) = (colliders.get(h1), colliders.get(h2)) {
-            if let (Some(rb1), Some(rb2)) = (
-                collider1.parent().and_then(|parent| bodies.get(parent)),
-                collider2.parent().and_then(|parent| bodies.get(parent)),
-            ) {
-                let normals1 =
-                    narrow_phase
-                        .contact_pair(h1, h2)
-                        .into_iter()
-                        .flat_map(|contact_pair| {
-                            contact_pair.manifolds.iter().map(|manifold| {
-                                #[cfg(dim2)]
-                                let z = 0.0;
-
-                                #[cfg(not(dim2))]
-                                let z = manifold.data.normal.z;
-
-                                Vec3::new(manifold.data.normal.x, manifold.data.normal.y, z)
-                            })
-                        });
-                let normals2 =
-                    narrow_phase
-                        .contact_pair(h2, h1)
-                        .into_iter()
-                        .flat_map(|contact_pair| {
-                            contact_pair.manifolds.iter().map(|manifold| {
-                                #[cfg(dim2)]
-                                let z = 0.0;
-
-                                #[cfg(not(dim2))]
-                                let z = manifold.data.normal.z;
-
-                                Vec3::new(manifold.data.normal.x, manifold.data.normal.y, z)
-                            })
-                        });
-
-                let d1 = CollisionData::new(
-                    Entity::from_bits(rb1.user_data as u64),
-                    Entity::from_bits(collider1.user_data as u64),
-                    collider1.collision_groups().into_bevy(),
-                    normals1,
-                );
-                let d2 = CollisionData::new(
-                    Entity::from_bits(rb2.user_data as u64),
-                    Entity::from_bits(collider2.user_data as u64),
-                    collider2.collision_groups().into_bevy(),
-                    normals2,
-                );
-                Some(
-                    if Entity::from_bits(rb1.user_data as u64)
-                        < Entity::from_bits(rb2.user_data as u64)
-                    {
-                        (d1, d2)
-                    } else {
-                        (d2, d1)
-                    },
-                )
-            } else {
-                None
-            }
+        let (c1, c2) = (colliders.get(h1)?, colliders.get(h2)?);
+        let rb1 = c1.parent().and_then(|parent| bodies.get(parent))?;
+        let rb2 = c2.parent().and_then(|parent| bodies.get(parent))?;
+
+        // The entities are stored in the user data, so converting them back is only a cast
+        let rb_entity1 = Entity::from_bits(rb1.user_data as u64);
+        let rb_entity2 = Entity::from_bits(rb2.user_data as u64);
+
+        let d1 = CollisionData::new(
+            rb_entity1,
+            Entity::from_bits(c1.user_data as u64),
+            c1.collision_groups().into_bevy(),
+            contact_normals(narrow_phase, h1, h2),
+        )
+        .with_velocity(velocity1);
+        let d2 = CollisionData::new(
+            rb_entity2,
+            Entity::from_bits(c2.user_data as u64),
+            c2.collision_groups().into_bevy(),
+            contact_normals(narrow_phase, h2, h1),
+        )
+        .with_velocity(velocity2);
+
+        Some(if rb_entity1 < rb_entity2 {
+            (d1, d2)
         } else {
-            None
-        }
+            (d2, d1)
+        })
     }
 }
 
+/// Returns the contact normals of the manifolds between the two colliders
+fn contact_normals(
+    narrow_phase: &NarrowPhase,
+    h1: ColliderHandle,
+    h2: ColliderHandle,
+) -> impl Iterator<Item = Vec3> + '_ {
+    narrow_phase
+        .contact_pair(h1, h2)
+        .into_iter()
+        .flat_map(|contact_pair| contact_pair.manifolds.iter().map(manifold_normal))
+}
+
+fn manifold_normal(manifold: &ContactManifold) -> Vec3 {
+    #[cfg(dim2)]
+    let z = 0.0;
+
+    #[cfg(not(dim2))]
+    let z = manifold.data.normal.z;
+
+    Vec3::new(manifold.data.normal.x, manifold.data.normal.y, z)
+}
+
+/// Returns a key identifying the pair of colliders, regardless of their order
+fn pair_key(h1: ColliderHandle, h2: ColliderHandle) -> (ColliderHandle, ColliderHandle) {
+    if h1.into_raw_parts() <= h2.into_raw_parts() {
+        (h1, h2)
+    } else {
+        (h2, h1)
+    }
+}
+
+/// Collision data of a `Stopped` event, that has no contact normals nor velocity
+fn without_normals(data: &CollisionData) -> CollisionData {
+    data.clone().with_normals([]).with_velocity(Vec3::ZERO)
+}
+
 #[cfg(test)]
 mod tests {
     use bevy::prelude::*;
@@ -600,26 +1749,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fire_events_reuses_the_buffers() {
+        let mut manager = EventManager::with_capacity(16);
+        let context = TestContext::default();
+
+        for _ in 0..3 {
+            manager.buffer.get_mut().unwrap().push((
+                rapier::prelude::CollisionEvent::Stopped(
+                    context.handle1,
+                    context.handle2,
+                    CollisionEventFlags::all(),
+                ),
+                (Vec3::ZERO, Vec3::ZERO),
+            ));
+            manager.fire_events(
+                &context.narrow_phase,
+                &context.bodies,
+                &context.colliders,
+                1,
+                &mut Events::<CollisionEvent>::default(),
+                |_| None,
+            );
+
+            let buffer = manager.buffer.get_mut().unwrap();
+            assert!(buffer.is_empty());
+            assert!(buffer.capacity() >= 16);
+            assert!(manager.pending.is_empty());
+            assert!(manager.pending.capacity() >= 16);
+        }
+    }
+
     #[test]
     fn contact_started_fires_collision_started() {
-        let manager = EventManager::default();
+        let mut manager = EventManager::with_capacity(16);
         let context = TestContext::default();
 
-        manager
-            .send
-            .send(rapier::prelude::CollisionEvent::Started(
+        manager.buffer.get_mut().unwrap().push((
+            rapier::prelude::CollisionEvent::Started(
                 context.handle1,
                 context.handle2,
                 CollisionEventFlags::all(),
-            ))
-            .unwrap();
+            ),
+            (Vec3::ZERO, Vec3::ZERO),
+        ));
 
         let mut events = Events::<CollisionEvent>::default();
         manager.fire_events(
             &context.narrow_phase,
             &context.bodies,
             &context.colliders,
+            1,
             &mut events,
+            |_| None,
         );
         let events: Vec<CollisionEvent> = events.get_reader().iter(&events).cloned().collect();
 
@@ -634,24 +1816,26 @@ mod tests {
 
     #[test]
     fn contact_stopped_fires_collision_stopped() {
-        let manager = EventManager::default();
+        let mut manager = EventManager::with_capacity(16);
         let context = TestContext::default();
 
-        manager
-            .send
-            .send(rapier::prelude::CollisionEvent::Stopped(
+        manager.buffer.get_mut().unwrap().push((
+            rapier::prelude::CollisionEvent::Stopped(
                 context.handle1,
                 context.handle2,
                 CollisionEventFlags::all(),
-            ))
-            .unwrap();
+            ),
+            (Vec3::ZERO, Vec3::ZERO),
+        ));
 
         let mut events = Events::<CollisionEvent>::default();
         manager.fire_events(
             &context.narrow_phase,
             &context.bodies,
             &context.colliders,
+            1,
             &mut events,
+            |_| None,
         );
         let events: Vec<CollisionEvent> = events.get_reader().iter(&events).cloned().collect();
 
@@ -666,24 +1850,26 @@ mod tests {
 
     #[test]
     fn contains_rigid_body_entities() {
-        let manager = EventManager::default();
+        let mut manager = EventManager::with_capacity(16);
         let context = TestContext::default();
 
-        manager
-            .send
-            .send(rapier::prelude::CollisionEvent::Started(
+        manager.buffer.get_mut().unwrap().push((
+            rapier::prelude::CollisionEvent::Started(
                 context.handle1,
                 context.handle2,
                 CollisionEventFlags::all(),
-            ))
-            .unwrap();
+            ),
+            (Vec3::ZERO, Vec3::ZERO),
+        ));
 
         let mut events = Events::<CollisionEvent>::default();
         manager.fire_events(
             &context.narrow_phase,
             &context.bodies,
             &context.colliders,
+            1,
             &mut events,
+            |_| None,
         );
         assert_eq!(
             events
@@ -698,24 +1884,26 @@ mod tests {
 
     #[test]
     fn contains_collision_layers() {
-        let manager = EventManager::default();
+        let mut manager = EventManager::with_capacity(16);
         let context = TestContext::default();
 
-        manager
-            .send
-            .send(rapier::prelude::CollisionEvent::Started(
+        manager.buffer.get_mut().unwrap().push((
+            rapier::prelude::CollisionEvent::Started(
                 context.handle1,
                 context.handle2,
                 CollisionEventFlags::all(),
-            ))
-            .unwrap();
+            ),
+            (Vec3::ZERO, Vec3::ZERO),
+        ));
 
         let mut events = Events::<CollisionEvent>::default();
         manager.fire_events(
             &context.narrow_phase,
             &context.bodies,
             &context.colliders,
+            1,
             &mut events,
+            |_| None,
         );
         assert_eq!(
             events
@@ -728,6 +1916,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contains_step_index() {
+        let mut manager = EventManager::with_capacity(16);
+        let context = TestContext::default();
+
+        manager.buffer.get_mut().unwrap().push((
+            rapier::prelude::CollisionEvent::Started(
+                context.handle1,
+                context.handle2,
+                CollisionEventFlags::all(),
+            ),
+            (Vec3::ZERO, Vec3::ZERO),
+        ));
+
+        let mut events = Events::<CollisionEvent>::default();
+        manager.fire_events(
+            &context.narrow_phase,
+            &context.bodies,
+            &context.colliders,
+            42,
+            &mut events,
+            |_| None,
+        );
+        let event = events.get_reader().iter(&events).next().unwrap().clone();
+        assert_eq!(event.step(), 42);
+        let (data1, data2) = event.data();
+        assert_eq!(data1.step(), 42);
+        assert_eq!(data2.step(), 42);
+    }
+
+    #[test]
+    fn contains_velocities_of_the_bodies() {
+        let mut manager = EventManager::with_capacity(16);
+        let context = TestContext::default();
+
+        manager.buffer.get_mut().unwrap().push((
+            rapier::prelude::CollisionEvent::Started(
+                context.handle2,
+                context.handle1,
+                CollisionEventFlags::all(),
+            ),
+            (Vec3::Y, Vec3::X * 2.0),
+        ));
+
+        let mut events = Events::<CollisionEvent>::default();
+        manager.fire_events(
+            &context.narrow_phase,
+            &context.bodies,
+            &context.colliders,
+            1,
+            &mut events,
+            |_| None,
+        );
+        let event = events.get_reader().iter(&events).next().unwrap().clone();
+        assert_eq!(event.relative_velocity(), Vec3::new(2.0, -1.0, 0.0));
+        let (data1, data2) = event.data();
+        assert_eq!(data1.rigid_body_entity(), context.rb_entity_1);
+        assert_eq!(data1.velocity(), Vec3::X * 2.0);
+        assert_eq!(data2.velocity(), Vec3::Y);
+    }
+
+    #[test]
+    fn pair_key_uses_network_ids() {
+        let mut manager = EventManager::with_capacity(16);
+        let context = TestContext::default();
+
+        manager.buffer.get_mut().unwrap().push((
+            rapier::prelude::CollisionEvent::Started(
+                context.handle1,
+                context.handle2,
+                CollisionEventFlags::all(),
+            ),
+            (Vec3::ZERO, Vec3::ZERO),
+        ));
+
+        let mut events = Events::<CollisionEvent>::default();
+        let collider_entity_1 = context.collider_entity_1;
+        manager.fire_events(
+            &context.narrow_phase,
+            &context.bodies,
+            &context.colliders,
+            1,
+            &mut events,
+            |entity| (entity == collider_entity_1).then(|| NetworkId(100)),
+        );
+        let event = events.get_reader().iter(&events).next().unwrap().clone();
+        assert_eq!(
+            event.pair_key(),
+            (
+                StableId::Network(NetworkId(100)),
+                StableId::Entity(context.collider_entity_2)
+            )
+        );
+        let (data1, data2) = event.data();
+        assert_eq!(data1.stable_id(), StableId::Network(NetworkId(100)));
+        assert_eq!(
+            data2.stable_id(),
+            StableId::Entity(context.collider_entity_2)
+        );
+    }
+
     /// Marker struct for Ray cast test collider shape
     #[derive(Component)]
     struct RayCastTestCollider;
@@ -820,6 +2109,73 @@ mod tests {
         app.update();
     }
 
+    #[test]
+    fn ray_cast_with_layers_stops_at_max_distance() {
+        fn ray_cast(mut runs: Local<'_, i32>, physics_world: PhysicsWorld<'_, '_>) {
+            if *runs == 0 {
+                *runs += 1;
+                return;
+            }
+
+            let layers = CollisionLayers::default();
+            let too_short =
+                physics_world.ray_cast_with_layers(Vec3::ZERO, Vec3::Y, 80.0, layers, None);
+            assert!(too_short.is_none());
+
+            let infinite = physics_world.ray_cast_with_layers(
+                Vec3::ZERO,
+                Vec3::Y,
+                f32::INFINITY,
+                layers,
+                None,
+            );
+            assert!(
+                infinite
+                    .expect("Ray cast did not collide when we expected it to")
+                    .collision_point
+                    .distance(Vec3::new(0., 90., 0.))
+                    < 0.1
+            );
+        }
+
+        let mut app = setup_ray_cast_test_app();
+        app.add_system(ray_cast);
+
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn ray_cast_with_layers_ignores_the_given_entity() {
+        fn ray_cast(
+            mut runs: Local<'_, i32>,
+            physics_world: PhysicsWorld<'_, '_>,
+            test_colliders: Query<'_, '_, Entity, With<RayCastTestCollider>>,
+        ) {
+            if *runs == 0 {
+                *runs += 1;
+                return;
+            }
+
+            let entity = test_colliders.single();
+            let start = Vec3::new(0., 100., 0.);
+            let layers = CollisionLayers::default();
+
+            let hit = physics_world.ray_cast_with_layers(start, Vec3::Y, 50.0, layers, None);
+            assert_eq!(hit.map(|info| info.entity), Some(entity));
+
+            let ignored =
+                physics_world.ray_cast_with_layers(start, Vec3::Y, 50.0, layers, Some(entity));
+            assert!(ignored.is_none());
+        }
+
+        let mut app = setup_ray_cast_test_app();
+        app.add_system(ray_cast);
+
+        app.update();
+        app.update();
+    }
+
     #[test]
     fn shape_cast_hit() {
         /// System to test shape casting
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use heron_core::{GroundDetection, PlatformerController};
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::{IntegrationParameters, RigidBodyType};
+
+use crate::worlds::Worlds;
+
+/// Updates the velocity of the rigid bodies having a [`PlatformerController`]
+pub(crate) fn apply_platformer_controllers(
+    mut worlds: Worlds<'_, '_>,
+    integration_parameters: Res<'_, IntegrationParameters>,
+    mut query: Query<'_, '_, (Entity, &mut PlatformerController, &GroundDetection)>,
+) {
+    for (entity, mut controller, ground) in &mut query {
+        let body = match worlds
+            .body_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).bodies.get_mut(handle.0))
+        {
+            Some(body)
+                if body.is_dynamic()
+                    || body.body_type() == RigidBodyType::KinematicVelocityBased =>
+            {
+                body
+            }
+            _ => continue,
+        };
+
+        let current: Vec3 = (*body.linvel()).into_bevy();
+        let velocity = controller
+            .next_velocity(
+                current.truncate(),
+                ground.ground_normal(),
+                integration_parameters.dt,
+            )
+            .extend(current.z);
+        if velocity != current {
+            body.set_linvel(velocity.into_rapier(), true);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+use heron_core::{AttachedToParent, Joint, Ragdoll, RigidBody, SpringJoint};
+
+use crate::rapier::dynamics::{GenericJoint, GenericJointBuilder, JointAxesMask, JointAxis};
+use crate::rapier::math::Isometry;
+use crate::worlds::{HandleChanges, WorldMut, Worlds};
+
+#[cfg(dim2)]
+const LOCKED_AXES: JointAxesMask = JointAxesMask::LOCKED_REVOLUTE_AXES;
+#[cfg(dim3)]
+const LOCKED_AXES: JointAxesMask = JointAxesMask::LOCKED_SPHERICAL_AXES;
+
+#[cfg(dim2)]
+const ANGULAR_AXES: [JointAxis; 1] = [JointAxis::AngX];
+#[cfg(dim3)]
+const ANGULAR_AXES: [JointAxis; 3] = [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ];
+
+/// Sets the type of the bones according to the state of their [`Ragdoll`]
+///
+/// Changing the type of a body recreates it, together with the joints of the ragdoll.
+pub(crate) fn update_state(
+    ragdolls: Query<'_, '_, (Entity, &Ragdoll), Changed<Ragdoll>>,
+    children: Query<'_, '_, &Children>,
+    mut bones: Query<'_, '_, &mut RigidBody>,
+) {
+    for (root, ragdoll) in ragdolls.iter() {
+        let body_type = ragdoll.state.body_type();
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            if let Ok(mut body) = bones.get_mut(entity) {
+                if *body != body_type {
+                    *body = body_type;
+                }
+            }
+            if let Ok(children) = children.get(entity) {
+                stack.extend(children.iter().copied());
+            }
+        }
+    }
+}
+
+/// Connects each bone of the [`Ragdoll`]s to its closest ancestor bone
+///
+/// The hierarchies are only visited when a ragdoll changed or a rigid body was (re)created.
+pub(crate) fn create_joints(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut changes: ResMut<'_, HandleChanges>,
+    ragdolls: Query<'_, '_, (Entity, &Ragdoll)>,
+    changed_ragdolls: Query<'_, '_, (), Changed<Ragdoll>>,
+    children: Query<'_, '_, &Children>,
+    other_joints: Query<'_, '_, (), Or<(With<Joint>, With<SpringJoint>, With<AttachedToParent>)>>,
+    handles: Query<'_, '_, (), With<super::JointHandle>>,
+) {
+    if changed_ragdolls.is_empty() && changes.created_bodies.is_empty() {
+        return;
+    }
+
+    let mut created = false;
+    for (root, ragdoll) in ragdolls.iter() {
+        let mut stack: Vec<(Entity, Option<super::RigidBodyHandle>)> = vec![(root, None)];
+        while let Some((entity, parent)) = stack.pop() {
+            let bone = worlds.body_handle(entity);
+            if let (Some(bone), Some(parent)) = (bone, parent) {
+                // The handles of the joints removed in this frame are only removed at the end of
+                // the stage
+                let is_jointed = other_joints.contains(entity)
+                    || (handles.contains(entity) && !changes.removed_joints.contains(&entity));
+                if bone.1 == parent.1 && !is_jointed {
+                    let world = worlds.get_mut(bone.1);
+                    if let Some(data) = build(&world, &bone, &parent, ragdoll.angle_limit) {
+                        let handle = world.impulse_joints.insert(bone.0, parent.0, data, true);
+                        commands
+                            .entity(entity)
+                            .insert(super::JointHandle(handle, bone.1));
+                        created = true;
+                    }
+                }
+            }
+
+            if let Ok(children) = children.get(entity) {
+                let parent = bone.or(parent);
+                stack.extend(children.iter().map(|child| (*child, parent)));
+            }
+        }
+    }
+    changes.created_joints |= created;
+}
+
+/// Removes the joints of the bones when the [`Ragdoll`] component is removed
+pub(crate) fn remove_joints(
+    mut commands: Commands<'_, '_>,
+    mut worlds: Worlds<'_, '_>,
+    mut changes: ResMut<'_, HandleChanges>,
+    removed: RemovedComponents<'_, Ragdoll>,
+    children: Query<'_, '_, &Children>,
+    handles: Query<
+        '_,
+        '_,
+        &super::JointHandle,
+        (
+            Without<Joint>,
+            Without<SpringJoint>,
+            Without<AttachedToParent>,
+        ),
+    >,
+) {
+    let mut stack: Vec<Entity> = removed.iter().collect();
+    while let Some(entity) = stack.pop() {
+        if let Ok(handle) = handles.get(entity) {
+            worlds
+                .get_mut(handle.1)
+                .impulse_joints
+                .remove(handle.0, true);
+            commands.entity(entity).remove::<super::JointHandle>();
+            changes.remove_joint(entity);
+        }
+        if let Ok(children) = children.get(entity) {
+            stack.extend(children.iter().copied());
+        }
+    }
+}
+
+/// Returns a ball joint (revolute in 2d) located at the origin of the bone, and limited around the
+/// current relative rotation of the bones
+fn build(
+    world: &WorldMut<'_>,
+    bone: &super::RigidBodyHandle,
+    parent: &super::RigidBodyHandle,
+    angle_limit: f32,
+) -> Option<GenericJoint> {
+    let position1 = *world.bodies.get(bone.0)?.position();
+    let position2 = *world.bodies.get(parent.0)?.position();
+
+    let mut builder = GenericJointBuilder::new(LOCKED_AXES)
+        .local_frame1(Isometry::identity())
+        .local_frame2(position2.inv_mul(&position1));
+    for axis in ANGULAR_AXES {
+        builder = builder.limits(
+            axis,
+            crate::joints::angular_limits(-angle_limit, angle_limit),
+        );
+    }
+
+    Some(builder.into())
+}
@@ -0,0 +1,189 @@
+//! Snapshot and re-simulation of the physics world, for rollback netcode
+//!
+//! A [`PhysicsSnapshot`] captures the state of the rapier world at a given tick. It can later be
+//! restored, after which [`resimulate`] re-runs as many physics steps as needed within a single
+//! frame, giving a chance to apply the corrected inputs before each step.
+//!
+//! Only the default [physics world](heron_core::PhysicsWorldId) is captured and re-simulated. The
+//! snapshot contains the rapier state only, so the rigid bodies and collision shapes must be the
+//! same when restoring it as when it was captured.
+//!
+//! With the `serde-serialize` feature, the snapshots can be serialized to be sent over the
+//! network.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use heron_core::*;
+//! # use heron_rapier::rollback::{self, PhysicsSnapshot};
+//! # struct Snapshots(Vec<PhysicsSnapshot>);
+//! # fn corrected_input(tick: usize) -> Velocity { Velocity::default() }
+//! # #[derive(Component)]
+//! # struct Player;
+//! fn rollback(world: &mut World) {
+//!     let tick = 3;
+//!     let snapshot = world.resource::<Snapshots>().0[tick].clone();
+//!     snapshot.restore(world);
+//!
+//!     let steps = world.resource::<Snapshots>().0.len() - tick;
+//!     rollback::resimulate(world, steps, |world, step| {
+//!         let mut players = world.query_filtered::<&mut Velocity, With<Player>>();
+//!         for mut velocity in players.iter_mut(world) {
+//!             *velocity = corrected_input(tick + step);
+//!         }
+//!     });
+//! }
+//! ```
+
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::ecs::world::{Mut, World};
+
+use heron_core::{PhysicsDiagnostics, PhysicsSystem};
+
+use crate::pipeline::StepCount;
+use crate::rapier::dynamics::{
+    CCDSolver, ImpulseJointSet, IslandManager, MultibodyJointSet, RigidBodySet,
+};
+use crate::rapier::geometry::{BroadPhase, ColliderSet, NarrowPhase};
+use crate::rapier::pipeline::QueryPipeline;
+use crate::{body, velocity};
+
+/// State of the physics world at a given moment
+///
+/// See the [module documentation](self) for more details.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PhysicsSnapshot {
+    islands: IslandManager,
+    broad_phase: BroadPhase,
+    narrow_phase: NarrowPhase,
+    bodies: RigidBodySet,
+    colliders: ColliderSet,
+    impulse_joints: ImpulseJointSet,
+    multibody_joints: MultibodyJointSet,
+    ccd_solver: CCDSolver,
+    query_pipeline: QueryPipeline,
+    step_count: u64,
+}
+
+impl PhysicsSnapshot {
+    /// Capture the current state of the physics world
+    #[must_use]
+    pub fn capture(world: &World) -> Self {
+        Self {
+            islands: world.resource::<IslandManager>().clone(),
+            broad_phase: world.resource::<BroadPhase>().clone(),
+            narrow_phase: world.resource::<NarrowPhase>().clone(),
+            bodies: world.resource::<RigidBodySet>().clone(),
+            colliders: world.resource::<ColliderSet>().clone(),
+            impulse_joints: world.resource::<ImpulseJointSet>().clone(),
+            multibody_joints: world.resource::<MultibodyJointSet>().clone(),
+            ccd_solver: world.resource::<CCDSolver>().clone(),
+            query_pipeline: world.resource::<QueryPipeline>().clone(),
+            step_count: world.resource::<PhysicsDiagnostics>().step_count,
+        }
+    }
+
+    /// Restore the physics world to the state of the snapshot
+    ///
+    /// The `Transform`, [`Velocity`](heron_core::Velocity) and
+    /// [`PhysicsPose`](heron_core::PhysicsPose) components of the rigid bodies are updated
+    /// accordingly. The [`step_count`](PhysicsDiagnostics::step_count) is restored as well, so that
+    /// the re-simulated steps have the same index as the original ones.
+    pub fn restore(&self, world: &mut World) {
+        *world.resource_mut::<IslandManager>() = self.islands.clone();
+        *world.resource_mut::<BroadPhase>() = self.broad_phase.clone();
+        *world.resource_mut::<NarrowPhase>() = self.narrow_phase.clone();
+        *world.resource_mut::<RigidBodySet>() = self.bodies.clone();
+        *world.resource_mut::<ColliderSet>() = self.colliders.clone();
+        *world.resource_mut::<ImpulseJointSet>() = self.impulse_joints.clone();
+        *world.resource_mut::<MultibodyJointSet>() = self.multibody_joints.clone();
+        *world.resource_mut::<CCDSolver>() = self.ccd_solver.clone();
+        *world.resource_mut::<QueryPipeline>() = self.query_pipeline.clone();
+        world.resource_mut::<PhysicsDiagnostics>().step_count = self.step_count;
+
+        world.resource_scope(|world, mut schedule: Mut<'_, ResimulationSchedule>| {
+            schedule.sync.run(world);
+        });
+    }
+}
+
+/// Run `steps` physics steps immediately
+///
+/// The `before_step` closure is called before each step with the index of the step (starting at
+/// `0`), so that the inputs of the corresponding tick can be applied.
+///
+/// The physics steps run regardless of the [`PhysicsSteps`](heron_core::PhysicsSteps) and
+/// [`PhysicsTime`](heron_core::PhysicsTime) resources, and with the current integration
+/// parameters. Note that the [`CollisionEvent`](heron_core::CollisionEvent)s are fired again for
+/// the re-simulated steps.
+pub fn resimulate(world: &mut World, steps: usize, mut before_step: impl FnMut(&mut World, usize)) {
+    world.resource_scope(|world, mut schedule: Mut<'_, ResimulationSchedule>| {
+        for step in 0..steps {
+            before_step(world, step);
+            schedule.run_step(world, false);
+        }
+    });
+}
+
+/// Copy of the heron physics systems, without run criteria, used to step the physics on demand
+///
+/// The state of the copied systems is kept in resources, shared with the regular systems. But the
+/// copied systems detect the changes made since they last ran, rather than since the regular
+/// systems last ran. So the copy doesn't re-create the physics objects whose components changed,
+/// which is left to the regular systems: the rigid bodies, collision shapes and joints must be the
+/// same when re-simulating as when the snapshot was captured.
+pub(crate) struct ResimulationSchedule {
+    /// Updates the physics worlds from the components
+    preparation: SystemStage,
+    /// Steps all the physics worlds
+    step: SystemStage,
+    /// Steps the default physics world only, since it is the only one captured by the snapshots
+    resimulation: SystemStage,
+    sync: SystemStage,
+}
+
+impl ResimulationSchedule {
+    pub(crate) fn new() -> Self {
+        Self {
+            preparation: crate::preparation_systems(crate::removed_component_systems())
+                .into_iter()
+                .fold(SystemStage::parallel(), SystemStage::with_system_set),
+            step: step_stage().with_system(crate::step_extra_worlds()),
+            resimulation: step_stage(),
+            sync: SystemStage::parallel()
+                .with_system(body::update_bevy_transform)
+                .with_system(body::update_physics_pose)
+                .with_system(velocity::update_velocity_component),
+        }
+    }
+
+    /// Prepares the physics worlds and runs one physics step, in all the physics worlds or in the
+    /// default one only
+    pub(crate) fn run_step(&mut self, world: &mut World, extra_worlds: bool) {
+        self.preparation.run(world);
+
+        let step_count = std::mem::replace(&mut *world.resource_mut::<StepCount>(), StepCount(1));
+        if extra_worlds {
+            self.step.run(world);
+        } else {
+            self.resimulation.run(world);
+        }
+        *world.resource_mut::<StepCount>() = step_count;
+    }
+}
+
+fn step_stage() -> SystemStage {
+    let stage = SystemStage::parallel()
+        .with_system_set(crate::step_systems())
+        .with_system_set(crate::write_back_systems().after(PhysicsSystem::Events));
+
+    #[cfg(feature = "vehicle")]
+    let stage = stage.with_system_set(crate::vehicle::systems());
+
+    stage
+}
@@ -1,143 +1,406 @@
 use bevy::prelude::*;
-use fnv::FnvHashMap;
+use fnv::FnvHashSet;
 
-use heron_core::{CollisionLayers, CollisionShape, PhysicMaterial, RigidBody, SensorShape};
+use heron_core::{
+    ActiveCollisions, ColliderAabb, ColliderCreationFailed, ColliderCreationFailure, ColliderMass,
+    ColliderOffset, CollisionLayers, CollisionShape, InvalidCollisionShape, MissingRigidBody,
+    PhysicMaterial, PhysicsDisabled, RigidBody, Sensor, SensorMass, SensorShape, ShapeLocalPose,
+    SolidShape,
+};
 
-use crate::convert::IntoRapier;
-use crate::rapier::dynamics::{IslandManager, RigidBodySet};
+use crate::convert::{IntoBevy, IntoRapier};
 use crate::rapier::geometry::{
-    ActiveCollisionTypes, Collider, ColliderBuilder, ColliderHandle, ColliderSet, InteractionGroups,
+    ActiveCollisionTypes, Collider, ColliderBuilder, ColliderHandle, InteractionGroups,
 };
 use crate::rapier::math::Point;
+use crate::rapier::parry::mass_properties::MassProperties;
 use crate::rapier::pipeline::ActiveEvents;
+use crate::shape_cache::ShapeCache;
+use crate::worlds::{HandleChanges, Worlds};
 
-pub(crate) type HandleMap = FnvHashMap<Entity, ColliderHandle>;
+pub(crate) type HandleMap = crate::handle_map::HandleMap<ColliderHandle>;
 
 pub(crate) fn create(
     mut commands: Commands<'_, '_>,
-    mut bodies: ResMut<'_, RigidBodySet>,
-    mut colliders: ResMut<'_, ColliderSet>,
-    mut handles: ResMut<'_, HandleMap>,
-    rigid_bodies: Query<'_, '_, (&RigidBody, &super::RigidBodyHandle, Option<&PhysicMaterial>)>,
+    mut worlds: Worlds<'_, '_>,
+    mut failures: EventWriter<'_, '_, ColliderCreationFailed>,
+    mut reported: Local<'_, FnvHashSet<Entity>>,
+    mut cache: ResMut<'_, ShapeCache>,
+    missing_rigid_body: Res<'_, MissingRigidBody>,
+    removed_shapes: RemovedComponents<'_, CollisionShape>,
+    mut changes: ResMut<'_, HandleChanges>,
+    rigid_bodies: Query<'_, '_, (&RigidBody, Option<&Sensor>, Option<&PhysicMaterial>)>,
+    new_shapes: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<CollisionShape>,
+            Without<super::ColliderHandle>,
+            Without<PhysicsDisabled>,
+            // Invalid shapes are only validated again when they change
+            Or<(Without<InvalidCollisionShape>, Changed<CollisionShape>)>,
+        ),
+    >,
     collision_shapes: Query<
         '_,
         '_,
         (
-            Entity,
             &CollisionShape,
             Option<&Parent>,
             Option<&Transform>,
             Option<&CollisionLayers>,
             Option<&SensorShape>,
+            Option<&SolidShape>,
+            Option<&ColliderOffset>,
+            Option<&ActiveCollisions>,
+            Option<&SensorMass>,
+            Option<&ColliderMass>,
+            Option<&InvalidCollisionShape>,
         ),
-        Without<super::ColliderHandle>,
+        Without<PhysicsDisabled>,
     >,
+    disabled: Query<'_, '_, (), With<PhysicsDisabled>>,
+    invalids: Query<'_, '_, (), With<InvalidCollisionShape>>,
+    any_bodies: Query<'_, '_, (), With<RigidBody>>,
 ) {
-    for (entity, shape, parent, transform, layers, sensor_flag) in collision_shapes.iter() {
-        let collider = if let Ok((body, rigid_body_handle, material)) = rigid_bodies.get(entity) {
-            Some((
-                shape.build(
-                    entity,
-                    sensor_flag.is_some() || matches!(body, RigidBody::Sensor),
-                    material,
-                    None,
-                    layers,
-                ),
-                rigid_body_handle,
-            ))
-        } else if let Some((body, rigid_body_handle, material)) =
-            parent.and_then(|p| rigid_bodies.get(p.get()).ok())
-        {
-            Some((
-                shape.build(
-                    entity,
-                    sensor_flag.is_some() || matches!(body, RigidBody::Sensor),
-                    material,
-                    transform,
-                    layers,
-                ),
-                rigid_body_handle,
-            ))
-        } else {
-            None
+    for entity in removed_shapes.iter() {
+        reported.remove(&entity);
+        if invalids.get(entity).is_ok() {
+            commands.entity(entity).remove::<InvalidCollisionShape>();
+        }
+    }
+
+    // The colliders removed in this frame still have their handle component, until the end of the
+    // stage
+    let HandleChanges {
+        removed_colliders,
+        created_colliders,
+        ..
+    } = &mut *changes;
+    for entity in new_shapes.iter().chain(removed_colliders.iter().copied()) {
+        if worlds.collider_handle(entity).is_some() {
+            continue;
+        }
+        let (
+            shape,
+            parent,
+            transform,
+            layers,
+            sensor_flag,
+            solid_flag,
+            offset,
+            active_collisions,
+            sensor_mass,
+            collider_mass,
+            invalid,
+        ) = match collision_shapes.get(entity) {
+            Ok(components) => components,
+            Err(_) => continue,
         };
 
-        if let Some((collider, rigid_body_handle)) = collider {
-            let handle = colliders.insert_with_parent(collider, rigid_body_handle.0, &mut bodies);
-            commands
-                .entity(entity)
-                .insert(super::ColliderHandle(handle));
-            handles.insert(entity, handle);
+        if parent.map_or(false, |parent| disabled.get(parent.get()).is_ok()) {
+            continue;
+        }
+
+        // The transform is relative to the rigid body only when the shape is on a child entity
+        let body = match rigid_body_of(entity, &worlds, &rigid_bodies) {
+            Some(body) => Some((body, local_position(None, offset))),
+            None => parent
+                .and_then(|p| rigid_body_of(p.get(), &worlds, &rigid_bodies))
+                .map(|body| (body, local_position(transform, offset))),
+        };
+
+        let collider = match body {
+            Some(((body, sensor, material, rigid_body_handle), position)) => {
+                cache.collider_builder(shape).map(|builder| {
+                    let collider = build(
+                        with_mass(builder, collider_mass, sensor_flag.is_some(), sensor_mass),
+                        entity,
+                        is_sensor(
+                            sensor_flag.is_some(),
+                            solid_flag.is_some(),
+                            is_sensor_body(*body, sensor),
+                        ),
+                        material,
+                        position,
+                        layers,
+                        active_collisions,
+                    );
+                    (collider, rigid_body_handle)
+                })
+            }
+            None => Err(ColliderCreationFailure::MissingRigidBody),
+        };
+
+        match collider {
+            Ok((collider, rigid_body_handle)) => {
+                let world = worlds.get_mut(rigid_body_handle.1);
+                let handle =
+                    world
+                        .colliders
+                        .insert_with_parent(collider, rigid_body_handle.0, world.bodies);
+                let mut entity_commands = commands.entity(entity);
+                entity_commands.insert(super::ColliderHandle(handle, rigid_body_handle.1));
+                if invalid.is_some() {
+                    entity_commands.remove::<InvalidCollisionShape>();
+                }
+                world.collider_handles.insert(entity, handle);
+                *created_colliders = true;
+                reported.remove(&entity);
+            }
+            Err(reason @ ColliderCreationFailure::InvalidShape { .. }) => {
+                error!("Cannot create the collider of {:?}: {}", entity, reason);
+                commands.entity(entity).insert(InvalidCollisionShape);
+                failures.send(ColliderCreationFailed { entity, reason });
+            }
+            Err(ColliderCreationFailure::MissingRigidBody)
+                if *missing_rigid_body == MissingRigidBody::InsertStatic
+                    // The rigid body may exist, but not be created yet in the physics world
+                    && any_bodies.get(entity).is_err()
+                    && parent.map_or(true, |parent| any_bodies.get(parent.get()).is_err()) =>
+            {
+                warn!(
+                    "The collision shape {:?} has no rigid body. Inserting `RigidBody::Static`",
+                    entity
+                );
+                commands.entity(entity).insert(RigidBody::Static);
+            }
+            Err(reason) => {
+                if reported.insert(entity) {
+                    failures.send(ColliderCreationFailed { entity, reason });
+                }
+            }
         }
     }
 }
 
+/// Returns the components of the rigid body of the entity, if it is created in the physics world
+fn rigid_body_of<'a>(
+    entity: Entity,
+    worlds: &Worlds<'_, '_>,
+    rigid_bodies: &'a Query<'_, '_, (&RigidBody, Option<&Sensor>, Option<&PhysicMaterial>)>,
+) -> Option<(
+    &'a RigidBody,
+    Option<&'a Sensor>,
+    Option<&'a PhysicMaterial>,
+    super::RigidBodyHandle,
+)> {
+    let (body, sensor, material) = rigid_bodies.get(entity).ok()?;
+    let handle = worlds.body_handle(entity)?;
+    Some((body, sensor, material, handle))
+}
+
 pub(crate) fn update_position(
-    mut colliders: ResMut<'_, ColliderSet>,
+    mut worlds: Worlds<'_, '_>,
+    query: Query<
+        '_,
+        '_,
+        (&Transform, Option<&ColliderOffset>, &super::ColliderHandle),
+        (
+            Or<(Changed<Transform>, Changed<ColliderOffset>)>,
+            Without<RigidBody>,
+        ),
+    >,
+) {
+    for (transform, offset, handle) in query.iter() {
+        if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
+            collider.set_position_wrt_parent(
+                local_position(Some(transform), offset)
+                    .unwrap_or_default()
+                    .into_rapier(),
+            );
+        }
+    }
+}
+
+pub(crate) fn update_offset(
+    mut worlds: Worlds<'_, '_>,
     query: Query<
         '_,
         '_,
-        (&Transform, &super::ColliderHandle),
-        (Changed<Transform>, Without<RigidBody>),
+        (&ColliderOffset, &super::ColliderHandle),
+        (Changed<ColliderOffset>, With<RigidBody>),
     >,
 ) {
-    for (transform, handle) in query.iter() {
-        if let Some(collider) = colliders.get_mut(handle.0) {
-            collider
-                .set_position_wrt_parent((transform.translation, transform.rotation).into_rapier());
+    for (offset, handle) in query.iter() {
+        if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
+            collider.set_position_wrt_parent((offset.translation, offset.rotation).into_rapier());
         }
     }
 }
 
+pub(crate) fn reset_offset(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<
+        '_,
+        '_,
+        (
+            Option<&Transform>,
+            Option<&RigidBody>,
+            &super::ColliderHandle,
+        ),
+    >,
+    removed: RemovedComponents<'_, ColliderOffset>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| query.get(entity).ok())
+        .for_each(|(transform, body, handle)| {
+            if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
+                let transform = if body.is_some() { None } else { transform };
+                collider.set_position_wrt_parent(
+                    local_position(transform, None)
+                        .unwrap_or_default()
+                        .into_rapier(),
+                );
+            }
+        });
+}
+
+pub(crate) fn reset_position(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, (Option<&ColliderOffset>, &super::ColliderHandle), Without<RigidBody>>,
+    removed: RemovedComponents<'_, Transform>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| query.get(entity).ok())
+        .for_each(|(offset, handle)| {
+            if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
+                collider.set_position_wrt_parent(
+                    local_position(None, offset)
+                        .unwrap_or_default()
+                        .into_rapier(),
+                );
+            }
+        });
+}
+
+/// Position of a collider relative to its rigid body, given the transform of the child entity
+/// (if the collision shape is not on the rigid body entity) and the collider offset
+fn local_position(
+    transform: Option<&Transform>,
+    offset: Option<&ColliderOffset>,
+) -> Option<(Vec3, Quat)> {
+    if transform.is_none() && offset.is_none() {
+        return None;
+    }
+
+    let (translation, rotation) = transform.map_or((Vec3::ZERO, Quat::IDENTITY), |it| {
+        (it.translation, it.rotation)
+    });
+
+    Some(offset.map_or((translation, rotation), |offset| {
+        offset.apply_to(translation, rotation)
+    }))
+}
+
 pub(crate) fn update_collision_groups(
-    mut colliders: ResMut<'_, ColliderSet>,
+    mut worlds: Worlds<'_, '_>,
     query: Query<'_, '_, (&CollisionLayers, &super::ColliderHandle), Changed<CollisionLayers>>,
 ) {
     for (layers, handle) in query.iter() {
-        if let Some(collider) = colliders.get_mut(handle.0) {
+        if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
             collider.set_collision_groups(layers.into_rapier());
         }
     }
 }
 
-pub(crate) fn update_sensor_flag(
-    mut colliders: ResMut<'_, ColliderSet>,
-    query: Query<'_, '_, &super::ColliderHandle, Changed<SensorShape>>,
+pub(crate) fn update_active_collision_types(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, (&ActiveCollisions, &super::ColliderHandle), Changed<ActiveCollisions>>,
+) {
+    for (active_collisions, handle) in query.iter() {
+        if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
+            collider.set_active_collision_types((*active_collisions).into_rapier());
+        }
+    }
+}
+
+pub(crate) fn reset_active_collision_types(
+    mut worlds: Worlds<'_, '_>,
+    handles: Query<'_, '_, &super::ColliderHandle>,
+    removed: RemovedComponents<'_, ActiveCollisions>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(entity).ok())
+        .for_each(|handle| {
+            if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
+                collider.set_active_collision_types(ActiveCollisionTypes::all());
+            }
+        });
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn update_body_sensor_flag(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, &super::RigidBodyHandle, Changed<Sensor>>,
+    sensor_shapes: Query<'_, '_, (), With<SensorShape>>,
+    solid_shapes: Query<'_, '_, (), With<SolidShape>>,
 ) {
     for handle in query.iter() {
-        if let Some(collider) = colliders.get_mut(handle.0) {
-            collider.set_sensor(true);
+        let world = worlds.get_mut(handle.1);
+        if let Some(body) = world.bodies.get(handle.0) {
+            for collider_handle in body.colliders() {
+                if let Some(collider) = world.colliders.get_mut(*collider_handle) {
+                    let entity = Entity::from_bits(collider.user_data as u64);
+                    collider.set_sensor(is_sensor(
+                        sensor_shapes.get(entity).is_ok(),
+                        solid_shapes.get(entity).is_ok(),
+                        true,
+                    ));
+                }
+            }
         }
     }
 }
 
 #[allow(clippy::cast_possible_truncation)]
-pub(crate) fn remove_sensor_flag(
-    bodies: Res<'_, RigidBodySet>,
-    mut colliders: ResMut<'_, ColliderSet>,
-    rigid_bodies: Query<'_, '_, &RigidBody>,
-    collider_handles: Query<'_, '_, &super::ColliderHandle>,
-    removed: RemovedComponents<'_, SensorShape>,
+pub(crate) fn remove_body_sensor_flag(
+    mut worlds: Worlds<'_, '_>,
+    rigid_bodies: Query<'_, '_, (&RigidBody, &super::RigidBodyHandle)>,
+    sensor_shapes: Query<'_, '_, (), With<SensorShape>>,
+    solid_shapes: Query<'_, '_, (), With<SolidShape>>,
+    removed: RemovedComponents<'_, Sensor>,
 ) {
     removed
         .iter()
-        .filter_map(|e| collider_handles.get(e).ok())
-        .for_each(|handle| {
-            if let Some(collider) = colliders.get_mut(handle.0) {
-                let rigid_body = collider.parent().and_then(|parent| {
-                    bodies
-                        .get(parent)
-                        .map(|b| Entity::from_bits(b.user_data as u64))
-                        .and_then(|e| rigid_bodies.get(e).ok())
-                });
-
-                collider.set_sensor(matches!(rigid_body, Some(RigidBody::Sensor)));
+        .filter_map(|e| rigid_bodies.get(e).ok())
+        .for_each(|(body_type, handle)| {
+            let world = worlds.get_mut(handle.1);
+            if let Some(body) = world.bodies.get(handle.0) {
+                for collider_handle in body.colliders() {
+                    if let Some(collider) = world.colliders.get_mut(*collider_handle) {
+                        let entity = Entity::from_bits(collider.user_data as u64);
+                        collider.set_sensor(is_sensor(
+                            sensor_shapes.get(entity).is_ok(),
+                            solid_shapes.get(entity).is_ok(),
+                            is_sensor_body(*body_type, None),
+                        ));
+                    }
+                }
             }
         });
 }
 
+/// Returns true if all the colliders of the body should be sensors
+#[allow(deprecated)]
+fn is_sensor_body(body: RigidBody, sensor: Option<&Sensor>) -> bool {
+    sensor.is_some() || matches!(body, RigidBody::Sensor)
+}
+
+/// Returns true if a collider should be a sensor
+///
+/// A [`SensorShape`] is always a sensor, and a [`SolidShape`] is never a sensor unless it is also
+/// a [`SensorShape`]. The other colliders are sensors if their body is a sensor.
+fn is_sensor(is_sensor_shape: bool, is_solid_shape: bool, is_sensor_body: bool) -> bool {
+    is_sensor_shape || (is_sensor_body && !is_solid_shape)
+}
+
 pub(crate) fn reset_collision_groups(
-    mut colliders: ResMut<'_, ColliderSet>,
+    mut worlds: Worlds<'_, '_>,
     handles: Query<'_, '_, &super::ColliderHandle>,
     removed: RemovedComponents<'_, CollisionLayers>,
 ) {
@@ -145,87 +408,219 @@ pub(crate) fn reset_collision_groups(
         .iter()
         .filter_map(|entity| handles.get(entity).ok())
         .for_each(|handle| {
-            if let Some(collider) = colliders.get_mut(handle.0) {
+            if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
                 collider.set_collision_groups(InteractionGroups::default());
             }
         });
 }
 
+/// Fills the [`ColliderAabb`] components with the bounding boxes computed by rapier
+pub(crate) fn update_aabb_component(
+    mut worlds: Worlds<'_, '_>,
+    mut query: Query<'_, '_, (Entity, &mut ColliderAabb)>,
+) {
+    for (entity, mut aabb) in &mut query {
+        if let Some(collider) = worlds
+            .collider_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).colliders.get(handle.0))
+        {
+            let new_aabb = collider_aabb(collider);
+            if *aabb != new_aabb {
+                *aabb = new_aabb;
+            }
+        }
+    }
+}
+
+/// Fills the [`ShapeLocalPose`] components with the positions of the colliders relative to their
+/// rigid bodies
+pub(crate) fn update_local_pose_component(
+    mut worlds: Worlds<'_, '_>,
+    mut query: Query<'_, '_, (Entity, &mut ShapeLocalPose)>,
+) {
+    for (entity, mut pose) in &mut query {
+        if let Some(position) = worlds
+            .collider_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).colliders.get(handle.0))
+            .and_then(Collider::position_wrt_parent)
+        {
+            let (translation, rotation) = position.into_bevy();
+            let new_pose = ShapeLocalPose {
+                translation,
+                rotation,
+            };
+            if *pose != new_pose {
+                *pose = new_pose;
+            }
+        }
+    }
+}
+
+pub(crate) fn collider_aabb(collider: &Collider) -> ColliderAabb {
+    let aabb = collider.compute_aabb();
+    ColliderAabb::new(aabb.mins.coords.into_bevy(), aabb.maxs.coords.into_bevy())
+}
+
 pub(crate) fn remove_invalids_after_components_removed(
     mut commands: Commands<'_, '_>,
-    mut handles: ResMut<'_, HandleMap>,
-    mut bodies: ResMut<'_, RigidBodySet>,
-    mut islands: ResMut<'_, IslandManager>,
-    mut colliders: ResMut<'_, ColliderSet>,
+    mut worlds: Worlds<'_, '_>,
     shapes_removed: RemovedComponents<'_, CollisionShape>,
+    parents_removed: RemovedComponents<'_, Parent>,
+    sensor_shapes_removed: RemovedComponents<'_, SensorShape>,
+    sensor_masses_removed: RemovedComponents<'_, SensorMass>,
+    collider_masses_removed: RemovedComponents<'_, ColliderMass>,
+    solid_shapes_removed: RemovedComponents<'_, SolidShape>,
+    mut changes: ResMut<'_, HandleChanges>,
     collider_entities: Query<'_, '_, Entity, With<super::ColliderHandle>>,
+    child_colliders: Query<'_, '_, (), (With<super::ColliderHandle>, Without<RigidBody>)>,
 ) {
-    for entity in shapes_removed.iter() {
-        if let Some(handle) = handles.remove(&entity) {
-            colliders.remove(handle, &mut islands, &mut bodies, true);
-            if collider_entities.get(entity).is_ok() {
-                commands.entity(entity).remove::<super::ColliderHandle>();
+    // A shape detached from its parent rigid body cannot stay attached to it
+    let detached = parents_removed
+        .iter()
+        .filter(|entity| child_colliders.get(*entity).is_ok());
+
+    // Rapier cannot change the mass of a collider, so it is re-created without the mass override
+    let mass_changed = sensor_shapes_removed
+        .iter()
+        .chain(sensor_masses_removed.iter())
+        .chain(collider_masses_removed.iter());
+
+    for entity in shapes_removed
+        .iter()
+        .chain(detached)
+        .chain(mass_changed)
+        .chain(solid_shapes_removed.iter())
+    {
+        for world in worlds.iter_mut() {
+            if let Some(handle) = world.collider_handles.remove(entity) {
+                world
+                    .colliders
+                    .remove(handle, world.islands, world.bodies, true);
+                if collider_entities.get(entity).is_ok() {
+                    commands.entity(entity).remove::<super::ColliderHandle>();
+                }
+                changes.removed_colliders.push(entity);
             }
         }
     }
 }
 
-#[allow(clippy::type_complexity)]
 pub(crate) fn remove_invalids_after_component_changed(
     mut commands: Commands<'_, '_>,
-    mut handles: ResMut<'_, HandleMap>,
-    mut bodies: ResMut<'_, RigidBodySet>,
-    mut islands: ResMut<'_, IslandManager>,
-    mut colliders: ResMut<'_, ColliderSet>,
-    changed: Query<'_, '_, (Entity, &super::ColliderHandle), Changed<CollisionShape>>,
+    mut worlds: Worlds<'_, '_>,
+    mut handle_changes: ResMut<'_, HandleChanges>,
+    changed: Query<
+        '_,
+        '_,
+        (Entity, &super::ColliderHandle),
+        Or<(
+            Changed<CollisionShape>,
+            Added<PhysicsDisabled>,
+            Changed<SensorShape>,
+            Changed<SensorMass>,
+            Changed<ColliderMass>,
+            Changed<SolidShape>,
+        )>,
+    >,
+    reparented: Query<
+        '_,
+        '_,
+        (Entity, &super::ColliderHandle),
+        (Changed<Parent>, Without<RigidBody>),
+    >,
 ) {
-    for (entity, handle) in changed.iter() {
-        colliders.remove(handle.0, &mut islands, &mut bodies, true);
+    // The colliders of reparented shapes are re-created on their new parent rigid body
+    for (entity, handle) in changed.iter().chain(reparented.iter()) {
+        let world = worlds.get_mut(handle.1);
+        world
+            .colliders
+            .remove(handle.0, world.islands, world.bodies, true);
         commands.entity(entity).remove::<super::ColliderHandle>();
-        handles.remove(&entity);
+        world.collider_handles.remove(entity);
+        handle_changes.removed_colliders.push(entity);
     }
 }
 
 pub(crate) trait ColliderFactory {
-    fn collider_builder(&self) -> ColliderBuilder;
-
-    fn build(
-        &self,
-        entity: Entity,
-        is_sensor: bool,
-        material: Option<&PhysicMaterial>,
-        transform: Option<&Transform>,
-        layers: Option<&CollisionLayers>,
-    ) -> Collider {
-        let mut builder = self
-            .collider_builder()
-            .user_data(entity.to_bits().into())
-            .sensor(is_sensor);
-
-        if let Some(material) = material {
-            builder = builder
-                .restitution(material.restitution)
-                .density(material.density)
-                .friction(material.friction);
-        }
+    fn try_collider_builder(&self) -> Result<ColliderBuilder, ColliderCreationFailure>;
 
-        if let Some(transform) = transform {
-            builder = builder.position((transform.translation, transform.rotation).into_rapier());
+    #[cfg(test)]
+    fn collider_builder(&self) -> ColliderBuilder {
+        match self.try_collider_builder() {
+            Ok(builder) => builder,
+            Err(failure) => panic!("{}", failure),
         }
+    }
+}
+
+/// Overrides the mass of the collider with its [`ColliderMass`] if any, unless it is
+/// [`ColliderMass::Material`]
+///
+/// Otherwise, overrides the mass of sensor shapes, which don't contribute to the mass of their
+/// rigid body unless they have a [`SensorMass`]
+fn with_mass(
+    builder: ColliderBuilder,
+    collider_mass: Option<&ColliderMass>,
+    is_sensor_shape: bool,
+    sensor_mass: Option<&SensorMass>,
+) -> ColliderBuilder {
+    let with_total_mass = |mass: f32| {
+        let mut mass_properties = builder.shape.mass_properties(1.0);
+        mass_properties.set_mass(mass, true);
+        mass_properties
+    };
 
-        if let Some(layers) = layers {
-            builder = builder.collision_groups(layers.into_rapier());
+    let mass_properties = match (collider_mass, sensor_mass) {
+        (Some(ColliderMass::Material) | None, _) if !is_sensor_shape => return builder,
+        (Some(ColliderMass::Zero), _) | (Some(ColliderMass::Material) | None, None) => {
+            MassProperties::default()
         }
+        (Some(ColliderMass::Density(density)), _) => builder.shape.mass_properties(*density),
+        (Some(ColliderMass::Mass(mass)), _) => with_total_mass(*mass),
+        (Some(ColliderMass::Material) | None, Some(mass)) => with_total_mass(mass.0),
+    };
+
+    // The mass properties take precedence over the density of the material
+    builder.mass_properties(mass_properties)
+}
+
+fn build(
+    builder: ColliderBuilder,
+    entity: Entity,
+    is_sensor: bool,
+    material: Option<&PhysicMaterial>,
+    position: Option<(Vec3, Quat)>,
+    layers: Option<&CollisionLayers>,
+    active_collisions: Option<&ActiveCollisions>,
+) -> Collider {
+    let mut builder = builder.user_data(entity.to_bits().into()).sensor(is_sensor);
+
+    if let Some(material) = material {
+        builder = builder
+            .restitution(material.restitution)
+            .density(material.density)
+            .friction(material.friction);
+    }
+
+    if let Some(position) = position {
+        builder = builder.position(position.into_rapier());
+    }
 
-        builder
-            .active_collision_types(ActiveCollisionTypes::all()) // Activate all collision types
-            .build()
+    if let Some(layers) = layers {
+        builder = builder.collision_groups(layers.into_rapier());
     }
+
+    builder
+        .active_collision_types(active_collisions.copied().unwrap_or_default().into_rapier())
+        .build()
 }
 
 impl ColliderFactory for CollisionShape {
-    fn collider_builder(&self) -> ColliderBuilder {
-        match self {
+    fn try_collider_builder(&self) -> Result<ColliderBuilder, ColliderCreationFailure> {
+        self.validate()
+            .map_err(|reason| ColliderCreationFailure::InvalidShape { reason })?;
+
+        let builder = match self {
             CollisionShape::Sphere { radius } => ColliderBuilder::ball(*radius),
             CollisionShape::Capsule {
                 half_segment: half_height,
@@ -238,7 +633,20 @@ impl ColliderFactory for CollisionShape {
             CollisionShape::ConvexHull {
                 points,
                 border_radius,
-            } => convex_hull_builder(points.as_slice(), *border_radius),
+            } => convex_hull_builder(points.as_slice(), *border_radius).ok_or(
+                ColliderCreationFailure::InvalidShape {
+                    reason: "Failed to compute the convex hull",
+                },
+            )?,
+            CollisionShape::ConvexPolyhedron {
+                points,
+                indices,
+                border_radius,
+            } => convex_polyhedron_builder(points.as_slice(), indices, *border_radius).ok_or(
+                ColliderCreationFailure::InvalidShape {
+                    reason: "Failed to create the convex polyhedron",
+                },
+            )?,
             CollisionShape::HeightField { size, heights } => heightfield_builder(*size, heights),
             #[cfg(dim3)]
             CollisionShape::Cone {
@@ -250,11 +658,20 @@ impl ColliderFactory for CollisionShape {
                 half_height,
                 radius,
             } => ColliderBuilder::cylinder(*half_height, *radius),
+            #[cfg(dim2)]
+            CollisionShape::Triangle {
+                a,
+                b,
+                c,
+                border_radius,
+            } => triangle_builder(*a, *b, *c, *border_radius),
             CollisionShape::Custom { shape } => {
                 if let Some(builder) = shape.downcast_ref::<ColliderBuilder>() {
                     builder.clone()
                 } else {
-                    panic!("Unsupported custom collision shape is used: {:?}", shape);
+                    return Err(ColliderCreationFailure::UnsupportedCustomShape {
+                        type_name: shape.type_name(),
+                    });
                 }
             }
             any_other => {
@@ -264,12 +681,23 @@ impl ColliderFactory for CollisionShape {
                 );
                 ColliderBuilder::ball(1.0)
             }
-        }
+        };
+
         // General all types of collision events
-        .active_events(ActiveEvents::all())
+        Ok(builder.active_events(ActiveEvents::all()))
     }
 }
 
+#[inline]
+#[cfg(dim2)]
+fn triangle_builder(a: Vec2, b: Vec2, c: Vec2, border_radius: Option<f32>) -> ColliderBuilder {
+    let (a, b, c) = (a.into_rapier(), b.into_rapier(), c.into_rapier());
+    border_radius.map_or_else(
+        || ColliderBuilder::triangle(a, b, c),
+        |border_radius| ColliderBuilder::round_triangle(a, b, c, border_radius),
+    )
+}
+
 #[inline]
 #[cfg(dim2)]
 fn cuboid_builder(half_extends: Vec3, border_radius: Option<f32>) -> ColliderBuilder {
@@ -298,22 +726,47 @@ fn cuboid_builder(half_extends: Vec3, border_radius: Option<f32>) -> ColliderBui
 }
 
 #[inline]
-fn convex_hull_builder(points: &[Vec3], border_radius: Option<f32>) -> ColliderBuilder {
+fn convex_hull_builder(points: &[Vec3], border_radius: Option<f32>) -> Option<ColliderBuilder> {
     let points: Vec<Point<f32>> = points.into_rapier();
     border_radius.map_or_else(
-        || ColliderBuilder::convex_hull(points.as_slice()).expect("Failed to create convex-hull"),
-        |border_radius| {
-            ColliderBuilder::round_convex_hull(points.as_slice(), border_radius)
-                .expect("Failed to create convex-hull")
-        },
+        || ColliderBuilder::convex_hull(points.as_slice()),
+        |border_radius| ColliderBuilder::round_convex_hull(points.as_slice(), border_radius),
     )
 }
 
+#[inline]
+#[cfg(dim2)]
+fn convex_polyhedron_builder(
+    points: &[Vec3],
+    _indices: &[[u32; 3]],
+    border_radius: Option<f32>,
+) -> Option<ColliderBuilder> {
+    let points: Vec<Point<f32>> = points.into_rapier();
+    match border_radius {
+        Some(border_radius) => ColliderBuilder::round_convex_polyline(points, border_radius),
+        None => ColliderBuilder::convex_polyline(points),
+    }
+}
+
+#[inline]
+#[cfg(dim3)]
+fn convex_polyhedron_builder(
+    points: &[Vec3],
+    indices: &[[u32; 3]],
+    border_radius: Option<f32>,
+) -> Option<ColliderBuilder> {
+    let points: Vec<Point<f32>> = points.into_rapier();
+    match border_radius {
+        Some(border_radius) => ColliderBuilder::round_convex_mesh(points, indices, border_radius),
+        None => ColliderBuilder::convex_mesh(points, indices),
+    }
+}
+
 #[inline]
 #[cfg(dim2)]
 #[allow(clippy::cast_precision_loss)]
 fn heightfield_builder(size: Vec2, heights: &[Vec<f32>]) -> ColliderBuilder {
-    let len = heights.get(0).map(Vec::len).unwrap_or_default();
+    let len = heights.first().map(Vec::len).unwrap_or_default();
     ColliderBuilder::heightfield(
         crate::rapier::na::DVector::from_iterator(len, heights.iter().flatten().take(len).copied()),
         crate::rapier::na::Vector2::new(size.x, 1.0),
@@ -325,7 +778,7 @@ fn heightfield_builder(size: Vec2, heights: &[Vec<f32>]) -> ColliderBuilder {
 #[allow(clippy::cast_precision_loss)]
 fn heightfield_builder(size: Vec2, heights: &[Vec<f32>]) -> ColliderBuilder {
     let nrows = heights.len();
-    let ncols = heights.get(0).map(Vec::len).unwrap_or_default();
+    let ncols = heights.first().map(Vec::len).unwrap_or_default();
     ColliderBuilder::heightfield(
         crate::rapier::na::DMatrix::from_iterator(nrows, ncols, heights.iter().flatten().copied()),
         crate::rapier::na::Vector3::new(size.x, 1.0, size.y),
@@ -437,6 +890,83 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(dim2)]
+    fn build_convex_polyhedron() {
+        let collider = CollisionShape::ConvexPolyhedron {
+            points: vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0), Vec3::Y],
+            indices: Vec::new(),
+            border_radius: None,
+        }
+        .collider_builder()
+        .build();
+
+        let polygon = collider
+            .shape()
+            .as_convex_polygon()
+            .expect("Created shape was not a convex polygon");
+        assert_eq!(polygon.points().len(), 4);
+    }
+
+    #[test]
+    #[cfg(dim3)]
+    fn build_convex_polyhedron() {
+        let collider = CollisionShape::ConvexPolyhedron {
+            points: vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z],
+            indices: vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+            border_radius: None,
+        }
+        .collider_builder()
+        .build();
+
+        let polyhedron = collider
+            .shape()
+            .as_convex_polyhedron()
+            .expect("Created shape was not a convex polyhedron");
+        assert_eq!(polyhedron.points().len(), 4);
+    }
+
+    #[test]
+    #[cfg(dim2)]
+    fn build_triangle() {
+        let collider = CollisionShape::Triangle {
+            a: Vec2::new(0.0, 1.0),
+            b: Vec2::new(-1.0, 0.0),
+            c: Vec2::new(1.0, 0.0),
+            border_radius: None,
+        }
+        .collider_builder()
+        .build();
+
+        let triangle = collider
+            .shape()
+            .as_triangle()
+            .expect("Created shape was not a triangle");
+        assert_ulps_eq!(triangle.a.y, 1.0);
+        assert_ulps_eq!(triangle.b.x, -1.0);
+        assert_ulps_eq!(triangle.c.x, 1.0);
+    }
+
+    #[test]
+    #[cfg(dim2)]
+    fn build_round_triangle() {
+        let collider = CollisionShape::Triangle {
+            a: Vec2::new(0.0, 1.0),
+            b: Vec2::new(-1.0, 0.0),
+            c: Vec2::new(1.0, 0.0),
+            border_radius: Some(0.5),
+        }
+        .collider_builder()
+        .build();
+
+        let triangle = collider
+            .shape()
+            .as_round_triangle()
+            .expect("Created shape was not a round triangle");
+        assert_ulps_eq!(triangle.border_radius, 0.5);
+        assert_ulps_eq!(triangle.inner_shape.a.y, 1.0);
+    }
+
     #[test]
     fn build_custom_collider_builder() {
         let collider = CollisionShape::Custom {
@@ -464,4 +994,43 @@ mod tests {
             .collider_builder(),
         );
     }
+
+    #[test]
+    fn degenerate_convex_hull_is_invalid() {
+        let result = CollisionShape::ConvexHull {
+            points: vec![Vec3::ZERO; 4],
+            border_radius: None,
+        }
+        .try_collider_builder();
+
+        assert!(matches!(
+            result,
+            Err(ColliderCreationFailure::InvalidShape { .. })
+        ));
+    }
+
+    #[test]
+    fn negative_radius_is_invalid() {
+        let result = CollisionShape::Sphere { radius: -1.0 }.try_collider_builder();
+
+        assert!(matches!(
+            result,
+            Err(ColliderCreationFailure::InvalidShape { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(dim3)]
+    fn ragged_heightfield_is_invalid() {
+        let result = CollisionShape::HeightField {
+            size: Vec2::new(1.0, 1.0),
+            heights: vec![vec![0.0, 1.0], vec![0.0]],
+        }
+        .try_collider_builder();
+
+        assert!(matches!(
+            result,
+            Err(ColliderCreationFailure::InvalidShape { .. })
+        ));
+    }
 }
@@ -0,0 +1,234 @@
+use std::sync::Arc;
+
+use bevy::ecs::prelude::*;
+use fnv::FnvHashMap;
+
+use heron_core::{ColliderCreationFailure, CollisionShape};
+
+use crate::rapier::geometry::{ColliderBuilder, SharedShape};
+use crate::rapier::pipeline::ActiveEvents;
+use crate::shape::ColliderFactory;
+
+/// Cache of the shapes built by rapier
+///
+/// Identical collision shapes share the same [`SharedShape`] allocation, which saves memory and
+/// build time when spawning many identical bodies.
+#[derive(Default)]
+pub(crate) struct ShapeCache(FnvHashMap<ShapeKey, SharedShape>);
+
+impl ShapeCache {
+    /// Returns a collider builder for the shape, reusing the cached shape if any
+    pub(crate) fn collider_builder(
+        &mut self,
+        shape: &CollisionShape,
+    ) -> Result<ColliderBuilder, ColliderCreationFailure> {
+        let key = match ShapeKey::of(shape) {
+            Some(key) => key,
+            None => return shape.try_collider_builder(),
+        };
+
+        if let Some(shared) = self.0.get(&key) {
+            return Ok(ColliderBuilder::new(shared.clone()).active_events(ActiveEvents::all()));
+        }
+
+        let builder = shape.try_collider_builder()?;
+        self.0.insert(key, builder.shape.clone());
+        Ok(builder)
+    }
+
+    /// Forget the shapes that are not used by any collider anymore
+    pub(crate) fn remove_unused(&mut self) {
+        self.0.retain(|_, shape| Arc::strong_count(&shape.0) > 1);
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+pub(crate) fn remove_unused_shapes(mut cache: ResMut<'_, ShapeCache>) {
+    cache.remove_unused();
+}
+
+/// Exact representation of a [`CollisionShape`] value that can be hashed
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct ShapeKey(Vec<u32>);
+
+impl ShapeKey {
+    /// Returns the key of the shape, or `None` if the shape cannot be cached
+    fn of(shape: &CollisionShape) -> Option<Self> {
+        let mut key = Self(Vec::new());
+        match shape {
+            CollisionShape::Sphere { radius } => {
+                key.push(0).push_f32(*radius);
+            }
+            CollisionShape::Capsule {
+                half_segment,
+                radius,
+            } => {
+                key.push(1).push_f32(*half_segment).push_f32(*radius);
+            }
+            CollisionShape::Cuboid {
+                half_extends,
+                border_radius,
+            } => {
+                key.push(2)
+                    .push_f32s(&half_extends.to_array())
+                    .push_option(*border_radius);
+            }
+            CollisionShape::ConvexHull {
+                points,
+                border_radius,
+            } => {
+                key.push(3).push_option(*border_radius);
+                for point in points {
+                    key.push_f32s(&point.to_array());
+                }
+            }
+            CollisionShape::HeightField { size, heights } => {
+                key.push(4).push_f32s(&size.to_array());
+                for row in heights {
+                    #[allow(clippy::cast_possible_truncation)]
+                    key.push(row.len() as u32).push_f32s(row);
+                }
+            }
+            #[cfg(dim3)]
+            CollisionShape::Cone {
+                half_height,
+                radius,
+            } => {
+                key.push(5).push_f32(*half_height).push_f32(*radius);
+            }
+            #[cfg(dim3)]
+            CollisionShape::Cylinder {
+                half_height,
+                radius,
+            } => {
+                key.push(6).push_f32(*half_height).push_f32(*radius);
+            }
+            #[cfg(dim2)]
+            CollisionShape::Triangle {
+                a,
+                b,
+                c,
+                border_radius,
+            } => {
+                key.push(7)
+                    .push_f32s(&a.to_array())
+                    .push_f32s(&b.to_array())
+                    .push_f32s(&c.to_array())
+                    .push_option(*border_radius);
+            }
+            CollisionShape::ConvexPolyhedron {
+                points,
+                indices,
+                border_radius,
+            } => {
+                #[allow(clippy::cast_possible_truncation)]
+                key.push(8)
+                    .push_option(*border_radius)
+                    .push(points.len() as u32);
+                for point in points {
+                    key.push_f32s(&point.to_array());
+                }
+                for triangle in indices {
+                    key.push(triangle[0]).push(triangle[1]).push(triangle[2]);
+                }
+            }
+            _ => return None,
+        }
+        Some(key)
+    }
+
+    fn push(&mut self, value: u32) -> &mut Self {
+        self.0.push(value);
+        self
+    }
+
+    fn push_f32(&mut self, value: f32) -> &mut Self {
+        self.push(value.to_bits())
+    }
+
+    fn push_f32s(&mut self, values: &[f32]) -> &mut Self {
+        self.0.extend(values.iter().map(|it| it.to_bits()));
+        self
+    }
+
+    fn push_option(&mut self, value: Option<f32>) -> &mut Self {
+        match value {
+            Some(value) => self.push(1).push_f32(value),
+            None => self.push(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Vec3;
+    use heron_core::CustomCollisionShape;
+
+    use super::*;
+
+    #[test]
+    fn identical_shapes_share_the_same_allocation() {
+        let mut cache = ShapeCache::default();
+        let shape = CollisionShape::Cuboid {
+            half_extends: Vec3::new(1.0, 2.0, 3.0),
+            border_radius: Some(0.5),
+        };
+
+        let shape1 = cache.collider_builder(&shape).unwrap().shape;
+        let shape2 = cache.collider_builder(&shape.clone()).unwrap().shape;
+
+        assert!(Arc::ptr_eq(&shape1.0, &shape2.0));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn different_shapes_do_not_share_allocation() {
+        let mut cache = ShapeCache::default();
+
+        let shape1 = cache
+            .collider_builder(&CollisionShape::Sphere { radius: 1.0 })
+            .unwrap()
+            .shape;
+        let shape2 = cache
+            .collider_builder(&CollisionShape::Sphere { radius: 2.0 })
+            .unwrap()
+            .shape;
+
+        assert!(!Arc::ptr_eq(&shape1.0, &shape2.0));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn custom_shapes_are_not_cached() {
+        let mut cache = ShapeCache::default();
+
+        let _builder = cache
+            .collider_builder(&CollisionShape::Custom {
+                shape: CustomCollisionShape::new(ColliderBuilder::ball(1.0)),
+            })
+            .unwrap();
+
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn remove_unused_shapes() {
+        let mut cache = ShapeCache::default();
+        let used = cache
+            .collider_builder(&CollisionShape::Sphere { radius: 1.0 })
+            .unwrap();
+        let unused = cache
+            .collider_builder(&CollisionShape::Sphere { radius: 2.0 })
+            .unwrap();
+
+        drop(unused);
+        cache.remove_unused();
+
+        assert_eq!(cache.len(), 1);
+        drop(used);
+    }
+}
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use fnv::FnvHashMap;
+
+use heron_core::SurfaceVelocity;
+
+use crate::convert::IntoRapier;
+use crate::rapier::geometry::Collider;
+use crate::rapier::math::Vector;
+use crate::rapier::pipeline::{ActiveHooks, ContactModificationContext, PhysicsHooks};
+use crate::worlds::Worlds;
+
+/// Surface velocities of the collision shape entities, used as physics hooks to drag the bodies in
+/// contact along the surfaces
+#[derive(Default)]
+pub(crate) struct SurfaceVelocities(FnvHashMap<Entity, Vec3>);
+
+impl SurfaceVelocities {
+    /// Surface velocity of the collider in world space, or zero if it has none
+    #[allow(clippy::cast_possible_truncation)]
+    fn world_velocity(&self, collider: &Collider) -> Vector<f32> {
+        self.0
+            .get(&Entity::from_bits(collider.user_data as u64))
+            .map_or_else(Vector::zeros, |velocity| {
+                let velocity: Vector<f32> = (*velocity).into_rapier();
+                collider.rotation() * velocity
+            })
+    }
+}
+
+impl PhysicsHooks for SurfaceVelocities {
+    fn modify_solver_contacts(&self, context: &mut ContactModificationContext<'_>) {
+        let (collider1, collider2) = match (
+            context.colliders.get(context.collider1),
+            context.colliders.get(context.collider2),
+        ) {
+            (Some(collider1), Some(collider2)) => (collider1, collider2),
+            _ => return,
+        };
+
+        // The solver makes the second collider move at the tangent velocity relative to the first
+        let tangent_velocity = self.world_velocity(collider1) - self.world_velocity(collider2);
+        for contact in context.solver_contacts.iter_mut() {
+            contact.tangent_velocity = tangent_velocity;
+        }
+    }
+}
+
+/// Keeps the [`SurfaceVelocities`] and the active hooks of the colliders in sync with the
+/// [`SurfaceVelocity`] components
+pub(crate) fn update_surface_velocities(
+    mut worlds: Worlds<'_, '_>,
+    mut surface_velocities: ResMut<'_, SurfaceVelocities>,
+    changed: Query<
+        '_,
+        '_,
+        (Entity, &super::ColliderHandle, &SurfaceVelocity),
+        Or<(Changed<SurfaceVelocity>, Added<super::ColliderHandle>)>,
+    >,
+    handles: Query<'_, '_, &super::ColliderHandle>,
+    removed: RemovedComponents<'_, SurfaceVelocity>,
+) {
+    for entity in removed.iter() {
+        surface_velocities.0.remove(&entity);
+        if let Some(collider) = handles
+            .get(entity)
+            .ok()
+            .and_then(|handle| worlds.get_mut(handle.1).colliders.get_mut(handle.0))
+        {
+            collider
+                .set_active_hooks(collider.active_hooks() - ActiveHooks::MODIFY_SOLVER_CONTACTS);
+        }
+    }
+
+    for (entity, handle, velocity) in changed.iter() {
+        surface_velocities.0.insert(entity, velocity.linear);
+        if let Some(collider) = worlds.get_mut(handle.1).colliders.get_mut(handle.0) {
+            collider
+                .set_active_hooks(collider.active_hooks() | ActiveHooks::MODIFY_SOLVER_CONTACTS);
+        }
+    }
+}
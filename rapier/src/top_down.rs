@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use heron_core::TopDownController;
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::{IntegrationParameters, RigidBodyType};
+
+use crate::worlds::Worlds;
+
+/// Updates the velocity (and facing) of the rigid bodies having a [`TopDownController`]
+pub(crate) fn apply_top_down_controllers(
+    mut worlds: Worlds<'_, '_>,
+    integration_parameters: Res<'_, IntegrationParameters>,
+    query: Query<'_, '_, (Entity, &TopDownController)>,
+) {
+    for (entity, controller) in query.iter() {
+        let body = match worlds
+            .body_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).bodies.get_mut(handle.0))
+        {
+            Some(body)
+                if body.is_dynamic()
+                    || body.body_type() == RigidBodyType::KinematicVelocityBased =>
+            {
+                body
+            }
+            _ => continue,
+        };
+
+        let current: Vec3 = (*body.linvel()).into_bevy();
+        let velocity = controller
+            .next_velocity(current.truncate(), integration_parameters.dt)
+            .extend(current.z);
+        if velocity != current {
+            body.set_linvel(velocity.into_rapier(), true);
+        }
+
+        let direction = controller.direction();
+        if controller.facing && direction != Vec2::ZERO {
+            let mut position = *body.position();
+            position.rotation = Quat::from_rotation_z(direction.y.atan2(direction.x)).into_rapier();
+            body.set_position(position, true);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use heron_core::vehicle::{RaycastVehicle, WheelContact, WheelContacts};
+use heron_core::{CollisionLayers, CollisionShape, PhysicsSystem};
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::IntegrationParameters;
+use crate::rapier::math::{Point, Vector};
+use crate::worlds::Worlds;
+use crate::{PhysicsWorld, ShapeCastCollisionType};
+
+pub(crate) fn systems() -> SystemSet {
+    SystemSet::new()
+        .with_system(insert_wheel_contacts)
+        .with_system(
+            update_wheel_contacts
+                .label(PhysicsSystem::PreStep)
+                .before(apply_suspension_forces)
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            apply_suspension_forces
+                .label(PhysicsSystem::PreStep)
+                .before(PhysicsSystem::Events),
+        )
+}
+
+fn insert_wheel_contacts(
+    mut commands: Commands<'_, '_>,
+    vehicles: Query<'_, '_, Entity, (With<RaycastVehicle>, Without<WheelContacts>)>,
+) {
+    for entity in vehicles.iter() {
+        commands.entity(entity).insert(WheelContacts::default());
+    }
+}
+
+fn update_wheel_contacts(
+    physics_world: PhysicsWorld<'_, '_>,
+    parents: Query<'_, '_, &Parent>,
+    mut vehicles: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &GlobalTransform,
+            &RaycastVehicle,
+            &super::RigidBodyHandle,
+            &mut WheelContacts,
+        ),
+    >,
+) {
+    for (entity, transform, vehicle, handle, mut contacts) in &mut vehicles {
+        if !handle.world().is_default() {
+            continue;
+        }
+
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let down = rotation * Vec3::NEG_Y;
+        let is_ground = |other: Entity| {
+            other != entity && parents.get(other).map_or(true, |p| p.get() != entity)
+        };
+
+        let wheel_contacts = vehicle
+            .wheels
+            .iter()
+            .map(|wheel| {
+                let anchor = translation + rotation * wheel.anchor;
+                let hit = physics_world.shape_cast_with_filter(
+                    &CollisionShape::Sphere {
+                        radius: wheel.radius,
+                    },
+                    anchor,
+                    rotation,
+                    down * wheel.suspension.rest_length,
+                    CollisionLayers::default(),
+                    is_ground,
+                )?;
+
+                let suspension_length = match hit.collision_type {
+                    ShapeCastCollisionType::Collided(info) => {
+                        (info.self_end_position - anchor).length()
+                    }
+                    ShapeCastCollisionType::AlreadyPenetrating => 0.0,
+                };
+
+                Some(WheelContact {
+                    entity: hit.entity,
+                    point: anchor + down * (suspension_length + wheel.radius),
+                    suspension_length,
+                    suspension_force: 0.0,
+                })
+            })
+            .collect();
+
+        contacts.set(wheel_contacts);
+    }
+}
+
+fn apply_suspension_forces(
+    mut worlds: Worlds<'_, '_>,
+    integration_parameters: Res<'_, IntegrationParameters>,
+    mut vehicles: Query<
+        '_,
+        '_,
+        (
+            &GlobalTransform,
+            &RaycastVehicle,
+            &super::RigidBodyHandle,
+            &mut WheelContacts,
+        ),
+    >,
+) {
+    for (transform, vehicle, handle, mut contacts) in &mut vehicles {
+        let body = match worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+            Some(body) => body,
+            None => continue,
+        };
+
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let up = rotation * Vec3::Y;
+
+        for (index, wheel) in vehicle.wheels.iter().enumerate() {
+            let contact = match contacts.get_mut(index) {
+                Some(contact) => contact,
+                None => continue,
+            };
+
+            let anchor: Point<f32> = (translation + rotation * wheel.anchor).into_rapier();
+            let velocity: Vec3 = body.velocity_at_point(&anchor).into_bevy();
+            let compression = wheel.suspension.rest_length - contact.suspension_length;
+            let force = (wheel.suspension.stiffness * compression
+                - wheel.suspension.damping * velocity.dot(up))
+            .clamp(0.0, wheel.suspension.max_force);
+
+            contact.suspension_force = force;
+
+            let impulse: Vector<f32> = (up * force * integration_parameters.dt).into_rapier();
+            body.apply_impulse_at_point(impulse, anchor, true);
+        }
+    }
+}
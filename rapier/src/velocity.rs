@@ -1,21 +1,58 @@
 use bevy::ecs::prelude::*;
+#[cfg(dim2)]
+use bevy::log::warn;
+#[cfg(dim2)]
+use bevy::math::Vec3;
+#[cfg(dim2)]
+use fnv::FnvHashSet;
 
 use heron_core::utils::NearZero;
-use heron_core::{RigidBody, Velocity};
+#[cfg(dim2)]
+use heron_core::{Acceleration, AxisAngle, Force};
+use heron_core::{
+    KinematicOverride, Movement, PhysicsTime, RigidBody, SleepHint, Velocity, VelocityConstraint,
+    VelocityTimeSpace, VelocityTolerance,
+};
 
 use crate::convert::{IntoBevy, IntoRapier};
-use crate::rapier::dynamics::RigidBodySet;
+use crate::rapier;
+use crate::worlds::Worlds;
 
 pub(crate) fn update_rapier_velocity(
-    mut bodies: ResMut<'_, RigidBodySet>,
-    query: Query<'_, '_, (&super::RigidBodyHandle, Option<&RigidBody>, &Velocity)>,
+    mut worlds: Worlds<'_, '_>,
+    tolerance: Res<'_, VelocityTolerance>,
+    time: Res<'_, PhysicsTime>,
+    time_space: Res<'_, VelocityTimeSpace>,
+    query: Query<
+        '_,
+        '_,
+        (
+            &super::RigidBodyHandle,
+            Option<&RigidBody>,
+            &Velocity,
+            Option<&SleepHint>,
+        ),
+        Without<KinematicOverride>,
+    >,
 ) {
     let dynamic_bodies = query
         .iter()
-        .filter(|(_, body_type, _)| body_type.copied().unwrap_or_default().can_have_velocity());
+        .filter(|(_, body_type, _, _)| body_type.copied().unwrap_or_default().can_have_velocity());
+
+    for (handle, _, velocity, hint) in dynamic_bodies {
+        if let Some(body) = worlds.get_mut(handle.1).bodies.get_mut(handle.0) {
+            let hint = hint.copied().unwrap_or_default();
+            let velocity = time_space.velocity_to_simulation(&time, *velocity);
+            // Only stopping a moving body puts it to sleep, so that it can still be woken up
+            if hint.should_sleep(velocity) && !rapier_velocity(body).is_near_zero() {
+                body.sleep();
+                continue;
+            }
+
+            if tolerance.is_negligible(rapier_velocity(body), velocity) {
+                continue;
+            }
 
-    for (handle, _, velocity) in dynamic_bodies {
-        if let Some(body) = bodies.get_mut(handle.0) {
             let wake_up = !velocity.is_near_zero();
             body.set_linvel(velocity.linear.into_rapier(), wake_up);
             body.set_angvel(velocity.angular.into_rapier(), wake_up);
@@ -24,22 +61,99 @@ pub(crate) fn update_rapier_velocity(
 }
 
 pub(crate) fn update_velocity_component(
-    bodies: Res<'_, RigidBodySet>,
-    mut velocities: Query<'_, '_, (&super::RigidBodyHandle, &mut Velocity)>,
+    mut worlds: Worlds<'_, '_>,
+    time: Res<'_, PhysicsTime>,
+    time_space: Res<'_, VelocityTimeSpace>,
+    mut velocities: Query<'_, '_, (Entity, &mut Velocity), With<RigidBody>>,
 ) {
-    for (handle, mut velocity) in velocities.iter_mut() {
-        if let Some(body) = bodies.get(handle.0).filter(|it| it.is_dynamic()) {
-            velocity.linear = (*body.linvel()).into_bevy();
+    for (entity, mut velocity) in &mut velocities {
+        if let Some(body) = worlds
+            .body_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).bodies.get(handle.0))
+            .filter(|it| it.is_dynamic())
+        {
+            *velocity = time_space.velocity_from_simulation(&time, rapier_velocity(body));
+        }
+    }
+}
 
-            #[cfg(dim2)]
-            {
-                velocity.angular = heron_core::AxisAngle::from(bevy::math::Vec3::Z * body.angvel());
+/// Derives the [`Movement`] of the bodies from their [`Velocity`]
+pub(crate) fn update_movement_component(mut query: Query<'_, '_, (&Velocity, &mut Movement)>) {
+    for (velocity, mut movement) in &mut query {
+        movement.update(velocity.linear);
+    }
+}
+
+/// Clamps the linear velocity of the bodies having a [`VelocityConstraint`]
+pub(crate) fn apply_velocity_constraints(
+    mut worlds: Worlds<'_, '_>,
+    query: Query<'_, '_, (Entity, &VelocityConstraint)>,
+) {
+    for (entity, constraint) in query.iter() {
+        if let Some(body) = worlds
+            .body_handle(entity)
+            .and_then(|handle| worlds.get_mut(handle.1).bodies.get_mut(handle.0))
+            .filter(|it| it.is_dynamic())
+        {
+            let velocity = (*body.linvel()).into_bevy();
+            let clamped = constraint.clamp(velocity, body.rotation().into_bevy());
+            if clamped != velocity {
+                body.set_linvel(clamped.into_rapier(), false);
             }
+        }
+    }
+}
+
+/// Warns once per entity about the angular velocities, accelerations and forces that are not
+/// around the Z axis, since the other axes are ignored in 2d
+#[cfg(dim2)]
+#[allow(clippy::type_complexity)]
+pub(crate) fn warn_unsupported_rotations(
+    mut warned: Local<'_, FnvHashSet<Entity>>,
+    changed: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            Option<&Velocity>,
+            Option<&Acceleration>,
+            Option<&Force>,
+        ),
+        Or<(Changed<Velocity>, Changed<Acceleration>, Changed<Force>)>,
+    >,
+    all: Query<'_, '_, (), Or<(With<Velocity>, With<Acceleration>, With<Force>)>>,
+) {
+    warned.retain(|entity| all.contains(*entity));
 
-            #[cfg(dim3)]
-            {
-                velocity.angular = (*body.angvel()).into_bevy().into();
+    for (entity, velocity, acceleration, force) in changed.iter() {
+        let angles = [
+            velocity.map(|it| it.angular),
+            acceleration.map(|it| it.angular),
+            force.map(|it| it.angular),
+        ];
+        let unsupported = angles.into_iter().flatten().find(|angle| {
+            let axis = angle.axis();
+            !axis.x.is_near_zero() || !axis.y.is_near_zero()
+        });
+        if let Some(angle) = unsupported {
+            if warned.insert(entity) {
+                warn!(
+                    "Only the rotation around the Z axis is supported in 2d, but {:?} got {:?}. The other axes are ignored",
+                    entity, angle
+                );
             }
         }
     }
 }
+
+pub(crate) fn rapier_velocity(body: &rapier::dynamics::RigidBody) -> Velocity {
+    let linear = (*body.linvel()).into_bevy();
+
+    #[cfg(dim2)]
+    let angular = AxisAngle::from(Vec3::Z * body.angvel());
+
+    #[cfg(dim3)]
+    let angular = (*body.angvel()).into_bevy().into();
+
+    Velocity { linear, angular }
+}
@@ -0,0 +1,224 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::prelude::*;
+use bevy::ecs::system::SystemParam;
+use fnv::FnvHashMap;
+
+use heron_core::PhysicsWorldId;
+
+use crate::pipeline::{CollisionEventsCapacity, EventManager};
+use crate::rapier::dynamics::{
+    CCDSolver, ImpulseJointSet, IslandManager, MultibodyJointSet, RigidBodySet,
+};
+use crate::rapier::geometry::{BroadPhase, ColliderSet, NarrowPhase};
+use crate::rapier::pipeline::{PhysicsPipeline, QueryPipeline};
+use crate::{body, shape};
+
+/// State of a physics world other than the default one
+///
+/// The default world is stored in individual resources.
+pub(crate) struct RapierWorld {
+    pub(crate) pipeline: PhysicsPipeline,
+    pub(crate) query_pipeline: QueryPipeline,
+    pub(crate) islands: IslandManager,
+    pub(crate) broad_phase: BroadPhase,
+    pub(crate) narrow_phase: NarrowPhase,
+    pub(crate) bodies: RigidBodySet,
+    pub(crate) colliders: ColliderSet,
+    pub(crate) impulse_joints: ImpulseJointSet,
+    pub(crate) multibody_joints: MultibodyJointSet,
+    pub(crate) ccd_solver: CCDSolver,
+    pub(crate) body_handles: body::HandleMap,
+    pub(crate) collider_handles: shape::HandleMap,
+    pub(crate) event_manager: EventManager,
+}
+
+impl RapierWorld {
+    fn new(events_capacity: usize) -> Self {
+        Self {
+            pipeline: crate::physics_pipeline(),
+            query_pipeline: QueryPipeline::new(),
+            islands: IslandManager::new(),
+            broad_phase: BroadPhase::new(),
+            narrow_phase: NarrowPhase::new(),
+            bodies: RigidBodySet::new(),
+            colliders: ColliderSet::new(),
+            impulse_joints: ImpulseJointSet::new(),
+            multibody_joints: MultibodyJointSet::new(),
+            ccd_solver: CCDSolver::new(),
+            body_handles: body::HandleMap::default(),
+            collider_handles: shape::HandleMap::default(),
+            event_manager: EventManager::with_capacity(events_capacity),
+        }
+    }
+
+    fn as_mut(&mut self) -> WorldMut<'_> {
+        WorldMut {
+            bodies: &mut self.bodies,
+            colliders: &mut self.colliders,
+            islands: &mut self.islands,
+            impulse_joints: &mut self.impulse_joints,
+            multibody_joints: &mut self.multibody_joints,
+            narrow_phase: &self.narrow_phase,
+            body_handles: &mut self.body_handles,
+            collider_handles: &mut self.collider_handles,
+        }
+    }
+}
+
+/// Resource containing the physics worlds other than the default one
+#[derive(Default)]
+pub(crate) struct ExtraWorlds(pub(crate) FnvHashMap<PhysicsWorldId, RapierWorld>);
+
+/// Physics objects removed or created since the last physics step frame
+///
+/// The handle components are inserted and removed with commands, which are only applied at the end
+/// of `CoreStage::PostUpdate`. So the systems running after the removal and the creation, in the
+/// same stage, rely on this resource rather than on `Without` and `Added` filters.
+#[derive(Default)]
+pub(crate) struct HandleChanges {
+    /// Entities whose rigid body was removed, and that may need a new one
+    pub(crate) removed_bodies: Vec<Entity>,
+    /// Entities whose collider was removed, and that may need a new one
+    pub(crate) removed_colliders: Vec<Entity>,
+    /// Entities whose joint was removed, and that may need a new one
+    pub(crate) removed_joints: Vec<Entity>,
+    /// Entities of the new rigid bodies
+    pub(crate) created_bodies: Vec<Entity>,
+    /// Whether colliders were created
+    pub(crate) created_colliders: bool,
+    /// Whether joints were created
+    pub(crate) created_joints: bool,
+}
+
+impl HandleChanges {
+    /// Records that the joint of the entity was removed
+    ///
+    /// A joint may be removed both because its component changed and because one of its bodies
+    /// was removed, but it must only be re-created once.
+    pub(crate) fn remove_joint(&mut self, entity: Entity) {
+        if !self.removed_joints.contains(&entity) {
+            self.removed_joints.push(entity);
+        }
+    }
+}
+
+/// Forgets the changes, once all the physics objects are created
+pub(crate) fn clear_handle_changes(mut changes: ResMut<'_, HandleChanges>) {
+    changes.removed_bodies.clear();
+    changes.removed_colliders.clear();
+    changes.removed_joints.clear();
+    changes.created_bodies.clear();
+    changes.created_colliders = false;
+    changes.created_joints = false;
+}
+
+/// Mutable access to the rigid bodies and colliders of a physics world
+pub(crate) struct WorldMut<'a> {
+    pub(crate) bodies: &'a mut RigidBodySet,
+    pub(crate) colliders: &'a mut ColliderSet,
+    pub(crate) islands: &'a mut IslandManager,
+    pub(crate) impulse_joints: &'a mut ImpulseJointSet,
+    pub(crate) multibody_joints: &'a mut MultibodyJointSet,
+    pub(crate) narrow_phase: &'a NarrowPhase,
+    pub(crate) body_handles: &'a mut body::HandleMap,
+    pub(crate) collider_handles: &'a mut shape::HandleMap,
+}
+
+/// System parameter giving access to the rigid bodies and colliders of all physics worlds
+#[derive(SystemParam)]
+pub(crate) struct Worlds<'w, 's> {
+    bodies: ResMut<'w, RigidBodySet>,
+    colliders: ResMut<'w, ColliderSet>,
+    islands: ResMut<'w, IslandManager>,
+    impulse_joints: ResMut<'w, ImpulseJointSet>,
+    multibody_joints: ResMut<'w, MultibodyJointSet>,
+    narrow_phase: Res<'w, NarrowPhase>,
+    body_handles: ResMut<'w, body::HandleMap>,
+    collider_handles: ResMut<'w, shape::HandleMap>,
+    extra: ResMut<'w, ExtraWorlds>,
+    events_capacity: Option<Res<'w, CollisionEventsCapacity>>,
+    #[system_param(ignore)]
+    marker: PhantomData<&'s usize>,
+}
+
+impl Worlds<'_, '_> {
+    /// Returns the physics world of the given id, creating it if necessary
+    pub(crate) fn get_mut(&mut self, world: PhysicsWorldId) -> WorldMut<'_> {
+        if world.is_default() {
+            self.default_world()
+        } else {
+            let capacity = self
+                .events_capacity
+                .as_deref()
+                .copied()
+                .unwrap_or_default()
+                .0;
+            self.extra
+                .0
+                .entry(world)
+                .or_insert_with(|| RapierWorld::new(capacity))
+                .as_mut()
+        }
+    }
+
+    /// Iterates over all the physics worlds, starting with the default one
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = WorldMut<'_>> {
+        let default = WorldMut {
+            bodies: &mut self.bodies,
+            colliders: &mut self.colliders,
+            islands: &mut self.islands,
+            impulse_joints: &mut self.impulse_joints,
+            multibody_joints: &mut self.multibody_joints,
+            narrow_phase: &self.narrow_phase,
+            body_handles: &mut self.body_handles,
+            collider_handles: &mut self.collider_handles,
+        };
+        std::iter::once(default).chain(self.extra.0.values_mut().map(RapierWorld::as_mut))
+    }
+
+    /// Returns the handle of the rigid body of the entity, in whichever physics world it is
+    ///
+    /// Unlike the `RigidBodyHandle` component, which is inserted with commands, it includes the
+    /// bodies created earlier in the same stage.
+    pub(crate) fn body_handle(&self, entity: Entity) -> Option<crate::RigidBodyHandle> {
+        if let Some(handle) = self.body_handles.get(entity) {
+            return Some(crate::RigidBodyHandle(*handle, PhysicsWorldId::default()));
+        }
+        self.extra.0.iter().find_map(|(id, world)| {
+            world
+                .body_handles
+                .get(entity)
+                .map(|handle| crate::RigidBodyHandle(*handle, *id))
+        })
+    }
+
+    /// Returns the handle of the collider of the entity, in whichever physics world it is
+    ///
+    /// Unlike the `ColliderHandle` component, which is inserted with commands, it includes the
+    /// colliders created earlier in the same stage.
+    pub(crate) fn collider_handle(&self, entity: Entity) -> Option<crate::ColliderHandle> {
+        if let Some(handle) = self.collider_handles.get(entity) {
+            return Some(crate::ColliderHandle(*handle, PhysicsWorldId::default()));
+        }
+        self.extra.0.iter().find_map(|(id, world)| {
+            world
+                .collider_handles
+                .get(entity)
+                .map(|handle| crate::ColliderHandle(*handle, *id))
+        })
+    }
+
+    fn default_world(&mut self) -> WorldMut<'_> {
+        WorldMut {
+            bodies: &mut self.bodies,
+            colliders: &mut self.colliders,
+            islands: &mut self.islands,
+            impulse_joints: &mut self.impulse_joints,
+            multibody_joints: &mut self.multibody_joints,
+            narrow_phase: &self.narrow_phase,
+            body_handles: &mut self.body_handles,
+            collider_handles: &mut self.collider_handles,
+        }
+    }
+}
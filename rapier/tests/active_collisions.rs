@@ -0,0 +1,144 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{ActiveCollisions, CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, RapierPlugin};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn active_collision_types(app: &App, entity: Entity) -> ActiveCollisionTypes {
+    app.world
+        .resource::<ColliderSet>()
+        .get(
+            app.world
+                .get::<ColliderHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap()
+        .active_collision_types()
+}
+
+#[test]
+fn activates_all_collision_types_by_default() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            GlobalTransform::default(),
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        active_collision_types(&app, entity),
+        ActiveCollisionTypes::all()
+    );
+}
+
+#[test]
+fn sets_the_active_collision_types() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            ActiveCollisions {
+                kinematic_static: false,
+                ..ActiveCollisions::all()
+            },
+            GlobalTransform::default(),
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        active_collision_types(&app, entity),
+        ActiveCollisionTypes::all() - ActiveCollisionTypes::KINEMATIC_FIXED
+    );
+}
+
+#[test]
+fn updates_the_active_collision_types() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            GlobalTransform::default(),
+        ))
+        .id();
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(ActiveCollisions::dynamic_only());
+
+    app.update();
+
+    assert_eq!(
+        active_collision_types(&app, entity),
+        ActiveCollisionTypes::DYNAMIC_DYNAMIC
+            | ActiveCollisionTypes::DYNAMIC_KINEMATIC
+            | ActiveCollisionTypes::DYNAMIC_FIXED
+    );
+}
+
+#[test]
+fn restores_the_active_collision_types_on_removal() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            ActiveCollisions::dynamic_only(),
+            GlobalTransform::default(),
+        ))
+        .id();
+
+    app.update();
+
+    app.world.entity_mut(entity).remove::<ActiveCollisions>();
+
+    app.update();
+
+    assert_eq!(
+        active_collision_types(&app, entity),
+        ActiveCollisionTypes::all()
+    );
+}
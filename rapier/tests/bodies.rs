@@ -11,7 +11,7 @@ use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 use bevy::time::TimePlugin;
 
-use heron_core::{CollisionShape, PhysicsSteps, PhysicsTime, RigidBody};
+use heron_core::{Ccd, CollisionShape, PhysicsSteps, PhysicsTime, RigidBody};
 use heron_rapier::convert::{IntoBevy, IntoRapier};
 use heron_rapier::{ColliderHandle, RapierPlugin, RigidBodyHandle};
 use utils::*;
@@ -276,3 +276,37 @@ fn despawn_body_while_paused() {
     let colliders = app.world.resource::<ColliderSet>();
     assert_eq!(colliders.len(), 0);
 }
+
+#[test]
+fn ccd_is_enabled_by_the_component() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 0.1 },
+            GlobalTransform::default(),
+            Ccd,
+        ))
+        .id();
+
+    app.update();
+
+    let is_ccd_enabled = |app: &App| {
+        let handle = app.world.get::<RigidBodyHandle>(entity).unwrap();
+        app.world
+            .resource::<RigidBodySet>()
+            .get(handle.into_rapier())
+            .unwrap()
+            .is_ccd_enabled()
+    };
+
+    assert!(is_ccd_enabled(&app));
+
+    app.world.entity_mut(entity).remove::<Ccd>();
+    app.update();
+
+    assert!(!is_ccd_enabled(&app));
+}
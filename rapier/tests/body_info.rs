@@ -0,0 +1,48 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsBodyInfo, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+#[test]
+fn body_info_is_updated_after_step() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X * 2.0),
+            PhysicsBodyInfo::default(),
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    let info = app.world.get::<PhysicsBodyInfo>(entity).unwrap();
+    assert!(info.mass() > 0.0);
+    assert!((info.linear_momentum().x - info.mass() * 2.0).abs() < 1e-3);
+    assert!((info.kinetic_energy() - info.mass() * 2.0).abs() < 1e-3);
+}
@@ -142,6 +142,7 @@ fn create_kinematic_velocity_based_body() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn create_sensor_body() {
     let mut app = test_app();
 
@@ -206,6 +207,7 @@ fn can_change_to_static_after_creation() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn can_change_to_sensor_after_creation() {
     let mut app = test_app();
 
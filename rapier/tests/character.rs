@@ -0,0 +1,169 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    CharacterBundle, CollisionShape, Gravity, GravityDirection, GroundDetection, PhysicsSteps,
+    RigidBody, Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -10.0))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_ground(app: &mut App) -> Entity {
+    let transform = Transform::from_xyz(0.0, -1.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 1.0, 10.0),
+                border_radius: None,
+            },
+        ))
+        .id()
+}
+
+fn spawn_character(app: &mut App, translation: Vec3) -> Entity {
+    let transform = Transform::from_translation(translation);
+    app.world
+        .spawn()
+        .insert_bundle((transform, GlobalTransform::from(transform)))
+        .insert_bundle(CharacterBundle::default())
+        .id()
+}
+
+#[test]
+fn character_lands_on_the_ground() {
+    let mut app = test_app();
+    spawn_ground(&mut app);
+    let character = spawn_character(&mut app, Vec3::new(0.0, 1.5, 0.0));
+
+    app.update();
+    assert!(!app
+        .world
+        .get::<GroundDetection>(character)
+        .unwrap()
+        .is_grounded());
+
+    for _ in 0..60 {
+        app.update();
+    }
+
+    assert!(app
+        .world
+        .get::<GroundDetection>(character)
+        .unwrap()
+        .is_grounded());
+
+    let transform = app.world.get::<Transform>(character).unwrap();
+    assert!((transform.translation.y - 1.0).abs() < 0.1);
+    assert_eq!(transform.rotation, Quat::IDENTITY);
+}
+
+#[test]
+fn character_reports_the_ground_entity_and_normal() {
+    let mut app = test_app();
+    let ground = spawn_ground(&mut app);
+    let character = spawn_character(&mut app, Vec3::new(0.0, 1.5, 0.0));
+
+    app.update();
+    let detection = app.world.get::<GroundDetection>(character).unwrap();
+    assert_eq!(detection.ground_entity(), None);
+    assert_eq!(detection.ground_normal(), None);
+
+    for _ in 0..60 {
+        app.update();
+    }
+
+    let detection = app.world.get::<GroundDetection>(character).unwrap();
+    assert_eq!(detection.ground_entity(), Some(ground));
+    assert!(detection
+        .ground_normal()
+        .unwrap()
+        .abs_diff_eq(Vec3::Y, 1e-3));
+}
+
+#[test]
+fn ground_is_opposite_to_the_gravity_direction_of_the_body() {
+    let mut app = test_app();
+    let ground = spawn_ground(&mut app);
+    let character = spawn_character(&mut app, Vec3::new(0.0, -3.5, 0.0));
+    app.world
+        .entity_mut(character)
+        .insert(GravityDirection::from(Vec3::Y * 10.0));
+
+    for _ in 0..60 {
+        app.update();
+    }
+
+    let detection = app.world.get::<GroundDetection>(character).unwrap();
+    assert_eq!(detection.ground_entity(), Some(ground));
+    assert!(detection
+        .ground_normal()
+        .unwrap()
+        .abs_diff_eq(-Vec3::Y, 1e-3));
+}
+
+#[test]
+fn character_is_not_grounded_without_ground() {
+    let mut app = test_app();
+    let character = spawn_character(&mut app, Vec3::ZERO);
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert!(!app
+        .world
+        .get::<GroundDetection>(character)
+        .unwrap()
+        .is_grounded());
+}
+
+#[test]
+fn walls_are_not_ground() {
+    let mut app = test_app();
+    let transform = Transform::from_xyz(1.0, 0.0, 0.0);
+    app.world.spawn().insert_bundle((
+        transform,
+        GlobalTransform::from(transform),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(0.5, 10.0, 10.0),
+            border_radius: None,
+        },
+    ));
+    let character = spawn_character(&mut app, Vec3::ZERO);
+
+    for _ in 0..60 {
+        app.world.get_mut::<Velocity>(character).unwrap().linear.x = 10.0;
+        app.update();
+    }
+
+    assert!(app.world.get::<Transform>(character).unwrap().translation.x > 0.0);
+    assert!(!app
+        .world
+        .get::<GroundDetection>(character)
+        .unwrap()
+        .is_grounded());
+}
@@ -148,3 +148,145 @@ fn updating_local_transform_of_a_rigid_body_doesnt_affect_the_shape() {
 
     assert_eq!(actual_translation, Vec3::default());
 }
+
+#[test]
+fn uses_the_child_transform_added_after_the_collider_creation() {
+    let mut app = test_app();
+
+    let shape_entity = app
+        .world
+        .spawn()
+        .insert(CollisionShape::Sphere { radius: 1.0 })
+        .id();
+
+    app.world
+        .spawn()
+        .insert_bundle((GlobalTransform::default(), RigidBody::Dynamic))
+        .push_children(&[shape_entity]);
+
+    app.update();
+
+    app.world
+        .entity_mut(shape_entity)
+        .insert(Transform::from_xyz(1.0, 2.0, 0.0));
+
+    app.update();
+
+    let colliders = app.world.resource::<ColliderSet>();
+    let (_, collider) = colliders.iter().next().unwrap();
+    let (actual_translation, _) = collider.position_wrt_parent().unwrap().into_bevy();
+
+    assert_eq!(actual_translation, Vec3::new(1.0, 2.0, 0.0));
+}
+
+#[test]
+fn resets_the_position_when_the_child_transform_is_removed() {
+    let mut app = test_app();
+
+    let shape_entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_xyz(1.0, 2.0, 0.0),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.world
+        .spawn()
+        .insert_bundle((GlobalTransform::default(), RigidBody::Dynamic))
+        .push_children(&[shape_entity]);
+
+    app.update();
+
+    app.world.entity_mut(shape_entity).remove::<Transform>();
+
+    app.update();
+
+    let colliders = app.world.resource::<ColliderSet>();
+    let (_, collider) = colliders.iter().next().unwrap();
+    let (actual_translation, _) = collider.position_wrt_parent().unwrap().into_bevy();
+
+    assert_eq!(actual_translation, Vec3::ZERO);
+}
+
+#[test]
+fn shape_follows_the_parent_rigid_body() {
+    let mut app = test_app();
+
+    let shape_entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_xyz(1.0, 0.0, 0.0),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicPositionBased,
+        ))
+        .push_children(&[shape_entity])
+        .id();
+
+    app.update();
+
+    app.world.get_mut::<Transform>(body).unwrap().translation = Vec3::new(0.0, 2.0, 0.0);
+
+    app.update();
+
+    let colliders = app.world.resource::<ColliderSet>();
+    let (_, collider) = colliders.iter().next().unwrap();
+    let (actual_translation, _) = collider.position().into_bevy();
+
+    assert_eq!(actual_translation, Vec3::new(1.0, 2.0, 0.0));
+}
+
+#[test]
+fn moves_the_shape_to_the_new_parent_rigid_body() {
+    let mut app = test_app();
+
+    let shape_entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_xyz(1.0, 0.0, 0.0),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.world
+        .spawn()
+        .insert_bundle((GlobalTransform::default(), RigidBody::Dynamic))
+        .push_children(&[shape_entity]);
+
+    let new_parent = app
+        .world
+        .spawn()
+        .insert_bundle((GlobalTransform::from_xyz(0.0, 5.0, 0.0), RigidBody::Static))
+        .id();
+
+    app.update();
+
+    app.world
+        .entity_mut(new_parent)
+        .push_children(&[shape_entity]);
+
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    let colliders = app.world.resource::<ColliderSet>();
+    assert_eq!(colliders.len(), 1);
+
+    let (_, collider) = colliders.iter().next().unwrap();
+    let parent = bodies.get(collider.parent().unwrap()).unwrap();
+    assert!(parent.is_fixed());
+
+    let (actual_translation, _) = collider.position().into_bevy();
+    assert_eq!(actual_translation, Vec3::new(1.0, 5.0, 0.0));
+}
@@ -0,0 +1,92 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionLayers, CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::{ClosestColliderInfo, PhysicsWorld, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_ball(app: &mut App, x: f32) -> Entity {
+    let transform = Transform::from_xyz(x, 0.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+fn closest(
+    app: &mut App,
+    point: Vec3,
+    max_distance: f32,
+    layers: CollisionLayers,
+) -> Option<ClosestColliderInfo> {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state
+        .get_mut(&mut app.world)
+        .closest_collider(point, max_distance, layers)
+}
+
+#[test]
+fn returns_the_closest_entity() {
+    let mut app = test_app();
+    let _far_ball = spawn_ball(&mut app, -10.0);
+    let near_ball = spawn_ball(&mut app, 5.0);
+    app.update();
+
+    let result = closest(&mut app, Vec3::ZERO, 10.0, CollisionLayers::default()).unwrap();
+
+    assert_eq!(result.entity, near_ball);
+    assert!((result.distance - 4.0).abs() < 0.001);
+    assert!((result.point - Vec3::X * 4.0).length() < 0.001);
+}
+
+#[test]
+fn returns_none_if_nothing_is_within_max_distance() {
+    let mut app = test_app();
+    let _ball = spawn_ball(&mut app, 5.0);
+    app.update();
+
+    assert!(closest(&mut app, Vec3::ZERO, 3.0, CollisionLayers::default()).is_none());
+}
+
+#[test]
+fn distance_is_zero_inside_a_shape() {
+    let mut app = test_app();
+    let ball = spawn_ball(&mut app, 0.0);
+    app.update();
+
+    let result = closest(&mut app, Vec3::X * 0.5, 1.0, CollisionLayers::default()).unwrap();
+
+    assert_eq!(result.entity, ball);
+    assert_eq!(result.distance, 0.0);
+}
+
+#[test]
+fn ignores_shapes_not_interacting_with_layers() {
+    let mut app = test_app();
+    let _ball = spawn_ball(&mut app, 5.0);
+    app.update();
+
+    assert!(closest(&mut app, Vec3::ZERO, 10.0, CollisionLayers::none()).is_none());
+}
@@ -0,0 +1,57 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{ColliderAabb, CollisionShape, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+#[test]
+fn aabb_is_updated_after_each_step() {
+    let mut app = test_app();
+
+    let transform = Transform::from_xyz(2.0, 3.0, 0.0);
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::KinematicVelocityBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+            ColliderAabb::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let aabb = *app.world.get::<ColliderAabb>(entity).unwrap();
+    assert_eq!(aabb.center().truncate(), Vec2::new(3.0, 3.0));
+    assert_eq!(aabb.half_extents().truncate(), Vec2::new(1.0, 1.0));
+
+    #[cfg(dim3)]
+    assert_eq!(aabb.half_extents().z, 1.0);
+    #[cfg(dim2)]
+    assert_eq!(aabb.half_extents().z, 0.0);
+
+    app.update();
+
+    let aabb = *app.world.get::<ColliderAabb>(entity).unwrap();
+    assert_eq!(aabb.center().truncate(), Vec2::new(4.0, 3.0));
+}
@@ -0,0 +1,156 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::event::Events;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    ColliderCreationFailed, ColliderCreationFailure, CollisionShape, CustomCollisionShape,
+    InvalidCollisionShape, MissingRigidBody, PhysicsSteps, RigidBody,
+};
+use heron_rapier::{ColliderHandle, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn failures(app: &App) -> Vec<ColliderCreationFailed> {
+    let events = app.world.resource::<Events<ColliderCreationFailed>>();
+    events.get_reader().iter(events).cloned().collect()
+}
+
+#[test]
+fn missing_rigid_body_is_reported_once() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    assert_eq!(
+        failures(&app),
+        vec![ColliderCreationFailed {
+            entity,
+            reason: ColliderCreationFailure::MissingRigidBody
+        }]
+    );
+
+    app.world.entity_mut(entity).insert(RigidBody::Static);
+    app.update();
+
+    assert!(app.world.get::<ColliderHandle>(entity).is_some());
+}
+
+#[test]
+fn static_rigid_body_can_be_inserted_for_orphan_shapes() {
+    let mut app = test_app();
+    app.insert_resource(MissingRigidBody::InsertStatic);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.update();
+    app.update();
+    app.update();
+
+    assert!(failures(&app).is_empty());
+    assert_eq!(
+        app.world.get::<RigidBody>(entity).copied(),
+        Some(RigidBody::Static)
+    );
+    assert!(app.world.get::<ColliderHandle>(entity).is_some());
+}
+
+#[test]
+fn unsupported_custom_shape_is_reported_instead_of_panicking() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Custom {
+                shape: CustomCollisionShape::new(()),
+            },
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        failures(&app),
+        vec![ColliderCreationFailed {
+            entity,
+            reason: ColliderCreationFailure::UnsupportedCustomShape { type_name: "()" }
+        }]
+    );
+    assert!(app.world.get::<ColliderHandle>(entity).is_none());
+}
+
+#[test]
+fn degenerate_convex_hull_is_marked_instead_of_panicking() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::ConvexHull {
+                points: vec![Vec3::ZERO; 4],
+                border_radius: None,
+            },
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    let failures = failures(&app);
+    assert_eq!(failures.len(), 1);
+    assert!(matches!(
+        failures[0],
+        ColliderCreationFailed {
+            entity: e,
+            reason: ColliderCreationFailure::InvalidShape { .. }
+        } if e == entity
+    ));
+    assert!(app.world.get::<InvalidCollisionShape>(entity).is_some());
+    assert!(app.world.get::<ColliderHandle>(entity).is_none());
+
+    app.world
+        .entity_mut(entity)
+        .insert(CollisionShape::Sphere { radius: 1.0 });
+    app.update();
+
+    assert!(app.world.get::<InvalidCollisionShape>(entity).is_none());
+    assert!(app.world.get::<ColliderHandle>(entity).is_some());
+}
@@ -0,0 +1,94 @@
+#![cfg(all(any(dim2, dim3), feature = "collider-debug-info"))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    ColliderDebugInfo, CollisionLayers, CollisionShape, LayerNames, PhysicsLayer, PhysicsSteps,
+    RigidBody,
+};
+use heron_rapier::RapierPlugin;
+
+enum Layer {
+    World,
+    Player,
+}
+
+impl PhysicsLayer for Layer {
+    fn to_bits(&self) -> u32 {
+        match self {
+            Layer::World => 1,
+            Layer::Player => 2,
+        }
+    }
+
+    fn all_bits() -> u32 {
+        3
+    }
+}
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+#[test]
+fn debug_info_is_inserted_and_updated() {
+    let mut app = test_app();
+    {
+        let mut names = app.world.resource_mut::<LayerNames>();
+        names.insert(Layer::World, "World");
+        names.insert(Layer::Player, "Player");
+    }
+
+    let transform = Transform::from_xyz(1.0, 2.0, 0.0);
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(1.0, 2.0, 3.0),
+                border_radius: None,
+            },
+            CollisionLayers::none()
+                .with_group(Layer::Player)
+                .with_mask(Layer::World),
+        ))
+        .id();
+
+    app.update();
+
+    let info = app.world.get::<ColliderDebugInfo>(entity).unwrap().clone();
+    assert_eq!(info.shape_kind, "Cuboid");
+    assert_eq!(info.layer_names, vec!["Player"]);
+    assert_eq!(info.aabb.center().truncate(), Vec2::new(1.0, 2.0));
+
+    #[cfg(dim2)]
+    assert_eq!(info.volume, 8.0);
+    #[cfg(dim3)]
+    assert_eq!(info.volume, 48.0);
+
+    app.world
+        .entity_mut(entity)
+        .insert(CollisionShape::Sphere { radius: 1.0 })
+        .insert(CollisionLayers::none().with_group(Layer::World));
+    app.update();
+    app.update();
+
+    let info = app.world.get::<ColliderDebugInfo>(entity).unwrap();
+    assert_eq!(info.shape_kind, "Sphere");
+    assert_eq!(info.layer_names, vec!["World"]);
+}
@@ -0,0 +1,87 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{ColliderOffset, CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::convert::{IntoBevy, IntoRapier};
+use heron_rapier::{ColliderHandle, RapierPlugin};
+
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn collider_translation(app: &App, entity: Entity) -> Vec3 {
+    let colliders = app.world.resource::<ColliderSet>();
+    let handle = app.world.get::<ColliderHandle>(entity).unwrap();
+    let (translation, _) = colliders
+        .get(handle.into_rapier())
+        .unwrap()
+        .position()
+        .into_bevy();
+    translation
+}
+
+#[test]
+fn collision_shape_on_rigid_body_can_be_offset() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::from_translation(Vec3::X),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+            ColliderOffset::from_translation(Vec3::Y * 2.0),
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(collider_translation(&app, entity), Vec3::new(1.0, 2.0, 0.0));
+}
+
+#[test]
+fn offset_can_be_updated_and_removed() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(ColliderOffset::from_translation(Vec3::X));
+    app.update();
+
+    assert_eq!(collider_translation(&app, entity), Vec3::X);
+
+    app.world.entity_mut(entity).remove::<ColliderOffset>();
+    app.update();
+
+    assert_eq!(collider_translation(&app, entity), Vec3::ZERO);
+}
@@ -0,0 +1,69 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionLayers, CollisionShape, PhysicsSteps, RigidBody, Sensor};
+use heron_rapier::{CollisionShapeInfo, PhysicsWorld, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn collision_shapes(app: &mut App) -> Vec<CollisionShapeInfo> {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state.get_mut(&mut app.world).collision_shapes().collect()
+}
+
+#[test]
+fn returns_the_footprint_of_each_collision_shape() {
+    let mut app = test_app();
+    let transform = Transform::from_xyz(5.0, 0.0, 0.0);
+    let ball = app
+        .world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+            CollisionLayers::from_bits(1, 2),
+        ))
+        .id();
+    let sensor = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            Sensor,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.update();
+
+    let shapes = collision_shapes(&mut app);
+    assert_eq!(shapes.len(), 2);
+
+    let ball_info = shapes.iter().find(|shape| shape.entity == ball).unwrap();
+    assert!((ball_info.aabb.center() - Vec3::X * 5.0).length() < 0.001);
+    assert!((ball_info.aabb.max().x - 6.0).abs() < 0.001);
+    assert_eq!(ball_info.layers, CollisionLayers::from_bits(1, 2));
+    assert!(!ball_info.is_sensor);
+
+    let sensor_info = shapes.iter().find(|shape| shape.entity == sensor).unwrap();
+    assert!(sensor_info.is_sensor);
+}
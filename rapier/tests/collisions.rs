@@ -0,0 +1,132 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, Collisions, Gravity, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_millis(16)))
+        .insert_resource(Gravity::from(Vec3::Y * -9.81))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn normal(app: &App, entity: Entity, other: Entity) -> Vec3 {
+    app.world
+        .get::<Collisions>(entity)
+        .unwrap()
+        .get(&other)
+        .expect("the entities should be colliding")
+        .normals()[0]
+}
+
+#[test]
+fn normals_are_refreshed_while_the_contact_persists() {
+    let mut app = test_app();
+
+    let ground_transform = Transform::from_xyz(0.0, -1.0, 0.0);
+    let ground = app
+        .world
+        .spawn()
+        .insert_bundle((
+            ground_transform,
+            GlobalTransform::from(ground_transform),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 1.0, 10.0),
+                border_radius: None,
+            },
+        ))
+        .id();
+
+    let ball_transform = Transform::from_xyz(0.0, 0.99, 0.0);
+    let ball = app
+        .world
+        .spawn()
+        .insert_bundle((
+            ball_transform,
+            GlobalTransform::from(ball_transform),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Collisions::default(),
+        ))
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(normal(&app, ball, ground).x.abs() < 0.01);
+
+    app.world.get_mut::<Transform>(ground).unwrap().rotation = Quat::from_rotation_z(0.3);
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(normal(&app, ball, ground).x.abs() > 0.1);
+}
+
+#[test]
+fn normals_of_all_the_touching_shapes_are_reported() {
+    let mut app = test_app();
+
+    let ground_transform = Transform::from_xyz(0.0, -1.0, 0.0);
+    let ground = app
+        .world
+        .spawn()
+        .insert_bundle((
+            ground_transform,
+            GlobalTransform::from(ground_transform),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 1.0, 10.0),
+                border_radius: None,
+            },
+            Collisions::default(),
+        ))
+        .id();
+
+    let body_transform = Transform::from_xyz(0.0, 0.49, 0.0);
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            body_transform,
+            GlobalTransform::from(body_transform),
+            RigidBody::Dynamic,
+        ))
+        .with_children(|children| {
+            for x in [-2.0, 2.0] {
+                children.spawn_bundle((
+                    Transform::from_xyz(x, 0.0, 0.0),
+                    GlobalTransform::default(),
+                    CollisionShape::Sphere { radius: 0.5 },
+                ));
+            }
+        })
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let normals = app
+        .world
+        .get::<Collisions>(ground)
+        .unwrap()
+        .get(&body)
+        .expect("the entities should be colliding")
+        .normals();
+    assert_eq!(normals.len(), 2);
+}
@@ -8,7 +8,7 @@ use bevy::reflect::TypeRegistryArc;
 use bevy::time::TimePlugin;
 
 use heron_core::utils::NearZero;
-use heron_core::{CollisionShape, PhysicMaterial, PhysicsSteps, RigidBody};
+use heron_core::{ColliderMass, CollisionShape, PhysicMaterial, PhysicsSteps, RigidBody};
 use heron_rapier::convert::{IntoBevy, IntoRapier};
 use heron_rapier::RapierPlugin;
 use utils::*;
@@ -125,3 +125,122 @@ fn density_can_be_updated_after_creation() {
 
     assert_eq!(body.mass_properties(), &MassProperties::from_ball(2.0, 1.0));
 }
+
+fn body_mass(app: &App, entity: Entity) -> f32 {
+    app.world
+        .resource::<RigidBodySet>()
+        .get(
+            app.world
+                .get::<heron_rapier::RigidBodyHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap()
+        .mass()
+}
+
+#[test]
+fn collider_mass_overrides_the_material_density() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            PhysicMaterial {
+                density: 2.0,
+                ..Default::default()
+            },
+            ColliderMass::Mass(10.0),
+        ))
+        .id();
+
+    app.update();
+
+    assert!((body_mass(&app, entity) - 10.0).abs() < 1e-3);
+}
+
+#[test]
+fn collider_with_zero_mass_does_not_contribute_to_the_body_mass() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            ColliderMass::Mass(5.0),
+        ))
+        .with_children(|children| {
+            children.spawn_bundle((
+                CollisionShape::Sphere { radius: 10.0 },
+                ColliderMass::Zero,
+                Transform::from_xyz(0.0, 20.0, 0.0),
+                GlobalTransform::default(),
+            ));
+        })
+        .id();
+
+    app.update();
+
+    assert!((body_mass(&app, entity) - 5.0).abs() < 1e-3);
+}
+
+#[test]
+fn collider_density_can_be_overridden() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.update();
+    let default_mass = body_mass(&app, entity);
+
+    app.world
+        .entity_mut(entity)
+        .insert(ColliderMass::Density(3.0));
+    app.update();
+
+    assert!((body_mass(&app, entity) - default_mass * 3.0).abs() < 1e-3);
+
+    app.world.entity_mut(entity).remove::<ColliderMass>();
+    app.update();
+
+    assert!((body_mass(&app, entity) - default_mass).abs() < 1e-3);
+}
+
+#[test]
+fn default_collider_mass_keeps_the_material_density() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.update();
+    let default_mass = body_mass(&app, entity);
+
+    app.world.entity_mut(entity).insert(ColliderMass::default());
+    app.update();
+
+    assert!(default_mass > 0.0);
+    assert!((body_mass(&app, entity) - default_mass).abs() < 1e-3);
+}
@@ -0,0 +1,151 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::{Duration, Instant};
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::{Time, TimePlugin};
+
+use bevy::ecs::event::Events;
+use heron_core::{
+    CollisionLayers, CollisionShape, PhysicsDiagnostics, PhysicsStepEvent, PhysicsSteps, RigidBody,
+    Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+#[test]
+fn step_count_and_simulation_time_increase_after_each_step() {
+    let mut app = test_app();
+
+    app.update();
+    app.update();
+
+    let diagnostics = app.world.resource::<PhysicsDiagnostics>();
+    assert_eq!(diagnostics.step_count, 2);
+    assert_eq!(diagnostics.simulation_time, Duration::from_secs(2));
+}
+
+#[test]
+fn step_events_are_fired_after_each_step() {
+    let mut app = test_app();
+    let mut reader = app
+        .world
+        .resource::<Events<PhysicsStepEvent>>()
+        .get_reader();
+
+    app.update();
+    app.update();
+
+    let events: Vec<PhysicsStepEvent> = reader
+        .iter(app.world.resource::<Events<PhysicsStepEvent>>())
+        .copied()
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            PhysicsStepEvent {
+                step: 1,
+                duration: Duration::from_secs(1)
+            },
+            PhysicsStepEvent {
+                step: 2,
+                duration: Duration::from_secs(1)
+            },
+        ]
+    );
+}
+
+#[test]
+fn slow_frames_perform_one_fixed_size_step_per_elapsed_interval() {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .init_resource::<Time>()
+        .insert_resource(PhysicsSteps::from_delta_time(Duration::from_millis(10)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    let mut reader = app
+        .world
+        .resource::<Events<PhysicsStepEvent>>()
+        .get_reader();
+
+    let start = Instant::now();
+    for elapsed in [Duration::ZERO, Duration::from_millis(35)] {
+        app.world
+            .resource_mut::<Time>()
+            .update_with_instant(start + elapsed);
+        app.update();
+    }
+
+    let events: Vec<PhysicsStepEvent> = reader
+        .iter(app.world.resource::<Events<PhysicsStepEvent>>())
+        .copied()
+        .collect();
+    assert_eq!(
+        events,
+        (1..=3)
+            .map(|step| PhysicsStepEvent {
+                step,
+                duration: Duration::from_millis(10)
+            })
+            .collect::<Vec<_>>()
+    );
+    let diagnostics = app.world.resource::<PhysicsDiagnostics>();
+    assert_eq!(diagnostics.simulation_time, Duration::from_millis(30));
+}
+
+#[test]
+fn counts_active_bodies_and_contact_pairs() {
+    let mut app = test_app();
+
+    for x in [0.0, 1.0] {
+        app.world.spawn().insert_bundle((
+            Transform::from_translation(Vec3::X * x),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::Y * 10.0),
+        ));
+    }
+
+    app.update();
+
+    let diagnostics = app.world.resource::<PhysicsDiagnostics>();
+    assert_eq!(diagnostics.active_bodies, 2);
+    assert_eq!(diagnostics.contact_pairs, 1);
+    assert_eq!(diagnostics.joints, 0);
+}
+
+#[test]
+fn counts_pairs_filtered_by_layers() {
+    let mut app = test_app();
+
+    for layers in [CollisionLayers::default(), CollisionLayers::none()] {
+        app.world.spawn().insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            layers,
+        ));
+    }
+
+    app.update();
+    app.update();
+
+    let diagnostics = app.world.resource::<PhysicsDiagnostics>();
+    assert_eq!(diagnostics.candidate_pairs, 1);
+    assert_eq!(diagnostics.filtered_pairs, 1);
+    assert_eq!(diagnostics.contact_pairs, 0);
+}
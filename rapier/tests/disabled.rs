@@ -0,0 +1,123 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::event::Events;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    ColliderCreationFailed, CollisionShape, PhysicsDisabled, PhysicsSteps, RigidBody, Velocity,
+};
+use heron_rapier::{ColliderHandle, RapierPlugin, RigidBodyHandle};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicVelocityBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id()
+}
+
+#[test]
+fn disabled_body_is_removed_from_the_simulation() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.update();
+
+    app.world.entity_mut(entity).insert(PhysicsDisabled);
+    app.update();
+
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_none());
+    assert!(app.world.get::<ColliderHandle>(entity).is_none());
+    assert_eq!(app.world.resource::<RigidBodySet>().len(), 0);
+    assert_eq!(app.world.resource::<ColliderSet>().len(), 0);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        Vec3::X
+    );
+}
+
+#[test]
+fn body_is_added_back_when_enabled() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.world.entity_mut(entity).insert(PhysicsDisabled);
+    app.update();
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_none());
+
+    app.world.entity_mut(entity).remove::<PhysicsDisabled>();
+    app.update();
+
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_some());
+    assert!(app.world.get::<ColliderHandle>(entity).is_some());
+    assert_eq!(app.world.resource::<RigidBodySet>().len(), 1);
+}
+
+#[test]
+fn disabling_a_child_shape_only_removes_its_collider() {
+    let mut app = test_app();
+    let body = spawn_body(&mut app);
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.world.entity_mut(body).push_children(&[child]);
+    app.update();
+    assert_eq!(app.world.resource::<ColliderSet>().len(), 2);
+
+    app.world.entity_mut(child).insert(PhysicsDisabled);
+    app.update();
+
+    assert!(app.world.get::<RigidBodyHandle>(body).is_some());
+    assert!(app.world.get::<ColliderHandle>(child).is_none());
+    assert_eq!(app.world.resource::<ColliderSet>().len(), 1);
+}
+
+#[test]
+fn shapes_of_disabled_body_are_not_reported_as_failures() {
+    let mut app = test_app();
+    let body = spawn_body(&mut app);
+    app.world.entity_mut(body).insert(PhysicsDisabled);
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.world.entity_mut(body).push_children(&[child]);
+    app.update();
+
+    let events = app.world.resource::<Events<ColliderCreationFailed>>();
+    assert_eq!(events.get_reader().iter(events).count(), 0);
+    assert!(app.world.get::<ColliderHandle>(child).is_none());
+}
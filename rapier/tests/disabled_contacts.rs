@@ -0,0 +1,146 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    CollisionEvent, CollisionShape, DisabledContacts, NoSelfCollision, PhysicsSteps, RigidBody,
+    Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_millis(16)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn(app: &mut App, x: f32, body: RigidBody, velocity: Vec3) -> Entity {
+    let transform = Transform::from_xyz(x, 0.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            body,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(velocity),
+        ))
+        .id()
+}
+
+/// Returns the position of the ball after two seconds, and the number of collisions started
+fn run_two_seconds(app: &mut App, ball: Entity) -> (f32, usize) {
+    let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+    let mut started = 0;
+    for _ in 0..120 {
+        app.update();
+        let events = app.world.resource::<Events<CollisionEvent>>();
+        started += reader
+            .iter(events)
+            .filter(|event| event.is_started())
+            .count();
+    }
+    let x = app.world.get::<Transform>(ball).unwrap().translation.x;
+    (x, started)
+}
+
+#[test]
+fn bodies_collide_by_default() {
+    let mut app = test_app();
+    spawn(&mut app, 0.0, RigidBody::Static, Vec3::ZERO);
+    let ball = spawn(&mut app, -5.0, RigidBody::Dynamic, Vec3::X * 10.0);
+
+    assert!(run_two_seconds(&mut app, ball).0 < 0.0);
+}
+
+#[test]
+fn disabled_contacts_let_the_bodies_go_through_each_other() {
+    let mut app = test_app();
+    let wall = spawn(&mut app, 0.0, RigidBody::Static, Vec3::ZERO);
+    let ball = spawn(&mut app, -5.0, RigidBody::Dynamic, Vec3::X * 10.0);
+    app.world
+        .resource_mut::<DisabledContacts>()
+        .disable(wall, ball);
+
+    let (x, started) = run_two_seconds(&mut app, ball);
+    assert!(x > 2.0);
+    assert!(started > 0);
+}
+
+#[test]
+fn contacts_can_be_enabled_again() {
+    let mut app = test_app();
+    let wall = spawn(&mut app, 0.0, RigidBody::Static, Vec3::ZERO);
+    let ball = spawn(&mut app, -5.0, RigidBody::Dynamic, Vec3::X * 10.0);
+
+    app.world
+        .resource_mut::<DisabledContacts>()
+        .disable(ball, wall);
+    app.update();
+    app.world
+        .resource_mut::<DisabledContacts>()
+        .enable(wall, ball);
+
+    assert!(run_two_seconds(&mut app, ball).0 < 0.0);
+}
+
+#[test]
+fn shapes_of_a_hierarchy_without_self_collision_go_through_each_other() {
+    let mut app = test_app();
+    let wall = spawn(&mut app, 0.0, RigidBody::Static, Vec3::ZERO);
+    let ball = spawn(&mut app, -5.0, RigidBody::Dynamic, Vec3::X * 10.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            NoSelfCollision,
+        ))
+        .push_children(&[wall, ball]);
+
+    let (x, started) = run_two_seconds(&mut app, ball);
+    assert!(x > 2.0);
+    assert_eq!(started, 0);
+}
+
+#[test]
+fn shapes_of_nested_hierarchies_without_self_collision_go_through_each_other() {
+    let mut app = test_app();
+    let wall = spawn(&mut app, 0.0, RigidBody::Static, Vec3::ZERO);
+    let ball = spawn(&mut app, -5.0, RigidBody::Dynamic, Vec3::X * 10.0);
+    let outer = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            NoSelfCollision,
+        ))
+        .push_children(&[wall])
+        .id();
+    let inner = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            NoSelfCollision,
+        ))
+        .push_children(&[ball])
+        .id();
+    app.world.entity_mut(outer).push_children(&[inner]);
+
+    let (x, started) = run_two_seconds(&mut app, ball);
+    assert!(x > 2.0);
+    assert_eq!(started, 0);
+}
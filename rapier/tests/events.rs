@@ -1,4 +1,6 @@
 #![cfg(any(dim2, dim3))]
+// Keep testing `RigidBody::Sensor` as long as it is supported
+#![allow(deprecated)]
 
 use std::time::Duration;
 
@@ -96,6 +98,8 @@ fn collision_events_are_fired(#[case] type1: RigidBody, #[case] type2: RigidBody
     assert!(matches!(&events[1], CollisionEvent::Stopped(_, _)));
     assert_eq!(events[0].collision_shape_entities(), (entity1, entity2));
     assert_eq!(events[1].collision_shape_entities(), (entity1, entity2));
+    assert!(events[0].step() > 0);
+    assert_eq!(events[1].step(), events[0].step() + 1);
 
     match (type1, type2) {
         (RigidBody::Sensor, RigidBody::Dynamic) => {
@@ -149,3 +153,50 @@ fn collect_events(
     let events = app.world.resource::<Events<CollisionEvent>>();
     reader.iter(events).cloned().collect()
 }
+
+#[rstest]
+#[case(RigidBody::Sensor, RigidBody::Dynamic)]
+#[case(RigidBody::Dynamic, RigidBody::Dynamic)]
+fn stopped_event_is_fired_when_an_entity_is_despawned(
+    #[case] type1: RigidBody,
+    #[case] type2: RigidBody,
+) {
+    let mut app = test_app();
+
+    let entity1 = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 10.0 },
+            type1,
+        ))
+        .id();
+
+    let entity2 = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::X * 5.0),
+            GlobalTransform::from_translation(Vec3::X * 5.0),
+            CollisionShape::Sphere { radius: 10.0 },
+            type2,
+        ))
+        .id();
+
+    let mut event_reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+
+    app.update();
+    let events = collect_events(&app, &mut event_reader);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], CollisionEvent::Started(_, _)));
+
+    app.world.despawn(entity2);
+    app.update();
+
+    let events = collect_events(&app, &mut event_reader);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], CollisionEvent::Stopped(_, _)));
+    assert_eq!(events[0].collision_shape_entities(), (entity1, entity2));
+}
@@ -0,0 +1,127 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, FluidArea, Gravity, PhysicsSteps, RigidBody, Sensor, Velocity};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_area(app: &mut App, area: FluidArea) {
+    app.world.spawn().insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        RigidBody::Static,
+        Sensor,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::splat(10.0),
+            border_radius: None,
+        },
+        area,
+    ));
+}
+
+fn spawn_body(app: &mut App, translation: Vec3, velocity: Velocity) -> Entity {
+    let transform = Transform::from_translation(translation);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 0.5 },
+            velocity,
+        ))
+        .id()
+}
+
+fn velocity_after_updates(app: &mut App, entity: Entity, updates: usize) -> Velocity {
+    for _ in 0..updates {
+        app.update();
+    }
+    *app.world.get::<Velocity>(entity).unwrap()
+}
+
+#[test]
+fn lighter_bodies_float() {
+    let mut app = test_app();
+    app.insert_resource(Gravity::from(Vec3::Y * -10.0));
+    spawn_area(&mut app, FluidArea::new(2.0));
+    let body = spawn_body(&mut app, Vec3::ZERO, Velocity::default());
+
+    assert!(velocity_after_updates(&mut app, body, 10).linear.y > 0.0);
+}
+
+#[test]
+fn heavier_bodies_sink() {
+    let mut app = test_app();
+    app.insert_resource(Gravity::from(Vec3::Y * -10.0));
+    spawn_area(&mut app, FluidArea::new(0.5));
+    let body = spawn_body(&mut app, Vec3::ZERO, Velocity::default());
+
+    let velocity = velocity_after_updates(&mut app, body, 10).linear.y;
+    assert!(velocity < 0.0);
+    assert!(velocity > -10.0 * 10.0 / 60.0);
+}
+
+#[test]
+fn drag_slows_down_bodies() {
+    let mut app = test_app();
+    spawn_area(&mut app, FluidArea::new(0.0).with_linear_drag(5.0));
+    let body = spawn_body(&mut app, Vec3::ZERO, Velocity::from_linear(Vec3::X * 10.0));
+
+    let velocity = velocity_after_updates(&mut app, body, 10).linear.x;
+    assert!(velocity > 0.0);
+    assert!(velocity < 5.0);
+}
+
+#[test]
+fn flow_drags_bodies_along() {
+    let mut app = test_app();
+    spawn_area(
+        &mut app,
+        FluidArea::new(0.0)
+            .with_linear_drag(5.0)
+            .with_flow_velocity(Vec3::X * 10.0),
+    );
+    let body = spawn_body(&mut app, Vec3::ZERO, Velocity::default());
+
+    let velocity = velocity_after_updates(&mut app, body, 10).linear.x;
+    assert!(velocity > 5.0);
+    assert!(velocity <= 10.0);
+}
+
+#[test]
+fn bodies_outside_the_area_are_not_affected() {
+    let mut app = test_app();
+    spawn_area(
+        &mut app,
+        FluidArea::new(2.0)
+            .with_linear_drag(5.0)
+            .with_flow_velocity(Vec3::X * 10.0),
+    );
+    let body = spawn_body(
+        &mut app,
+        Vec3::Y * 20.0,
+        Velocity::from_linear(Vec3::X * 10.0),
+    );
+
+    let velocity = velocity_after_updates(&mut app, body, 10);
+    assert_eq!(velocity.linear, Vec3::X * 10.0);
+}
@@ -0,0 +1,108 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    Acceleration, CollisionShape, Force, PhysicMaterial, PhysicsSteps, RigidBody, Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, density: f32) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            PhysicMaterial {
+                density,
+                ..Default::default()
+            },
+            Velocity::default(),
+        ))
+        .id()
+}
+
+#[test]
+fn heavier_bodies_accelerate_less_with_the_same_force() {
+    let mut app = test_app();
+
+    let light = spawn_body(&mut app, 1.0);
+    let heavy = spawn_body(&mut app, 2.0);
+
+    for entity in [light, heavy] {
+        app.world
+            .entity_mut(entity)
+            .insert(Force::from_linear(Vec3::X * 10.0));
+    }
+
+    app.update();
+    app.update();
+
+    let light_velocity = app.world.get::<Velocity>(light).unwrap().linear.x;
+    let heavy_velocity = app.world.get::<Velocity>(heavy).unwrap().linear.x;
+
+    assert!(heavy_velocity > 0.0);
+    assert!((light_velocity - heavy_velocity * 2.0).abs() < 0.001);
+}
+
+#[test]
+fn acceleration_does_not_depend_on_mass() {
+    let mut app = test_app();
+
+    let light = spawn_body(&mut app, 1.0);
+    let heavy = spawn_body(&mut app, 2.0);
+
+    for entity in [light, heavy] {
+        app.world
+            .entity_mut(entity)
+            .insert(Acceleration::from_linear(Vec3::X));
+    }
+
+    app.update();
+    app.update();
+
+    let light_velocity = app.world.get::<Velocity>(light).unwrap().linear;
+    let heavy_velocity = app.world.get::<Velocity>(heavy).unwrap().linear;
+
+    assert!((light_velocity - Vec3::X).length() < 0.001);
+    assert!((heavy_velocity - Vec3::X).length() < 0.001);
+}
+
+#[test]
+fn removing_the_force_stops_accelerating() {
+    let mut app = test_app();
+
+    let entity = spawn_body(&mut app, 1.0);
+    app.world
+        .entity_mut(entity)
+        .insert(Force::from_linear(Vec3::X * 10.0));
+
+    app.update();
+    app.update();
+
+    app.world.entity_mut(entity).remove::<Force>();
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+
+    app.update();
+
+    let new_velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!((new_velocity - velocity).length() < 0.001);
+}
@@ -0,0 +1,174 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    CollisionShape, DisableGravity, Gravity, GravityArea, GravityDirection, GravityScale,
+    PhysicsSteps, RigidBody, Sensor, Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -10.0))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_area(app: &mut App, area: GravityArea) {
+    app.world.spawn().insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        RigidBody::Static,
+        Sensor,
+        CollisionShape::Sphere { radius: 10.0 },
+        area,
+    ));
+}
+
+fn spawn_body(app: &mut App, translation: Vec3) -> Entity {
+    let transform = Transform::from_translation(translation);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 0.5 },
+            Velocity::default(),
+        ))
+        .id()
+}
+
+fn velocity_after_updates(app: &mut App, entity: Entity, updates: usize) -> Vec3 {
+    for _ in 0..updates {
+        app.update();
+    }
+    app.world.get::<Velocity>(entity).unwrap().linear
+}
+
+#[test]
+fn gravity_direction_overrides_global_gravity() {
+    let mut app = test_app();
+    let body = spawn_body(&mut app, Vec3::ZERO);
+    app.world
+        .entity_mut(body)
+        .insert(GravityDirection::from(Vec3::X * 10.0));
+
+    let velocity = velocity_after_updates(&mut app, body, 10);
+    assert!(velocity.x > 1.0);
+    assert!(velocity.y.abs() < 0.001);
+}
+
+#[test]
+fn global_gravity_is_restored_when_the_override_is_removed() {
+    let mut app = test_app();
+    let body = spawn_body(&mut app, Vec3::ZERO);
+    app.world
+        .entity_mut(body)
+        .insert(GravityDirection::from(Vec3::ZERO));
+
+    assert_eq!(velocity_after_updates(&mut app, body, 10), Vec3::ZERO);
+
+    app.world.entity_mut(body).remove::<GravityDirection>();
+    assert!(velocity_after_updates(&mut app, body, 10).y < -1.0);
+}
+
+#[test]
+fn directional_area_replaces_global_gravity() {
+    let mut app = test_app();
+    spawn_area(&mut app, GravityArea::Directional(Vec3::X * 10.0));
+    let body = spawn_body(&mut app, Vec3::ZERO);
+
+    let velocity = velocity_after_updates(&mut app, body, 10);
+    assert!(velocity.x > 1.0);
+    assert!(velocity.y.abs() < 0.5);
+}
+
+#[test]
+fn radial_area_pulls_toward_its_center() {
+    let mut app = test_app();
+    spawn_area(&mut app, GravityArea::Radial { strength: 10.0 });
+    let body = spawn_body(&mut app, Vec3::X * 5.0);
+
+    let velocity = velocity_after_updates(&mut app, body, 10);
+    assert!(velocity.x < -1.0);
+    assert!(velocity.y.abs() < 0.5);
+}
+
+#[test]
+fn bodies_outside_the_area_are_not_affected() {
+    let mut app = test_app();
+    spawn_area(&mut app, GravityArea::Radial { strength: 10.0 });
+    let body = spawn_body(&mut app, Vec3::X * 20.0);
+
+    let velocity = velocity_after_updates(&mut app, body, 10);
+    assert_eq!(velocity.x, 0.0);
+    assert!(velocity.y < -1.0);
+}
+
+#[test]
+fn disable_gravity_makes_the_body_float() {
+    let mut app = test_app();
+    let body = spawn_body(&mut app, Vec3::ZERO);
+    app.world.entity_mut(body).insert(DisableGravity);
+
+    assert_eq!(velocity_after_updates(&mut app, body, 10), Vec3::ZERO);
+
+    app.world.entity_mut(body).remove::<DisableGravity>();
+    assert!(velocity_after_updates(&mut app, body, 10).y < -1.0);
+
+    app.world.entity_mut(body).insert(DisableGravity);
+    *app.world.get_mut::<Velocity>(body).unwrap() = Velocity::default();
+    assert_eq!(velocity_after_updates(&mut app, body, 10), Vec3::ZERO);
+}
+
+#[test]
+fn disable_gravity_ignores_gravity_areas() {
+    let mut app = test_app();
+    spawn_area(&mut app, GravityArea::Directional(Vec3::X * 10.0));
+    let body = spawn_body(&mut app, Vec3::ZERO);
+    app.world.entity_mut(body).insert(DisableGravity);
+
+    assert_eq!(velocity_after_updates(&mut app, body, 10), Vec3::ZERO);
+}
+
+#[test]
+fn gravity_scale_scales_the_global_gravity() {
+    let mut app = test_app();
+    let normal = spawn_body(&mut app, Vec3::ZERO);
+    let scaled = spawn_body(&mut app, Vec3::X * 5.0);
+    app.world.entity_mut(scaled).insert(GravityScale(0.5));
+
+    app.update();
+    let normal_velocity = velocity_after_updates(&mut app, normal, 10);
+    let scaled_velocity = app.world.get::<Velocity>(scaled).unwrap().linear;
+    assert!((scaled_velocity.y - normal_velocity.y * 0.5).abs() < 0.01);
+
+    app.world.entity_mut(scaled).remove::<GravityScale>();
+    let before = app.world.get::<Velocity>(scaled).unwrap().linear.y;
+    let after = velocity_after_updates(&mut app, scaled, 10).y;
+    assert!((after - before + 10.0 * 10.0 / 60.0).abs() < 0.01);
+}
+
+#[test]
+fn gravity_scale_scales_the_gravity_of_areas() {
+    let mut app = test_app();
+    spawn_area(&mut app, GravityArea::Directional(Vec3::X * 10.0));
+    let body = spawn_body(&mut app, Vec3::ZERO);
+    app.world.entity_mut(body).insert(GravityScale(0.0));
+
+    assert_eq!(velocity_after_updates(&mut app, body, 10), Vec3::ZERO);
+}
@@ -0,0 +1,85 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, PhysicsWorldId, RigidBody};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, PhysicsWorld, RapierPlugin, RigidBodyHandle};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+#[test]
+fn handles_are_displayed_with_index_and_generation() {
+    let mut app = test_app();
+    let _ = spawn_body(&mut app);
+    let entity = spawn_body(&mut app);
+    app.update();
+
+    let body = app.world.get::<RigidBodyHandle>(entity).unwrap();
+    assert_eq!((body.index(), body.generation()), (1, 0));
+    assert_eq!(body.to_string(), "RigidBody(1v0)");
+
+    let collider = app.world.get::<ColliderHandle>(entity).unwrap();
+    assert_eq!(collider.to_string(), "Collider(1v0)");
+}
+
+#[test]
+fn display_includes_non_default_world() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.world.entity_mut(entity).insert(PhysicsWorldId(3));
+    app.update();
+
+    let body = app.world.get::<RigidBodyHandle>(entity).unwrap();
+    assert_eq!(body.to_string(), "RigidBody(0v0@3)");
+}
+
+#[test]
+fn finds_entities_of_rapier_handles() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.update();
+
+    let body = app
+        .world
+        .get::<RigidBodyHandle>(entity)
+        .unwrap()
+        .into_rapier();
+    let collider = app
+        .world
+        .get::<ColliderHandle>(entity)
+        .unwrap()
+        .into_rapier();
+
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    let world = state.get_mut(&mut app.world);
+    assert_eq!(world.entity_of_rigid_body(body), Some(entity));
+    assert_eq!(world.entity_of_collider(collider), Some(entity));
+}
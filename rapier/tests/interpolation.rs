@@ -0,0 +1,106 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::{Duration, Instant};
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::{Time, TimePlugin};
+
+use heron_core::{
+    CollisionShape, Interpolate, PhysicsPose, PhysicsSteps, RigidBody, Teleport, Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, translation: Vec3, velocity: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(velocity),
+            Interpolate::default(),
+        ))
+        .id()
+}
+
+#[test]
+fn inserts_the_physics_pose_at_the_initial_position() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::X * 5.0, Vec3::ZERO);
+
+    app.update();
+
+    let pose = app.world.get::<PhysicsPose>(entity).unwrap();
+    assert_eq!(pose.translation, Vec3::X * 5.0);
+}
+
+#[test]
+fn transform_follows_the_latest_pose_when_stepping_every_frame() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::ZERO, Vec3::X * 10.0);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let pose = *app.world.get::<PhysicsPose>(entity).unwrap();
+    assert!(pose.translation.x > 0.0);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        pose.translation
+    );
+}
+
+#[test]
+fn transform_is_interpolated_between_the_last_two_poses_when_stepping_with_a_timer() {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .init_resource::<Time>()
+        .insert_resource(PhysicsSteps::from_delta_time(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    let entity = spawn_body(&mut app, Vec3::ZERO, Vec3::X * 10.0);
+
+    let start = Instant::now();
+    for seconds in [0.0, 1.0, 2.0, 2.5] {
+        app.world
+            .resource_mut::<Time>()
+            .update_with_instant(start + Duration::from_secs_f32(seconds));
+        app.update();
+    }
+
+    let pose = *app.world.get::<PhysicsPose>(entity).unwrap();
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!(pose.translation.x > 0.0);
+    assert!((translation.x - (pose.translation.x - 5.0)).abs() < 0.001);
+}
+
+#[test]
+fn teleport_is_not_interpolated() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::ZERO, Vec3::ZERO);
+    app.update();
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Teleport::to(Vec3::Y * 10.0));
+    app.update();
+
+    let interpolate = app.world.get::<Interpolate>(entity).unwrap();
+    assert_eq!(interpolate.pose(0.0).unwrap().translation, Vec3::Y * 10.0);
+}
@@ -0,0 +1,130 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, Sensor};
+use heron_rapier::{PhysicsWorld, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_sensor(app: &mut App, x: f32) -> Entity {
+    let transform = Transform::from_xyz(x, 0.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+            Sensor,
+        ))
+        .id()
+}
+
+fn spawn_body(app: &mut App, x: f32) -> Entity {
+    let transform = Transform::from_xyz(x, 0.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+fn is_intersecting(app: &mut App, a: Entity, b: Entity) -> bool {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state.get_mut(&mut app.world).is_intersecting(a, b)
+}
+
+fn intersections_of(app: &mut App, entity: Entity) -> Vec<Entity> {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state.get_mut(&mut app.world).intersections_of(entity)
+}
+
+#[test]
+fn detects_intersection_with_sensor() {
+    let mut app = test_app();
+    let sensor = spawn_sensor(&mut app, 0.0);
+    let inside = spawn_body(&mut app, 1.0);
+    let outside = spawn_body(&mut app, 10.0);
+
+    app.update();
+
+    assert!(is_intersecting(&mut app, sensor, inside));
+    assert!(is_intersecting(&mut app, inside, sensor));
+    assert!(!is_intersecting(&mut app, sensor, outside));
+    assert_eq!(intersections_of(&mut app, sensor), vec![inside]);
+    assert_eq!(intersections_of(&mut app, inside), vec![sensor]);
+    assert!(intersections_of(&mut app, outside).is_empty());
+}
+
+#[test]
+fn considers_collision_shapes_of_rigid_body_children() {
+    let mut app = test_app();
+    let sensor = spawn_sensor(&mut app, 0.0);
+    let mut shapes = Vec::new();
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicPositionBased,
+        ))
+        .with_children(|children| {
+            for x in [-1.0, 1.0] {
+                let transform = Transform::from_xyz(x, 0.0, 0.0);
+                shapes.push(
+                    children
+                        .spawn_bundle((
+                            transform,
+                            GlobalTransform::from(transform),
+                            CollisionShape::Sphere { radius: 0.5 },
+                        ))
+                        .id(),
+                );
+            }
+        })
+        .id();
+
+    app.update();
+
+    assert!(is_intersecting(&mut app, body, sensor));
+    assert_eq!(intersections_of(&mut app, body), vec![sensor]);
+
+    let mut intersections = intersections_of(&mut app, sensor);
+    intersections.sort();
+    shapes.sort();
+    assert_eq!(intersections, shapes);
+}
+
+#[test]
+fn solid_contacts_are_not_intersections() {
+    let mut app = test_app();
+    let a = spawn_body(&mut app, 0.0);
+    let b = spawn_body(&mut app, 1.0);
+    app.world.entity_mut(a).insert(RigidBody::Dynamic);
+
+    app.update();
+
+    assert!(!is_intersecting(&mut app, a, b));
+    assert!(intersections_of(&mut app, a).is_empty());
+}
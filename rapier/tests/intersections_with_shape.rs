@@ -0,0 +1,127 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionLayers, CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::{PhysicsWorld, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_ball(app: &mut App, x: f32) -> Entity {
+    let transform = Transform::from_xyz(x, 0.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id()
+}
+
+fn intersections(
+    app: &mut App,
+    x: f32,
+    layers: CollisionLayers,
+    filter: impl Fn(Entity) -> bool,
+) -> Vec<Entity> {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    let mut entities = state.get_mut(&mut app.world).intersections_with_shape(
+        &CollisionShape::Cuboid {
+            half_extends: Vec3::splat(2.0),
+            border_radius: None,
+        },
+        Vec3::X * x,
+        Quat::IDENTITY,
+        layers,
+        filter,
+    );
+    entities.sort();
+    entities
+}
+
+#[test]
+fn returns_overlapping_entities() {
+    let mut app = test_app();
+    let ball1 = spawn_ball(&mut app, 0.0);
+    let ball2 = spawn_ball(&mut app, 2.5);
+    let _far_ball = spawn_ball(&mut app, 10.0);
+    app.update();
+
+    assert_eq!(
+        intersections(&mut app, 0.0, CollisionLayers::default(), |_| true),
+        vec![ball1, ball2]
+    );
+}
+
+#[test]
+fn returns_empty_vec_if_nothing_overlaps() {
+    let mut app = test_app();
+    let _ball = spawn_ball(&mut app, 0.0);
+    app.update();
+
+    assert!(intersections(&mut app, 20.0, CollisionLayers::default(), |_| true).is_empty());
+}
+
+#[test]
+fn excludes_filtered_entities() {
+    let mut app = test_app();
+    let ball1 = spawn_ball(&mut app, 0.0);
+    let ball2 = spawn_ball(&mut app, 2.5);
+    app.update();
+
+    assert_eq!(
+        intersections(&mut app, 0.0, CollisionLayers::default(), |e| e != ball1),
+        vec![ball2]
+    );
+}
+
+#[test]
+fn excludes_entities_of_non_interacting_layers() {
+    let mut app = test_app();
+    let ball1 = spawn_ball(&mut app, 0.0);
+    let ball2 = spawn_ball(&mut app, 2.5);
+    app.world
+        .entity_mut(ball1)
+        .insert(CollisionLayers::from_bits(2, 1));
+    app.update();
+
+    assert_eq!(
+        intersections(&mut app, 0.0, CollisionLayers::from_bits(1, 1), |_| true),
+        vec![ball2]
+    );
+}
+
+#[test]
+fn returns_empty_vec_if_the_shape_is_invalid() {
+    let mut app = test_app();
+    let _ball = spawn_ball(&mut app, 0.0);
+    app.update();
+
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    let entities = state.get_mut(&mut app.world).intersections_with_shape(
+        &CollisionShape::Sphere { radius: -1.0 },
+        Vec3::ZERO,
+        Quat::IDENTITY,
+        CollisionLayers::default(),
+        |_| true,
+    );
+
+    assert!(entities.is_empty());
+}
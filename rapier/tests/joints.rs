@@ -0,0 +1,417 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    AttachedToParent, CollisionEvent, CollisionShape, Joint, JointLimits, JointMotor, PhysicsSteps,
+    Ragdoll, RagdollState, RigidBody, SpringJoint, Velocity,
+};
+use heron_rapier::{JointHandle, RapierPlugin};
+
+#[cfg(dim2)]
+use heron_rapier::rapier2d::dynamics::ImpulseJointSet;
+#[cfg(dim3)]
+use heron_rapier::rapier3d::dynamics::ImpulseJointSet;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_frame(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+        ))
+        .id()
+}
+
+fn spawn_body(app: &mut App, joint: Joint) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::default(),
+            joint,
+        ))
+        .id()
+}
+
+fn joint_count(app: &App) -> usize {
+    app.world.resource::<ImpulseJointSet>().len()
+}
+
+#[test]
+fn creates_joint() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_body(&mut app, Joint::fixed(frame));
+
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(body).is_some());
+    assert_eq!(joint_count(&app), 1);
+}
+
+#[test]
+fn removes_joint() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_body(&mut app, Joint::fixed(frame));
+
+    app.update();
+    app.world.entity_mut(body).remove::<Joint>();
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(body).is_none());
+    assert_eq!(joint_count(&app), 0);
+}
+
+#[test]
+fn recreates_joint_when_a_body_is_recreated() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    spawn_body(&mut app, Joint::fixed(frame));
+
+    app.update();
+    *app.world.get_mut::<RigidBody>(frame).unwrap() = RigidBody::KinematicPositionBased;
+    app.update();
+    app.update();
+
+    assert_eq!(joint_count(&app), 1);
+}
+
+#[test]
+fn revolute_motor_rotates_body() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_body(&mut app, Joint::revolute(frame));
+    app.world
+        .entity_mut(body)
+        .insert(JointMotor::velocity(2.0, 10.0));
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let velocity = app.world.get::<Velocity>(body).unwrap();
+    assert!(velocity.angular.angle() > 1.0);
+    assert!(velocity.linear.length() < 0.1);
+}
+
+#[test]
+fn revolute_limits_stop_the_motor() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_body(&mut app, Joint::revolute(frame));
+    app.world
+        .entity_mut(body)
+        .insert_bundle((JointMotor::velocity(2.0, 10.0), JointLimits::new(0.0, 0.5)));
+
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let (axis, angle) = app
+        .world
+        .get::<Transform>(body)
+        .unwrap()
+        .rotation
+        .to_axis_angle();
+    assert!((axis.z * angle - 0.5).abs() < 0.1);
+
+    app.world.entity_mut(body).remove::<JointLimits>();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(app.world.get::<JointHandle>(body).is_some());
+    let velocity = app.world.get::<Velocity>(body).unwrap();
+    assert!(velocity.angular.angle() > 1.0);
+}
+
+#[test]
+fn prismatic_motor_translates_body() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_body(&mut app, Joint::prismatic(frame, Vec3::X));
+
+    app.update();
+    app.world
+        .entity_mut(body)
+        .insert(JointMotor::velocity(2.0, 10.0));
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let velocity = app.world.get::<Velocity>(body).unwrap();
+    assert!(velocity.linear.x.abs() > 1.0);
+    assert!(velocity.linear.y.abs() < 0.1);
+}
+
+#[test]
+fn removing_motor_stops_it() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_body(&mut app, Joint::prismatic(frame, Vec3::X));
+    app.world
+        .entity_mut(body)
+        .insert(JointMotor::velocity(2.0, 10.0));
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    app.world.entity_mut(body).remove::<JointMotor>();
+    *app.world.get_mut::<Velocity>(body).unwrap() = Velocity::default();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let velocity = app.world.get::<Velocity>(body).unwrap();
+    assert!(velocity.linear.length() < 0.1);
+}
+
+fn spawn_spring_body(app: &mut App, translation: Vec3, spring: SpringJoint) -> Entity {
+    let transform = Transform::from_translation(translation);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 0.5 },
+            Velocity::default(),
+            spring,
+        ))
+        .id()
+}
+
+#[test]
+fn creates_and_removes_spring_joint() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_spring_body(
+        &mut app,
+        Vec3::X * 2.0,
+        SpringJoint::new(frame, 2.0, 10.0, 1.0),
+    );
+
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(body).is_some());
+    assert_eq!(joint_count(&app), 1);
+
+    app.world.entity_mut(body).remove::<SpringJoint>();
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(body).is_none());
+    assert_eq!(joint_count(&app), 0);
+}
+
+#[test]
+fn spring_pulls_body_toward_rest_length() {
+    let mut app = test_app();
+    let frame = spawn_frame(&mut app);
+    let body = spawn_spring_body(
+        &mut app,
+        Vec3::X * 5.0,
+        SpringJoint::new(frame, 2.0, 10.0, 10.0),
+    );
+
+    for _ in 0..20 {
+        app.update();
+    }
+
+    let translation = app.world.get::<Transform>(body).unwrap().translation;
+    assert!((translation.x - 2.0).abs() < 0.5);
+    assert!(translation.y.abs() < 0.1);
+}
+
+#[test]
+fn attached_body_is_welded_to_its_parent() {
+    let mut app = test_app();
+    let parent = spawn_frame(&mut app);
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_xyz(2.0, 0.0, 0.0),
+            GlobalTransform::from_xyz(2.0, 0.0, 0.0),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            AttachedToParent,
+        ))
+        .id();
+    app.world.entity_mut(parent).push_children(&[child]);
+
+    app.update();
+    assert!(app.world.get::<JointHandle>(child).is_some());
+    assert_eq!(joint_count(&app), 1);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    let translation = app
+        .world
+        .get::<GlobalTransform>(child)
+        .unwrap()
+        .translation();
+    assert!((translation - Vec3::X * 2.0).length() < 0.1);
+
+    app.world.entity_mut(child).remove::<AttachedToParent>();
+    app.update();
+    assert!(app.world.get::<JointHandle>(child).is_none());
+    assert_eq!(joint_count(&app), 0);
+}
+
+#[test]
+fn attached_body_follows_its_moving_parent() {
+    let mut app = test_app();
+    let parent = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id();
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_xyz(1.5, 0.0, 0.0),
+            GlobalTransform::from_xyz(1.5, 0.0, 0.0),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            AttachedToParent,
+        ))
+        .id();
+    app.world.entity_mut(parent).push_children(&[child]);
+
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let parent_translation = app
+        .world
+        .get::<GlobalTransform>(parent)
+        .unwrap()
+        .translation();
+    let child_translation = app
+        .world
+        .get::<GlobalTransform>(child)
+        .unwrap()
+        .translation();
+    let local_translation = app.world.get::<Transform>(child).unwrap().translation;
+
+    assert!(parent_translation.x > 1.0);
+    assert!((child_translation - parent_translation - Vec3::X * 1.5).length() < 0.1);
+    assert!((local_translation - Vec3::X * 1.5).length() < 0.1);
+}
+
+fn spawn_bone(app: &mut App, translation: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 0.5 },
+        ))
+        .id()
+}
+
+#[test]
+fn ragdoll_bones_are_connected_to_their_parent_bone() {
+    let mut app = test_app();
+    let pelvis = spawn_bone(&mut app, Vec3::ZERO);
+    let joint = app
+        .world
+        .spawn()
+        .insert_bundle((Transform::default(), GlobalTransform::default()))
+        .id();
+    let leg = spawn_bone(&mut app, Vec3::Y * -1.0);
+    let foot = spawn_bone(&mut app, Vec3::Y * -2.0);
+    app.world.entity_mut(pelvis).push_children(&[joint]);
+    app.world.entity_mut(joint).push_children(&[leg]);
+    app.world.entity_mut(leg).push_children(&[foot]);
+    app.world.entity_mut(pelvis).insert(Ragdoll::default());
+
+    app.update();
+
+    for bone in [pelvis, leg, foot] {
+        assert_eq!(
+            app.world.get::<RigidBody>(bone),
+            Some(&RigidBody::KinematicPositionBased)
+        );
+    }
+    assert!(app.world.get::<JointHandle>(pelvis).is_none());
+    assert!(app.world.get::<JointHandle>(leg).is_some());
+    assert!(app.world.get::<JointHandle>(foot).is_some());
+    assert_eq!(joint_count(&app), 2);
+
+    app.world.get_mut::<Ragdoll>(pelvis).unwrap().state = RagdollState::Simulated;
+    app.update();
+    app.update();
+
+    for bone in [pelvis, leg, foot] {
+        assert_eq!(app.world.get::<RigidBody>(bone), Some(&RigidBody::Dynamic));
+    }
+    assert!(app.world.get::<JointHandle>(leg).is_some());
+    assert!(app.world.get::<JointHandle>(foot).is_some());
+    assert_eq!(joint_count(&app), 2);
+
+    app.world.entity_mut(pelvis).remove::<Ragdoll>();
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(leg).is_none());
+    assert_eq!(joint_count(&app), 0);
+}
+
+#[test]
+fn adjacent_ragdoll_bones_do_not_collide() {
+    let mut app = test_app();
+    let pelvis = spawn_bone(&mut app, Vec3::ZERO);
+    let leg = spawn_bone(&mut app, Vec3::Y * -0.5);
+    app.world.entity_mut(pelvis).push_children(&[leg]);
+    app.world
+        .entity_mut(pelvis)
+        .insert(Ragdoll::new(RagdollState::Simulated));
+
+    let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+    let mut started = 0;
+    for _ in 0..10 {
+        app.update();
+        let events = app.world.resource::<Events<CollisionEvent>>();
+        started += reader
+            .iter(events)
+            .filter(|event| event.is_started())
+            .count();
+    }
+
+    assert!(app.world.get::<JointHandle>(leg).is_some());
+    assert_eq!(started, 0);
+}
@@ -0,0 +1,122 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, KinematicOverride, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::RapierPlugin;
+
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, velocity: Velocity) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            velocity,
+        ))
+        .id()
+}
+
+#[test]
+fn override_makes_the_body_kinematic_without_recreating_it() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Velocity::from_linear(Vec3::X));
+    app.update();
+    let handle = *app
+        .world
+        .get::<heron_rapier::RigidBodyHandle>(entity)
+        .unwrap();
+
+    app.world
+        .entity_mut(entity)
+        .insert(KinematicOverride::default());
+    app.update();
+
+    assert_eq!(
+        *app.world
+            .get::<heron_rapier::RigidBodyHandle>(entity)
+            .unwrap(),
+        handle
+    );
+    let bodies = app.world.resource::<RigidBodySet>();
+    let (_, body) = bodies.iter().next().unwrap();
+    assert!(body.is_kinematic());
+
+    app.world
+        .entity_mut(entity)
+        .get_mut::<Transform>()
+        .unwrap()
+        .translation = Vec3::new(0.0, 10.0, 0.0);
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    let (_, body) = bodies.iter().next().unwrap();
+    assert_eq!(body.translation().y, 10.0);
+}
+
+#[test]
+fn removing_the_override_makes_the_body_dynamic_again() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Velocity::from_linear(Vec3::X));
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(KinematicOverride::restoring_velocity());
+    app.update();
+
+    app.world.entity_mut(entity).remove::<KinematicOverride>();
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    let (_, body) = bodies.iter().next().unwrap();
+    assert!(body.is_dynamic());
+    assert_eq!((*body.linvel()).into_bevy().x, 1.0);
+}
+
+#[test]
+fn removing_the_override_keeps_the_current_velocity() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Velocity::from_linear(Vec3::X));
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(KinematicOverride::default());
+    app.update();
+    app.update();
+
+    app.world.entity_mut(entity).remove::<KinematicOverride>();
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    let (_, body) = bodies.iter().next().unwrap();
+    assert!(body.is_dynamic());
+    assert_eq!((*body.linvel()).into_bevy(), Vec3::ZERO);
+    assert_eq!(
+        app.world.get::<Velocity>(entity).unwrap().linear,
+        Vec3::ZERO
+    );
+}
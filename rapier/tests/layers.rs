@@ -1,4 +1,6 @@
 #![cfg(any(dim2, dim3))]
+// Keep testing `RigidBody::Sensor` as long as it is supported
+#![allow(deprecated)]
 
 use std::time::Duration;
 
@@ -7,7 +9,7 @@ use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 use bevy::time::TimePlugin;
 
-use heron_core::{CollisionLayers, CollisionShape, PhysicsLayer, PhysicsSteps, RigidBody};
+use heron_core::{CollisionLayers, CollisionShape, PhysicsLayer, PhysicsSteps, RigidBody, Sensor};
 use heron_rapier::convert::IntoRapier;
 use heron_rapier::{ColliderHandle, RapierPlugin};
 use utils::*;
@@ -149,3 +151,38 @@ fn restore_the_collision_groups_on_removal() {
 
     assert_eq!(collider.collision_groups().memberships, u32::MAX)
 }
+
+#[test]
+fn sets_the_collision_groups_of_a_body_with_the_sensor_component() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::Static,
+            Sensor,
+            CollisionShape::Sphere { radius: 1.0 },
+            CollisionLayers::none()
+                .with_group(TestLayer::A)
+                .with_mask(TestLayer::B),
+            GlobalTransform::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let colliders = app.world.resource::<ColliderSet>();
+    let collider = colliders
+        .get(
+            app.world
+                .get::<ColliderHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap();
+
+    assert!(collider.is_sensor());
+    assert_eq!(collider.collision_groups().memberships, 1);
+    assert_eq!(collider.collision_groups().filter, 2);
+}
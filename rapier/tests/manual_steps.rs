@@ -0,0 +1,86 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsStepRequest, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::{step_physics, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::manual(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+#[test]
+fn steps_only_when_requested() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicVelocityBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id();
+
+    let translation = |app: &App| app.world.get::<Transform>(entity).unwrap().translation;
+
+    app.update();
+    app.update();
+    assert_eq!(translation(&app), Vec3::ZERO);
+
+    app.world.send_event(PhysicsStepRequest);
+    app.update();
+    assert_eq!(translation(&app), Vec3::X);
+
+    app.update();
+    assert_eq!(translation(&app), Vec3::X);
+
+    app.world.send_event(PhysicsStepRequest);
+    app.world.send_event(PhysicsStepRequest);
+    app.update();
+    app.update();
+    app.update();
+    assert_eq!(translation(&app), Vec3::X * 3.0);
+}
+
+#[test]
+fn step_physics_advances_by_the_given_duration() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicVelocityBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id();
+
+    let translation = |app: &App| app.world.get::<Transform>(entity).unwrap().translation;
+
+    step_physics(&mut app.world, Duration::from_secs_f32(2.5));
+    assert!((translation(&app) - Vec3::X * 2.5).length() < 0.001);
+
+    app.update();
+    assert!((translation(&app) - Vec3::X * 2.5).length() < 0.001);
+
+    app.world.send_event(PhysicsStepRequest);
+    app.update();
+    assert!((translation(&app) - Vec3::X * 3.5).length() < 0.001);
+}
@@ -0,0 +1,110 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, MissingTransforms, PhysicsSteps, RigidBody};
+use heron_rapier::{RapierPlugin, RigidBodyHandle};
+
+fn test_app(missing_transforms: MissingTransforms) -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .insert_resource(missing_transforms)
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+#[test]
+fn missing_transforms_are_inserted() {
+    let mut app = test_app(MissingTransforms::Insert);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((RigidBody::Dynamic, CollisionShape::Sphere { radius: 1.0 }))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        app.world.get::<Transform>(entity).copied(),
+        Some(Transform::default())
+    );
+    assert!(app.world.get::<GlobalTransform>(entity).is_some());
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_some());
+}
+
+#[test]
+fn missing_transform_is_computed_from_global_transform() {
+    let mut app = test_app(MissingTransforms::Insert);
+
+    let translation = Vec3::new(1.0, 2.0, 0.0);
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::from_translation(translation),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        translation
+    );
+}
+
+#[test]
+fn nothing_is_inserted_when_warning() {
+    let mut app = test_app(MissingTransforms::Warn);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((RigidBody::Dynamic, CollisionShape::Sphere { radius: 1.0 }))
+        .id();
+
+    app.update();
+
+    assert!(app.world.get::<Transform>(entity).is_none());
+    assert!(app.world.get::<GlobalTransform>(entity).is_none());
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_none());
+}
+
+#[test]
+fn missing_transform_of_a_child_is_relative_to_its_parent() {
+    let mut app = test_app(MissingTransforms::Insert);
+
+    let parent_transform = Transform::from_xyz(1.0, 0.0, 0.0);
+    let parent = app
+        .world
+        .spawn()
+        .insert_bundle((parent_transform, GlobalTransform::from(parent_transform)))
+        .with_children(|children| {
+            children.spawn_bundle((
+                GlobalTransform::from_translation(Vec3::new(3.0, 0.0, 0.0)),
+                RigidBody::Static,
+                CollisionShape::Sphere { radius: 1.0 },
+            ));
+        })
+        .id();
+    let child = app.world.get::<Children>(parent).unwrap()[0];
+
+    app.update();
+
+    assert_eq!(
+        app.world.get::<Transform>(child).unwrap().translation,
+        Vec3::new(2.0, 0.0, 0.0)
+    );
+}
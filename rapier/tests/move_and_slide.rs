@@ -0,0 +1,104 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::{MoveAndSlideResult, PhysicsWorld, RapierPlugin};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn(app: &mut App, x: f32, body: RigidBody, shape: CollisionShape) -> Entity {
+    let transform = Transform::from_xyz(x, 0.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((transform, GlobalTransform::from(transform), body, shape))
+        .id()
+}
+
+/// Spawns a ball at the origin and a wall whose left face is at `x = 1.5`
+fn setup() -> (App, Entity, Entity) {
+    let mut app = test_app();
+    let ball = spawn(
+        &mut app,
+        0.0,
+        RigidBody::KinematicPositionBased,
+        CollisionShape::Sphere { radius: 0.5 },
+    );
+    let wall = spawn(
+        &mut app,
+        2.0,
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(0.5, 10.0, 10.0),
+            border_radius: None,
+        },
+    );
+    app.update();
+    (app, ball, wall)
+}
+
+fn move_and_slide(app: &mut App, entity: Entity, translation: Vec3) -> Option<MoveAndSlideResult> {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state
+        .get_mut(&mut app.world)
+        .move_and_slide(entity, translation)
+}
+
+#[test]
+fn moves_freely_if_nothing_is_hit() {
+    let (mut app, ball, _) = setup();
+
+    let result = move_and_slide(&mut app, ball, Vec3::new(-3.0, 1.0, 0.0)).unwrap();
+
+    assert_eq!(result.translation, Vec3::new(-3.0, 1.0, 0.0));
+    assert!(result.hits.is_empty());
+}
+
+#[test]
+fn stops_before_the_wall() {
+    let (mut app, ball, wall) = setup();
+
+    let result = move_and_slide(&mut app, ball, Vec3::X * 3.0).unwrap();
+
+    assert!(result.translation.x < 1.0);
+    assert!(result.translation.x > 0.95);
+    assert_eq!(result.hits.len(), 1);
+    assert_eq!(result.hits[0].entity, wall);
+    assert!(result.hits[0].normal.abs_diff_eq(-Vec3::X, 0.001));
+    assert!((result.hits[0].point.x - 1.5).abs() < 0.001);
+}
+
+#[test]
+fn slides_along_the_wall() {
+    let (mut app, ball, wall) = setup();
+
+    let result = move_and_slide(&mut app, ball, Vec3::new(3.0, 2.0, 0.0)).unwrap();
+
+    assert!(result.translation.x < 1.0);
+    assert!(result.translation.x > 0.95);
+    assert!((result.translation.y - 2.0).abs() < 0.01);
+    assert_eq!(result.hits[0].entity, wall);
+}
+
+#[test]
+fn returns_none_for_entity_without_collision_shape() {
+    let (mut app, _, _) = setup();
+    let entity = app.world.spawn().id();
+
+    assert!(move_and_slide(&mut app, entity, Vec3::X).is_none());
+}
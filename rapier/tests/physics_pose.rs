@@ -0,0 +1,103 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsPose, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::convert::{IntoBevy, IntoRapier};
+use heron_rapier::{RapierPlugin, RigidBodyHandle};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, velocity: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(velocity),
+            PhysicsPose::default(),
+        ))
+        .id()
+}
+
+fn rapier_translation(app: &App, entity: Entity) -> Vec3 {
+    let handle = app.world.get::<RigidBodyHandle>(entity).unwrap();
+    let body = app
+        .world
+        .resource::<RigidBodySet>()
+        .get(handle.into_rapier())
+        .unwrap();
+    body.position().into_bevy().0
+}
+
+#[test]
+fn writes_pose_instead_of_transform() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::X * 10.0);
+
+    app.update();
+    app.update();
+
+    let pose = app.world.get::<PhysicsPose>(entity).unwrap();
+    assert!(pose.translation.x > 0.0);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        Vec3::ZERO
+    );
+    assert_eq!(
+        app.world
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .translation(),
+        Vec3::ZERO
+    );
+}
+
+#[test]
+fn changing_pose_teleports_body() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::ZERO);
+
+    app.update();
+
+    app.world
+        .get_mut::<PhysicsPose>(entity)
+        .unwrap()
+        .translation = Vec3::Y * 42.0;
+    app.update();
+
+    assert_eq!(rapier_translation(&app, entity).y, 42.0);
+}
+
+#[test]
+fn changing_transform_does_not_move_body() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::ZERO);
+
+    app.update();
+
+    *app.world.get_mut::<GlobalTransform>(entity).unwrap() =
+        GlobalTransform::from_translation(Vec3::Y * 42.0);
+    app.update();
+
+    assert_eq!(rapier_translation(&app, entity), Vec3::ZERO);
+}
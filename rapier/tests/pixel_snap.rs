@@ -0,0 +1,83 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, PixelSnap, RigidBody, Velocity};
+use heron_rapier::convert::{IntoBevy, IntoRapier};
+use heron_rapier::{RapierPlugin, RigidBodyHandle};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, velocity: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(velocity),
+            PixelSnap::new(1.0),
+        ))
+        .id()
+}
+
+fn rapier_translation(app: &App, entity: Entity) -> Vec3 {
+    let handle = app.world.get::<RigidBodyHandle>(entity).unwrap();
+    let body = app
+        .world
+        .resource::<RigidBodySet>()
+        .get(handle.into_rapier())
+        .unwrap();
+    body.position().into_bevy().0
+}
+
+#[test]
+fn snaps_transform_but_keeps_exact_position() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::X * 10.4);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let exact = rapier_translation(&app, entity);
+    assert!(exact.x > 20.0);
+    assert!((exact.x - exact.x.round()).abs() > 0.1);
+
+    let transform = app.world.get::<Transform>(entity).unwrap();
+    let global = app.world.get::<GlobalTransform>(entity).unwrap();
+    assert_eq!(transform.translation.x, exact.x.round());
+    assert_eq!(global.translation().x, exact.x.round());
+}
+
+#[test]
+fn moving_the_transform_still_teleports_the_body() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Vec3::X * 10.4);
+
+    app.update();
+    app.update();
+
+    app.world.get_mut::<Transform>(entity).unwrap().translation = Vec3::new(-100.0, 0.0, 0.0);
+    app.update();
+
+    assert!(rapier_translation(&app, entity).x < -80.0);
+}
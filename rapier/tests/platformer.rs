@@ -0,0 +1,135 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    CharacterBundle, CollisionShape, Gravity, GroundDetection, PhysicsSteps, PlatformerController,
+    RigidBody, Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -10.0))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_ground(app: &mut App) {
+    let transform = Transform::from_xyz(0.0, -1.0, 0.0);
+    app.world.spawn().insert_bundle((
+        transform,
+        GlobalTransform::from(transform),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(100.0, 1.0, 100.0),
+            border_radius: None,
+        },
+    ));
+}
+
+/// Spawns a character standing on the ground, and waits for it to be grounded
+fn spawn_character(app: &mut App, controller: PlatformerController) -> Entity {
+    let transform = Transform::from_xyz(0.0, 1.0, 0.0);
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((transform, GlobalTransform::from(transform)))
+        .insert_bundle(CharacterBundle::default())
+        .insert(controller)
+        .id();
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(is_grounded(app, entity));
+    entity
+}
+
+fn is_grounded(app: &App, entity: Entity) -> bool {
+    app.world
+        .get::<GroundDetection>(entity)
+        .unwrap()
+        .is_grounded()
+}
+
+fn velocity(app: &App, entity: Entity) -> Vec3 {
+    app.world.get::<Velocity>(entity).unwrap().linear
+}
+
+#[test]
+fn walks_on_the_ground() {
+    let mut app = test_app();
+    spawn_ground(&mut app);
+    let character = spawn_character(&mut app, PlatformerController::new(5.0, 5.0));
+
+    app.world
+        .get_mut::<PlatformerController>(character)
+        .unwrap()
+        .set_direction(1.0);
+    for _ in 0..30 {
+        app.update();
+    }
+
+    assert!((velocity(&app, character).x - 5.0).abs() < 0.1);
+    assert!(is_grounded(&app, character));
+}
+
+#[test]
+fn jumps_and_lands() {
+    let mut app = test_app();
+    spawn_ground(&mut app);
+    let character = spawn_character(&mut app, PlatformerController::new(5.0, 5.0));
+
+    app.world
+        .get_mut::<PlatformerController>(character)
+        .unwrap()
+        .jump();
+    app.update();
+    assert!(velocity(&app, character).y > 4.0);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(!is_grounded(&app, character));
+    assert!(app.world.get::<Transform>(character).unwrap().translation.y > 1.5);
+
+    for _ in 0..120 {
+        app.update();
+    }
+    assert!(is_grounded(&app, character));
+}
+
+#[test]
+fn cannot_jump_in_the_air() {
+    let mut app = test_app();
+    let transform = Transform::from_xyz(0.0, 10.0, 0.0);
+    let character = app
+        .world
+        .spawn()
+        .insert_bundle((transform, GlobalTransform::from(transform)))
+        .insert_bundle(CharacterBundle::default())
+        .insert(PlatformerController::new(5.0, 5.0))
+        .id();
+    app.update();
+
+    app.world
+        .get_mut::<PlatformerController>(character)
+        .unwrap()
+        .jump();
+    app.update();
+
+    assert!(velocity(&app, character).y <= 0.0);
+}
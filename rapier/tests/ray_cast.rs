@@ -0,0 +1,95 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionLayers, CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::{PhysicsWorld, RapierPlugin, RayCastInfo};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn ray_cast(app: &mut App) -> Option<RayCastInfo> {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state
+        .get_mut(&mut app.world)
+        .ray_cast(Vec3::ZERO, Vec3::X * 100.0, true)
+}
+
+fn wall() -> CollisionShape {
+    CollisionShape::Cuboid {
+        half_extends: Vec3::new(0.5, 10.0, 10.0),
+        border_radius: None,
+    }
+}
+
+#[test]
+fn hit_on_the_body_entity_returns_the_same_entity_twice() {
+    let mut app = test_app();
+    let transform = Transform::from_xyz(10.0, 0.0, 0.0);
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            wall(),
+        ))
+        .id();
+    app.update();
+
+    let hit = ray_cast(&mut app).expect("the ray should hit the wall");
+
+    assert_eq!(hit.entity, body);
+    assert_eq!(hit.rigid_body_entity, body);
+    assert_eq!(hit.layers, CollisionLayers::default());
+}
+
+#[test]
+fn hit_on_a_child_shape_returns_both_entities() {
+    let mut app = test_app();
+    let transform = Transform::from_xyz(10.0, 0.0, 0.0);
+    let layers = CollisionLayers::from_bits(2, u32::MAX);
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+        ))
+        .id();
+    let shape = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::from(transform),
+            wall(),
+            layers,
+        ))
+        .id();
+    app.world.entity_mut(body).push_children(&[shape]);
+    app.update();
+
+    let hit = ray_cast(&mut app).expect("the ray should hit the wall");
+
+    assert_eq!(hit.entity, shape);
+    assert_eq!(hit.rigid_body_entity, body);
+    assert_eq!(hit.layers, layers);
+    assert!((hit.collision_point - Vec3::X * 9.5).length() < 0.001);
+}
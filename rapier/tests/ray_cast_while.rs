@@ -0,0 +1,112 @@
+#![cfg(any(dim2, dim3))]
+
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionLayers, CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::{PhysicsWorld, RapierPlugin, RayCastHit};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_wall(app: &mut App, x: f32, layers: CollisionLayers) -> Entity {
+    let transform = Transform::from_xyz(x, 0.0, 0.0);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(0.5, 10.0, 10.0),
+                border_radius: None,
+            },
+            layers,
+        ))
+        .id()
+}
+
+fn ray_cast_while(
+    app: &mut App,
+    max_distance: f32,
+    visit: impl FnMut(&RayCastHit) -> ControlFlow<()>,
+) -> usize {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state.get_mut(&mut app.world).ray_cast_while(
+        Vec3::ZERO,
+        Vec3::X,
+        max_distance,
+        CollisionLayers::default(),
+        visit,
+    )
+}
+
+#[test]
+fn visits_all_hits_in_order() {
+    let mut app = test_app();
+    let far = spawn_wall(&mut app, 30.0, CollisionLayers::default());
+    let near = spawn_wall(&mut app, 10.0, CollisionLayers::from_bits(1, u32::MAX));
+    let middle = spawn_wall(&mut app, 20.0, CollisionLayers::default());
+    app.update();
+
+    let mut hits = Vec::new();
+    let visited = ray_cast_while(&mut app, f32::INFINITY, |hit| {
+        hits.push(hit.clone());
+        ControlFlow::Continue(())
+    });
+
+    assert_eq!(visited, 3);
+    assert_eq!(
+        hits.iter().map(|hit| hit.entity).collect::<Vec<_>>(),
+        vec![near, middle, far]
+    );
+    assert!((hits[0].distance - 9.5).abs() < 0.001);
+    assert!((hits[0].collision_point - Vec3::X * 9.5).length() < 0.001);
+    assert!((hits[0].normal + Vec3::X).length() < 0.001);
+    assert_eq!(hits[0].layers, CollisionLayers::from_bits(1, u32::MAX));
+}
+
+#[test]
+fn stops_when_the_closure_breaks() {
+    let mut app = test_app();
+    let near = spawn_wall(&mut app, 10.0, CollisionLayers::default());
+    let _middle = spawn_wall(&mut app, 20.0, CollisionLayers::default());
+    let _far = spawn_wall(&mut app, 30.0, CollisionLayers::default());
+    app.update();
+
+    let mut hits = Vec::new();
+    let visited = ray_cast_while(&mut app, f32::INFINITY, |hit| {
+        hits.push(hit.entity);
+        ControlFlow::Break(())
+    });
+
+    assert_eq!(visited, 1);
+    assert_eq!(hits, vec![near]);
+}
+
+#[test]
+fn ignores_hits_beyond_max_distance() {
+    let mut app = test_app();
+    let _near = spawn_wall(&mut app, 10.0, CollisionLayers::default());
+    let _middle = spawn_wall(&mut app, 20.0, CollisionLayers::default());
+    let _far = spawn_wall(&mut app, 30.0, CollisionLayers::default());
+    app.update();
+
+    let visited = ray_cast_while(&mut app, 25.0, |_| ControlFlow::Continue(()));
+
+    assert_eq!(visited, 2);
+}
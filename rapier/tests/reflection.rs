@@ -0,0 +1,60 @@
+#![cfg(any(dim2, dim3))]
+
+use std::any::TypeId;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::{FromReflect, TypeRegistryArc};
+
+use heron_core::{
+    CollisionEvent, CollisionLayers, Damping, Gravity, PhysicMaterial, PhysicsSteps, RigidBody,
+    Velocity, ZoneEnter,
+};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .add_plugin(CorePlugin)
+        .add_plugin(heron_core::CorePlugin);
+    builder
+}
+
+#[test]
+fn components_resources_and_events_are_registered() {
+    let app = test_app();
+    let registry = app.world.resource::<TypeRegistryArc>().read();
+
+    for type_id in [
+        TypeId::of::<RigidBody>(),
+        TypeId::of::<Damping>(),
+        TypeId::of::<Velocity>(),
+        TypeId::of::<Gravity>(),
+        TypeId::of::<PhysicsSteps>(),
+        TypeId::of::<CollisionEvent>(),
+        TypeId::of::<ZoneEnter>(),
+    ] {
+        assert!(registry.get(type_id).is_some());
+    }
+}
+
+#[test]
+fn components_can_be_rebuilt_from_reflection() {
+    let material = PhysicMaterial::RUBBER;
+    assert_eq!(
+        PhysicMaterial::from_reflect(material.clone_value().as_ref()),
+        Some(material)
+    );
+
+    let velocity = Velocity::from_linear(Vec3::X).with_angular(Vec3::Z.into());
+    assert_eq!(
+        Velocity::from_reflect(velocity.clone_value().as_ref()),
+        Some(velocity)
+    );
+
+    let layers = CollisionLayers::from_bits(1, 2);
+    assert_eq!(
+        CollisionLayers::from_reflect(layers.clone_value().as_ref()),
+        Some(layers)
+    );
+}
@@ -7,6 +7,7 @@ use bevy::reflect::TypeRegistryArc;
 use bevy::time::TimePlugin;
 
 use heron_core::Gravity;
+use heron_core::PhysicsDimension;
 use heron_core::PhysicsTime;
 use heron_rapier::RapierPlugin;
 use utils::*;
@@ -59,3 +60,19 @@ fn rapier_world_is_registered() {
     assert!(app.world.contains_resource::<ColliderSet>());
     assert!(app.world.contains_resource::<IntegrationParameters>());
 }
+
+#[test]
+fn dimension_is_defined_by_the_backend() {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin::default());
+
+    let dimension = *app.world.resource::<PhysicsDimension>();
+
+    #[cfg(dim2)]
+    assert_eq!(dimension, PhysicsDimension::Two);
+
+    #[cfg(dim3)]
+    assert_eq!(dimension, PhysicsDimension::Three);
+}
@@ -0,0 +1,125 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsDiagnostics, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::rollback::{self, PhysicsSnapshot};
+use heron_rapier::{RapierPlugin, RigidBodyHandle};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X * 10.0),
+        ))
+        .id()
+}
+
+fn translation(app: &App, entity: Entity) -> Vec3 {
+    app.world.get::<Transform>(entity).unwrap().translation
+}
+
+#[test]
+fn restore_resets_bodies_to_snapshot() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+    let snapshot = PhysicsSnapshot::capture(&app.world);
+    let expected = translation(&app, entity);
+
+    app.update();
+    app.update();
+    assert_ne!(translation(&app, entity), expected);
+
+    snapshot.restore(&mut app.world);
+
+    assert_eq!(translation(&app, entity), expected);
+    assert_eq!(
+        app.world.get::<Velocity>(entity).unwrap().linear,
+        Vec3::X * 10.0
+    );
+}
+
+#[test]
+fn resimulate_reproduces_the_same_steps() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+    let snapshot = PhysicsSnapshot::capture(&app.world);
+
+    app.update();
+    app.update();
+    let expected = translation(&app, entity);
+
+    snapshot.restore(&mut app.world);
+    let mut steps = Vec::new();
+    rollback::resimulate(&mut app.world, 2, |_, step| steps.push(step));
+
+    assert_eq!(steps, vec![0, 1]);
+    assert!(translation(&app, entity).abs_diff_eq(expected, 0.001));
+}
+
+#[test]
+fn resimulate_applies_corrected_inputs() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+    let snapshot = PhysicsSnapshot::capture(&app.world);
+    let start = translation(&app, entity);
+
+    app.update();
+
+    snapshot.restore(&mut app.world);
+    rollback::resimulate(&mut app.world, 1, |world, _| {
+        world.get_mut::<Velocity>(entity).unwrap().linear = Vec3::Y * 10.0;
+    });
+
+    let moved = translation(&app, entity) - start;
+    assert!(moved.y > 5.0);
+    assert!(moved.x.abs() < 0.001);
+}
+
+#[test]
+fn resimulate_keeps_the_bodies_and_restores_the_step_count() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+    let snapshot = PhysicsSnapshot::capture(&app.world);
+    let handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+
+    app.update();
+    app.update();
+
+    snapshot.restore(&mut app.world);
+    assert_eq!(app.world.resource::<PhysicsDiagnostics>().step_count, 1);
+
+    rollback::resimulate(&mut app.world, 2, |_, _| {});
+    app.update();
+
+    assert_eq!(app.world.resource::<PhysicsDiagnostics>().step_count, 4);
+    assert_eq!(app.world.get::<RigidBodyHandle>(entity), Some(&handle));
+}
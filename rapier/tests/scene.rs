@@ -0,0 +1,172 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::entity::EntityMap;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::DynamicScene;
+use bevy::time::TimePlugin;
+use serde::de::DeserializeSeed;
+
+use heron_core::{
+    CollisionLayers, CollisionShape, Collisions, Joint, PhysicMaterial, PhysicsSteps, RigidBody,
+    SensorEvents, Teleport, Velocity, WatchCollisionsWith,
+};
+use heron_rapier::{JointHandle, RapierPlugin, RigidBodyHandle};
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin)
+        .register_type::<Transform>()
+        .register_type::<GlobalTransform>();
+    builder
+}
+
+fn heightfield() -> CollisionShape {
+    CollisionShape::HeightField {
+        size: Vec2::new(10.0, 10.0),
+        heights: vec![vec![0.0, 1.0, 0.0], vec![0.5, 1.5, 0.5]],
+    }
+}
+
+/// Serialize the world of the app into a RON scene, and deserialize it back
+fn round_trip(app: &App) -> DynamicScene {
+    let registry = app.world.resource::<TypeRegistryArc>();
+    let ron = DynamicScene::from_world(&app.world, registry)
+        .serialize_ron(registry)
+        .expect("Failed to serialize the scene");
+
+    let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+    SceneDeserializer {
+        type_registry: &registry.read(),
+    }
+    .deserialize(&mut deserializer)
+    .expect("Failed to deserialize the scene")
+}
+
+#[test]
+fn physics_components_round_trip_through_a_scene() {
+    let mut source = test_app();
+    let ground = source
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            heightfield(),
+            PhysicMaterial::ICE,
+        ))
+        .id();
+    source.world.spawn().insert_bundle((
+        Transform::from_xyz(0.0, 5.0, 0.0),
+        GlobalTransform::default(),
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 1.0 },
+        CollisionLayers::from_bits(1, 3),
+        Velocity::from_linear(Vec3::X),
+        Joint::fixed(ground),
+    ));
+
+    let scene = round_trip(&source);
+
+    let mut app = test_app();
+    let mut entity_map = EntityMap::default();
+    scene
+        .write_to_world(&mut app.world, &mut entity_map)
+        .expect("Failed to spawn the scene");
+
+    let new_ground = entity_map.get(ground).unwrap();
+    let shape = app.world.get::<CollisionShape>(new_ground).unwrap();
+    assert!(
+        matches!(shape, CollisionShape::HeightField { heights, .. } if heights[1] == [0.5, 1.5, 0.5])
+    );
+    assert_eq!(
+        app.world.get::<PhysicMaterial>(new_ground),
+        Some(&PhysicMaterial::ICE)
+    );
+
+    let mut query = app
+        .world
+        .query::<(Entity, &RigidBody, &CollisionLayers, &Velocity, &Joint)>();
+    let (body, rigid_body, layers, velocity, joint) = query.single(&app.world);
+    assert_eq!(*rigid_body, RigidBody::Dynamic);
+    assert_eq!(*layers, CollisionLayers::from_bits(1, 3));
+    assert_eq!(velocity.linear, Vec3::X);
+    assert_eq!(joint.body, new_ground);
+
+    app.update();
+
+    assert!(app.world.get::<RigidBodyHandle>(new_ground).is_some());
+    assert!(app.world.get::<RigidBodyHandle>(body).is_some());
+    assert!(app.world.get::<JointHandle>(body).is_some());
+}
+
+#[test]
+fn optional_fields_round_trip_through_a_scene() {
+    let mut source = test_app();
+    let teleport = Teleport::to(Vec3::Y).with_rotation(Quat::from_rotation_z(1.0));
+    source.world.spawn().insert(teleport);
+
+    let scene = round_trip(&source);
+
+    let mut app = test_app();
+    scene
+        .write_to_world(&mut app.world, &mut EntityMap::default())
+        .expect("Failed to spawn the scene");
+
+    let mut query = app.world.query::<&Teleport>();
+    assert_eq!(*query.single(&app.world), teleport);
+}
+
+#[test]
+fn scene_can_be_saved_while_bodies_are_in_contact() {
+    let mut app = test_app();
+    let ground = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 1.0, 10.0),
+                border_radius: None,
+            },
+        ))
+        .id();
+    app.world.spawn().insert_bundle((
+        Transform::from_xyz(0.0, 1.5, 0.0),
+        GlobalTransform::from_xyz(0.0, 1.5, 0.0),
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 1.0 },
+        Collisions::default(),
+        SensorEvents::default(),
+        WatchCollisionsWith::new(ground),
+    ));
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let mut query = app.world.query::<&Collisions>();
+    assert!(!query.single(&app.world).is_empty());
+
+    let scene = round_trip(&app);
+
+    let mut new_app = test_app();
+    scene
+        .write_to_world(&mut new_app.world, &mut EntityMap::default())
+        .expect("Failed to spawn the scene");
+
+    let mut query = new_app.world.query::<&Collisions>();
+    assert!(query.single(&new_app.world).is_empty());
+}
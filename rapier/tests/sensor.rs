@@ -0,0 +1,204 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, Sensor, SensorShape};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, RapierPlugin, RigidBodyHandle};
+use utils::*;
+
+#[cfg(dim2)]
+use heron_rapier::rapier2d::dynamics::RigidBodyType;
+#[cfg(dim3)]
+use heron_rapier::rapier3d::dynamics::RigidBodyType;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn is_sensor(app: &App, entity: Entity) -> bool {
+    app.world
+        .resource::<ColliderSet>()
+        .get(
+            app.world
+                .get::<ColliderHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap()
+        .is_sensor()
+}
+
+fn rapier_body_type(app: &App, entity: Entity) -> RigidBodyType {
+    app.world
+        .resource::<RigidBodySet>()
+        .get(
+            app.world
+                .get::<RigidBodyHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap()
+        .body_type()
+}
+
+#[test]
+fn sensor_can_be_combined_with_any_body_type() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            RigidBody::KinematicPositionBased,
+            Sensor,
+        ))
+        .id();
+
+    app.update();
+
+    assert!(is_sensor(&app, entity));
+    assert_eq!(
+        rapier_body_type(&app, entity),
+        RigidBodyType::KinematicPositionBased
+    );
+}
+
+#[test]
+fn sensor_applies_to_child_shapes() {
+    let mut app = test_app();
+
+    let mut child = None;
+    app.world
+        .spawn()
+        .insert_bundle((GlobalTransform::default(), RigidBody::Static, Sensor))
+        .with_children(|children| {
+            child = Some(
+                children
+                    .spawn_bundle((
+                        CollisionShape::Sphere { radius: 1.0 },
+                        Transform::default(),
+                        GlobalTransform::default(),
+                    ))
+                    .id(),
+            );
+        });
+
+    app.update();
+
+    assert!(is_sensor(&app, child.unwrap()));
+}
+
+#[test]
+fn can_add_sensor_after_creation() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            RigidBody::Dynamic,
+        ))
+        .id();
+
+    app.update();
+    assert!(!is_sensor(&app, entity));
+
+    app.world.entity_mut(entity).insert(Sensor);
+    app.update();
+
+    assert!(is_sensor(&app, entity));
+    assert_eq!(rapier_body_type(&app, entity), RigidBodyType::Dynamic);
+}
+
+#[test]
+fn can_remove_sensor_after_creation() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            RigidBody::Static,
+            Sensor,
+        ))
+        .id();
+
+    app.update();
+
+    app.world.entity_mut(entity).remove::<Sensor>();
+    app.update();
+
+    assert!(!is_sensor(&app, entity));
+}
+
+#[test]
+fn removing_sensor_keeps_sensor_shapes() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            RigidBody::Static,
+            Sensor,
+            SensorShape,
+        ))
+        .id();
+
+    app.update();
+
+    app.world.entity_mut(entity).remove::<Sensor>();
+    app.update();
+
+    assert!(is_sensor(&app, entity));
+}
+
+#[test]
+fn changing_body_type_keeps_sensor() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            RigidBody::Static,
+            Sensor,
+        ))
+        .id();
+
+    app.update();
+
+    *app.world.get_mut::<RigidBody>(entity).unwrap() = RigidBody::KinematicVelocityBased;
+    app.update();
+
+    assert!(is_sensor(&app, entity));
+    assert_eq!(
+        rapier_body_type(&app, entity),
+        RigidBodyType::KinematicVelocityBased
+    );
+}
@@ -7,9 +7,11 @@ use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 use bevy::time::TimePlugin;
 
-use heron_core::{CollisionShape, PhysicsSteps, RigidBody, SensorShape};
+use heron_core::{
+    CollisionShape, PhysicsSteps, RigidBody, Sensor, SensorMass, SensorShape, SolidShape,
+};
 use heron_rapier::convert::IntoRapier;
-use heron_rapier::{ColliderHandle, RapierPlugin};
+use heron_rapier::{ColliderHandle, RapierPlugin, RigidBodyHandle};
 use utils::*;
 
 mod utils;
@@ -127,6 +129,7 @@ fn sensor_flag_can_removed() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn removing_sensor_flag_has_no_effect_if_body_is_sensor() {
     let mut app = test_app();
 
@@ -160,3 +163,229 @@ fn removing_sensor_flag_has_no_effect_if_body_is_sensor() {
 
     assert!(collider.is_sensor());
 }
+
+#[test]
+fn removing_sensor_flag_has_no_effect_if_body_has_the_sensor_component() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Static,
+            Sensor,
+            CollisionShape::Sphere { radius: 1.0 },
+            SensorShape,
+        ))
+        .id();
+
+    app.update();
+
+    app.world.entity_mut(entity).remove::<SensorShape>();
+
+    app.update();
+
+    let collider = app
+        .world
+        .resource::<ColliderSet>()
+        .get(
+            app.world
+                .get::<ColliderHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap();
+
+    assert!(collider.is_sensor());
+}
+
+fn is_sensor(app: &App, entity: Entity) -> bool {
+    app.world
+        .resource::<ColliderSet>()
+        .get(
+            app.world
+                .get::<ColliderHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap()
+        .is_sensor()
+}
+
+fn spawn_sensor_body_with_solid_child(app: &mut App) -> (Entity, Entity) {
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Static,
+            Sensor,
+            CollisionShape::Sphere { radius: 5.0 },
+        ))
+        .id();
+
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            SolidShape,
+        ))
+        .id();
+
+    app.world.entity_mut(body).push_children(&[child]);
+    (body, child)
+}
+
+#[test]
+fn a_sensor_body_can_have_a_solid_shape() {
+    let mut app = test_app();
+    let (body, child) = spawn_sensor_body_with_solid_child(&mut app);
+
+    app.update();
+
+    assert!(is_sensor(&app, body));
+    assert!(!is_sensor(&app, child));
+}
+
+#[test]
+fn solid_shape_stays_solid_when_sensor_is_added_or_removed() {
+    let mut app = test_app();
+    let (body, child) = spawn_sensor_body_with_solid_child(&mut app);
+    app.update();
+
+    app.world.entity_mut(body).remove::<Sensor>();
+    app.update();
+    assert!(!is_sensor(&app, body));
+    assert!(!is_sensor(&app, child));
+
+    app.world.entity_mut(body).insert(Sensor);
+    app.update();
+    assert!(is_sensor(&app, body));
+    assert!(!is_sensor(&app, child));
+}
+
+#[test]
+fn solid_flag_can_be_removed() {
+    let mut app = test_app();
+    let (_, child) = spawn_sensor_body_with_solid_child(&mut app);
+    app.update();
+
+    app.world.entity_mut(child).remove::<SolidShape>();
+    app.update();
+    app.update();
+
+    assert!(is_sensor(&app, child));
+}
+
+#[test]
+fn sensor_shape_takes_precedence_over_solid_shape() {
+    let mut app = test_app();
+    let (_, child) = spawn_sensor_body_with_solid_child(&mut app);
+    app.world.entity_mut(child).insert(SensorShape);
+
+    app.update();
+
+    assert!(is_sensor(&app, child));
+}
+
+fn spawn_body_with_sensor_child(
+    app: &mut App,
+    sensor_mass: Option<SensorMass>,
+) -> (Entity, Entity) {
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    let mut child = app.world.spawn();
+    child.insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        CollisionShape::Sphere { radius: 1.0 },
+        SensorShape,
+    ));
+    if let Some(sensor_mass) = sensor_mass {
+        child.insert(sensor_mass);
+    }
+    let child = child.id();
+
+    app.world.entity_mut(body).push_children(&[child]);
+    (body, child)
+}
+
+fn mass_of(app: &App, entity: Entity) -> f32 {
+    app.world
+        .resource::<RigidBodySet>()
+        .get(
+            app.world
+                .get::<RigidBodyHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap()
+        .mass()
+}
+
+fn mass_of_unit_ball(app: &mut App) -> f32 {
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.update();
+    mass_of(app, entity)
+}
+
+#[test]
+fn sensor_shape_does_not_contribute_to_the_mass() {
+    let mut app = test_app();
+    let expected = mass_of_unit_ball(&mut app);
+
+    let (body, _) = spawn_body_with_sensor_child(&mut app, None);
+    app.update();
+
+    assert!((mass_of(&app, body) - expected).abs() < 0.001);
+}
+
+#[test]
+fn sensor_shape_contributes_the_sensor_mass() {
+    let mut app = test_app();
+    let expected = mass_of_unit_ball(&mut app) + 50.0;
+
+    let (body, _) = spawn_body_with_sensor_child(&mut app, Some(SensorMass(50.0)));
+    app.update();
+
+    assert!((mass_of(&app, body) - expected).abs() < 0.001);
+}
+
+#[test]
+fn sensor_mass_can_be_changed_and_removed() {
+    let mut app = test_app();
+    let ball_mass = mass_of_unit_ball(&mut app);
+
+    let (body, child) = spawn_body_with_sensor_child(&mut app, Some(SensorMass(50.0)));
+    app.update();
+
+    app.world.entity_mut(child).insert(SensorMass(20.0));
+    app.update();
+    app.update();
+    assert!((mass_of(&app, body) - ball_mass - 20.0).abs() < 0.001);
+
+    app.world.entity_mut(child).remove::<SensorMass>();
+    app.update();
+    app.update();
+    assert!((mass_of(&app, body) - ball_mass).abs() < 0.001);
+}
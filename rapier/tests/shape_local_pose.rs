@@ -0,0 +1,87 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{ColliderOffset, CollisionShape, PhysicsSteps, RigidBody, ShapeLocalPose};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+#[test]
+fn local_pose_of_child_shape_is_filled() {
+    let mut app = test_app();
+
+    let body = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+        ))
+        .id();
+
+    let child_transform = Transform::from_xyz(1.0, 2.0, 0.0);
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            child_transform,
+            GlobalTransform::from(child_transform),
+            CollisionShape::Sphere { radius: 1.0 },
+            ColliderOffset::from_translation(Vec3::X),
+            ShapeLocalPose::default(),
+        ))
+        .id();
+    app.world.entity_mut(body).push_children(&[child]);
+
+    app.update();
+
+    let pose = *app.world.get::<ShapeLocalPose>(child).unwrap();
+    assert_eq!(pose.translation, Vec3::new(2.0, 2.0, 0.0));
+    assert_eq!(pose.rotation, Quat::IDENTITY);
+
+    app.world.get_mut::<Transform>(child).unwrap().translation = Vec3::new(-1.0, 0.0, 0.0);
+    app.update();
+
+    let pose = *app.world.get::<ShapeLocalPose>(child).unwrap();
+    assert_eq!(pose.translation, Vec3::ZERO);
+}
+
+#[test]
+fn local_pose_of_body_shape_is_the_offset() {
+    let mut app = test_app();
+
+    let transform = Transform::from_xyz(5.0, 5.0, 0.0);
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+            ColliderOffset::from_translation(Vec3::Y),
+            ShapeLocalPose::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let pose = *app.world.get::<ShapeLocalPose>(entity).unwrap();
+    assert_eq!(pose.translation, Vec3::Y);
+}
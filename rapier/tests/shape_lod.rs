@@ -0,0 +1,89 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, ShapeLod, ShapeLodReference};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, RapierPlugin};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn collider_radius(app: &App, entity: Entity) -> f32 {
+    app.world
+        .resource::<ColliderSet>()
+        .get(
+            app.world
+                .get::<ColliderHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap()
+        .shape()
+        .as_ball()
+        .unwrap()
+        .radius
+}
+
+#[test]
+fn switches_shape_when_reference_crosses_the_distance() {
+    let mut app = test_app();
+
+    let reference = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            ShapeLodReference,
+        ))
+        .id();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            ShapeLod {
+                near: CollisionShape::Sphere { radius: 1.0 },
+                far: CollisionShape::Sphere { radius: 2.0 },
+                distance: 10.0,
+            },
+        ))
+        .id();
+
+    app.update();
+    assert_eq!(collider_radius(&app, entity), 1.0);
+
+    app.world
+        .entity_mut(reference)
+        .insert(GlobalTransform::from_translation(Vec3::X * 20.0));
+    app.update();
+    app.update();
+    assert_eq!(collider_radius(&app, entity), 2.0);
+
+    app.world
+        .entity_mut(reference)
+        .insert(GlobalTransform::from_translation(Vec3::X * 5.0));
+    app.update();
+    app.update();
+    assert_eq!(collider_radius(&app, entity), 1.0);
+}
@@ -0,0 +1,103 @@
+#![cfg(any(dim2, dim3))]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, RapierPlugin};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn(app: &mut App, shape: CollisionShape) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            shape,
+        ))
+        .id()
+}
+
+fn shares_shape(app: &App, entity1: Entity, entity2: Entity) -> bool {
+    let colliders = app.world.resource::<ColliderSet>();
+    let shape = |entity| {
+        &colliders
+            .get(
+                app.world
+                    .get::<ColliderHandle>(entity)
+                    .unwrap()
+                    .into_rapier(),
+            )
+            .unwrap()
+            .shared_shape()
+            .0
+    };
+    Arc::ptr_eq(shape(entity1), shape(entity2))
+}
+
+#[test]
+fn identical_shapes_are_shared() {
+    let mut app = test_app();
+    let shape = CollisionShape::Capsule {
+        half_segment: 1.0,
+        radius: 0.5,
+    };
+    let entity1 = spawn(&mut app, shape.clone());
+    let entity2 = spawn(&mut app, shape);
+
+    app.update();
+
+    assert!(shares_shape(&app, entity1, entity2));
+}
+
+#[test]
+fn changing_a_shared_shape_does_not_affect_the_others() {
+    let mut app = test_app();
+    let shape = CollisionShape::Sphere { radius: 1.0 };
+    let entity1 = spawn(&mut app, shape.clone());
+    let entity2 = spawn(&mut app, shape);
+
+    app.update();
+    *app.world.get_mut::<CollisionShape>(entity1).unwrap() = CollisionShape::Sphere { radius: 2.0 };
+    app.update();
+
+    assert!(!shares_shape(&app, entity1, entity2));
+
+    let colliders = app.world.resource::<ColliderSet>();
+    let radius = |entity| {
+        colliders
+            .get(
+                app.world
+                    .get::<ColliderHandle>(entity)
+                    .unwrap()
+                    .into_rapier(),
+            )
+            .unwrap()
+            .shape()
+            .as_ball()
+            .unwrap()
+            .radius
+    };
+    assert_eq!(radius(entity1), 2.0);
+    assert_eq!(radius(entity2), 1.0);
+}
@@ -0,0 +1,86 @@
+#![cfg(any(dim2, dim3))]
+#![allow(deprecated)]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{stage, CollisionShape, PhysicsSteps, PhysicsSystem, RigidBody};
+use heron_rapier::RapierPlugin;
+
+use utils::*;
+
+mod utils;
+
+#[derive(Default)]
+struct Counter(usize);
+
+fn count(mut counter: ResMut<'_, Counter>) {
+    counter.0 += 1;
+}
+
+#[test]
+fn systems_of_the_deprecated_stages_still_run() {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .init_resource::<Counter>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin)
+        .stage(stage::ROOT, |schedule: &mut Schedule| {
+            schedule.add_system_to_stage(stage::UPDATE, count)
+        });
+
+    app.update();
+    app.update();
+
+    assert_eq!(app.world.resource::<Counter>().0, 2);
+}
+
+#[derive(Default)]
+struct Created {
+    bodies: usize,
+    colliders: usize,
+}
+
+fn count_created(
+    bodies: Res<'_, RigidBodySet>,
+    colliders: Res<'_, ColliderSet>,
+    mut created: ResMut<'_, Created>,
+) {
+    created.bodies = bodies.len();
+    created.colliders = colliders.len();
+}
+
+#[test]
+fn systems_can_run_between_the_body_and_collider_creation() {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .init_resource::<Created>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin)
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            count_created
+                .after(PhysicsSystem::BodyCreation)
+                .before(PhysicsSystem::ColliderCreation),
+        );
+    app.world.spawn().insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 1.0 },
+    ));
+
+    app.update();
+
+    let created = app.world.resource::<Created>();
+    assert_eq!(created.bodies, 1);
+    assert_eq!(created.colliders, 0);
+}
@@ -0,0 +1,94 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    CollisionShape, Gravity, PhysicMaterial, PhysicsSteps, RigidBody, SurfaceVelocity, Velocity,
+};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, RapierPlugin};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -10.0))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_box(app: &mut App, body: RigidBody, translation: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            body,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(5.0, 0.5, 5.0),
+                border_radius: None,
+            },
+            PhysicMaterial {
+                friction: 1.0,
+                ..Default::default()
+            },
+            Velocity::default(),
+        ))
+        .id()
+}
+
+#[test]
+fn surface_velocity_activates_the_solver_hooks() {
+    let mut app = test_app();
+    let conveyor = spawn_box(&mut app, RigidBody::Static, Vec3::ZERO);
+    app.world
+        .entity_mut(conveyor)
+        .insert(SurfaceVelocity::from_linear(Vec3::X));
+
+    app.update();
+    app.update();
+
+    let colliders = app.world.resource::<ColliderSet>();
+    let collider = colliders
+        .get(
+            app.world
+                .get::<ColliderHandle>(conveyor)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap();
+
+    assert!(collider
+        .active_hooks()
+        .contains(ActiveHooks::MODIFY_SOLVER_CONTACTS));
+}
+
+#[test]
+fn bodies_are_dragged_along_the_surface() {
+    let mut app = test_app();
+    let conveyor = spawn_box(&mut app, RigidBody::Static, Vec3::ZERO);
+    app.world
+        .entity_mut(conveyor)
+        .insert(SurfaceVelocity::from_linear(Vec3::X * 2.0));
+    let crate_entity = spawn_box(&mut app, RigidBody::Dynamic, Vec3::Y * 1.0);
+
+    for _ in 0..60 {
+        app.update();
+    }
+
+    let velocity = app.world.get::<Velocity>(crate_entity).unwrap();
+    assert!(velocity.linear.x > 1.0, "{:?}", velocity);
+}
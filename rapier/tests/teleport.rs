@@ -0,0 +1,155 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    CollisionShape, KinematicTeleportThreshold, PhysicsSteps, RigidBody, Teleport, Velocity,
+};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::RapierPlugin;
+
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, velocity: Velocity) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            velocity,
+        ))
+        .id()
+}
+
+#[test]
+fn teleports_the_body_and_removes_the_component() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Velocity::default());
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Teleport::to(Vec3::new(10.0, 5.0, 0.0)));
+    app.update();
+
+    assert!(app.world.get::<Teleport>(entity).is_none());
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        Vec3::new(10.0, 5.0, 0.0)
+    );
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    let (_, body) = bodies.iter().next().unwrap();
+    assert_eq!(body.position().into_bevy().0, Vec3::new(10.0, 5.0, 0.0));
+    assert!(!body.is_sleeping());
+}
+
+#[test]
+fn keeps_the_velocity_by_default() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Velocity::from_linear(Vec3::X));
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Teleport::to(Vec3::Y * 10.0));
+    app.update();
+
+    assert_eq!(app.world.get::<Velocity>(entity).unwrap().linear, Vec3::X);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        Vec3::new(1.0, 10.0, 0.0)
+    );
+}
+
+#[test]
+fn can_reset_the_velocity() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Velocity::from_linear(Vec3::X));
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Teleport::to(Vec3::Y * 10.0).with_velocity_reset());
+    app.update();
+
+    assert_eq!(
+        app.world.get::<Velocity>(entity).unwrap().linear,
+        Vec3::ZERO
+    );
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation,
+        Vec3::Y * 10.0
+    );
+}
+
+#[test]
+fn can_set_the_rotation() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Velocity::default());
+    app.update();
+
+    let rotation = Quat::from_rotation_z(1.0);
+    app.world
+        .entity_mut(entity)
+        .insert(Teleport::to(Vec3::ZERO).with_rotation(rotation));
+    app.update();
+
+    let actual = app.world.get::<Transform>(entity).unwrap().rotation;
+    assert!(actual.angle_between(rotation) < 0.001);
+}
+
+fn kinematic_speed_after_jump(app: &mut App) -> f32 {
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.update();
+
+    app.world.get_mut::<Transform>(entity).unwrap().translation = Vec3::X * 100.0;
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    let (_, body) = bodies.iter().next().unwrap();
+    assert_eq!(body.position().into_bevy().0, Vec3::X * 100.0);
+    body.linvel().norm()
+}
+
+#[test]
+fn kinematic_body_moves_with_a_velocity_by_default() {
+    let mut app = test_app();
+    assert!(kinematic_speed_after_jump(&mut app) > 1.0);
+}
+
+#[test]
+fn kinematic_body_is_teleported_above_the_threshold() {
+    let mut app = test_app();
+    app.insert_resource(KinematicTeleportThreshold::new(10.0));
+    assert!(kinematic_speed_after_jump(&mut app) < 0.001);
+}
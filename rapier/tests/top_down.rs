@@ -0,0 +1,95 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, TopDownController, Velocity};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(0.1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_character(app: &mut App, controller: TopDownController) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::default(),
+            controller,
+        ))
+        .id()
+}
+
+fn set_direction(app: &mut App, entity: Entity, direction: Vec2) {
+    app.world
+        .get_mut::<TopDownController>(entity)
+        .unwrap()
+        .set_direction(direction);
+}
+
+#[test]
+fn accelerates_in_the_input_direction() {
+    let mut app = test_app();
+    let entity = spawn_character(
+        &mut app,
+        TopDownController::new(10.0).with_acceleration(20.0),
+    );
+    set_direction(&mut app, entity, Vec2::X);
+
+    app.update();
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!((velocity - Vec3::X * 2.0).length() < 0.001);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!((velocity - Vec3::X * 10.0).length() < 0.001);
+}
+
+#[test]
+fn stops_without_input() {
+    let mut app = test_app();
+    let entity = spawn_character(&mut app, TopDownController::new(10.0));
+    set_direction(&mut app, entity, Vec2::Y);
+    for _ in 0..5 {
+        app.update();
+    }
+
+    set_direction(&mut app, entity, Vec2::ZERO);
+    for _ in 0..5 {
+        app.update();
+    }
+
+    assert_eq!(
+        app.world.get::<Velocity>(entity).unwrap().linear,
+        Vec3::ZERO
+    );
+}
+
+#[test]
+fn faces_the_input_direction() {
+    let mut app = test_app();
+    let entity = spawn_character(&mut app, TopDownController::new(10.0).with_facing(true));
+    set_direction(&mut app, entity, Vec2::Y);
+
+    app.update();
+
+    let rotation = app.world.get::<Transform>(entity).unwrap().rotation;
+    assert!((rotation * Vec3::X - Vec3::Y).length() < 0.001);
+}
@@ -0,0 +1,152 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionLayers, CollisionShape, PhysicsSteps, RigidBody, Sensor};
+use heron_rapier::{PhysicsWorld, RapierPlugin};
+
+const GROUND: CollisionLayers = CollisionLayers::from_bits(0b01, u32::MAX);
+const PLAYER: CollisionLayers = CollisionLayers::from_bits(0b10, u32::MAX);
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_ground(app: &mut App, body_type: RigidBody) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            body_type,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 1.0, 10.0),
+                border_radius: None,
+            },
+            GROUND,
+        ))
+        .id()
+}
+
+fn spawn_player(app: &mut App, y: f32) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::Y * y),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            PLAYER,
+        ))
+        .id()
+}
+
+fn touches_layer(app: &mut App, entity: Entity, layers: CollisionLayers) -> bool {
+    let mut state = SystemState::<PhysicsWorld<'_, '_>>::new(&mut app.world);
+    state.get_mut(&mut app.world).touches_layer(entity, layers)
+}
+
+#[test]
+fn detects_contact_with_layer() {
+    let mut app = test_app();
+    let ground = spawn_ground(&mut app, RigidBody::Static);
+    let player = spawn_player(&mut app, 1.5);
+
+    app.update();
+
+    assert!(touches_layer(&mut app, player, GROUND));
+    assert!(touches_layer(&mut app, ground, PLAYER));
+    assert!(!touches_layer(&mut app, player, PLAYER));
+}
+
+#[test]
+fn detects_intersection_with_sensor_layer() {
+    let mut app = test_app();
+    let ground = spawn_ground(&mut app, RigidBody::Static);
+    app.world.entity_mut(ground).insert(Sensor);
+    let player = spawn_player(&mut app, 0.0);
+
+    app.update();
+
+    assert!(touches_layer(&mut app, player, GROUND));
+}
+
+#[test]
+fn considers_collision_shapes_of_rigid_body_children() {
+    let mut app = test_app();
+    spawn_ground(&mut app, RigidBody::Static);
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::Y * 1.5),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+        ))
+        .with_children(|children| {
+            children.spawn_bundle((
+                Transform::default(),
+                GlobalTransform::default(),
+                CollisionShape::Sphere { radius: 1.0 },
+                PLAYER,
+            ));
+        })
+        .id();
+
+    app.update();
+
+    assert!(touches_layer(&mut app, player, GROUND));
+}
+
+#[test]
+fn considers_collision_shapes_of_rigid_body_children_when_the_body_has_a_shape() {
+    let mut app = test_app();
+    spawn_ground(&mut app, RigidBody::Static);
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::Y * 5.0),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 0.5 },
+            PLAYER,
+        ))
+        .with_children(|children| {
+            children.spawn_bundle((
+                Transform::from_translation(Vec3::Y * -3.5),
+                GlobalTransform::default(),
+                CollisionShape::Sphere { radius: 1.0 },
+                PLAYER,
+            ));
+        })
+        .id();
+
+    app.update();
+
+    assert!(touches_layer(&mut app, player, GROUND));
+}
+
+#[test]
+fn returns_false_when_not_touching_anything() {
+    let mut app = test_app();
+    spawn_ground(&mut app, RigidBody::Static);
+    let player = spawn_player(&mut app, 100.0);
+
+    app.update();
+
+    assert!(!touches_layer(&mut app, player, GROUND));
+}
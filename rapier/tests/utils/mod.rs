@@ -2,12 +2,14 @@
 #[cfg(dim2)]
 pub use heron_rapier::rapier2d::{
     dynamics::{IntegrationParameters, MassProperties, RigidBodyDamping, RigidBodySet},
-    geometry::ColliderSet,
+    geometry::{ActiveCollisionTypes, ColliderSet},
     math::Vector,
+    pipeline::ActiveHooks,
 };
 #[cfg(dim3)]
 pub use heron_rapier::rapier3d::{
     dynamics::{IntegrationParameters, MassProperties, RigidBodyDamping, RigidBodySet},
-    geometry::ColliderSet,
+    geometry::{ActiveCollisionTypes, ColliderSet},
     math::Vector,
+    pipeline::ActiveHooks,
 };
@@ -0,0 +1,102 @@
+#![cfg(all(any(dim2, dim3), feature = "vehicle"))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::vehicle::{RaycastVehicle, Suspension, Wheel, WheelContacts};
+use heron_core::{CollisionShape, Gravity, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -10.0))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_ground(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 0.5, 10.0),
+                border_radius: None,
+            },
+        ))
+        .id()
+}
+
+fn spawn_vehicle(app: &mut App) -> Entity {
+    let wheel = |x: f32| {
+        Wheel::new(Vec3::new(x, -0.25, 0.0), 0.2).with_suspension(Suspension::new(0.5, 200.0, 20.0))
+    };
+
+    let transform = Transform::from_translation(Vec3::Y * 1.5);
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Dynamic,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(1.0, 0.25, 1.0),
+                border_radius: None,
+            },
+            RaycastVehicle::default()
+                .with_wheel(wheel(-0.8))
+                .with_wheel(wheel(0.8)),
+        ))
+        .id()
+}
+
+#[test]
+fn vehicle_is_held_above_the_ground_by_its_wheels() {
+    let mut app = test_app();
+    let ground = spawn_ground(&mut app);
+    let vehicle = spawn_vehicle(&mut app);
+
+    for _ in 0..300 {
+        app.update();
+    }
+
+    let contacts = app.world.get::<WheelContacts>(vehicle).unwrap();
+    assert!(contacts.is_grounded());
+    for contact in contacts.iter() {
+        let contact = contact.expect("all wheels should touch the ground");
+        assert_eq!(contact.entity, ground);
+        assert!(contact.suspension_force > 0.0);
+        assert!(contact.suspension_length < 0.5);
+    }
+
+    // The chassis would rest at 0.75 if it were lying on the ground
+    let height = app.world.get::<Transform>(vehicle).unwrap().translation.y;
+    assert!(height > 1.0, "{}", height);
+    assert!(height < 1.5, "{}", height);
+}
+
+#[test]
+fn vehicle_is_not_grounded_without_ground() {
+    let mut app = test_app();
+    let vehicle = spawn_vehicle(&mut app);
+
+    app.update();
+    app.update();
+
+    let contacts = app.world.get::<WheelContacts>(vehicle).unwrap();
+    assert!(!contacts.is_grounded());
+    assert_eq!(contacts.iter().count(), 2);
+}
@@ -235,3 +235,184 @@ fn z_components_is_preserved() {
 
     assert_eq!(5.0, actual_translation.z);
 }
+
+#[rstest]
+#[case(VelocityTolerance::default(), false)]
+#[case(VelocityTolerance::new(0.1, 0.1), true)]
+fn negligible_velocity_changes_do_not_wake_up_the_body(
+    #[case] tolerance: VelocityTolerance,
+    #[case] expected_sleeping: bool,
+) {
+    let mut app = test_app();
+    app.insert_resource(tolerance);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 2.0 },
+            Transform::default(),
+            GlobalTransform::default(),
+            Velocity::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let handle = app
+        .world
+        .get::<heron_rapier::RigidBodyHandle>(entity)
+        .unwrap()
+        .into_rapier();
+    app.world
+        .resource_mut::<RigidBodySet>()
+        .get_mut(handle)
+        .unwrap()
+        .sleep();
+
+    app.world.get_mut::<Velocity>(entity).unwrap().linear = Vec3::X * 0.01;
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    assert_eq!(bodies.get(handle).unwrap().is_sleeping(), expected_sleeping);
+}
+
+#[rstest]
+#[case(SleepHint::WakeWhenMoving, false)]
+#[case(SleepHint::SleepWhenStopped, true)]
+fn sleep_hint_defines_if_stopped_bodies_fall_asleep(
+    #[case] hint: SleepHint,
+    #[case] expected_sleeping: bool,
+) {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 2.0 },
+            Transform::default(),
+            GlobalTransform::default(),
+            Velocity::from_linear(Vec3::X),
+            hint,
+        ))
+        .id();
+
+    app.update();
+
+    let handle = app
+        .world
+        .get::<heron_rapier::RigidBodyHandle>(entity)
+        .unwrap()
+        .into_rapier();
+    assert!(!app
+        .world
+        .resource::<RigidBodySet>()
+        .get(handle)
+        .unwrap()
+        .is_sleeping());
+
+    *app.world.get_mut::<Velocity>(entity).unwrap() = Velocity::default();
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    assert_eq!(bodies.get(handle).unwrap().is_sleeping(), expected_sleeping);
+}
+
+#[test]
+fn stopped_body_is_not_put_back_to_sleep_once_woken_up() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 2.0 },
+            Transform::default(),
+            GlobalTransform::default(),
+            Velocity::from_linear(Vec3::X),
+            SleepHint::SleepWhenStopped,
+        ))
+        .id();
+
+    app.update();
+    *app.world.get_mut::<Velocity>(entity).unwrap() = Velocity::default();
+    app.update();
+
+    let handle = app
+        .world
+        .get::<heron_rapier::RigidBodyHandle>(entity)
+        .unwrap()
+        .into_rapier();
+    let mut bodies = app.world.resource_mut::<RigidBodySet>();
+    let body = bodies.get_mut(handle).unwrap();
+    assert!(body.is_sleeping());
+    body.wake_up(true);
+
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    assert!(!bodies.get(handle).unwrap().is_sleeping());
+}
+
+#[test]
+fn real_time_velocity_is_scaled_by_physics_time() {
+    let mut app = test_app();
+    app.insert_resource(PhysicsTime::new(0.5))
+        .insert_resource(VelocityTimeSpace::Real);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X * 2.0),
+        ))
+        .id();
+
+    app.update();
+
+    let bodies = app.world.resource::<RigidBodySet>();
+    let body = bodies
+        .get(
+            app.world
+                .get::<heron_rapier::RigidBodyHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap();
+
+    assert_eq!(body.linvel().x, 4.0);
+    assert_eq!(app.world.get::<Velocity>(entity).unwrap().linear.x, 2.0);
+}
+
+#[test]
+fn movement_is_derived_from_the_velocity_after_the_step() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Transform::default(),
+            GlobalTransform::default(),
+            Velocity::from_linear(Vec3::X * 2.0),
+            Movement::default(),
+        ))
+        .id();
+
+    app.update();
+
+    let movement = app.world.get::<Movement>(entity).unwrap();
+    assert!(movement.is_moving());
+    assert!((movement.speed() - 2.0).abs() < 1e-5);
+    assert!(movement.direction().abs_diff_eq(Vec3::X, 1e-5));
+}
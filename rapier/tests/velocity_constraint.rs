@@ -0,0 +1,83 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, Velocity, VelocityConstraint};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, transform: Transform, constraint: VelocityConstraint) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            transform,
+            GlobalTransform::from(transform),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::default(),
+            constraint,
+        ))
+        .id()
+}
+
+#[test]
+fn velocity_is_clamped_after_step() {
+    let mut app = test_app();
+    let entity = spawn_body(
+        &mut app,
+        Transform::default(),
+        VelocityConstraint::default().with_max(Vec3::new(f32::INFINITY, 1.0, f32::INFINITY)),
+    );
+    app.update();
+
+    *app.world.get_mut::<Velocity>(entity).unwrap() =
+        Velocity::from_linear(Vec3::new(2.0, 5.0, 0.0));
+    app.update();
+
+    assert_eq!(
+        app.world.get::<Velocity>(entity).unwrap().linear,
+        Vec3::new(2.0, 1.0, 0.0)
+    );
+}
+
+#[test]
+fn velocity_is_clamped_in_local_space() {
+    let mut app = test_app();
+    let entity = spawn_body(
+        &mut app,
+        Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
+        VelocityConstraint::default()
+            .with_min(Vec3::new(0.0, f32::NEG_INFINITY, f32::NEG_INFINITY))
+            .in_local_space(),
+    );
+    app.update();
+
+    // Moving along the local x axis of the body is allowed
+    *app.world.get_mut::<Velocity>(entity).unwrap() =
+        Velocity::from_linear(Vec3::new(-2.0, 0.0, 0.0));
+    app.update();
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!((velocity.x + 2.0).abs() < 0.001);
+
+    // Moving backward is not
+    *app.world.get_mut::<Velocity>(entity).unwrap() =
+        Velocity::from_linear(Vec3::new(2.0, 0.0, 0.0));
+    app.update();
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!(velocity.x.abs() < 0.001);
+}
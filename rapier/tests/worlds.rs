@@ -0,0 +1,137 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::ecs::event::{Events, ManualEventReader};
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{
+    CollisionEvent, CollisionShape, Gravity, PhysicsSteps, PhysicsWorldId, PhysicsWorldSettings,
+    RigidBody, Velocity, WorldSettings,
+};
+use heron_rapier::{RapierPlugin, RigidBodyHandle};
+
+use utils::*;
+
+mod utils;
+
+const OTHER_WORLD: PhysicsWorldId = PhysicsWorldId(1);
+
+fn test_app() -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin);
+    builder
+}
+
+fn spawn_body(app: &mut App, world: PhysicsWorldId) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::default(),
+            world,
+        ))
+        .id()
+}
+
+fn collision_events(app: &App) -> usize {
+    let events = app.world.resource::<Events<CollisionEvent>>();
+    ManualEventReader::<CollisionEvent>::default()
+        .iter(events)
+        .count()
+}
+
+#[test]
+fn bodies_in_different_worlds_do_not_collide() {
+    let mut app = test_app();
+    let body1 = spawn_body(&mut app, PhysicsWorldId::DEFAULT);
+    let body2 = spawn_body(&mut app, OTHER_WORLD);
+
+    app.update();
+
+    assert_eq!(collision_events(&app), 0);
+    for entity in [body1, body2] {
+        assert_eq!(
+            app.world.get::<Velocity>(entity).unwrap().linear,
+            Vec3::ZERO
+        );
+    }
+}
+
+#[test]
+fn bodies_in_the_same_extra_world_collide() {
+    let mut app = test_app();
+    spawn_body(&mut app, OTHER_WORLD);
+    spawn_body(&mut app, OTHER_WORLD);
+
+    app.update();
+
+    assert_eq!(collision_events(&app), 1);
+}
+
+#[test]
+fn extra_world_uses_its_own_gravity() {
+    let mut app = test_app();
+    app.world.resource_mut::<PhysicsWorldSettings>().insert(
+        OTHER_WORLD,
+        WorldSettings::default().with_gravity(Gravity::from(Vec3::Y * -10.0)),
+    );
+    let default_body = spawn_body(&mut app, PhysicsWorldId::DEFAULT);
+    let other_body = spawn_body(&mut app, OTHER_WORLD);
+
+    app.update();
+    app.update();
+
+    assert_eq!(
+        app.world.get::<Velocity>(default_body).unwrap().linear,
+        Vec3::ZERO
+    );
+    assert!(app.world.get::<Velocity>(other_body).unwrap().linear.y < -1.0);
+}
+
+#[test]
+fn extra_world_is_paused_when_time_scale_is_zero() {
+    let mut app = test_app();
+    app.world.resource_mut::<PhysicsWorldSettings>().insert(
+        OTHER_WORLD,
+        WorldSettings::default()
+            .with_gravity(Gravity::from(Vec3::Y * -10.0))
+            .with_time_scale(0.0),
+    );
+    let body = spawn_body(&mut app, OTHER_WORLD);
+
+    app.update();
+    app.update();
+
+    assert_eq!(app.world.get::<Velocity>(body).unwrap().linear, Vec3::ZERO);
+}
+
+#[test]
+fn body_is_moved_when_world_changes() {
+    let mut app = test_app();
+    let body = spawn_body(&mut app, PhysicsWorldId::DEFAULT);
+
+    app.update();
+    assert_eq!(app.world.resource::<RigidBodySet>().len(), 1);
+
+    app.world.entity_mut(body).insert(OTHER_WORLD);
+    app.update();
+
+    assert_eq!(app.world.resource::<RigidBodySet>().len(), 0);
+    assert_eq!(app.world.resource::<ColliderSet>().len(), 0);
+    assert_eq!(
+        app.world.get::<RigidBodyHandle>(body).unwrap().world(),
+        OTHER_WORLD
+    );
+}
@@ -0,0 +1,89 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+
+use heron_core::{PhysicsSteps, PhysicsWriteBack, RigidBody, Velocity};
+use heron_rapier::RapierPlugin;
+
+/// Translation seen by the user systems of the `Update` stage
+#[derive(Default)]
+struct SeenTranslation(Vec3);
+
+fn record_translation(
+    mut seen: ResMut<'_, SeenTranslation>,
+    query: Query<'_, '_, &Transform, With<RigidBody>>,
+) {
+    seen.0 = query.single().translation;
+}
+
+fn test_app(write_back: PhysicsWriteBack) -> App {
+    let mut builder = App::new();
+    builder
+        .init_resource::<TypeRegistryArc>()
+        .init_resource::<SeenTranslation>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .insert_resource(write_back)
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin)
+        .add_system(record_translation);
+    builder
+}
+
+fn spawn_moving_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::KinematicVelocityBased,
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id()
+}
+
+#[test]
+fn writes_back_right_after_the_step_by_default() {
+    let mut app = test_app(PhysicsWriteBack::default());
+    let entity = spawn_moving_body(&mut app);
+
+    app.update();
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation.x,
+        1.0
+    );
+
+    app.update();
+    assert_eq!(app.world.resource::<SeenTranslation>().0.x, 1.0);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation.x,
+        2.0
+    );
+}
+
+#[test]
+fn writes_back_in_the_pre_update_stage_of_next_frame() {
+    let mut app = test_app(PhysicsWriteBack::PreUpdate);
+    let entity = spawn_moving_body(&mut app);
+
+    app.update();
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation.x,
+        0.0
+    );
+
+    app.update();
+    assert_eq!(app.world.resource::<SeenTranslation>().0.x, 1.0);
+    assert_eq!(
+        app.world.get::<Transform>(entity).unwrap().translation.x,
+        1.0
+    );
+
+    app.update();
+    assert_eq!(app.world.resource::<SeenTranslation>().0.x, 2.0);
+}
@@ -1,6 +1,7 @@
 #![deny(future_incompatible, nonstandard_style)]
 #![warn(missing_docs, rust_2018_idioms, clippy::pedantic)]
 #![allow(clippy::needless_pass_by_value, clippy::needless_doctest_main)]
+#![cfg_attr(not(any(dim2, dim3)), allow(missing_docs))]
 #![cfg(any(dim2, dim3))]
 
 //! An ergonomic physics API for 2d and 3d [bevy] games. (powered by [rapier])
@@ -34,10 +35,37 @@
 //! * `2d` Enable simulation only on the first 2 axes `x` and `y`. Incompatible with the feature `3d`, therefore require to disable the default features.
 //! * `debug-2d` Render 2d collision shapes
 //! * `debug-3d` Render 3d collision shapes
+//! * `playground-2d`/`playground-3d` Add the [`PhysicsPlaygroundPlugin`], a sandbox scene to reproduce issues with minimal code
 //! * `collision-from-mesh` Add the [`PendingConvexCollision`] component to generate convex hull collision shapes from a mesh
+//! * `collider-debug-info` Maintain a [`ColliderDebugInfo`] component with a human-readable summary of each collision shape
+//! * `debug-mesh` Add [`CollisionShape::to_debug_mesh`](prelude::CollisionShape::to_debug_mesh) to build a bevy `Mesh` of a collision shape, for rendering it with custom materials
+//! * `impact-sounds` Add the [`ImpactSoundsPlugin`] to emit events with the sound to play when two surfaces hit each other
+//! * `material-library` Add the [`MaterialLibraryPlugin`] to load named [`PhysicMaterial`](prelude::PhysicMaterial)s from RON files
+//! * `layer-names` Show the names of the layers in the `Debug` output of [`CollisionLayers`](prelude::CollisionLayers) built from a derived [`PhysicsLayer`](prelude::PhysicsLayer)
 //! * `enhanced-determinism` Enable rapier's [enhanced-determinism](https://rapier.rs/docs/user_guides/rust/determinism)
+//! * `parallel` Step the physics with rapier's parallel solver, in a thread pool configured by [`PhysicsPlugin::with_threads`]
+//! * `profiler` Measure the time spent in each phase of the physics step (see [`PhysicsDiagnostics`](prelude::PhysicsDiagnostics))
+//! * `serde-serialize` Make the [`PhysicsSnapshot`](rapier_plugin::rollback::PhysicsSnapshot) serializable with [serde](https://serde.rs)
+//! * `test-utils` Add the [`test_utils`] module, with helpers to write integration tests of games using heron
+//! * `vehicle` Add the [`vehicle`] module to simulate raycast vehicles
 //!
 //!
+//! ### Library crates shared between 2d and 3d games
+//!
+//! Only the final binary should choose the dimension, by enabling the `2d` or `3d` feature of
+//! `heron`. A library crate used by both 2d and 3d games should instead depend on `heron_core`
+//! without any feature. Its components, resources and events are the same in both dimensions, and
+//! the [`PhysicsDimension`] resource tells at runtime which one was chosen. Note that the
+//! [`CollisionShape`](prelude::CollisionShape) variants exclusive to one dimension (like `Cone` or
+//! `Triangle`) are not available to such a crate.
+//!
+//! ```toml
+//! heron_core = "3"
+//! ```
+//!
+//! Instead of `heron::prelude`, one may import `heron::prelude2d` or `heron::prelude3d` which only
+//! contain the types suited for the chosen dimension.
+//!
 //! ## Install the plugin
 //!
 //! The [`PhysicsPlugin`] should be installed to enable physics and collision detection.
@@ -124,14 +152,27 @@
 //! * How to define the [`PhysicMaterial`]
 //! * How to get the current [`Collisions`]
 //! * How to listen to [`CollisionEvent`]
+//! * How to query the physics world (ray casts, shape casts...) with [`PhysicsWorld`]
 //! * How to define [`RotationConstraints`]
 //! * How to define [`CustomCollisionShape`] for [`heron_rapier`]
 
 use bevy::app::{App, Plugin};
 
 pub use heron_core::*;
+#[cfg(debug)]
+pub use heron_debug::DebugRenderSettings;
+#[cfg(playground)]
+pub use heron_debug::PhysicsPlaygroundPlugin;
 pub use heron_macros::*;
 use heron_rapier::RapierPlugin;
+pub use heron_rapier::{
+    step_physics, ClosestColliderInfo, CollisionEventsCapacity, CollisionShapeInfo,
+    MoveAndSlideHit, MoveAndSlideResult, PhysicsWorld, RayCastHit, RayCastInfo,
+    ShapeCastCollisionInfo, ShapeCastCollisionType, ShapeCastInfo,
+};
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
 /// Physics behavior powered by [rapier](https://rapier.rs)
 ///
@@ -140,16 +181,90 @@ pub mod rapier_plugin {
     pub use heron_rapier::*;
 }
 
+/// Re-exports shared by all the preludes
+mod common {
+    pub use crate::{
+        Acceleration, ActiveCollisions, AttachedToParent, AxisAngle, BitLayer, Ccd,
+        CharacterBundle, ClosestColliderInfo, ColliderAabb, ColliderCreationFailed, ColliderMass,
+        ColliderOffset, CollisionEvent, CollisionLayers, CollisionShape, CollisionShapeInfo,
+        Collisions, Damping, DisableGravity, DisabledContacts, FluidArea, Force, Gravity,
+        GravityArea, GravityDirection, GravityScale, GroundDetection, Interpolate,
+        InvalidCollisionShape, Joint, JointKind, JointLimits, JointMotor, KinematicOverride,
+        KinematicTeleportThreshold, MissingRigidBody, MissingTransforms, MoveAndSlideHit,
+        MoveAndSlideResult, Movement, NetworkId, NoSelfCollision, PhysicMaterial, PhysicsBodyInfo,
+        PhysicsDiagnostics, PhysicsDiagnosticsPlugin, PhysicsDimension, PhysicsDisabled,
+        PhysicsLayer, PhysicsPlugin, PhysicsPose, PhysicsStepEvent, PhysicsStepRequest,
+        PhysicsSystem, PhysicsTime, PhysicsWorld, PhysicsWorldId, PhysicsWorldSettings,
+        PhysicsWriteBack, PixelSnap, Ragdoll, RagdollState, RayCastHit, RayCastInfo, RigidBody,
+        RotationConstraints, Sensor, SensorEvents, SensorMass, ShapeCastCollisionInfo,
+        ShapeCastCollisionType, ShapeCastInfo, ShapeLocalPose, ShapeLod, ShapeLodReference,
+        SleepHint, SpringJoint, SurfaceVelocity, Teleport, Velocity, VelocityConstraint,
+        VelocityTimeSpace, VelocityTolerance, WatchCollisionsWith, WorldSettings, ZoneDetector,
+        ZoneEnter, ZoneExit,
+    };
+}
+
 /// Re-exports of the most commons/useful types
 pub mod prelude {
-    pub use heron_macros::*;
-
+    pub use crate::common::*;
     #[allow(deprecated)]
-    pub use crate::{
-        stage, Acceleration, AxisAngle, CollisionEvent, CollisionLayers, CollisionShape,
-        Collisions, Damping, Gravity, PhysicMaterial, PhysicsLayer, PhysicsPlugin, PhysicsSystem,
-        PhysicsTime, RigidBody, RotationConstraints, Velocity,
-    };
+    pub use crate::{stage, PlatformerController, TopDownController};
+}
+
+/// Re-exports of the most commons/useful types, suited for 2d games
+///
+/// Compared to [`prelude`], it also exports the bevy 2d math types and doesn't export deprecated
+/// items. It is only available when the `2d` feature is enabled (and `3d` is not), so its
+/// [`CollisionShape`](prelude2d::CollisionShape) has the 2d-only variants, but not the 3d-only
+/// ones.
+///
+/// ```
+/// use heron::prelude2d::*;
+///
+/// let mut controller = TopDownController::default();
+/// controller.set_direction(Vec2::X);
+/// let triangle = CollisionShape::Triangle {
+///     a: Vec2::Y,
+///     b: Vec2::NEG_X,
+///     c: Vec2::X,
+///     border_radius: None,
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use heron::prelude2d::*;
+///
+/// let cone = CollisionShape::Cone {
+///     half_height: 1.0,
+///     radius: 1.0,
+/// };
+/// ```
+#[cfg(dim2)]
+pub mod prelude2d {
+    pub use bevy::math::{Quat, Vec2, Vec3};
+
+    pub use crate::common::*;
+    pub use crate::{PlatformerController, TopDownController};
+}
+
+/// Re-exports of the most commons/useful types, suited for 3d games
+///
+/// Compared to [`prelude`], it also exports the bevy 3d math types and doesn't export deprecated
+/// items, nor the character controllers of 2d games. It is only available when the `3d` feature
+/// is enabled.
+///
+/// ```
+/// use heron::prelude3d::{CollisionShape, Vec3};
+/// ```
+///
+/// ```compile_fail
+/// use heron::prelude3d::TopDownController;
+/// ```
+#[cfg(dim3)]
+pub mod prelude3d {
+    pub use bevy::math::{Quat, Vec3};
+
+    pub use crate::common::*;
 }
 
 /// Plugin to install to enable collision detection and physics behavior.
@@ -158,10 +273,74 @@ pub mod prelude {
 pub struct PhysicsPlugin {
     #[cfg(debug)]
     debug: heron_debug::DebugPlugin,
+    #[cfg(feature = "parallel")]
+    threads: Option<heron_rapier::PhysicsThreads>,
+    missing_transforms: Option<MissingTransforms>,
+    missing_rigid_body: Option<MissingRigidBody>,
+    collision_events_capacity: Option<CollisionEventsCapacity>,
+}
+
+impl PhysicsPlugin {
+    /// Defines what to do with the rigid bodies that lack a `Transform` or a `GlobalTransform`
+    ///
+    /// It is the same as inserting the [`MissingTransforms`] resource.
+    pub fn with_missing_transforms(mut self, missing_transforms: MissingTransforms) -> Self {
+        self.missing_transforms = Some(missing_transforms);
+        self
+    }
+
+    /// Defines what to do with the collision shapes that have no rigid body
+    ///
+    /// It is the same as inserting the [`MissingRigidBody`] resource.
+    pub fn with_missing_rigid_body(mut self, missing_rigid_body: MissingRigidBody) -> Self {
+        self.missing_rigid_body = Some(missing_rigid_body);
+        self
+    }
+
+    /// Reserve memory for the given number of collision events per physics step
+    ///
+    /// It is the same as inserting the [`CollisionEventsCapacity`] resource before adding the
+    /// plugin.
+    pub fn with_collision_events_capacity(mut self, capacity: usize) -> Self {
+        self.collision_events_capacity = Some(CollisionEventsCapacity(capacity));
+        self
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl PhysicsPlugin {
+    /// Step the physics simulation with the given number of threads
+    ///
+    /// It is only available with the `parallel` feature. A value of `0` lets rayon choose, which is
+    /// usually the number of logical CPUs.
+    ///
+    /// It is the same as inserting the [`PhysicsThreads`](heron_rapier::PhysicsThreads) resource
+    /// before adding the plugin.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Some(heron_rapier::PhysicsThreads(threads));
+        self
+    }
 }
 
 impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
+        #[cfg(feature = "parallel")]
+        if let Some(threads) = self.threads {
+            app.insert_resource(threads);
+        }
+
+        if let Some(missing_transforms) = self.missing_transforms {
+            app.insert_resource(missing_transforms);
+        }
+
+        if let Some(missing_rigid_body) = self.missing_rigid_body {
+            app.insert_resource(missing_rigid_body);
+        }
+
+        if let Some(capacity) = self.collision_events_capacity {
+            app.insert_resource(capacity);
+        }
+
         app.add_plugin(RapierPlugin);
 
         #[cfg(debug)]
@@ -0,0 +1,238 @@
+//! Helpers to write integration tests of games using heron
+//!
+//! It is only available with the `test-utils` feature, which is meant to be enabled in the
+//! `dev-dependencies` of a game.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use heron::prelude::*;
+//! use heron::test_utils::{assert_collision_started, headless_app, step_n};
+//!
+//! let mut app = headless_app();
+//! let ground = app
+//!     .world
+//!     .spawn()
+//!     .insert_bundle((Transform::default(), GlobalTransform::default()))
+//!     .insert(RigidBody::Static)
+//!     .insert(CollisionShape::Sphere { radius: 1.0 })
+//!     .id();
+//! let ball = app
+//!     .world
+//!     .spawn()
+//!     .insert_bundle((Transform::default(), GlobalTransform::default()))
+//!     .insert(RigidBody::Dynamic)
+//!     .insert(CollisionShape::Sphere { radius: 1.0 })
+//!     .id();
+//!
+//! step_n(&mut app, 2);
+//!
+//! assert_collision_started(&app, ground, ball);
+//! ```
+
+use std::time::Duration;
+
+use bevy::app::{App, CoreStage};
+use bevy::core::CorePlugin;
+use bevy::ecs::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::time::TimePlugin;
+use bevy::transform::transform_propagate_system;
+
+use heron_rapier::RapierPlugin;
+
+use crate::{CollisionEvent, PhysicsSteps};
+
+/// Resource containing all the [`CollisionEvent`]s fired since the app was created (or since the
+/// last call to [`clear_collision_events`])
+///
+/// It is inserted and maintained by the apps created with [`headless_app`].
+#[derive(Debug, Clone, Default)]
+pub struct CollisionEventLog(pub Vec<CollisionEvent>);
+
+/// Create an app with the physics and the minimum needed to run it without a window nor renderer
+///
+/// The debug rendering is not installed, even if the `debug-2d` or `debug-3d` feature is enabled.
+///
+/// The physics is stepped exactly once per update, with a time step of 1/60th of a second, so that
+/// the outcome of the tests doesn't depend on the real time. The fired [`CollisionEvent`]s are
+/// recorded in the [`CollisionEventLog`].
+#[must_use]
+pub fn headless_app() -> App {
+    headless_app_with_step(Duration::from_secs_f32(1.0 / 60.0))
+}
+
+/// Create an app like [`headless_app`], but stepping the physics by the given duration at each
+/// update
+#[must_use]
+pub fn headless_app_with_step(step: Duration) -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .init_resource::<CollisionEventLog>()
+        .insert_resource(PhysicsSteps::every_frame(step))
+        .add_plugin(CorePlugin)
+        .add_plugin(TimePlugin)
+        .add_plugin(RapierPlugin)
+        .add_system_to_stage(CoreStage::PostUpdate, transform_propagate_system)
+        .add_system_to_stage(CoreStage::Last, record_collision_events);
+    app
+}
+
+/// Update the app `n` times, which steps the physics `n` times in an app created with
+/// [`headless_app`]
+pub fn step_n(app: &mut App, n: usize) {
+    for _ in 0..n {
+        app.update();
+    }
+}
+
+/// Returns the collision events recorded since the app was created, or since the last call to
+/// [`clear_collision_events`]
+///
+/// # Panics
+///
+/// Panics if the app wasn't created with [`headless_app`]
+#[must_use]
+pub fn collision_events(app: &App) -> &[CollisionEvent] {
+    &app.world
+        .get_resource::<CollisionEventLog>()
+        .expect("the app must be created with `heron::test_utils::headless_app`")
+        .0
+}
+
+/// Forget the collision events recorded so far
+///
+/// # Panics
+///
+/// Panics if the app wasn't created with [`headless_app`]
+pub fn clear_collision_events(app: &mut App) {
+    app.world
+        .get_resource_mut::<CollisionEventLog>()
+        .expect("the app must be created with `heron::test_utils::headless_app`")
+        .0
+        .clear();
+}
+
+/// Assert that a collision between the two entities started
+///
+/// Each entity may be either a rigid body entity or a collision shape entity.
+///
+/// # Panics
+///
+/// Panics if no such [`CollisionEvent::Started`] was recorded
+#[track_caller]
+pub fn assert_collision_started(app: &App, entity1: Entity, entity2: Entity) {
+    let events = collision_events(app);
+    assert!(
+        events
+            .iter()
+            .any(|event| event.is_started() && involves(event, entity1, entity2)),
+        "expected a collision to start between {entity1:?} and {entity2:?}, but got: {events:#?}"
+    );
+}
+
+/// Assert that a collision between the two entities stopped
+///
+/// Each entity may be either a rigid body entity or a collision shape entity.
+///
+/// # Panics
+///
+/// Panics if no such [`CollisionEvent::Stopped`] was recorded
+#[track_caller]
+pub fn assert_collision_stopped(app: &App, entity1: Entity, entity2: Entity) {
+    let events = collision_events(app);
+    assert!(
+        events
+            .iter()
+            .any(|event| event.is_stopped() && involves(event, entity1, entity2)),
+        "expected a collision to stop between {entity1:?} and {entity2:?}, but got: {events:#?}"
+    );
+}
+
+/// Assert that no collision event was recorded between the two entities
+///
+/// Each entity may be either a rigid body entity or a collision shape entity.
+///
+/// # Panics
+///
+/// Panics if any [`CollisionEvent`] involving the two entities was recorded
+#[track_caller]
+pub fn assert_no_collision(app: &App, entity1: Entity, entity2: Entity) {
+    let events: Vec<&CollisionEvent> = collision_events(app)
+        .iter()
+        .filter(|event| involves(event, entity1, entity2))
+        .collect();
+    assert!(
+        events.is_empty(),
+        "expected no collision between {entity1:?} and {entity2:?}, but got: {events:#?}"
+    );
+}
+
+fn record_collision_events(
+    mut log: ResMut<'_, CollisionEventLog>,
+    mut events: EventReader<'_, '_, CollisionEvent>,
+) {
+    log.0.extend(events.iter().cloned());
+}
+
+fn involves(event: &CollisionEvent, entity1: Entity, entity2: Entity) -> bool {
+    let (data1, data2) = event.clone().data();
+    let matches = |data: &crate::CollisionData, entity: Entity| {
+        data.rigid_body_entity() == entity || data.collision_shape_entity() == entity
+    };
+    (matches(&data1, entity1) && matches(&data2, entity2))
+        || (matches(&data1, entity2) && matches(&data2, entity1))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::{CollisionShape, RigidBody, Sensor};
+
+    fn spawn(app: &mut App, x: f32, body: RigidBody) -> Entity {
+        let transform = Transform::from_xyz(x, 0.0, 0.0);
+        app.world
+            .spawn()
+            .insert_bundle((
+                transform,
+                GlobalTransform::from(transform),
+                body,
+                CollisionShape::Sphere { radius: 1.0 },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn records_collision_events() {
+        let mut app = headless_app();
+        let sensor = spawn(&mut app, 0.0, RigidBody::Static);
+        app.world.entity_mut(sensor).insert(Sensor);
+        let body = spawn(&mut app, 1.0, RigidBody::KinematicPositionBased);
+        let other = spawn(&mut app, 10.0, RigidBody::KinematicPositionBased);
+
+        step_n(&mut app, 2);
+        assert_collision_started(&app, body, sensor);
+        assert_no_collision(&app, other, sensor);
+
+        clear_collision_events(&mut app);
+        assert!(collision_events(&app).is_empty());
+
+        app.world.get_mut::<Transform>(body).unwrap().translation.x = 20.0;
+        step_n(&mut app, 2);
+        assert_collision_stopped(&app, sensor, body);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a collision to start")]
+    fn assertion_fails_without_collision() {
+        let mut app = headless_app();
+        let a = spawn(&mut app, 0.0, RigidBody::Static);
+        let b = spawn(&mut app, 10.0, RigidBody::Dynamic);
+
+        step_n(&mut app, 2);
+        assert_collision_started(&app, a, b);
+    }
+}
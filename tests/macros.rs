@@ -62,8 +62,61 @@ fn returns_expected_all_bits_mask() {
     assert_eq!(MyLayer::all_bits(), 0b111)
 }
 
+#[test]
+fn returns_layer_names() {
+    assert_eq!(
+        MyLayer::layer_names(),
+        &[(1, "World"), (2, "Player"), (4, "Enemies")]
+    )
+}
+
 #[test]
 fn max_layers_bits() {
     assert_eq!(MaxLayerCount::all_bits(), u32::MAX);
     assert_eq!(MaxLayerCount::all_bits(), 0xffffffff);
 }
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PhysicsLayer)]
+enum ExplicitLayer {
+    World,
+    #[layer(bit = 5, group = "enemies")]
+    Monster,
+    #[layer(group = "enemies", group = "bosses")]
+    #[layer(bit = 0)]
+    Boss,
+    Player,
+}
+
+#[rstest]
+#[case(ExplicitLayer::World, 0b10)]
+#[case(ExplicitLayer::Monster, 0b10_0000)]
+#[case(ExplicitLayer::Boss, 0b1)]
+#[case(ExplicitLayer::Player, 0b100)]
+fn returns_explicit_bits(#[case] layer: ExplicitLayer, #[case] expected_bits: u32) {
+    assert_eq!(layer.to_bits(), expected_bits)
+}
+
+#[test]
+fn all_bits_contains_explicit_bits() {
+    assert_eq!(ExplicitLayer::all_bits(), 0b10_0111)
+}
+
+#[test]
+fn generates_group_masks() {
+    assert_eq!(ExplicitLayer::ENEMIES_MASK, 0b10_0001);
+    assert_eq!(ExplicitLayer::BOSSES_MASK, 0b1);
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PhysicsLayer)]
+#[physics_layer(crate = "heron_core")]
+enum CoreLayer {
+    World,
+    Player,
+}
+
+#[test]
+fn can_implement_the_trait_of_another_crate() {
+    assert_eq!(CoreLayer::World.to_bits(), 1);
+    assert_eq!(CoreLayer::Player.to_bits(), 2);
+    assert_eq!(CoreLayer::all_bits(), 0b11);
+}